@@ -61,7 +61,7 @@ impl PkgLock {
         let pinned = &graph[node];
         let name = pinned.name.clone();
         let version = match &pinned.source {
-            pkg::SourcePinned::Registry(reg) => Some(reg.source.version.clone()),
+            pkg::SourcePinned::Registry(reg) => Some(reg.version.clone()),
             _ => None,
         };
         let source = pinned.source.to_string();
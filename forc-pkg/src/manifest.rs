@@ -198,6 +198,7 @@ pub struct DependencyDetails {
     pub(crate) tag: Option<String>,
     pub(crate) package: Option<String>,
     pub(crate) rev: Option<String>,
+    pub(crate) registry: Option<String>,
 }
 
 /// Parameters to pass through to the `sway_core::BuildConfig` during compilation.
@@ -222,6 +223,19 @@ impl Dependency {
             Self::Detailed(ref det) => det.package.as_deref(),
         }
     }
+
+    /// Check that the fields of the dependency may be combined.
+    ///
+    /// A `registry` only specifies where a `version` is fetched from, so it may not be combined
+    /// with a `path` or `git` source.
+    pub fn validate(&self) -> Result<()> {
+        if let Self::Detailed(ref det) = *self {
+            if det.registry.is_some() && (det.path.is_some() || det.git.is_some()) {
+                bail!("`registry` can only be combined with `version`");
+            }
+        }
+        Ok(())
+    }
 }
 
 impl PackageManifestFile {
@@ -430,12 +444,20 @@ impl PackageManifest {
     /// Validate the `PackageManifest`.
     ///
     /// This checks the project and organization names against a set of reserved/restricted
-    /// keywords and patterns.
+    /// keywords and patterns, and that the fields of each dependency may be combined.
     pub fn validate(&self) -> Result<()> {
         validate_name(&self.project.name, "package name")?;
         if let Some(ref org) = self.project.organization {
             validate_name(org, "organization name")?;
         }
+        let deps = self.deps().chain(
+            self.contract_deps()
+                .map(|(name, contract_dep)| (name, &contract_dep.dependency)),
+        );
+        for (name, dep) in deps {
+            dep.validate()
+                .map_err(|e| anyhow!("invalid dependency `{}`: {}", name, e))?;
+        }
         Ok(())
     }
 
@@ -844,3 +866,34 @@ impl std::ops::Deref for WorkspaceManifestFile {
         &self.manifest
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest_with_dep(dep: &str) -> PackageManifest {
+        let manifest = format!(
+            "[project]\nauthors = [\"Fuel Labs <contact@fuel.sh>\"]\nentry = \"lib.sw\"\n\
+            license = \"Apache-2.0\"\nname = \"app\"\n\n[dependencies]\nfoo = {}\n",
+            dep
+        );
+        toml::from_str(&manifest).unwrap()
+    }
+
+    #[test]
+    fn test_registry_dependency_fields() {
+        let registry = "registry = \"https://registry.fuel.network/\"";
+        let valid = manifest_with_dep(&format!("{{ version = \"^1\", {} }}", registry));
+        valid.validate().unwrap();
+
+        for source in ["path = \"../foo\"", "git = \"https://github.com/foo/bar\""] {
+            let invalid = manifest_with_dep(&format!("{{ {}, {} }}", source, registry));
+            let err = invalid.validate().unwrap_err().to_string();
+            assert!(
+                err.contains("`registry` can only be combined with `version`"),
+                "{}",
+                err
+            );
+        }
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
         BuildProfile, ConfigTimeConstant, Dependency, ManifestFile, MemberManifestFiles,
         PackageManifest, PackageManifestFile,
     },
-    CORE, PRELUDE, STD,
+    registry, CORE, PRELUDE, STD,
 };
 use anyhow::{anyhow, bail, Context, Error, Result};
 use forc_util::{
@@ -90,6 +90,11 @@ pub struct PinnedId(u64);
 pub struct BuiltPackage {
     pub build_target: BuildTarget,
     pub json_abi_program: ProgramABI,
+    /// The JSON ABI of each of the package's test functions that take parameters, keyed by the
+    /// index of the test's declaration.
+    ///
+    /// Empty unless the package is built for the Fuel target with tests included.
+    pub json_abi_tests: BTreeMap<usize, program_abi::ProgramABI>,
     pub storage_slots: Vec<StorageSlot>,
     pub bytecode: Vec<u8>,
     pub entries: Vec<FinalizedEntry>,
@@ -105,6 +110,10 @@ pub struct BuiltPackage {
 /// This is a map from each member package name to its associated built package.
 pub type BuiltWorkspace = HashMap<String, BuiltPackage>;
 
+/// The packages built for each requested output node, alongside the contract dependencies that
+/// were compiled in order to build them, keyed by their node.
+pub type BuiltWithContractDeps = (Vec<(NodeIx, BuiltPackage)>, HashMap<NodeIx, BuiltPackage>);
+
 #[derive(Debug)]
 pub enum Built {
     Package(Box<BuiltPackage>),
@@ -142,7 +151,7 @@ pub enum Source {
     Git(SourceGit),
     /// A path to a directory with a `Forc.toml` manifest at its root.
     Path(PathBuf),
-    /// A forc project hosted on the official registry.
+    /// A forc project published to a package registry.
     Registry(SourceRegistry),
 }
 
@@ -165,13 +174,20 @@ pub enum GitReference {
     Tag(String),
     Rev(String),
     DefaultBranch,
+    /// A semver version requirement, e.g. `^1.2`, satisfied by the tag with the highest matching
+    /// version.
+    ///
+    /// Tags are interpreted as versions with an optional `v` prefix, e.g. `v1.2.0` or `1.2.0`.
+    Version(String),
 }
 
-/// A package from the official registry.
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+/// A package from a package registry.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
 pub struct SourceRegistry {
-    /// The base version specified for the package.
-    pub version: semver::Version,
+    /// The URL of the registry in which the package is published.
+    pub registry: Url,
+    /// The requirement that the pinned version must satisfy.
+    pub version: semver::VersionReq,
 }
 
 /// A pinned instance of a git source.
@@ -202,10 +218,12 @@ pub struct SourcePathPinned {
 /// A pinned instance of the registry source.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
 pub struct SourceRegistryPinned {
-    /// The registry package with base version.
+    /// The registry package with its version requirement.
     pub source: SourceRegistry,
     /// The pinned version.
     pub version: semver::Version,
+    /// The SHA-256 hash of the pinned version's package tarball, encoded as hex.
+    pub checksum: String,
 }
 
 /// A pinned instance of the package source.
@@ -236,6 +254,16 @@ pub struct PinnedIdParseError;
 #[derive(Clone, Debug)]
 pub struct SourcePathPinnedParseError;
 
+/// Error returned upon failed parsing of `SourceRegistryPinned::from_str`.
+#[derive(Clone, Debug)]
+pub enum SourceRegistryPinnedParseError {
+    Prefix,
+    Url,
+    VersionReq,
+    Version,
+    Checksum,
+}
+
 /// Error returned upon failed parsing of `SourceGitPinned::from_str`.
 #[derive(Clone, Debug)]
 pub enum SourceGitPinnedParseError {
@@ -358,6 +386,11 @@ impl BuiltPackage {
         Ok(())
     }
 
+    /// The source map of the BuiltPackage, mapping each instruction of the bytecode to its span.
+    pub fn source_map(&self) -> &SourceMap {
+        &self.source_map
+    }
+
     /// Writes debug_info (source_map) of the BuiltPackage to the given `path`.
     pub fn write_debug_info(&self, path: &Path) -> Result<()> {
         let source_map_json =
@@ -697,6 +730,33 @@ impl BuildPlan {
         bfs.iter(&self.graph)
     }
 
+    /// Produce an iterator yielding the contract dependencies (both direct and transitive) of the
+    /// given node in order of compilation, along with the salt each contract is deployed with.
+    pub fn contract_dependencies(
+        &self,
+        n: NodeIx,
+    ) -> impl '_ + Iterator<Item = (NodeIx, fuel_tx::Salt)> {
+        let deps: HashSet<NodeIx> = self.node_deps(n).filter(|&dep| dep != n).collect();
+        self.compilation_order()
+            .iter()
+            .cloned()
+            .filter(move |dep| deps.contains(dep))
+            .filter_map(|dep| self.contract_salt(dep).map(|salt| (dep, salt)))
+    }
+
+    /// The salt with which the contract at the given node is deployed, as declared by the packages
+    /// that depend upon it.
+    ///
+    /// Returns `None` if no package declares the node as a contract dependency.
+    pub fn contract_salt(&self, n: NodeIx) -> Option<fuel_tx::Salt> {
+        self.graph
+            .edges_directed(n, Direction::Incoming)
+            .find_map(|edge| match edge.weight().kind {
+                DepKind::Contract { salt } => Some(salt),
+                DepKind::Library => None,
+            })
+    }
+
     /// Produce an iterator yielding build profiles from the member nodes of this BuildPlan.
     pub fn build_profiles(&self) -> impl '_ + Iterator<Item = (String, BuildProfile)> {
         let manifest_map = &self.manifest_map;
@@ -790,18 +850,21 @@ fn validate_graph(graph: &Graph, manifests: &MemberManifestFiles) -> Result<BTre
     let mut visited = HashSet::new();
     let edges = member_nodes
         .into_iter()
-        .flat_map(move |(n, _)| validate_deps(graph, n, manifests, &mut visited))
+        .flat_map(move |(n, pkg_manifest)| {
+            validate_deps(graph, n, pkg_manifest, manifests, &mut visited)
+        })
         .collect();
 
     Ok(edges)
 }
 
-/// Recursively validate all dependencies of the given `node`.
+/// Recursively validate all dependencies of the given `node`, whose manifest is `node_manifest`.
 ///
 /// Returns the set of invalid dependency edges.
 fn validate_deps(
     graph: &Graph,
     node: NodeIx,
+    node_manifest: &PackageManifestFile,
     manifests: &MemberManifestFiles,
     visited: &mut HashSet<NodeIx>,
 ) -> BTreeSet<EdgeIx> {
@@ -809,13 +872,13 @@ fn validate_deps(
     for edge in graph.edges_directed(node, Direction::Outgoing) {
         let dep_name = edge.weight();
         let dep_node = edge.target();
-        match validate_dep(graph, manifests, dep_name, dep_node) {
+        match validate_dep(graph, node_manifest, manifests, dep_name, dep_node) {
             Err(_) => {
                 remove.insert(edge.id());
             }
-            Ok(_) => {
+            Ok(dep_manifest) => {
                 if visited.insert(dep_node) {
-                    let rm = validate_deps(graph, dep_node, manifests, visited);
+                    let rm = validate_deps(graph, dep_node, &dep_manifest, manifests, visited);
                     remove.extend(rm);
                 }
                 continue;
@@ -830,14 +893,12 @@ fn validate_deps(
 /// Returns the `ManifestFile` in the case that the dependency is valid.
 fn validate_dep(
     graph: &Graph,
+    node_manifest: &PackageManifestFile,
     manifests: &MemberManifestFiles,
     dep_edge: &Edge,
     dep_node: NodeIx,
 ) -> Result<PackageManifestFile> {
     let dep_name = &dep_edge.name;
-    let node_manifest = manifests
-        .get(dep_name)
-        .ok_or_else(|| anyhow!("Couldn't find manifest file for {}", dep_name))?;
     // Check the validity of the dependency path, including its path root.
     let dep_path = dep_path(graph, node_manifest, dep_node, manifests).map_err(|e| {
         anyhow!(
@@ -851,12 +912,16 @@ fn validate_dep(
     let dep_manifest = PackageManifestFile::from_dir(&dep_path)?;
 
     // Check that the dependency's source matches the entry in the parent manifest.
-    let dep_entry = node_manifest
-        .dep(dep_name)
-        .ok_or_else(|| anyhow!("no entry in parent manifest"))?;
+    let dep_entry = match dep_edge.kind {
+        DepKind::Library => node_manifest.dep(dep_name),
+        DepKind::Contract { .. } => node_manifest
+            .contract_dep(dep_name)
+            .map(|contract_dep| &contract_dep.dependency),
+    }
+    .ok_or_else(|| anyhow!("no entry in parent manifest"))?;
     let dep_source = dep_to_source_patched(node_manifest, dep_name, dep_entry, manifests)?;
     let dep_pkg = graph[dep_node].unpinned(&dep_path);
-    if dep_pkg.source != dep_source {
+    if !source_satisfies(&dep_pkg.source, &dep_source) {
         bail!("dependency node's source does not match manifest entry");
     }
 
@@ -864,6 +929,28 @@ fn validate_dep(
 
     Ok(dep_manifest)
 }
+
+/// Whether or not the `source` of a pinned dependency satisfies the source declared for it within
+/// a manifest.
+///
+/// A git dependency with a version requirement is satisfied by any tag of the same repository
+/// whose version matches the requirement.
+fn source_satisfies(source: &Source, declared: &Source) -> bool {
+    match (source, declared) {
+        (
+            Source::Git(git),
+            Source::Git(SourceGit {
+                repo,
+                reference: GitReference::Version(req),
+            }),
+        ) => match (&git.reference, parse_version_req(req)) {
+            (GitReference::Tag(tag), Ok(req)) => git.repo == *repo && git_tag_satisfies(tag, &req),
+            _ => false,
+        },
+        _ => source == declared,
+    }
+}
+
 /// Part of dependency validation, any checks related to the depenency's manifest content.
 fn validate_dep_manifest(
     dep: &Pinned,
@@ -951,7 +1038,20 @@ fn dep_path(
                 node_manifest.project.name
             )
         }
-        SourcePinned::Registry(_reg) => unreachable!("registry dependencies not yet supported"),
+        SourcePinned::Registry(reg) => {
+            let pkg_path =
+                registry::registry_package_path(&dep.name, &reg.source.registry, &reg.version);
+            // Co-ordinate access to the registry cache directory using an advisory file lock.
+            let lock = path_lock(&pkg_path)?;
+            let _guard = lock.read()?;
+            find_dir_within(&pkg_path, &dep.name).ok_or_else(|| {
+                anyhow!(
+                    "failed to find package `{}` in {}",
+                    dep.name,
+                    reg.to_string()
+                )
+            })
+        }
         SourcePinned::Member => {
             // If a node has a root dependency it is a member of the workspace.
             manifests
@@ -1047,6 +1147,21 @@ impl GitReference {
             }
         }
 
+        // Find the commit for the tag with the highest version satisfying the requirement.
+        fn resolve_version(repo: &git2::Repository, version_req: &str) -> Result<git2::Oid> {
+            let req = parse_version_req(version_req)?;
+            let prefix = format!("refs/remotes/{}/tags/", DEFAULT_REMOTE_NAME);
+            let mut tags = vec![];
+            for reference in repo.references_glob(&format!("{}*", prefix))? {
+                if let Some(name) = reference?.name() {
+                    tags.push(name[prefix.len()..].to_string());
+                }
+            }
+            let tag = select_git_tag(&tags, &[req])
+                .ok_or_else(|| anyhow!("no tag satisfies version requirement `{}`", version_req))?;
+            resolve_tag(repo, tag)
+        }
+
         match self {
             GitReference::Tag(s) => {
                 resolve_tag(repo, s).with_context(|| format!("failed to find tag `{}`", s))
@@ -1054,6 +1169,7 @@ impl GitReference {
             GitReference::Branch(s) => resolve_branch(repo, s),
             GitReference::DefaultBranch => resolve_default_branch(repo),
             GitReference::Rev(s) => resolve_rev(repo, s),
+            GitReference::Version(s) => resolve_version(repo, s),
         }
     }
 }
@@ -1097,6 +1213,24 @@ impl SourceGitPinned {
     pub const PREFIX: &'static str = "git";
 }
 
+impl SourceRegistryPinned {
+    pub const PREFIX: &'static str = "registry";
+}
+
+impl PartialOrd for SourceRegistry {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SourceRegistry {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // `semver::VersionReq` is not `Ord`, so compare requirements by their string form.
+        (&self.registry, self.version.to_string())
+            .cmp(&(&other.registry, other.version.to_string()))
+    }
+}
+
 impl fmt::Display for PinnedId {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // Format the inner `u64` as hex.
@@ -1125,6 +1259,31 @@ impl fmt::Display for SourceGitPinned {
     }
 }
 
+impl fmt::Display for SourceRegistryPinned {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // registry+<url/to/registry>?version=<version_req>#<version>:<checksum>
+        //
+        // Whitespace is removed from the requirement so that the source may be used within the
+        // whitespace-separated dependency lines of the lock file.
+        let version_req: String = self
+            .source
+            .version
+            .to_string()
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        write!(
+            f,
+            "{}+{}?version={}#{}:{}",
+            Self::PREFIX,
+            self.source.registry,
+            version_req,
+            self.version,
+            self.checksum
+        )
+    }
+}
+
 impl fmt::Display for GitReference {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -1132,6 +1291,7 @@ impl fmt::Display for GitReference {
             GitReference::Tag(ref s) => write!(f, "tag={}", s),
             GitReference::Rev(ref _s) => write!(f, "rev"),
             GitReference::DefaultBranch => write!(f, "default-branch"),
+            GitReference::Version(ref s) => write!(f, "version={}", s),
         }
     }
 }
@@ -1142,7 +1302,7 @@ impl fmt::Display for SourcePinned {
             SourcePinned::Member => write!(f, "member"),
             SourcePinned::Path(src) => src.fmt(f),
             SourcePinned::Git(src) => src.fmt(f),
-            SourcePinned::Registry(_reg) => unimplemented!("pkg registries not yet implemented"),
+            SourcePinned::Registry(src) => src.fmt(f),
         }
     }
 }
@@ -1204,6 +1364,7 @@ impl FromStr for SourceGitPinned {
         // - `tag=<tag-name>#<commit-hash>`
         // - `rev#<commit-hash>`
         // - `default#<commit-hash>`
+        // - `version=<version-req>#<commit-hash>`
         let mut s_iter = s.split('#');
         let reference = s_iter.next().ok_or(SourceGitPinnedParseError::Reference)?;
         let commit_hash = s_iter
@@ -1215,6 +1376,7 @@ impl FromStr for SourceGitPinned {
 
         const BRANCH: &str = "branch=";
         const TAG: &str = "tag=";
+        const VERSION: &str = "version=";
         let reference = if reference.find(BRANCH) == Some(0) {
             GitReference::Branch(reference[BRANCH.len()..].to_string())
         } else if reference.find(TAG) == Some(0) {
@@ -1223,6 +1385,8 @@ impl FromStr for SourceGitPinned {
             GitReference::Rev(commit_hash.to_string())
         } else if reference == "default-branch" {
             GitReference::DefaultBranch
+        } else if reference.find(VERSION) == Some(0) {
+            GitReference::Version(reference[VERSION.len()..].to_string())
         } else {
             return Err(SourceGitPinnedParseError::Reference);
         };
@@ -1235,6 +1399,49 @@ impl FromStr for SourceGitPinned {
     }
 }
 
+impl FromStr for SourceRegistryPinned {
+    type Err = SourceRegistryPinnedParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // registry+<url/to/registry>?version=<version_req>#<version>:<checksum>
+        let s = s.trim();
+
+        // Check for "registry+" at the start.
+        let prefix_plus = format!("{}+", Self::PREFIX);
+        if s.find(&prefix_plus) != Some(0) {
+            return Err(SourceRegistryPinnedParseError::Prefix);
+        }
+        let s = &s[prefix_plus.len()..];
+
+        // Parse the registry URL.
+        let (registry_str, s) = s
+            .rsplit_once("?version=")
+            .ok_or(SourceRegistryPinnedParseError::Url)?;
+        let registry = Url::parse(registry_str).map_err(|_| SourceRegistryPinnedParseError::Url)?;
+
+        // Parse the version requirement, pinned version and checksum.
+        let (version_req, s) = s
+            .split_once('#')
+            .ok_or(SourceRegistryPinnedParseError::VersionReq)?;
+        let version = semver::VersionReq::parse(version_req)
+            .map_err(|_| SourceRegistryPinnedParseError::VersionReq)?;
+        let (pinned_version, checksum) = s
+            .split_once(':')
+            .ok_or(SourceRegistryPinnedParseError::Version)?;
+        let pinned_version = semver::Version::parse(pinned_version)
+            .map_err(|_| SourceRegistryPinnedParseError::Version)?;
+        if checksum.is_empty() || !checksum.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(SourceRegistryPinnedParseError::Checksum);
+        }
+
+        let source = SourceRegistry { registry, version };
+        Ok(Self {
+            source,
+            version: pinned_version,
+            checksum: checksum.to_string(),
+        })
+    }
+}
+
 impl FromStr for SourcePinned {
     type Err = SourcePinnedParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -1246,8 +1453,9 @@ impl FromStr for SourcePinned {
             SourcePinned::Path(src)
         } else if let Ok(src) = SourceGitPinned::from_str(s) {
             SourcePinned::Git(src)
+        } else if let Ok(src) = SourceRegistryPinned::from_str(s) {
+            SourcePinned::Registry(src)
         } else {
-            // TODO: Try parse registry source.
             return Err(SourcePinnedParseError);
         };
         Ok(source)
@@ -1554,6 +1762,34 @@ fn fetch_deps(
         let source = dep_to_source_patched(parent_manifest, &name, &dep, member_manifests)
             .context("Failed to source dependency")?;
 
+        // Resolve git version requirements to the tag shared by all dependents of the repository.
+        let source = match source {
+            Source::Git(SourceGit {
+                repo,
+                reference: GitReference::Version(req),
+            }) => resolve_git_version(
+                fetch_id,
+                offline,
+                node,
+                path_root,
+                &name,
+                repo,
+                parse_version_req(&req)?,
+                graph,
+                manifest_map,
+                fetched,
+                visited,
+                member_manifests,
+            )
+            .with_context(|| {
+                format!(
+                    "Failed to resolve version of dependency {:?} of {:?}",
+                    dep_name, graph[node].name
+                )
+            })?,
+            source => source,
+        };
+
         // If we haven't yet fetched this dependency, fetch it, pin it and add it to the graph.
         let dep_pkg = Pkg { name, source };
         let dep_node = match fetched.entry(dep_pkg) {
@@ -1614,7 +1850,7 @@ fn git_repo_dir_name(name: &str, repo: &Url) -> String {
     format!("{}-{:x}", name, repo_url_hash)
 }
 
-fn hash_url(url: &Url) -> u64 {
+pub(crate) fn hash_url(url: &Url) -> u64 {
     let mut hasher = hash_map::DefaultHasher::new();
     url.hash(&mut hasher);
     hasher.finish()
@@ -1673,6 +1909,12 @@ fn git_ref_to_refspecs(reference: &GitReference) -> (Vec<String>, bool) {
         GitReference::DefaultBranch => {
             refspecs.push(format!("+HEAD:refs/remotes/{}/HEAD", DEFAULT_REMOTE_NAME));
         }
+        GitReference::Version(_) => {
+            refspecs.push(format!(
+                "+refs/tags/*:refs/remotes/{}/tags/*",
+                DEFAULT_REMOTE_NAME
+            ));
+        }
     }
     (refspecs, tags)
 }
@@ -1737,6 +1979,260 @@ pub fn pin_git(fetch_id: u64, name: &str, source: SourceGit) -> Result<SourceGit
     })
 }
 
+/// Parse the version requirement of a git or registry dependency.
+fn parse_version_req(version_req: &str) -> Result<semver::VersionReq> {
+    semver::VersionReq::parse(version_req)
+        .map_err(|e| anyhow!("invalid version requirement `{}`: {}", version_req, e))
+}
+
+/// Interpret the given git tag as a version, e.g. `v1.2.0` or `1.2.0`.
+fn git_tag_version(tag: &str) -> Option<semver::Version> {
+    semver::Version::parse(tag.strip_prefix('v').unwrap_or(tag)).ok()
+}
+
+/// Whether or not the version of the given git tag satisfies the requirement.
+fn git_tag_satisfies(tag: &str, req: &semver::VersionReq) -> bool {
+    matches!(git_tag_version(tag), Some(version) if req.matches(&version))
+}
+
+/// Select the tag with the highest version that satisfies all of the given requirements.
+fn select_git_tag<'a>(tags: &'a [String], reqs: &[semver::VersionReq]) -> Option<&'a str> {
+    tags.iter()
+        .filter_map(|tag| git_tag_version(tag).map(|version| (version, tag)))
+        .filter(|(version, _)| reqs.iter().all(|req| req.matches(version)))
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, tag)| tag.as_str())
+}
+
+/// Select the tag of the git dependency `name` with the highest version that satisfies all of the
+/// given requirements, each of which is paired with the name of the package that declares it.
+///
+/// Produces an error describing each of the requirements in the case that no tag satisfies them.
+fn select_git_version(
+    name: &str,
+    repo: &Url,
+    tags: &[String],
+    reqs: &[(String, semver::VersionReq)],
+) -> Result<String> {
+    let version_reqs: Vec<_> = reqs.iter().map(|(_, req)| req.clone()).collect();
+    if let Some(tag) = select_git_tag(tags, &version_reqs) {
+        return Ok(tag.to_string());
+    }
+    let requirements: String = reqs
+        .iter()
+        .map(|(pkg_name, req)| format!("\n  `{}` requires `{}`", pkg_name, req))
+        .collect();
+    let mut versions: Vec<_> = tags.iter().filter_map(|tag| git_tag_version(tag)).collect();
+    versions.sort();
+    versions.dedup();
+    let available = match versions.is_empty() {
+        true => "none".to_string(),
+        false => versions
+            .iter()
+            .map(|version| version.to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+    };
+    bail!(
+        "failed to select a version of `{}` from {} that satisfies all requirements:{}\n\
+        available versions: {}",
+        name,
+        repo,
+        requirements,
+        available
+    )
+}
+
+/// List the tags of the given git repository without fetching any of its objects.
+fn remote_git_tags(repo: &Url) -> Result<Vec<String>> {
+    const TAGS_PREFIX: &str = "refs/tags/";
+    let mut remote = git2::Remote::create_detached(repo.as_str())?;
+    remote.connect(git2::Direction::Fetch).with_context(|| {
+        format!(
+            "failed to fetch `{}`. Check your connection or run in `--offline` mode",
+            repo
+        )
+    })?;
+    let tags = remote
+        .list()?
+        .iter()
+        .filter_map(|head| head.name().strip_prefix(TAGS_PREFIX))
+        // Skip the peeled commits of annotated tags, e.g. `refs/tags/v1.2.0^{}`.
+        .filter(|tag| !tag.ends_with("^{}"))
+        .map(|tag| tag.to_string())
+        .collect();
+    Ok(tags)
+}
+
+/// List the tags of the given git-sourced package that have previously been checked out.
+fn local_git_tags(name: &str, repo: &Url) -> Result<Vec<String>> {
+    let repo_dir = git_checkouts_directory().join(git_repo_dir_name(name, repo));
+    let mut tags = vec![];
+    if !repo_dir.exists() {
+        return Ok(tags);
+    }
+    for entry in fs::read_dir(repo_dir)? {
+        let index_path = entry?.path().join(".forc_index");
+        if let Ok(index_file) = fs::read_to_string(index_path) {
+            let index: GitSourceIndex = serde_json::from_str(&index_file)?;
+            if let GitReference::Tag(tag) = index.git_reference {
+                tags.push(tag);
+            }
+        }
+    }
+    Ok(tags)
+}
+
+/// Collect the version requirements placed upon the given git dependency node by each of its
+/// parents, paired with the name of the parent.
+///
+/// Dependencies upon a specific tag require exactly the version of that tag.
+fn git_version_reqs(
+    graph: &Graph,
+    manifest_map: &ManifestMap,
+    member_manifests: &MemberManifestFiles,
+    dep_node: NodeIx,
+) -> Result<Vec<(String, semver::VersionReq)>> {
+    let dep_name = &graph[dep_node].name;
+    let mut reqs = vec![];
+    for edge in graph.edges_directed(dep_node, Direction::Incoming) {
+        let parent = &graph[edge.source()];
+        let parent_manifest = &manifest_map[&parent.id()];
+        let dep_edge = edge.weight();
+        let dep = match dep_edge.kind {
+            DepKind::Library => parent_manifest.dep(&dep_edge.name),
+            DepKind::Contract { .. } => parent_manifest
+                .contract_dep(&dep_edge.name)
+                .map(|contract_dep| &contract_dep.dependency),
+        }
+        .ok_or_else(|| {
+            anyhow!(
+                "no entry for {:?} in manifest of {:?}",
+                dep_name,
+                parent.name
+            )
+        })?;
+        let source = dep_to_source_patched(parent_manifest, dep_name, dep, member_manifests)?;
+        let req = match source {
+            Source::Git(SourceGit {
+                reference: GitReference::Version(req),
+                ..
+            }) => parse_version_req(&req)?,
+            Source::Git(SourceGit {
+                reference: GitReference::Tag(tag),
+                ..
+            }) if git_tag_version(&tag).is_some() => {
+                let version = git_tag_version(&tag).expect("tag version was checked above");
+                parse_version_req(&format!("={}", version))?
+            }
+            _ => bail!(
+                "{:?} depends upon {:?} without a version requirement",
+                parent.name,
+                dep_name
+            ),
+        };
+        reqs.push((parent.name.clone(), req));
+    }
+    Ok(reqs)
+}
+
+/// Resolve the version requirement of the git dependency `name` declared by `node` to a tag of the
+/// repository, returning the tag's source.
+///
+/// All packages that depend upon the same repository by version share a single tag. In the case
+/// that the tag already selected for the dependency does not satisfy `req`, a tag satisfying the
+/// requirements of all of its parents is selected and the existing node is re-pinned to it.
+#[allow(clippy::too_many_arguments)]
+fn resolve_git_version(
+    fetch_id: u64,
+    offline: bool,
+    node: NodeIx,
+    path_root: PinnedId,
+    name: &str,
+    repo: Url,
+    req: semver::VersionReq,
+    graph: &mut Graph,
+    manifest_map: &mut ManifestMap,
+    fetched: &mut HashMap<Pkg, NodeIx>,
+    visited: &mut HashSet<NodeIx>,
+    member_manifests: &MemberManifestFiles,
+) -> Result<Source> {
+    let tag_source = |tag: String| {
+        let repo = repo.clone();
+        let reference = GitReference::Tag(tag);
+        Source::Git(SourceGit { repo, reference })
+    };
+
+    // Find the nodes for this dependency that are already pinned to a tag of the repository.
+    let tagged: Vec<(NodeIx, String)> = graph
+        .node_indices()
+        .filter_map(|n| match &graph[n].source {
+            SourcePinned::Git(git) if graph[n].name == name && git.source.repo == repo => {
+                match &git.source.reference {
+                    GitReference::Tag(tag) if git_tag_version(tag).is_some() => {
+                        Some((n, tag.clone()))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect();
+    if let Some((_, tag)) = tagged.iter().find(|(_, tag)| git_tag_satisfies(tag, &req)) {
+        return Ok(tag_source(tag.clone()));
+    }
+
+    let tags = match offline {
+        true => local_git_tags(name, &repo)?,
+        false => remote_git_tags(&repo)?,
+    };
+    let node_req = (graph[node].name.clone(), req);
+    let existing = match tagged.first() {
+        None => {
+            return Ok(tag_source(select_git_version(
+                name,
+                &repo,
+                &tags,
+                &[node_req],
+            )?))
+        }
+        Some(&(existing, _)) => existing,
+    };
+
+    // The selected tag does not satisfy this requirement, so select one satisfying all parents.
+    if petgraph::algo::has_path_connecting(&*graph, existing, node, None) {
+        bail!(
+            "failed to select a version of `{}`: it is required by its own dependency `{}`",
+            name,
+            graph[node].name
+        );
+    }
+    let mut reqs = git_version_reqs(graph, manifest_map, member_manifests, existing)?;
+    reqs.push(node_req);
+    let tag = select_git_version(name, &repo, &tags, &reqs)?;
+    let pkg = Pkg {
+        name: name.to_string(),
+        source: tag_source(tag),
+    };
+    let pinned = pin_pkg(fetch_id, path_root, &pkg, manifest_map, offline)?;
+    let prev_pkg = graph[existing].unpinned(manifest_map[&graph[existing].id()].dir());
+    fetched.remove(&prev_pkg);
+    fetched.insert(pkg.clone(), existing);
+    graph[existing] = pinned;
+
+    // Remove the dependencies of the previously selected version along with any packages that
+    // are no longer depended upon. Those of the new version are fetched upon revisiting the node.
+    let stale_edges = graph
+        .edges_directed(existing, Direction::Outgoing)
+        .map(|edge| edge.id())
+        .collect();
+    let member_names = member_manifests.keys().cloned().collect();
+    remove_deps(graph, &member_names, &stale_edges);
+    fetched.retain(|_, &mut n| graph.contains_node(n));
+    visited.retain(|&n| graph.contains_node(n) && n != existing);
+    Ok(pkg.source)
+}
+
 /// Given a package source, attempt to determine the pinned version or commit.
 ///
 /// Also updates the `path_map` with a path to the local copy of the source.
@@ -1856,13 +2352,52 @@ fn pin_pkg(
             }
             pinned
         }
-        Source::Registry(ref _source) => {
-            if offline {
-                bail!("Unable to fetch pkg {:?} in offline mode", name);
+        Source::Registry(ref registry_source) => {
+            // The highest matching version may have been published since we last fetched the
+            // index, so we only search the local cache when offline.
+            let pinned_registry = if offline {
+                registry::search_registry_cache(&name, registry_source)?.ok_or_else(|| {
+                    anyhow!(
+                        "Unable to fetch pkg {:?} from registry {} in offline mode",
+                        name,
+                        registry_source.registry
+                    )
+                })?
+            } else {
+                registry::pin_registry(&name, registry_source.clone())?
+            };
+            let source = SourcePinned::Registry(pinned_registry.clone());
+            let pinned = Pinned { name, source };
+            let id = pinned.id();
+            if let hash_map::Entry::Vacant(entry) = manifest_map.entry(id) {
+                let pkg_path = registry::registry_package_path(
+                    &pinned.name,
+                    &pinned_registry.source.registry,
+                    &pinned_registry.version,
+                );
+                // Co-ordinate access to the registry cache directory using an advisory file lock.
+                let mut lock = path_lock(&pkg_path)?;
+                {
+                    let _guard = lock.write()?;
+                    if !pkg_path.exists() {
+                        info!("  Fetching {}", pinned_registry.to_string());
+                    }
+                    registry::fetch_registry(fetch_id, &pinned.name, &pinned_registry)?;
+                }
+                let path = {
+                    let _guard = lock.read()?;
+                    find_dir_within(&pkg_path, &pinned.name).ok_or_else(|| {
+                        anyhow!(
+                            "failed to find package `{}` in {}",
+                            pinned.name,
+                            pinned_registry.to_string()
+                        )
+                    })?
+                };
+                let manifest = PackageManifestFile::from_dir(&path)?;
+                entry.insert(manifest);
             }
-            // TODO: determine registry pkg git URL, fetch to determine latest available
-            // semver-compatible version
-            bail!("registry dependencies are not yet supported");
+            pinned
         }
     };
     Ok(pinned)
@@ -2112,14 +2647,11 @@ fn dep_to_source(
     dep: &Dependency,
     member_manifests: &MemberManifestFiles,
 ) -> Result<Source> {
+    dep.validate()?;
     let source = match dep {
         Dependency::Simple(ref ver_str) => {
-            bail!(
-                "Unsupported dependency declaration in \"{}\": `{}` - \
-                currently only `git` and `path` dependencies are supported",
-                pkg_path.display(),
-                ver_str
-            )
+            let registry = registry::default_registry()?;
+            Source::Registry(registry_source(registry, ver_str)?)
         }
         Dependency::Detailed(ref det) => match (&det.path, &det.version, &det.git) {
             (Some(relative_path), _, _) => {
@@ -2138,20 +2670,33 @@ fn dep_to_source(
                 }
             }
             (_, _, Some(repo)) => {
-                let reference = match (&det.branch, &det.tag, &det.rev) {
-                    (Some(branch), None, None) => GitReference::Branch(branch.clone()),
-                    (None, Some(tag), None) => GitReference::Tag(tag.clone()),
-                    (None, None, Some(rev)) => GitReference::Rev(rev.clone()),
-                    (None, None, None) => GitReference::DefaultBranch,
+                let reference = match (&det.branch, &det.tag, &det.rev, &det.version) {
+                    (Some(branch), None, None, None) => GitReference::Branch(branch.clone()),
+                    (None, Some(tag), None, None) => GitReference::Tag(tag.clone()),
+                    (None, None, Some(rev), None) => GitReference::Rev(rev.clone()),
+                    (None, None, None, Some(version)) => {
+                        // Remove whitespace so that the requirement may be displayed within the
+                        // source of a lock file entry.
+                        let req = parse_version_req(version)?.to_string();
+                        GitReference::Version(req.chars().filter(|c| !c.is_whitespace()).collect())
+                    }
+                    (None, None, None, None) => GitReference::DefaultBranch,
                     _ => bail!(
                         "git dependencies support at most one reference: \
-                            either `branch`, `tag` or `rev`"
+                            either `branch`, `tag`, `rev` or `version`"
                     ),
                 };
                 let repo = Url::parse(repo)?;
                 let source = SourceGit { repo, reference };
                 Source::Git(source)
             }
+            (None, Some(version), None) => {
+                let registry = match &det.registry {
+                    Some(location) => registry::parse_registry(location, pkg_path)?,
+                    None => registry::default_registry()?,
+                };
+                Source::Registry(registry_source(registry, version)?)
+            }
             _ => {
                 bail!("unsupported set of fields for dependency: {:?}", dep);
             }
@@ -2160,6 +2705,12 @@ fn dep_to_source(
     Ok(source)
 }
 
+/// Construct a registry source from the registry URL and the version requirement string.
+fn registry_source(registry: Url, version: &str) -> Result<SourceRegistry> {
+    let version = parse_version_req(version)?;
+    Ok(SourceRegistry { registry, version })
+}
+
 /// If a patch exists for the given dependency source within the given project manifest, this
 /// returns the patch.
 fn dep_source_patch<'manifest>(
@@ -2473,10 +3024,17 @@ pub fn compile(
         }
         BuildTarget::EVM => match &asm_res.value {
             Some(ref v) => v.0.abi.as_ref().unwrap().clone(),
-            None => todo!(),
+            None => return fail(&asm_res.warnings, &asm_res.errors),
         },
     };
 
+    let json_abi_tests = match build_target {
+        BuildTarget::Fuel if build_profile.include_tests => {
+            typed_program.generate_json_abi_test_fns(engines.de(), engines.te())
+        }
+        _ => BTreeMap::new(),
+    };
+
     let entries = asm_res
         .value
         .as_ref()
@@ -2508,6 +3066,7 @@ pub fn compile(
             let built_package = BuiltPackage {
                 build_target,
                 json_abi_program,
+                json_abi_tests,
                 storage_slots,
                 bytecode,
                 tree_type,
@@ -2532,7 +3091,7 @@ pub const SWAY_BIN_HASH_SUFFIX: &str = "-bin-hash";
 pub const SWAY_BIN_ROOT_SUFFIX: &str = "-bin-root";
 
 /// Selects the build profile from all available build profiles in the workspace using build_opts.
-fn build_profile_from_opts(
+pub fn build_profile_from_opts(
     build_profiles: &HashMap<String, BuildProfile>,
     build_options: &BuildOpts,
 ) -> Result<(String, BuildProfile)> {
@@ -2592,6 +3151,20 @@ fn build_profile_from_opts(
 
 /// Builds a project with given BuildOptions.
 pub fn build_with_options(build_options: BuildOpts) -> Result<Built> {
+    let build_plan = BuildPlan::from_build_opts(&build_options)?;
+    let (built, _) = build_with_options_and_plan(&build_options, &build_plan)?;
+    Ok(built)
+}
+
+/// Builds the package or workspace described by the given `BuildPlan`, as for
+/// `build_with_options`.
+///
+/// Also returns the contract dependencies which were compiled along the way, by their node in
+/// the build plan, so that they may be deployed without compiling them again.
+pub fn build_with_options_and_plan(
+    build_options: &BuildOpts,
+    build_plan: &BuildPlan,
+) -> Result<(Built, HashMap<NodeIx, BuiltPackage>)> {
     let BuildOpts {
         minify,
         binary_outfile,
@@ -2600,7 +3173,7 @@ pub fn build_with_options(build_options: BuildOpts) -> Result<Built> {
         inject_map,
         build_target,
         ..
-    } = &build_options;
+    } = build_options;
 
     let current_dir = std::env::current_dir()?;
     let path = &build_options
@@ -2610,7 +3183,6 @@ pub fn build_with_options(build_options: BuildOpts) -> Result<Built> {
         .map(PathBuf::from)
         .unwrap_or_else(|| current_dir);
 
-    let build_plan = BuildPlan::from_build_opts(&build_options)?;
     let graph = build_plan.graph();
     let manifest_map = build_plan.manifest_map();
 
@@ -2621,7 +3193,7 @@ pub fn build_with_options(build_options: BuildOpts) -> Result<Built> {
         .find(|&pkg_manifest| pkg_manifest.dir() == path);
     let build_profiles: HashMap<String, BuildProfile> = build_plan.build_profiles().collect();
     // Get the selected build profile using build options
-    let (profile_name, build_profile) = build_profile_from_opts(&build_profiles, &build_options)?;
+    let (profile_name, build_profile) = build_profile_from_opts(&build_profiles, build_options)?;
     // If this is a workspace we want to have all members in the output.
     let outputs = match curr_manifest {
         Some(pkg_manifest) => std::iter::once(
@@ -2635,8 +3207,8 @@ pub fn build_with_options(build_options: BuildOpts) -> Result<Built> {
 
     // Build it!
     let mut built_workspace = HashMap::new();
-    let built_packages = build(
-        &build_plan,
+    let (built_packages, built_contract_deps) = build_with_contract_deps(
+        build_plan,
         *build_target,
         &build_profile,
         &outputs,
@@ -2667,9 +3239,9 @@ pub fn build_with_options(build_options: BuildOpts) -> Result<Built> {
             let built_pkg = built_workspace
                 .remove(&pkg_manifest.project.name)
                 .expect("package didn't exist in workspace");
-            Ok(Built::Package(Box::new(built_pkg)))
+            Ok((Built::Package(Box::new(built_pkg)), built_contract_deps))
         }
-        None => Ok(Built::Workspace(built_workspace)),
+        None => Ok((Built::Workspace(built_workspace), built_contract_deps)),
     }
 }
 
@@ -2720,6 +3292,19 @@ pub fn build(
     outputs: &HashSet<NodeIx>,
     inject_map: &ConstInjectionMap,
 ) -> anyhow::Result<Vec<(NodeIx, BuiltPackage)>> {
+    let (built_packages, _) = build_with_contract_deps(plan, target, profile, outputs, inject_map)?;
+    Ok(built_packages)
+}
+
+/// Build the given outputs as for `build`, also returning the contract dependencies compiled in
+/// order to build them.
+pub fn build_with_contract_deps(
+    plan: &BuildPlan,
+    target: BuildTarget,
+    profile: &BuildProfile,
+    outputs: &HashSet<NodeIx>,
+    inject_map: &ConstInjectionMap,
+) -> anyhow::Result<BuiltWithContractDeps> {
     let mut built_packages = Vec::new();
 
     let required: HashSet<NodeIx> = outputs
@@ -2796,7 +3381,7 @@ pub fn build(
         }
     }
 
-    Ok(built_packages)
+    Ok((built_packages, compiled_contract_deps))
 }
 
 /// Standardize the JSON ABI data structure by eliminating duplicate types. This is an iterative
@@ -3149,6 +3734,242 @@ fn test_source_git_pinned_parsing() {
     }
 }
 
+#[test]
+fn test_source_registry_pinned_parsing() {
+    let checksum = "a3f5a4c1e2b0d9f8e7c6b5a4d3c2b1a0f9e8d7c6b5a4f3e2d1c0b9a8f7e6d5c4";
+    let strings = [
+        format!(
+            "registry+https://registry.fuel.network/?version=^1.2#1.4.0:{}",
+            checksum
+        ),
+        format!(
+            "registry+file:///tmp/registry/?version=>=1.2,<3#2.0.0-rc.1:{}",
+            checksum
+        ),
+    ];
+
+    let expected = [
+        SourceRegistryPinned {
+            source: SourceRegistry {
+                registry: Url::parse("https://registry.fuel.network/").unwrap(),
+                version: semver::VersionReq::parse("^1.2").unwrap(),
+            },
+            version: semver::Version::parse("1.4.0").unwrap(),
+            checksum: checksum.to_string(),
+        },
+        SourceRegistryPinned {
+            source: SourceRegistry {
+                registry: Url::parse("file:///tmp/registry/").unwrap(),
+                version: semver::VersionReq::parse(">=1.2, <3").unwrap(),
+            },
+            version: semver::Version::parse("2.0.0-rc.1").unwrap(),
+            checksum: checksum.to_string(),
+        },
+    ];
+
+    for (string, expected) in strings.iter().zip(&expected) {
+        let parsed = SourceRegistryPinned::from_str(string).unwrap();
+        assert_eq!(&parsed, expected);
+        let serialized = expected.to_string();
+        assert_eq!(&serialized, string);
+        assert_eq!(
+            SourcePinned::from_str(string).unwrap(),
+            SourcePinned::Registry(expected.clone())
+        );
+    }
+
+    let invalid = [
+        format!(
+            "git+https://registry.fuel.network/?version=^1.2#1.4.0:{}",
+            checksum
+        ),
+        format!("registry+https://registry.fuel.network/#1.4.0:{}", checksum),
+        format!(
+            "registry+https://registry.fuel.network/?version=^1.2#1.4:{}",
+            checksum
+        ),
+        "registry+https://registry.fuel.network/?version=^1.2#1.4.0:".to_string(),
+        "registry+https://registry.fuel.network/?version=^1.2#1.4.0:not-hex".to_string(),
+    ];
+    for string in &invalid {
+        assert!(
+            SourceRegistryPinned::from_str(string).is_err(),
+            "{}",
+            string
+        );
+    }
+}
+
+#[test]
+fn test_select_git_tag() {
+    let tags: Vec<String> = [
+        "v1.0.0",
+        "1.2.0",
+        "v1.10.1",
+        "v2.0.0",
+        "v2.1.0-rc.1",
+        "latest",
+    ]
+    .iter()
+    .map(|tag| tag.to_string())
+    .collect();
+    let req = |s: &str| semver::VersionReq::parse(s).unwrap();
+    assert_eq!(select_git_tag(&tags, &[req("^1")]), Some("v1.10.1"));
+    assert_eq!(select_git_tag(&tags, &[req("*")]), Some("v2.0.0"));
+    assert_eq!(
+        select_git_tag(&tags, &[req("^1"), req("<1.5")]),
+        Some("1.2.0")
+    );
+    assert_eq!(select_git_tag(&tags, &[req("^1.2"), req("^2")]), None);
+
+    let repo = Url::parse("https://github.com/foo/bar").unwrap();
+    let reqs = [("a".to_string(), req("^1.2")), ("b".to_string(), req("^2"))];
+    let err = select_git_version("bar", &repo, &tags, &reqs).unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("`a` requires `^1.2`"));
+    assert!(msg.contains("`b` requires `^2`"));
+    assert!(msg.contains("available versions: 1.0.0, 1.2.0, 1.10.1, 2.0.0, 2.1.0-rc.1"));
+}
+
+/// Write a package manifest for a library of the given name with the given `[dependencies]`.
+#[cfg(test)]
+pub(crate) fn write_test_lib(dir: &Path, name: &str, deps: &str) {
+    fs::create_dir_all(dir.join("src")).unwrap();
+    let manifest = format!(
+        "[project]\nauthors = [\"Fuel Labs <contact@fuel.sh>\"]\nentry = \"lib.sw\"\n\
+        license = \"Apache-2.0\"\nname = \"{}\"\nimplicit-std = false\n\n[dependencies]\n{}",
+        name, deps
+    );
+    fs::write(dir.join(constants::MANIFEST_FILE_NAME), manifest).unwrap();
+    fs::write(dir.join("src/lib.sw"), format!("library {};\n", name)).unwrap();
+}
+
+/// Commit the working tree of the given repository and tag the commit with `tag`.
+#[cfg(test)]
+fn commit_test_tag(repo: &git2::Repository, tag: &str) {
+    let mut index = repo.index().unwrap();
+    index
+        .add_all(["*"], git2::IndexAddOption::DEFAULT, None)
+        .unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = git2::Signature::now("forc", "contact@fuel.sh").unwrap();
+    let parent = repo.head().ok().map(|head| head.peel_to_commit().unwrap());
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+    let commit = repo
+        .commit(Some("HEAD"), &signature, &signature, tag, &tree, &parents)
+        .unwrap();
+    let commit = repo.find_object(commit, None).unwrap();
+    repo.tag_lightweight(tag, &commit, false).unwrap();
+}
+
+/// A git repository within `dir` for the library `foo`, with a commit for each of the given tags.
+///
+/// Returns the repository alongside its URL.
+#[cfg(test)]
+fn init_test_git_repo(dir: &Path, tags: &[&str]) -> (git2::Repository, Url) {
+    let repo_dir = dir.join("foo");
+    let url = Url::from_directory_path(&repo_dir).unwrap();
+    // Remove anything left behind by an earlier run that failed before cleaning up.
+    let _ = fs::remove_dir_all(dir);
+    let _ = fs::remove_dir_all(git_checkouts_directory().join(git_repo_dir_name("foo", &url)));
+    let repo = git2::Repository::init(&repo_dir).unwrap();
+    for tag in tags {
+        write_test_lib(&repo_dir, "foo", &format!("# {}\n", tag));
+        commit_test_tag(&repo, tag);
+    }
+    (repo, url)
+}
+
+/// Build a plan for the package within `dir`, updating its lock file, and return the tag to which
+/// the dependency `foo` is pinned.
+#[cfg(test)]
+fn pinned_test_tag(dir: &Path) -> Result<String> {
+    let manifest_file = ManifestFile::from_dir(dir)?;
+    let member_manifests = manifest_file.member_manifests()?;
+    let lock_path = manifest_file.lock_path()?;
+    let plan = BuildPlan::from_lock_and_manifests(&lock_path, &member_manifests, false, false)?;
+    let graph = plan.graph();
+    let tags: Vec<String> = graph
+        .node_indices()
+        .filter_map(|n| match &graph[n].source {
+            SourcePinned::Git(git) if graph[n].name == "foo" => match &git.source.reference {
+                GitReference::Tag(tag) => Some(tag.clone()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    assert_eq!(tags.len(), 1, "`foo` is pinned once");
+    Ok(tags[0].clone())
+}
+
+#[test]
+fn test_git_version_lock_is_kept() {
+    let dir = std::env::temp_dir().join(format!("forc-pkg-git-lock-kept-{}", std::process::id()));
+    let (repo, url) = init_test_git_repo(&dir, &["v1.0.0", "v1.1.0", "v2.0.0"]);
+    let app_dir = dir.join("app");
+    let dep = |req: &str| format!("foo = {{ git = \"{}\", version = \"{}\" }}\n", url, req);
+    write_test_lib(&app_dir, "app", &dep("^1"));
+    assert_eq!(pinned_test_tag(&app_dir).unwrap(), "v1.1.0");
+
+    // A newly published version is not selected while the locked tag satisfies the requirement.
+    commit_test_tag(&repo, "v1.2.0");
+    assert_eq!(pinned_test_tag(&app_dir).unwrap(), "v1.1.0");
+
+    let _ = fs::remove_dir_all(git_checkouts_directory().join(git_repo_dir_name("foo", &url)));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_git_version_repinned() {
+    let dir = std::env::temp_dir().join(format!("forc-pkg-git-repinned-{}", std::process::id()));
+    let (_repo, url) = init_test_git_repo(&dir, &["v1.0.0", "v1.1.0", "v2.0.0"]);
+    let app_dir = dir.join("app");
+    let dep = |req: &str| format!("foo = {{ git = \"{}\", version = \"{}\" }}\n", url, req);
+    write_test_lib(&app_dir, "app", &dep("^1"));
+    assert_eq!(pinned_test_tag(&app_dir).unwrap(), "v1.1.0");
+
+    // The locked tag no longer satisfies the requirement, so the dependency is pinned anew.
+    write_test_lib(&app_dir, "app", &dep("^2"));
+    assert_eq!(pinned_test_tag(&app_dir).unwrap(), "v2.0.0");
+    let lock = fs::read_to_string(app_dir.join(constants::LOCK_FILE_NAME)).unwrap();
+    assert!(lock.contains("?tag=v2.0.0#"));
+    assert!(!lock.contains("?tag=v1.1.0#"));
+
+    write_test_lib(&app_dir, "app", &dep("=1.0.0"));
+    assert_eq!(pinned_test_tag(&app_dir).unwrap(), "v1.0.0");
+
+    let _ = fs::remove_dir_all(git_checkouts_directory().join(git_repo_dir_name("foo", &url)));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_git_version_shared_by_dependents() {
+    let dir = std::env::temp_dir().join(format!("forc-pkg-git-shared-{}", std::process::id()));
+    let (_repo, url) = init_test_git_repo(&dir, &["v1.0.0", "v1.1.0", "v1.2.0", "v2.0.0"]);
+    let app_dir = dir.join("app");
+    let bar_dir = dir.join("bar");
+    let dep = |req: &str| format!("foo = {{ git = \"{}\", version = \"{}\" }}\n", url, req);
+    let bar_dep = "bar = { path = \"../bar\" }\n";
+
+    // A single tag satisfying the requirements of both dependents is selected.
+    write_test_lib(&app_dir, "app", &format!("{}{}", bar_dep, dep("^1")));
+    write_test_lib(&bar_dir, "bar", &dep("<1.2"));
+    assert_eq!(pinned_test_tag(&app_dir).unwrap(), "v1.1.0");
+
+    // No tag satisfies conflicting requirements, so the requirement of each is reported.
+    let _ = fs::remove_file(app_dir.join(constants::LOCK_FILE_NAME));
+    write_test_lib(&bar_dir, "bar", &dep("^2"));
+    let err = format!("{:?}", pinned_test_tag(&app_dir).unwrap_err());
+    assert!(err.contains("`app` requires `^1`"), "{}", err);
+    assert!(err.contains("`bar` requires `^2`"), "{}", err);
+    assert!(err.contains("available versions: 1.0.0, 1.1.0, 1.2.0, 2.0.0"));
+
+    let _ = fs::remove_dir_all(git_checkouts_directory().join(git_repo_dir_name("foo", &url)));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
 /// Format an error message for an absent `Forc.toml`.
 pub fn manifest_file_missing(dir: &Path) -> anyhow::Error {
     let message = format!(
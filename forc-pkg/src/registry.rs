@@ -0,0 +1,566 @@
+//! Fetching packages from a forc package registry.
+//!
+//! A registry is any location addressable by a `file://`, `http://` or `https://` URL with the
+//! following layout:
+//!
+//! ```ignore
+//! <registry>/index/<name>                       ; One line of JSON per published version.
+//! <registry>/packages/<name>-<version>.tar.gz   ; The gzipped tarball of each version.
+//! ```
+//!
+//! Each line of a package's index file describes a single published version, e.g.
+//!
+//! ```ignore
+//! {"version":"1.2.0","checksum":"<sha256-of-tarball-as-hex>","yanked":false}
+//! ```
+//!
+//! Tarballs are verified against their checksum before they are unpacked into the registry cache
+//! under `$HOME/.forc/registry`, or under the directory given by [REGISTRY_CACHE_ENV_VAR]. A local
+//! directory may serve as a registry, which is useful for testing and for working offline.
+
+use crate::pkg::{hash_url, SourceRegistry, SourceRegistryPinned};
+use anyhow::{anyhow, bail, Context, Result};
+use forc_util::registry_cache_directory;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    fs,
+    io::Read,
+    path::{Component, Path, PathBuf},
+};
+use url::Url;
+
+/// The environment variable that specifies the registry used for dependencies that do not
+/// specify a `registry` of their own.
+pub const REGISTRY_ENV_VAR: &str = "FORC_REGISTRY";
+
+/// The environment variable that overrides the directory under which registry packages are cached.
+pub const REGISTRY_CACHE_ENV_VAR: &str = "FORC_REGISTRY_CACHE";
+
+/// The name of the file written to each unpacked package that records the package's checksum.
+const CACHE_INDEX_FILE_NAME: &str = ".forc_index";
+
+/// An entry within a registry's index, describing a single published version of a package.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct IndexEntry {
+    pub version: semver::Version,
+    /// The SHA-256 hash of the package tarball, encoded as hex.
+    pub checksum: String,
+    /// Yanked versions are never selected when resolving a version requirement, though packages
+    /// that are already cached remain usable.
+    #[serde(default)]
+    pub yanked: bool,
+}
+
+/// Everything needed to recognize a cached package in offline mode.
+#[derive(Deserialize, Serialize)]
+struct CacheIndex {
+    version: semver::Version,
+    checksum: String,
+}
+
+/// Parse the registry location given within a manifest or via [REGISTRY_ENV_VAR].
+///
+/// The location may be either a URL or a path to a local directory, relative to `base_dir`.
+pub fn parse_registry(location: &str, base_dir: &Path) -> Result<Url> {
+    if let Ok(url) = Url::parse(location) {
+        if url.scheme().len() > 1 {
+            return Ok(url);
+        }
+    }
+    let path = base_dir.join(location);
+    let canonical_path = path
+        .canonicalize()
+        .map_err(|e| anyhow!("Failed to canonicalize registry path {:?}: {}", path, e))?;
+    Url::from_directory_path(&canonical_path)
+        .map_err(|_| anyhow!("invalid registry path {:?}", canonical_path))
+}
+
+/// The registry to use for dependencies that do not specify one, as given by [REGISTRY_ENV_VAR].
+pub fn default_registry() -> Result<Url> {
+    let location = std::env::var(REGISTRY_ENV_VAR).map_err(|_| {
+        anyhow!(
+            "no registry specified: either specify a `registry` for the dependency \
+            or set the `{}` environment variable",
+            REGISTRY_ENV_VAR
+        )
+    })?;
+    parse_registry(&location, &std::env::current_dir()?)
+}
+
+/// Pin the given registry-sourced package to the highest published version that satisfies the
+/// source's version requirement.
+pub fn pin_registry(name: &str, source: SourceRegistry) -> Result<SourceRegistryPinned> {
+    let index = fetch_index(name, &source.registry)?;
+    let entry = select_version(&index, &source.version).ok_or_else(|| {
+        let available: Vec<String> = index
+            .iter()
+            .filter(|entry| !entry.yanked)
+            .map(|entry| entry.version.to_string())
+            .collect();
+        anyhow!(
+            "no version of package `{}` in registry {} matches the requirement `{}` \
+            (available versions: {})",
+            name,
+            source.registry,
+            source.version,
+            if available.is_empty() {
+                "none".to_string()
+            } else {
+                available.join(", ")
+            }
+        )
+    })?;
+    Ok(SourceRegistryPinned {
+        version: entry.version.clone(),
+        checksum: entry.checksum.to_lowercase(),
+        source,
+    })
+}
+
+/// Select the highest version within the index that satisfies the requirement and is not yanked.
+pub fn select_version<'a>(
+    index: &'a [IndexEntry],
+    req: &semver::VersionReq,
+) -> Option<&'a IndexEntry> {
+    index
+        .iter()
+        .filter(|entry| !entry.yanked && req.matches(&entry.version))
+        .max_by(|a, b| a.version.cmp(&b.version))
+}
+
+/// Search the registry cache for the highest version of the package that satisfies the source's
+/// version requirement.
+///
+/// Used in place of [pin_registry] in offline mode.
+pub fn search_registry_cache(
+    name: &str,
+    source: &SourceRegistry,
+) -> Result<Option<SourceRegistryPinned>> {
+    let registry_dir = registry_cache_dir(&source.registry);
+    if !registry_dir.exists() {
+        return Ok(None);
+    }
+    let prefix = format!("{}-", name);
+    let mut found: Option<CacheIndex> = None;
+    for entry in fs::read_dir(&registry_dir)? {
+        let entry = entry?;
+        let dir_name = entry.file_name();
+        let matches_name = dir_name
+            .to_str()
+            .map(|dir_name| dir_name.starts_with(&prefix))
+            .unwrap_or(false);
+        if !matches_name {
+            continue;
+        }
+        let index_path = entry.path().join(CACHE_INDEX_FILE_NAME);
+        let index: CacheIndex = match fs::read_to_string(&index_path)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+        {
+            Some(index) => index,
+            None => continue,
+        };
+        // Ensure the directory belongs to this package, not to one whose name shares a prefix.
+        if entry.path() != registry_package_path(name, &source.registry, &index.version) {
+            continue;
+        }
+        let is_newer = found
+            .as_ref()
+            .map(|found| index.version > found.version)
+            .unwrap_or(true);
+        if source.version.matches(&index.version) && is_newer {
+            found = Some(index);
+        }
+    }
+    Ok(found.map(|index| SourceRegistryPinned {
+        source: source.clone(),
+        version: index.version,
+        checksum: index.checksum,
+    }))
+}
+
+/// The path to which the given version of a registry package is unpacked.
+///
+/// The resulting directory is:
+///
+/// ```ignore
+/// $HOME/.forc/registry/cache/<registry_name>-<registry_url_hash>/<name>-<version>
+/// ```
+///
+/// unless the cache directory is overridden by [REGISTRY_CACHE_ENV_VAR].
+pub fn registry_package_path(name: &str, registry: &Url, version: &semver::Version) -> PathBuf {
+    registry_cache_dir(registry).join(format!("{}-{}", name, version))
+}
+
+/// Download the pinned package, verify its checksum and unpack it into the registry cache.
+///
+/// Returns the location of the unpacked package.
+///
+/// If the package is already cached, its recorded checksum is checked against the pinned checksum
+/// instead.
+///
+/// NOTE: This function assumes that the caller has acquired an advisory lock to co-ordinate
+/// access to the package's cache directory.
+pub fn fetch_registry(fetch_id: u64, name: &str, pinned: &SourceRegistryPinned) -> Result<PathBuf> {
+    let path = registry_package_path(name, &pinned.source.registry, &pinned.version);
+    if path.exists() {
+        let index_path = path.join(CACHE_INDEX_FILE_NAME);
+        let index: CacheIndex = serde_json::from_str(&fs::read_to_string(&index_path)?)
+            .with_context(|| format!("failed to read {}", index_path.display()))?;
+        if index.checksum != pinned.checksum {
+            bail!(
+                "checksum of cached package `{} {}` ({}) does not match the checksum \
+                published by the registry ({})",
+                name,
+                pinned.version,
+                index.checksum,
+                pinned.checksum
+            );
+        }
+        return Ok(path);
+    }
+
+    let tarball_url = pinned
+        .source
+        .registry
+        .join(&format!("packages/{}-{}.tar.gz", name, pinned.version))?;
+    let tarball = fetch_url(&tarball_url)?;
+    let checksum = hex::encode(Sha256::digest(&tarball));
+    if checksum != pinned.checksum {
+        bail!(
+            "checksum of package `{} {}` downloaded from {} ({}) does not match the checksum \
+            published by the registry ({})",
+            name,
+            pinned.version,
+            tarball_url,
+            checksum,
+            pinned.checksum
+        );
+    }
+
+    // Unpack to a temporary directory first so that an interrupted fetch never leaves a partially
+    // unpacked package in the cache.
+    let tmp_path = registry_cache_dir(&pinned.source.registry)
+        .join("tmp")
+        .join(format!("{:x}-{}-{}", fetch_id, name, pinned.version));
+    if tmp_path.exists() {
+        let _ = fs::remove_dir_all(&tmp_path);
+    }
+    fs::create_dir_all(&tmp_path)?;
+    let archive = flate2::read::GzDecoder::new(&tarball[..]);
+    unpack_tar(archive, &tmp_path).with_context(|| format!("failed to unpack {}", tarball_url))?;
+    let index = CacheIndex {
+        version: pinned.version.clone(),
+        checksum,
+    };
+    fs::write(
+        tmp_path.join(CACHE_INDEX_FILE_NAME),
+        serde_json::to_string(&index)?,
+    )?;
+    fs::rename(&tmp_path, &path)?;
+    Ok(path)
+}
+
+/// Fetch and parse the index of the given package.
+fn fetch_index(name: &str, registry: &Url) -> Result<Vec<IndexEntry>> {
+    let index_url = registry.join(&format!("index/{}", name))?;
+    let bytes = fetch_url(&index_url)
+        .with_context(|| format!("failed to fetch the index of package `{}`", name))?;
+    let index =
+        String::from_utf8(bytes).map_err(|_| anyhow!("index {} is not valid UTF-8", index_url))?;
+    index
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line)
+                .map_err(|e| anyhow!("invalid entry in index {}: {}", index_url, e))
+        })
+        .collect()
+}
+
+/// Read the contents at the given `file://`, `http://` or `https://` URL.
+fn fetch_url(url: &Url) -> Result<Vec<u8>> {
+    match url.scheme() {
+        "file" => {
+            let path = url
+                .to_file_path()
+                .map_err(|_| anyhow!("invalid file URL {}", url))?;
+            fs::read(&path).map_err(|e| anyhow!("failed to read {}: {}", path.display(), e))
+        }
+        "http" | "https" => {
+            // The blocking client may not be used from within an async runtime, such as that of
+            // `forc` itself, so the request is made on a dedicated thread.
+            let request_url = url.clone();
+            std::thread::spawn(move || -> reqwest::Result<Vec<u8>> {
+                let response = reqwest::blocking::get(request_url)?.error_for_status()?;
+                Ok(response.bytes()?.to_vec())
+            })
+            .join()
+            .map_err(|_| anyhow!("request to {} panicked", url))?
+            .with_context(|| {
+                format!(
+                    "failed to fetch `{}`. Check your connection or run in `--offline` mode",
+                    url
+                )
+            })
+        }
+        scheme => bail!("unsupported registry URL scheme `{}` in {}", scheme, url),
+    }
+}
+
+/// The directory under which the packages of the given registry are cached.
+fn registry_cache_dir(registry: &Url) -> PathBuf {
+    let registry_name = registry.host_str().unwrap_or(registry.scheme());
+    let cache_dir = std::env::var_os(REGISTRY_CACHE_ENV_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(registry_cache_directory);
+    cache_dir.join(format!("{}-{:x}", registry_name, hash_url(registry)))
+}
+
+/// Unpack the regular files and directories of the given tar archive into `dest`.
+///
+/// Entries of other types, such as links, are skipped. Entries whose path is absolute or that would
+/// escape `dest` are rejected.
+fn unpack_tar(archive: impl Read, dest: &Path) -> Result<()> {
+    fs::create_dir_all(dest)?;
+    let mut archive = tar::Archive::new(archive);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let entry_path = entry.path()?.into_owned();
+        if entry_path
+            .components()
+            .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+        {
+            bail!("entry `{}` has an invalid path", entry_path.display());
+        }
+        match entry.header().entry_type() {
+            tar::EntryType::Regular | tar::EntryType::Directory => {
+                entry.unpack_in(dest)?;
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        fetch_registry, pin_registry, registry_cache_dir, registry_package_path, select_version,
+        unpack_tar, IndexEntry, REGISTRY_CACHE_ENV_VAR,
+    };
+    use crate::{
+        manifest::ManifestFile,
+        pkg::{write_test_lib, BuildPlan, SourcePinned, SourceRegistry},
+    };
+    use sha2::{Digest, Sha256};
+    use std::{io::Write, path::Path};
+    use sway_utils::constants;
+
+    fn entry(version: &str, yanked: bool) -> IndexEntry {
+        IndexEntry {
+            version: version.parse().unwrap(),
+            checksum: String::new(),
+            yanked,
+        }
+    }
+
+    #[test]
+    fn test_select_version() {
+        let index = vec![
+            entry("1.1.0", false),
+            entry("1.3.0", true),
+            entry("1.2.5", false),
+            entry("2.0.0", false),
+        ];
+        let select = |req: &str| {
+            select_version(&index, &req.parse().unwrap()).map(|e| e.version.to_string())
+        };
+        assert_eq!(select("^1.1").as_deref(), Some("1.2.5"));
+        assert_eq!(select("=1.1.0").as_deref(), Some("1.1.0"));
+        assert_eq!(select(">=1.2, <3").as_deref(), Some("2.0.0"));
+        assert_eq!(select("^1.3"), None);
+    }
+
+    fn tar_entry(builder: &mut tar::Builder<Vec<u8>>, path: &str, data: &[u8]) {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        builder.append_data(&mut header, path, data).unwrap();
+    }
+
+    #[test]
+    fn test_unpack_tar() {
+        let dest = std::env::temp_dir().join(format!("forc-pkg-unpack-tar-{}", std::process::id()));
+        // Paths over 100 bytes are stored within a GNU long name entry.
+        let long_path = format!("pkg/src/{}.sw", "a".repeat(120));
+        let mut builder = tar::Builder::new(vec![]);
+        tar_entry(&mut builder, "pkg/Forc.toml", b"[project]\n");
+        tar_entry(&mut builder, "pkg/src/lib.sw", b"library pkg;\n");
+        tar_entry(&mut builder, &long_path, b"library a;\n");
+        let archive = builder.into_inner().unwrap();
+        unpack_tar(&archive[..], &dest).unwrap();
+        let lib = std::fs::read_to_string(dest.join("pkg/src/lib.sw")).unwrap();
+        assert_eq!(lib, "library pkg;\n");
+        let long = std::fs::read_to_string(dest.join(&long_path)).unwrap();
+        assert_eq!(long, "library a;\n");
+
+        // `tar::Builder` refuses to write such a path, so it is written to the header directly.
+        let mut header = tar::Header::new_old();
+        header.as_old_mut().name[..12].copy_from_slice(b"../escape.sw");
+        header.set_size(0);
+        header.set_cksum();
+        let mut builder = tar::Builder::new(vec![]);
+        builder.append(&header, &[][..]).unwrap();
+        let escaping = builder.into_inner().unwrap();
+        assert!(unpack_tar(&escaping[..], &dest).is_err());
+        assert!(!dest.join("../escape.sw").exists());
+        std::fs::remove_dir_all(&dest).unwrap();
+    }
+
+    /// Cache registry packages within a temporary directory rather than the user's `.forc`.
+    ///
+    /// Every test sets the same directory, so tests running in parallel never disagree.
+    fn use_test_cache() {
+        let cache_dir =
+            std::env::temp_dir().join(format!("forc-pkg-registry-cache-{}", std::process::id()));
+        std::env::set_var(REGISTRY_CACHE_ENV_VAR, cache_dir);
+    }
+
+    #[test]
+    fn test_fetch_registry_checksum_mismatch() {
+        use_test_cache();
+        let registry_dir =
+            std::env::temp_dir().join(format!("forc-pkg-registry-checksum-{}", std::process::id()));
+        std::fs::create_dir_all(registry_dir.join("index")).unwrap();
+        std::fs::create_dir_all(registry_dir.join("packages")).unwrap();
+
+        let mut builder = tar::Builder::new(vec![]);
+        tar_entry(&mut builder, "Forc.toml", b"[project]\n");
+        let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+        encoder.write_all(&builder.into_inner().unwrap()).unwrap();
+        let tarball = encoder.finish().unwrap();
+        std::fs::write(registry_dir.join("packages/foo-1.0.0.tar.gz"), &tarball).unwrap();
+
+        // The index publishes a checksum that differs from that of the tarball.
+        let checksum = hex::encode(Sha256::digest(&tarball));
+        let published = hex::encode(Sha256::digest(b"something else"));
+        let index = format!("{{\"version\":\"1.0.0\",\"checksum\":\"{}\"}}\n", published);
+        std::fs::write(registry_dir.join("index/foo"), index).unwrap();
+
+        let source = SourceRegistry {
+            registry: url::Url::from_directory_path(&registry_dir).unwrap(),
+            version: "^1".parse().unwrap(),
+        };
+        let pinned = pin_registry("foo", source).unwrap();
+        assert_eq!(pinned.checksum, published);
+        let err = fetch_registry(0, "foo", &pinned).unwrap_err().to_string();
+        assert!(err.contains(&format!(
+            "({}) does not match the checksum published by the registry ({})",
+            checksum, published
+        )));
+        // Nothing is unpacked into the registry cache.
+        let path = registry_package_path("foo", &pinned.source.registry, &pinned.version);
+        assert!(!path.exists());
+        std::fs::remove_dir_all(&registry_dir).unwrap();
+    }
+
+    /// Publish the library `foo` at the given version to the registry within `registry_dir`.
+    fn publish_test_lib(registry_dir: &Path, version: &str) {
+        let pkg_dir = registry_dir.join("src").join(version);
+        write_test_lib(&pkg_dir, "foo", &format!("# {}\n", version));
+        let mut builder = tar::Builder::new(vec![]);
+        builder.append_dir_all("foo", &pkg_dir).unwrap();
+        let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+        encoder.write_all(&builder.into_inner().unwrap()).unwrap();
+        let tarball = encoder.finish().unwrap();
+        let packages_dir = registry_dir.join("packages");
+        std::fs::create_dir_all(&packages_dir).unwrap();
+        std::fs::write(
+            packages_dir.join(format!("foo-{}.tar.gz", version)),
+            &tarball,
+        )
+        .unwrap();
+
+        let index_path = registry_dir.join("index/foo");
+        std::fs::create_dir_all(index_path.parent().unwrap()).unwrap();
+        let mut index = std::fs::read_to_string(&index_path).unwrap_or_default();
+        let checksum = hex::encode(Sha256::digest(&tarball));
+        index.push_str(&format!(
+            "{{\"version\":\"{}\",\"checksum\":\"{}\"}}\n",
+            version, checksum
+        ));
+        std::fs::write(&index_path, index).unwrap();
+    }
+
+    /// Build a plan for the package within `dir`, updating its lock file, and return the version to
+    /// which the dependency `foo` is pinned.
+    fn pinned_test_version(dir: &Path, offline: bool) -> anyhow::Result<String> {
+        let manifest_file = ManifestFile::from_dir(dir)?;
+        let member_manifests = manifest_file.member_manifests()?;
+        let lock_path = manifest_file.lock_path()?;
+        let plan =
+            BuildPlan::from_lock_and_manifests(&lock_path, &member_manifests, false, offline)?;
+        let graph = plan.graph();
+        let versions: Vec<String> = graph
+            .node_indices()
+            .filter_map(|n| match &graph[n].source {
+                SourcePinned::Registry(reg) if graph[n].name == "foo" => {
+                    Some(reg.version.to_string())
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(versions.len(), 1, "`foo` is pinned once");
+        Ok(versions[0].clone())
+    }
+
+    #[test]
+    fn test_registry_dependency_lock() {
+        use_test_cache();
+        let dir =
+            std::env::temp_dir().join(format!("forc-pkg-registry-lock-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let registry_dir = dir.join("registry");
+        for version in ["1.0.0", "1.1.0", "2.0.0"] {
+            publish_test_lib(&registry_dir, version);
+        }
+        let registry = url::Url::from_directory_path(&registry_dir).unwrap();
+        let app_dir = dir.join("app");
+        let lock_path = app_dir.join(constants::LOCK_FILE_NAME);
+        let dep = |req: &str| {
+            format!(
+                "foo = {{ version = \"{}\", registry = \"{}\" }}\n",
+                req, registry
+            )
+        };
+        write_test_lib(&app_dir, "app", &dep("^1"));
+        assert_eq!(pinned_test_version(&app_dir, false).unwrap(), "1.1.0");
+        let lock = std::fs::read_to_string(&lock_path).unwrap();
+        assert!(lock.contains(&format!("registry+{}?version=^1#1.1.0:", registry)));
+        let version = "1.1.0".parse().unwrap();
+        let cached = registry_package_path("foo", &registry, &version);
+        assert!(cached.join("foo/src/lib.sw").exists());
+
+        // A newly published version is not selected while the locked version satisfies the
+        // requirement.
+        publish_test_lib(&registry_dir, "1.2.0");
+        assert_eq!(pinned_test_version(&app_dir, false).unwrap(), "1.1.0");
+
+        // The locked version no longer satisfies the requirement, so the dependency is pinned anew.
+        write_test_lib(&app_dir, "app", &dep("^2"));
+        assert_eq!(pinned_test_version(&app_dir, false).unwrap(), "2.0.0");
+        let lock = std::fs::read_to_string(&lock_path).unwrap();
+        assert!(lock.contains(&format!("registry+{}?version=^2#2.0.0:", registry)));
+        assert!(!lock.contains("#1.1.0:"));
+
+        // Offline, the highest matching version within the cache is selected.
+        std::fs::remove_file(&lock_path).unwrap();
+        write_test_lib(&app_dir, "app", &dep("^1"));
+        assert_eq!(pinned_test_version(&app_dir, true).unwrap(), "1.1.0");
+
+        std::fs::remove_dir_all(registry_cache_dir(&registry)).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
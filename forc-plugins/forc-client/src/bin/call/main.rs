@@ -0,0 +1,15 @@
+use forc_client::ops::call::{cmd::CallCommand, op::call};
+use forc_tracing::init_tracing_subscriber;
+use std::process;
+
+use clap::Parser;
+
+#[tokio::main]
+async fn main() {
+    init_tracing_subscriber(Default::default());
+    let command = CallCommand::parse();
+    if let Err(err) = call(command).await {
+        tracing::error!("Error: {:?}", err);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,14 @@
+use forc_client::ops::predicate::{cmd::PredicateCommand, op::predicate};
+use forc_tracing::init_tracing_subscriber;
+use std::process;
+
+use clap::Parser;
+
+fn main() {
+    init_tracing_subscriber(Default::default());
+    let command = PredicateCommand::parse();
+    if let Err(err) = predicate(command) {
+        tracing::error!("Error: {:?}", err);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,87 @@
+use clap::Parser;
+use fuel_tx::{AssetId, ContractId};
+use std::path::PathBuf;
+
+use crate::ops::keystore::Signing;
+
+/// Call a method of a deployed contract.
+/// Encodes the arguments using the contract's JSON ABI, crafts a script transaction that calls the
+/// method, then sends it to a running node and decodes the result.
+#[derive(Debug, Default, Parser)]
+#[clap(bin_name = "forc call", version)]
+pub struct CallCommand {
+    /// 32-byte ID of the contract to call.
+    pub contract_id: ContractId,
+
+    /// Name of the contract method to call.
+    pub method: String,
+
+    /// Arguments to the method, encoded according to the contract's ABI.
+    ///
+    /// Either a single JSON object mapping parameter names to values, e.g.
+    /// `'{"amount": 42, "to": "0x..."}'`, or one `name=value` pair per parameter, e.g.
+    /// `amount=42 to=0x...`. Values are given as JSON; structs are objects, enums are
+    /// `{"Variant": value}` or `"Variant"`, and arrays and tuples are arrays.
+    pub args: Vec<String>,
+
+    /// Path to the contract's JSON ABI, e.g. `out/debug/<name>-abi.json`.
+    #[clap(long)]
+    pub abi: PathBuf,
+
+    /// Amount of coins to forward to the contract with the call.
+    #[clap(long, default_value = "0")]
+    pub amount: u64,
+
+    /// Asset ID of the coins to forward to the contract. Defaults to the base asset.
+    #[clap(long)]
+    pub asset_id: Option<AssetId>,
+
+    /// 32-byte IDs of any other contracts that will be called during the transaction.
+    #[clap(long = "contract")]
+    pub contract: Option<Vec<String>>,
+
+    /// Number of variable outputs to add to the transaction. These are required if the method
+    /// transfers coins to an address.
+    #[clap(long, default_value = "0")]
+    pub variable_outputs: u8,
+
+    /// Only craft transaction and print it out.
+    #[clap(long)]
+    pub dry_run: bool,
+
+    /// Execute the transaction and return the final mutated transaction along with receipts
+    /// (which includes whether the transaction reverted or not). The transaction is not inserted
+    /// in the node's view of the blockchain, (i.e. it does not affect the chain state).
+    #[clap(long)]
+    pub simulate: bool,
+
+    /// URL of the Fuel Client Node
+    #[clap(long, env = "FUEL_NODE_URL")]
+    pub node_url: Option<String>,
+
+    /// Pretty-print the outputs from the node.
+    #[clap(long = "pretty-print", short = 'r')]
+    pub pretty_print: bool,
+
+    /// Set the transaction gas limit. Defaults to the maximum gas limit.
+    #[clap(long)]
+    pub gas_limit: Option<u64>,
+
+    /// Set the transaction gas price. Defaults to 0.
+    #[clap(long)]
+    pub gas_price: Option<u64>,
+
+    #[clap(flatten)]
+    pub signing: Signing,
+
+    /// Call the contract within an in-process VM rather than a node, such as one deployed by
+    /// `forc deploy --local`.
+    #[clap(long)]
+    pub local: bool,
+
+    /// The file in which the state of the in-process VM is persisted.
+    ///
+    /// By default, this is `~/.forc/local/state.json`.
+    #[clap(long, requires = "local")]
+    pub local_state: Option<PathBuf>,
+}
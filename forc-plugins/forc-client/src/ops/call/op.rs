@@ -0,0 +1,268 @@
+use anyhow::{anyhow, Context, Result};
+use forc_util::format_log_receipts;
+use fuel_abi_types::program_abi::ProgramABI;
+use fuel_gql_client::client::FuelClient;
+use fuel_tx::{Address, AssetId, ContractId, Output, TransactionBuilder, Word};
+use fuel_vm::{
+    consts::{REG_CGAS, REG_IS, REG_ONE, REG_SSP},
+    prelude::Opcode,
+};
+use fuels_types::param_types::ParamType;
+use std::{fs, str::FromStr};
+use sway_utils::constants::DEFAULT_NODE_URL;
+use tracing::info;
+
+use crate::ops::local_node::LocalNodeLock;
+use crate::ops::receipt_util::print_decoded_receipts;
+use crate::ops::run::op::try_send_tx;
+use crate::ops::tx_util::{TransactionBuilderExt, TxParameters};
+use forc_util::abi::{AbiFunction, ReceiptDecoder};
+
+use super::cmd::CallCommand;
+
+/// The byte offsets of the parts of the script data read by the call script, following the
+/// contract ID and function selector at its start.
+const ARGS_WORD_OFFSET: Word = 40;
+const AMOUNT_OFFSET: Word = 48;
+const ASSET_ID_OFFSET: Word = 56;
+const ENCODED_ARGS_OFFSET: Word = 88;
+
+/// The length of the call frame parameters, i.e. the contract ID, selector and arguments word.
+const CALL_FRAME_PARAMS_LEN: Word = 48;
+
+pub struct CalledContract {
+    pub receipts: Vec<fuel_tx::Receipt>,
+}
+
+/// Calls a method of a deployed contract, as described by the contract's JSON ABI.
+///
+/// Upon success, returns the receipts of the call.
+pub async fn call(command: CallCommand) -> Result<CalledContract> {
+    let abi_json = fs::read_to_string(&command.abi)
+        .with_context(|| format!("failed to read ABI from {}", command.abi.display()))?;
+    let program_abi: ProgramABI = serde_json::from_str(&abi_json)
+        .with_context(|| format!("failed to parse ABI from {}", command.abi.display()))?;
+    let function = AbiFunction::from_program_abi(&program_abi, &command.method)?;
+    let encoded_args = function.encode_named_args(&command.args)?;
+    let decoder = ReceiptDecoder::from_program_abi(&program_abi)?.with_function(&function);
+
+    let contract_id = command.contract_id;
+    let asset_id = command.asset_id.unwrap_or_default();
+    let (script, script_data) = call_script(
+        &contract_id,
+        &function,
+        encoded_args,
+        command.amount,
+        &asset_id,
+    );
+
+    let other_contract_ids = command
+        .contract
+        .as_ref()
+        .into_iter()
+        .flat_map(|contracts| contracts.iter())
+        .map(|contract| {
+            ContractId::from_str(contract)
+                .map_err(|e| anyhow!("Failed to parse contract id: {}", e))
+        })
+        .collect::<Result<Vec<ContractId>>>()?;
+    let mut tx = TransactionBuilder::script(script, script_data);
+    tx.params(TxParameters::new(command.gas_limit, command.gas_price))
+        .add_contract(contract_id)
+        .add_contracts(other_contract_ids);
+    for _ in 0..command.variable_outputs {
+        tx.add_output(Output::variable(Address::zeroed(), 0, AssetId::zeroed()));
+    }
+
+    let receipts = if command.local {
+        let tx = tx.finalize_without_signature_as_transaction();
+        if command.dry_run {
+            info!("{:?}", tx);
+            return Ok(CalledContract { receipts: vec![] });
+        }
+        let mut node_lock = LocalNodeLock::new(command.local_state.as_deref())?;
+        let mut node = node_lock.load()?;
+        let receipts = node.transact(tx, command.simulate)?;
+        info!("{}", format_log_receipts(&receipts, command.pretty_print)?);
+        receipts
+    } else {
+        let node_url = command.node_url.as_deref().unwrap_or(DEFAULT_NODE_URL);
+        let client = FuelClient::new(node_url)?;
+        let signing_key = command.signing.signing_key()?;
+        let tx = tx
+            .finalize_signed(client, command.signing.unsigned, signing_key)
+            .await?;
+        if command.dry_run {
+            info!("{:?}", tx);
+            return Ok(CalledContract { receipts: vec![] });
+        }
+        try_send_tx(node_url, &tx.into(), command.pretty_print, command.simulate).await?
+    };
+    print_decoded_receipts(Some(&decoder), None, &contract_id, &receipts)?;
+    Ok(CalledContract { receipts })
+}
+
+/// Build a script that calls the given function of a contract, along with its script data.
+///
+/// The script data begins with the call frame parameters expected by `CALL`, i.e. the contract
+/// ID, the function selector and the arguments, followed by the amount and asset ID of the coins
+/// to forward and finally the encoded arguments. As with calls made from Sway, a single argument
+/// of a copy type is passed by value, while any other arguments are passed by reference to their
+/// encoding.
+fn call_script(
+    contract_id: &ContractId,
+    function: &AbiFunction,
+    encoded_args: Vec<u8>,
+    amount: Word,
+    asset_id: &AssetId,
+) -> (Vec<u8>, Vec<u8>) {
+    let args_by_value = match &function.params[..] {
+        [] => Some(0),
+        [(_, param_type)] if is_copy_type(param_type) => {
+            // Copy types are encoded as a single big-endian word, other than `()` which is empty.
+            let mut word = [0; 8];
+            let len = encoded_args.len().min(word.len());
+            word[8 - len..].copy_from_slice(&encoded_args[..len]);
+            Some(Word::from_be_bytes(word))
+        }
+        _ => None,
+    };
+
+    // The script data follows the script, so its address is computed relative to `$is`, which
+    // holds the address of the script itself. Scripts may not write to the transaction, so the
+    // call frame parameters are copied to the stack in order to fill in the arguments pointer.
+    let data_reg = 0x10;
+    let frame_reg = 0x11;
+    let args_reg = 0x12;
+    let amount_reg = 0x13;
+    let asset_id_reg = 0x14;
+    let mut ops = vec![
+        Opcode::ADDI(data_reg, REG_IS, 0),
+        Opcode::MOVE(frame_reg, REG_SSP),
+        Opcode::CFEI(CALL_FRAME_PARAMS_LEN as u32),
+        Opcode::MCPI(frame_reg, data_reg, CALL_FRAME_PARAMS_LEN as u16),
+    ];
+    if args_by_value.is_none() {
+        ops.push(Opcode::ADDI(args_reg, data_reg, ENCODED_ARGS_OFFSET as u16));
+        ops.push(Opcode::SW(
+            frame_reg,
+            args_reg,
+            (ARGS_WORD_OFFSET / 8) as u16,
+        ));
+    }
+    ops.push(Opcode::LW(amount_reg, data_reg, (AMOUNT_OFFSET / 8) as u16));
+    ops.push(Opcode::ADDI(asset_id_reg, data_reg, ASSET_ID_OFFSET as u16));
+    ops.push(Opcode::CALL(frame_reg, amount_reg, asset_id_reg, REG_CGAS));
+    ops.push(Opcode::RET(REG_ONE));
+    // Keep the script word aligned, so that the script data immediately follows it.
+    if ops.len() % 2 != 0 {
+        ops.push(Opcode::NOOP);
+    }
+    let script_len = (ops.len() * Opcode::LEN) as u16;
+    ops[0] = Opcode::ADDI(data_reg, REG_IS, script_len);
+    let script = ops.into_iter().collect();
+
+    let mut script_data = Vec::with_capacity(ENCODED_ARGS_OFFSET as usize + encoded_args.len());
+    script_data.extend_from_slice(contract_id.as_ref());
+    script_data.extend_from_slice(&function.selector());
+    script_data.extend_from_slice(&args_by_value.unwrap_or(0).to_be_bytes());
+    script_data.extend_from_slice(&amount.to_be_bytes());
+    script_data.extend_from_slice(asset_id.as_ref());
+    debug_assert_eq!(script_data.len() as Word, ENCODED_ARGS_OFFSET);
+    script_data.extend_from_slice(&encoded_args);
+    (script, script_data)
+}
+
+/// Whether values of the given type are passed to contract calls by value, matching
+/// `TypeInfo::is_copy_type` in the compiler.
+fn is_copy_type(param_type: &ParamType) -> bool {
+    matches!(
+        param_type,
+        ParamType::U8
+            | ParamType::U16
+            | ParamType::U32
+            | ParamType::U64
+            | ParamType::Bool
+            | ParamType::Unit
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::deploy::{cmd::DeployCommand, op::deploy};
+    use crate::ops::test_util::copy_test_project;
+    use fuel_tx::Receipt;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_call_local() {
+        let dir = copy_test_project("call_contract", "call-local");
+        let local_state = Some(dir.join("state.json"));
+        let deployed = deploy(DeployCommand {
+            path: Some(dir.display().to_string()),
+            offline_mode: true,
+            terse_mode: true,
+            local: true,
+            local_state: local_state.clone(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        let contract_id = deployed[0].id;
+        let abi = dir.join("out/debug/call_contract-abi.json");
+        let call_command = |method: &str, args: &[&str]| CallCommand {
+            contract_id,
+            method: method.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            abi: abi.clone(),
+            local: true,
+            local_state: local_state.clone(),
+            ..Default::default()
+        };
+        let program_abi: ProgramABI =
+            serde_json::from_str(&fs::read_to_string(&abi).unwrap()).unwrap();
+        let decode_return = |method: &str, receipts: &[Receipt]| {
+            let function = AbiFunction::from_program_abi(&program_abi, method).unwrap();
+            ReceiptDecoder::from_program_abi(&program_abi)
+                .unwrap()
+                .with_function(&function)
+                .decode_return(&contract_id, receipts)
+                .unwrap()
+        };
+
+        // A single copy type argument is passed by value.
+        let called = call(call_command("increment", &["value=41"]))
+            .await
+            .unwrap();
+        assert!(called.receipts.iter().any(|receipt| matches!(
+            receipt,
+            Receipt::Log { id, ra: 41, .. } if *id == contract_id
+        )));
+        assert_eq!(
+            decode_return("increment", &called.receipts),
+            Some(json!(42))
+        );
+
+        // Other arguments are passed by reference to their encoding.
+        let args = [r#"{"point": {"x": 1, "y": false}, "offset": 2}"#];
+        let called = call(call_command("flip", &args)).await.unwrap();
+        assert_eq!(
+            decode_return("flip", &called.receipts),
+            Some(json!({"x": 3, "y": true}))
+        );
+
+        // A dry run only prints the transaction.
+        let command = CallCommand {
+            dry_run: true,
+            ..call_command("increment", &["value=41"])
+        };
+        assert!(call(command).await.unwrap().receipts.is_empty());
+
+        let err = call(call_command("decrement", &[])).await.err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "program ABI has no function named `decrement`"
+        );
+    }
+}
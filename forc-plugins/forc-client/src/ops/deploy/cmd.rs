@@ -1,5 +1,8 @@
 use clap::Parser;
 use fuel_gql_client::fuel_crypto::SecretKey;
+use std::path::PathBuf;
+
+use crate::ops::keystore::Signing;
 
 #[derive(Debug, Default, Parser)]
 #[clap(bin_name = "forc deploy", version)]
@@ -76,15 +79,29 @@ pub struct DeployCommand {
     /// Output the time elapsed over each part of the compilation process.
     #[clap(long)]
     pub time_phases: bool,
-    /// Do not sign the transaction
-    #[clap(long)]
-    pub unsigned: bool,
     /// Set the transaction gas limit. Defaults to the maximum gas limit.
     #[clap(long)]
     pub gas_limit: Option<u64>,
     /// Set the transaction gas price. Defaults to 0.
     #[clap(long)]
     pub gas_price: Option<u64>,
+    #[clap(flatten)]
+    pub signing: Signing,
     /// Set the key to be used for signing.
-    pub signing_key: Option<SecretKey>,
+    ///
+    /// Deprecated, use `--signing-key` instead.
+    #[clap(
+        value_name = "SIGNING_KEY",
+        conflicts_with_all = &["signing-key", "account", "local"]
+    )]
+    pub positional_signing_key: Option<SecretKey>,
+    /// Deploy to an in-process VM rather than a node. The state of the VM is persisted between
+    /// invocations, so that contracts deployed this way may be called by `forc run --local`.
+    #[clap(long)]
+    pub local: bool,
+    /// The file in which the state of the in-process VM is persisted.
+    ///
+    /// By default, this is `~/.forc/local/state.json`.
+    #[clap(long, requires = "local")]
+    pub local_state: Option<PathBuf>,
 }
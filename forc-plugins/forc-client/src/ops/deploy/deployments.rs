@@ -0,0 +1,113 @@
+//! A record of the contracts deployed from a package or workspace, kept alongside its `Forc.lock`.
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The name of the file in which deployments are recorded.
+pub const DEPLOYMENTS_FILE_NAME: &str = "deployments.json";
+
+/// The contracts deployed to each network, keyed by the network and then by package name.
+///
+/// Networks are identified by the URL of the node deployed to, or for deployments made with
+/// `--local`, by the path of the local node's state.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Deployments {
+    #[serde(skip)]
+    path: PathBuf,
+    #[serde(flatten)]
+    networks: BTreeMap<String, BTreeMap<String, DeploymentRecord>>,
+}
+
+/// A single deployed contract.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct DeploymentRecord {
+    pub contract_id: String,
+    pub salt: String,
+    pub bytecode_root: String,
+}
+
+impl Deployments {
+    /// Load the deployments recorded at the given path, or an empty record if there is none yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut deployments = if path.exists() {
+            let contents = fs::read_to_string(path)?;
+            serde_json::from_str(&contents).map_err(|e| {
+                anyhow!(
+                    "failed to parse the deployments recorded at {}: {}",
+                    path.display(),
+                    e
+                )
+            })?
+        } else {
+            Self::default()
+        };
+        deployments.path = path.to_path_buf();
+        Ok(deployments)
+    }
+
+    /// The recorded deployment of the given package to the given network, if any.
+    pub fn get(&self, network: &str, pkg_name: &str) -> Option<&DeploymentRecord> {
+        self.networks.get(network)?.get(pkg_name)
+    }
+
+    /// Record the deployment of the given package to the given network, replacing any previous
+    /// record, and write the deployments back to disk.
+    pub fn record(
+        &mut self,
+        network: &str,
+        pkg_name: &str,
+        record: DeploymentRecord,
+    ) -> Result<()> {
+        self.networks
+            .entry(network.to_string())
+            .or_default()
+            .insert(pkg_name.to_string(), record);
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(&self.path, contents + "\n")
+            .with_context(|| format!("failed to write deployments to {}", self.path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(contract_id: &str) -> DeploymentRecord {
+        DeploymentRecord {
+            contract_id: contract_id.to_string(),
+            salt: "0x00".to_string(),
+            bytecode_root: "0x01".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_record_and_load() {
+        let dir = std::env::temp_dir().join("forc-client-test-deployments");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(DEPLOYMENTS_FILE_NAME);
+        let _ = fs::remove_file(&path);
+
+        let mut deployments = Deployments::load(&path).unwrap();
+        assert_eq!(deployments.get("testnet", "foo"), None);
+        deployments.record("testnet", "foo", record("0xa")).unwrap();
+        deployments.record("local", "foo", record("0xb")).unwrap();
+        deployments.record("testnet", "foo", record("0xc")).unwrap();
+
+        let deployments = Deployments::load(&path).unwrap();
+        assert_eq!(deployments.get("testnet", "foo"), Some(&record("0xc")));
+        assert_eq!(deployments.get("local", "foo"), Some(&record("0xb")));
+        assert_eq!(deployments.get("local", "bar"), None);
+
+        fs::write(&path, "[]").unwrap();
+        let err = Deployments::load(&path).unwrap_err();
+        assert!(err.to_string().starts_with(&format!(
+            "failed to parse the deployments recorded at {}",
+            path.display()
+        )));
+    }
+}
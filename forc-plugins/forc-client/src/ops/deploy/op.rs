@@ -1,5 +1,5 @@
 use anyhow::{bail, Context, Result};
-use forc_pkg::{self as pkg, PackageManifestFile};
+use forc_pkg::{self as pkg, manifest::ManifestFile, PackageManifestFile};
 use fuel_gql_client::client::types::TransactionStatus;
 use fuel_gql_client::{
     client::FuelClient,
@@ -13,12 +13,15 @@ use std::time::Duration;
 use sway_core::language::parsed::TreeType;
 use sway_core::BuildTarget;
 use sway_utils::constants::DEFAULT_NODE_URL;
-use tracing::info;
+use tracing::{info, warn};
 
-use crate::ops::pkg_util::built_pkgs_with_manifest;
+use crate::ops::local_node::{default_state_path, LocalNodeLock};
+use crate::ops::pkg_util::built_pkgs_with_plan;
+use crate::ops::receipt_util::print_receipt_summary;
 use crate::ops::tx_util::{TransactionBuilderExt, TxParameters, TX_SUBMIT_TIMEOUT_MS};
 
 use super::cmd::DeployCommand;
+use super::deployments::{DeploymentRecord, Deployments, DEPLOYMENTS_FILE_NAME};
 
 pub struct DeployedContract {
     pub id: fuel_tx::ContractId,
@@ -27,6 +30,11 @@ pub struct DeployedContract {
 /// Builds and deploys contract(s). If the given path corresponds to a workspace, all deployable members
 /// will be built and deployed.
 ///
+/// Contracts are deployed in order of compilation, so that each is deployed after any contracts it
+/// depends on, and with the salt declared by its dependents. Deployed contracts are recorded in
+/// `deployments.json` alongside the `Forc.lock`, and contracts that have already been deployed with
+/// identical bytecode and salt are skipped.
+///
 /// Upon success, returns the ID of each deployed contract in order of deployment.
 ///
 /// When deploying a single contract, only that contract's ID is returned.
@@ -38,35 +46,77 @@ pub async fn deploy(command: DeployCommand) -> Result<Vec<DeployedContract>> {
         std::env::current_dir()?
     };
     let build_opts = build_opts_from_cmd(&command);
-    let built_pkgs_with_manifest = built_pkgs_with_manifest(&curr_dir, build_opts)?;
-    for (member_manifest, built_pkg) in built_pkgs_with_manifest {
+    let (build_plan, built_pkgs) = built_pkgs_with_plan(&curr_dir, build_opts)?;
+    let lock_path = ManifestFile::from_dir(&curr_dir)?.lock_path()?;
+    let mut deployments = Deployments::load(&lock_path.with_file_name(DEPLOYMENTS_FILE_NAME))?;
+    let graph = build_plan.graph();
+    for (member_index, member_manifest, built_pkg) in built_pkgs {
         if member_manifest
             .check_program_type(vec![TreeType::Contract])
             .is_ok()
         {
-            let contract_id = deploy_pkg(&command, &member_manifest, &built_pkg).await?;
+            let pkg_name = &member_manifest.project.name;
+            let network = network_name(&command, &member_manifest);
+            for (dep_index, _) in build_plan.contract_dependencies(member_index) {
+                let dep_name = &graph[dep_index].name;
+                if deployments.get(&network, dep_name).is_none() {
+                    warn!(
+                        "contract dependency `{}` of `{}` has not been deployed to {}",
+                        dep_name, pkg_name, network
+                    );
+                }
+            }
+
+            let salt = build_plan
+                .contract_salt(member_index)
+                .unwrap_or_else(|| Salt::new([0; 32]));
+            let contract_id = deploy_pkg(&command, &member_manifest, &built_pkg, salt).await?;
+            let record = DeploymentRecord {
+                contract_id: format!("{:#x}", contract_id.id),
+                salt: format!("{:#x}", salt),
+                bytecode_root: format!("{:#x}", Contract::root_from_code(&built_pkg.bytecode)),
+            };
+            deployments.record(&network, pkg_name, record)?;
             contract_ids.push(contract_id);
         }
     }
     Ok(contract_ids)
 }
 
-/// Deploy a single pkg given deploy command and the manifest file
-pub async fn deploy_pkg(
-    command: &DeployCommand,
-    manifest: &PackageManifestFile,
-    compiled: &BuiltPackage,
-) -> Result<DeployedContract> {
+/// The name under which deployments of the given package are recorded: the URL of the node it is
+/// deployed to, or the path of the local node's state.
+fn network_name(command: &DeployCommand, manifest: &PackageManifestFile) -> String {
+    if command.local {
+        let path = command
+            .local_state
+            .clone()
+            .unwrap_or_else(default_state_path);
+        format!("local:{}", path.display())
+    } else {
+        node_url(command, manifest).to_string()
+    }
+}
+
+/// The URL of the node to deploy the given package to.
+fn node_url<'a>(command: &'a DeployCommand, manifest: &'a PackageManifestFile) -> &'a str {
     let node_url = match &manifest.network {
         Some(network) => &network.url,
         _ => DEFAULT_NODE_URL,
     };
+    command.url.as_deref().unwrap_or(node_url)
+}
 
-    let node_url = command.url.as_deref().unwrap_or(node_url);
-    let client = FuelClient::new(node_url)?;
-
+/// Deploy a single pkg given deploy command and the manifest file, with the given salt.
+///
+/// If a contract with the same ID, i.e. with identical bytecode, salt and storage, has already been
+/// deployed, it is not deployed again.
+pub async fn deploy_pkg(
+    command: &DeployCommand,
+    manifest: &PackageManifestFile,
+    compiled: &BuiltPackage,
+    salt: Salt,
+) -> Result<DeployedContract> {
     let bytecode = compiled.bytecode.clone().into();
-    let salt = Salt::new([0; 32]);
     let mut storage_slots = compiled.storage_slots.clone();
     storage_slots.sort();
 
@@ -76,32 +126,70 @@ pub async fn deploy_pkg(
     let contract_id = contract.id(&salt, &root, &state_root);
     info!("Contract id: 0x{}", hex::encode(contract_id));
 
-    let tx = TransactionBuilder::create(bytecode, salt, storage_slots.clone())
-        .params(TxParameters::new(command.gas_limit, command.gas_price))
-        .add_output(Output::contract_created(contract_id, state_root))
-        .finalize_signed(client.clone(), command.unsigned, command.signing_key)
+    let mut tx = TransactionBuilder::create(bytecode, salt, storage_slots.clone());
+    tx.params(TxParameters::new(command.gas_limit, command.gas_price))
+        .add_output(Output::contract_created(contract_id, state_root));
+
+    if command.local {
+        let mut node_lock = LocalNodeLock::new(command.local_state.as_deref())?;
+        let mut node = node_lock.load()?;
+        if node.contract_exists(&contract_id) {
+            info!("contract {} is already deployed, skipping", &contract_id);
+            return Ok(DeployedContract { id: contract_id });
+        }
+        let receipts = node.transact(tx.finalize_without_signature_as_transaction(), false)?;
+        print_receipt_summary(compiled, &contract_id, &receipts)?;
+        info!(
+            "contract {} deployed in local block {}",
+            &contract_id,
+            node.block_height()
+        );
+        return Ok(DeployedContract { id: contract_id });
+    }
+
+    let client = FuelClient::new(node_url(command, manifest))?;
+    if client
+        .contract(&format!("{:#x}", contract_id))
+        .await?
+        .is_some()
+    {
+        info!("contract {} is already deployed, skipping", &contract_id);
+        return Ok(DeployedContract { id: contract_id });
+    }
+
+    let signing_key = command
+        .signing
+        .signing_key_or_positional(command.positional_signing_key)?;
+    let tx = tx
+        .finalize_signed(client.clone(), command.signing.unsigned, signing_key)
         .await?;
 
     let tx = Transaction::from(tx);
 
-    let deployment_request = client.submit_and_await_commit(&tx).map(|res| match res {
-        Ok(logs) => match logs {
-            TransactionStatus::Submitted { .. } => {
-                bail!("contract {} deployment timed out", &contract_id);
-            }
-            TransactionStatus::Success { block_id, .. } => {
-                info!("contract {} deployed in block {}", &contract_id, &block_id);
-                Ok(contract_id)
-            }
-            e => {
-                bail!(
-                    "contract {} failed to deploy due to an error: {:?}",
-                    &contract_id,
-                    e
-                )
-            }
-        },
-        Err(e) => bail!("{e}"),
+    let tx_id = format!("{:#x}", tx.id());
+    let deployment_request = client.submit_and_await_commit(&tx).then(|res| async {
+        match res {
+            Ok(logs) => match logs {
+                TransactionStatus::Submitted { .. } => {
+                    bail!("contract {} deployment timed out", &contract_id);
+                }
+                TransactionStatus::Success { block_id, .. } => {
+                    info!("contract {} deployed in block {}", &contract_id, &block_id);
+                    Ok(contract_id)
+                }
+                e => {
+                    if let Ok(receipts) = client.receipts(&tx_id).await {
+                        print_receipt_summary(compiled, &contract_id, &receipts)?;
+                    }
+                    bail!(
+                        "contract {} failed to deploy due to an error: {:?}",
+                        &contract_id,
+                        e
+                    )
+                }
+            },
+            Err(e) => bail!("{e}"),
+        }
     });
 
     // submit contract deployment with a timeout
@@ -150,3 +238,63 @@ fn build_opts_from_cmd(cmd: &DeployCommand) -> pkg::BuildOpts {
         inject_map,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::call::{cmd::CallCommand, op::call};
+    use crate::ops::test_util::copy_test_project;
+    use fuel_tx::Receipt;
+
+    #[tokio::test]
+    async fn test_deploy_workspace_in_dependency_order() {
+        let dir = copy_test_project("deploy_workspace", "deploy-workspace");
+        let local_state = dir.join("state.json");
+        let command = || DeployCommand {
+            path: Some(dir.display().to_string()),
+            offline_mode: true,
+            terse_mode: true,
+            local: true,
+            local_state: Some(local_state.clone()),
+            ..Default::default()
+        };
+        let deployed = deploy(command()).await.unwrap();
+        let ids: Vec<_> = deployed.iter().map(|contract| contract.id).collect();
+        assert_eq!(ids.len(), 2);
+
+        // The dependency is deployed first, with the salt declared by its dependent.
+        let deployments = Deployments::load(&dir.join(DEPLOYMENTS_FILE_NAME)).unwrap();
+        let network = format!("local:{}", local_state.display());
+        let callee = deployments.get(&network, "callee").unwrap();
+        let caller = deployments.get(&network, "caller").unwrap();
+        assert_eq!(callee.contract_id, format!("{:#x}", ids[0]));
+        assert_eq!(callee.salt, format!("0x{}", "22".repeat(32)));
+        assert_eq!(caller.contract_id, format!("{:#x}", ids[1]));
+        assert_eq!(caller.salt, format!("{:#x}", Salt::zeroed()));
+
+        // The ID of the dependency compiled into its dependent is that of the deployed contract.
+        let called = call(CallCommand {
+            contract_id: ids[1],
+            method: "callee_id".to_string(),
+            abi: dir.join("caller/out/debug/caller-abi.json"),
+            local: true,
+            local_state: Some(local_state.clone()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        let returned = called.receipts.iter().find_map(|receipt| match receipt {
+            Receipt::ReturnData { id, data, .. } if *id == ids[1] => Some(data.clone()),
+            _ => None,
+        });
+        assert_eq!(returned.as_deref(), Some(&ids[0][..]));
+        let mut node_lock = LocalNodeLock::new(Some(&local_state)).unwrap();
+        let block_height = node_lock.load().unwrap().block_height();
+
+        // Deploying again skips the contracts that have already been deployed.
+        let redeployed = deploy(command()).await.unwrap();
+        let redeployed_ids: Vec<_> = redeployed.iter().map(|contract| contract.id).collect();
+        assert_eq!(redeployed_ids, ids);
+        assert_eq!(node_lock.load().unwrap().block_height(), block_height);
+    }
+}
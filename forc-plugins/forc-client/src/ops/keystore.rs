@@ -0,0 +1,252 @@
+//! Signing keys stored in an encrypted local keystore.
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::Args;
+use eth_keystore::KeystoreError;
+use forc_util::user_forc_directory;
+use fuel_gql_client::fuel_crypto::SecretKey;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use tracing::warn;
+
+/// The environment variable from which the password of a keystore account is read, if set.
+pub const KEYSTORE_PASSWORD_ENV: &str = "FORC_KEYSTORE_PASSWORD";
+
+/// The default location of the keystore, `~/.forc/keystore`.
+pub fn default_keystore_dir() -> PathBuf {
+    user_forc_directory().join("keystore")
+}
+
+/// A directory of accounts, each of which is a secret key encrypted with a password in the Web3
+/// Secret Storage format and named by its file name.
+///
+/// Accounts are indexed in the order of their names.
+pub struct Keystore {
+    dir: PathBuf,
+}
+
+impl Keystore {
+    /// Open the keystore at the given directory, or at the default location if `None`.
+    pub fn open(dir: Option<&Path>) -> Self {
+        let dir = dir
+            .map(Path::to_path_buf)
+            .unwrap_or_else(default_keystore_dir);
+        Self { dir }
+    }
+
+    /// The names of the accounts in the keystore, in the order of their indices.
+    pub fn accounts(&self) -> Result<Vec<String>> {
+        let entries = fs::read_dir(&self.dir)
+            .with_context(|| format!("failed to read keystore {}", self.dir.display()))?;
+        let mut names = vec![];
+        for entry in entries {
+            let entry = entry?;
+            if entry.file_type()?.is_file() {
+                names.push(entry.file_name().to_string_lossy().into_owned());
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+
+    /// Unlock the account with the given name or index, reading its password from the
+    /// `FORC_KEYSTORE_PASSWORD` environment variable or otherwise prompting for it.
+    pub fn unlock(&self, account: &str) -> Result<SecretKey> {
+        let name = self.account_name(account)?;
+        let password = match std::env::var(KEYSTORE_PASSWORD_ENV) {
+            Ok(password) => password,
+            Err(_) => {
+                prompt_password(&format!("Please enter the password for account `{name}`: "))?
+            }
+        };
+        self.unlock_with_password(&name, &password)
+    }
+
+    /// Decrypt the secret key of the account with the given name.
+    pub fn unlock_with_password(&self, name: &str, password: &str) -> Result<SecretKey> {
+        let bytes =
+            eth_keystore::decrypt_key(self.dir.join(name), password).map_err(|e| match e {
+                KeystoreError::MacMismatch => anyhow!("incorrect password for account `{name}`"),
+                e => anyhow!("failed to decrypt account `{name}`: {e}"),
+            })?;
+        SecretKey::try_from(&bytes[..])
+            .map_err(|e| anyhow!("account `{name}` does not hold a valid secret key: {e}"))
+    }
+
+    /// Resolve an account given by name or by index to its name.
+    fn account_name(&self, account: &str) -> Result<String> {
+        let accounts = self.accounts()?;
+        if accounts.iter().any(|name| name == account) {
+            return Ok(account.to_string());
+        }
+        if let Some(name) = account
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| accounts.get(index))
+        {
+            return Ok(name.clone());
+        }
+        if accounts.is_empty() {
+            bail!("keystore {} contains no accounts", self.dir.display());
+        }
+        let available: Vec<_> = accounts
+            .iter()
+            .enumerate()
+            .map(|(index, name)| format!("{index}: {name}"))
+            .collect();
+        bail!(
+            "no account `{}` in keystore {}, available accounts are:\n{}",
+            account,
+            self.dir.display(),
+            available.join("\n")
+        )
+    }
+}
+
+/// Select the key with which to sign a transaction: either the given `signing_key`, or that of the
+/// given `account` within the keystore at `keystore_dir`.
+pub fn select_signing_key(
+    signing_key: Option<SecretKey>,
+    account: Option<&str>,
+    keystore_dir: Option<&Path>,
+) -> Result<Option<SecretKey>> {
+    match account {
+        Some(account) => Keystore::open(keystore_dir).unlock(account).map(Some),
+        None => Ok(signing_key),
+    }
+}
+
+/// The options selecting how a transaction sent to a node is signed.
+///
+/// The in-process VM used with `--local` does not verify signatures or coin inputs, so these
+/// options conflict with `--local`.
+#[derive(Debug, Default, Args)]
+pub struct Signing {
+    /// Do not sign the transaction
+    #[clap(long, conflicts_with = "local")]
+    pub unsigned: bool,
+    /// Set the key to be used for signing.
+    ///
+    /// Prefer `--account`, as keys given on the command line may be recorded in shell history.
+    #[clap(long, conflicts_with = "local")]
+    pub signing_key: Option<SecretKey>,
+    /// Sign the transaction with the account of the given name or index in the keystore.
+    ///
+    /// The account's password is read from the `FORC_KEYSTORE_PASSWORD` environment variable, or
+    /// is otherwise prompted for.
+    #[clap(long, conflicts_with_all = &["signing-key", "unsigned", "local"])]
+    pub account: Option<String>,
+    /// The keystore directory containing the account used for signing.
+    ///
+    /// By default, this is `~/.forc/keystore`.
+    #[clap(long, requires = "account")]
+    pub keystore: Option<PathBuf>,
+}
+
+impl Signing {
+    /// The key with which to sign the transaction, unlocking the selected account if any.
+    pub fn signing_key(&self) -> Result<Option<SecretKey>> {
+        select_signing_key(
+            self.signing_key,
+            self.account.as_deref(),
+            self.keystore.as_deref(),
+        )
+    }
+
+    /// The same as [Signing::signing_key], but preferring a key given as the deprecated positional
+    /// argument of `forc run` and `forc deploy`.
+    pub fn signing_key_or_positional(
+        &self,
+        positional_signing_key: Option<SecretKey>,
+    ) -> Result<Option<SecretKey>> {
+        match positional_signing_key {
+            Some(signing_key) => {
+                warn!(
+                    "passing the signing key as a positional argument is deprecated, \
+                    use `--signing-key` instead"
+                );
+                Ok(Some(signing_key))
+            }
+            None => self.signing_key(),
+        }
+    }
+}
+
+/// Prompt for a password on the terminal, without echoing it.
+fn prompt_password(prompt: &str) -> Result<String> {
+    rpassword::prompt_password(prompt).context("failed to read password")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PASSWORD: &str = "correct horse battery staple";
+
+    /// A fresh keystore at a temporary directory holding an empty account for each given name.
+    ///
+    /// Suffices for tests that do not unlock accounts, avoiding the cost of encrypting keys.
+    fn keystore(dir_name: &str, names: &[&str]) -> Keystore {
+        let dir = std::env::temp_dir()
+            .join("forc-client-test-keystore")
+            .join(dir_name);
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        for name in names {
+            fs::write(dir.join(name), "").unwrap();
+        }
+        Keystore::open(Some(&dir))
+    }
+
+    /// Encrypt the given secret key as the account of the given name.
+    fn add_account(keystore: &Keystore, name: &str, secret: [u8; 32]) {
+        let mut rng = rand::thread_rng();
+        let file_name =
+            eth_keystore::encrypt_key(&keystore.dir, &mut rng, secret, PASSWORD).unwrap();
+        fs::rename(keystore.dir.join(file_name), keystore.dir.join(name)).unwrap();
+    }
+
+    #[test]
+    fn test_account_name() {
+        let keystore = keystore("account-name", &["bob", "alice", "2"]);
+        assert_eq!(keystore.accounts().unwrap(), ["2", "alice", "bob"]);
+        assert_eq!(keystore.account_name("bob").unwrap(), "bob");
+        assert_eq!(keystore.account_name("0").unwrap(), "2");
+        assert_eq!(keystore.account_name("1").unwrap(), "alice");
+        // Names take precedence over indices.
+        assert_eq!(keystore.account_name("2").unwrap(), "2");
+        let err = keystore.account_name("carol").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "no account `carol` in keystore {}, available accounts are:\n0: 2\n1: alice\n2: bob",
+                keystore.dir.display()
+            )
+        );
+    }
+
+    #[test]
+    fn test_account_name_empty_keystore() {
+        let keystore = keystore("empty", &[]);
+        let err = keystore.account_name("0").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("keystore {} contains no accounts", keystore.dir.display())
+        );
+    }
+
+    #[test]
+    fn test_unlock_with_password() {
+        let keystore = keystore("unlock", &[]);
+        add_account(&keystore, "alice", [1; 32]);
+        add_account(&keystore, "bob", [2; 32]);
+        let secret_key = keystore.unlock_with_password("bob", PASSWORD).unwrap();
+        assert_eq!(secret_key, SecretKey::try_from(&[2; 32][..]).unwrap());
+        let err = keystore
+            .unlock_with_password("alice", "wrong password")
+            .unwrap_err();
+        assert_eq!(err.to_string(), "incorrect password for account `alice`");
+    }
+}
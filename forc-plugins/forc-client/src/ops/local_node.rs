@@ -0,0 +1,614 @@
+//! An in-process VM that stands in for a node, with its state persisted on disk between
+//! invocations of `forc deploy --local` and `forc run --local`.
+
+use anyhow::{anyhow, bail, Result};
+use forc_util::user_forc_directory;
+use fuel_tx::{
+    Address, AssetId, Bytes32, ConsensusParameters, Contract, ContractId, IntoChecked, Receipt,
+    Salt, Transaction, Word,
+};
+use fuel_vm::fuel_storage::{MerkleRoot, MerkleRootStorage, StorageInspect, StorageMutate};
+use fuel_vm::{
+    crypto::ephemeral_merkle_root,
+    interpreter::{CheckedMetadata, ExecutableTransaction, Interpreter},
+    prelude::{Hasher, Infallible, InterpreterStorage},
+    storage::{ContractsAssets, ContractsInfo, ContractsRawCode, ContractsState},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    fs::{self, File},
+    path::{Path, PathBuf},
+};
+
+/// The TAI64 timestamp of the block at height 0, i.e. the Unix epoch.
+const GENESIS_TIMESTAMP: Word = (1 << 62) + 10;
+
+/// The number of seconds between consecutive blocks.
+const BLOCK_INTERVAL: Word = 10;
+
+/// The file in which the state of the local node is persisted unless another is specified.
+pub fn default_state_path() -> PathBuf {
+    user_forc_directory().join("local").join("state.json")
+}
+
+/// An advisory lock over the persisted state of a local node.
+///
+/// A [LocalNode] is loaded through the lock and holds it until it is dropped, so that concurrent
+/// invocations of `forc deploy --local` and `forc run --local` cannot overwrite each other's
+/// blocks.
+#[derive(Debug)]
+pub struct LocalNodeLock {
+    path: PathBuf,
+    lock: fd_lock::RwLock<File>,
+}
+
+/// A node executing transactions against an in-process VM.
+///
+/// Each executed transaction is included in a block of its own. Transactions are checked against
+/// the default `ConsensusParameters`, but the node does not track coins or messages: inputs
+/// spending them are assumed to exist and their signatures and predicates are not verified.
+#[derive(Debug)]
+pub struct LocalNode<'lock> {
+    path: &'lock Path,
+    storage: LocalStorage,
+    _guard: fd_lock::RwLockWriteGuard<'lock, File>,
+}
+
+/// The chain state of a `LocalNode`, which is what is persisted to disk.
+///
+/// Mirrors the VM's `MemoryStorage`, but may be serialized.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+struct LocalStorage {
+    /// The height of the latest block.
+    block_height: u32,
+    contracts: BTreeMap<ContractId, Contract>,
+    contract_infos: BTreeMap<ContractId, (Salt, Bytes32)>,
+    balances: BTreeMap<ContractId, BTreeMap<AssetId, Word>>,
+    contract_state: BTreeMap<ContractId, BTreeMap<Bytes32, Bytes32>>,
+}
+
+impl LocalNodeLock {
+    /// Create the lock over the state persisted at the given path, or at the default path if none
+    /// is given.
+    ///
+    /// The lock file is created alongside the state file.
+    pub fn new(path: Option<&Path>) -> Result<Self> {
+        let path = path
+            .map(Path::to_path_buf)
+            .unwrap_or_else(default_state_path);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut lock_path = path.clone().into_os_string();
+        lock_path.push(".lock");
+        let lock_file = File::create(&lock_path).map_err(|e| {
+            anyhow!(
+                "failed to create the local node lock file {}: {}",
+                Path::new(&lock_path).display(),
+                e
+            )
+        })?;
+        let lock = fd_lock::RwLock::new(lock_file);
+        Ok(Self { path, lock })
+    }
+
+    /// Wait for exclusive access to the local node, then load its state.
+    ///
+    /// If no state has been persisted yet, the node starts from an empty chain.
+    pub fn load(&mut self) -> Result<LocalNode<'_>> {
+        let guard = self.lock.write()?;
+        let path = self.path.as_path();
+        let storage = if path.exists() {
+            let contents = fs::read_to_string(path)?;
+            serde_json::from_str(&contents).map_err(|e| {
+                anyhow!(
+                    "failed to parse the local node state at {}: {}",
+                    path.display(),
+                    e
+                )
+            })?
+        } else {
+            LocalStorage::default()
+        };
+        Ok(LocalNode {
+            path,
+            storage,
+            _guard: guard,
+        })
+    }
+}
+
+impl LocalNode<'_> {
+    /// The height of the latest block.
+    pub fn block_height(&self) -> u32 {
+        self.storage.block_height
+    }
+
+    /// Whether or not the contract with the given ID has been deployed.
+    pub fn contract_exists(&self, contract_id: &ContractId) -> bool {
+        self.storage.contracts.contains_key(contract_id)
+    }
+
+    /// Execute the given transaction in a new block and return its receipts.
+    ///
+    /// The new block is persisted along with any changes to storage made by the transaction,
+    /// unless the transaction reverted. If `simulate` is set, nothing is persisted.
+    ///
+    /// Only the basic validity of the transaction is checked, see [LocalNode].
+    pub fn transact(&mut self, tx: Transaction, simulate: bool) -> Result<Vec<Receipt>> {
+        match tx {
+            Transaction::Script(script) => self.execute(script, simulate),
+            Transaction::Create(create) => self.execute(create, simulate),
+            Transaction::Mint(_) => bail!("mint transactions cannot be executed"),
+        }
+    }
+
+    fn execute<Tx>(&mut self, tx: Tx, simulate: bool) -> Result<Vec<Receipt>>
+    where
+        Tx: ExecutableTransaction + IntoChecked,
+        <Tx as IntoChecked>::Metadata: CheckedMetadata,
+    {
+        let params = ConsensusParameters::default();
+        let mut storage = self.storage.clone();
+        storage.block_height += 1;
+        let block_height = storage.block_height;
+        let tx = tx
+            .into_checked_basic(Word::from(block_height), &params)
+            .map_err(|e| anyhow!("invalid transaction: {:?}", e))?;
+        let mut interpreter = Interpreter::with_storage(storage, params);
+        let transition = interpreter
+            .transact(tx)
+            .map_err(|e| anyhow!("failed to execute transaction: {}", e))?;
+        let should_revert = transition.should_revert();
+        let receipts = transition.receipts().to_vec();
+        if !simulate {
+            if should_revert {
+                self.storage.block_height = block_height;
+            } else {
+                self.storage = interpreter.as_ref().clone();
+            }
+            self.save()?;
+        }
+        Ok(receipts)
+    }
+
+    fn save(&self) -> Result<()> {
+        fs::write(self.path, serde_json::to_string(&self.storage)?)?;
+        Ok(())
+    }
+}
+
+impl StorageInspect<ContractsRawCode> for LocalStorage {
+    type Error = Infallible;
+
+    fn get(&self, key: &ContractId) -> Result<Option<Cow<'_, Contract>>, Infallible> {
+        Ok(self.contracts.get(key).map(Cow::Borrowed))
+    }
+
+    fn contains_key(&self, key: &ContractId) -> Result<bool, Infallible> {
+        Ok(self.contracts.contains_key(key))
+    }
+}
+
+impl StorageMutate<ContractsRawCode> for LocalStorage {
+    fn insert(&mut self, key: &ContractId, value: &[u8]) -> Result<Option<Contract>, Infallible> {
+        Ok(self.contracts.insert(*key, value.into()))
+    }
+
+    fn remove(&mut self, key: &ContractId) -> Result<Option<Contract>, Infallible> {
+        Ok(self.contracts.remove(key))
+    }
+}
+
+impl StorageInspect<ContractsInfo> for LocalStorage {
+    type Error = Infallible;
+
+    fn get(&self, key: &ContractId) -> Result<Option<Cow<'_, (Salt, Bytes32)>>, Infallible> {
+        Ok(self.contract_infos.get(key).map(Cow::Borrowed))
+    }
+
+    fn contains_key(&self, key: &ContractId) -> Result<bool, Infallible> {
+        Ok(self.contract_infos.contains_key(key))
+    }
+}
+
+impl StorageMutate<ContractsInfo> for LocalStorage {
+    fn insert(
+        &mut self,
+        key: &ContractId,
+        value: &(Salt, Bytes32),
+    ) -> Result<Option<(Salt, Bytes32)>, Infallible> {
+        Ok(self.contract_infos.insert(*key, *value))
+    }
+
+    fn remove(&mut self, key: &ContractId) -> Result<Option<(Salt, Bytes32)>, Infallible> {
+        Ok(self.contract_infos.remove(key))
+    }
+}
+
+impl StorageInspect<ContractsAssets<'_>> for LocalStorage {
+    type Error = Infallible;
+
+    fn get(&self, key: &(&ContractId, &AssetId)) -> Result<Option<Cow<'_, Word>>, Infallible> {
+        let balance = self
+            .balances
+            .get(key.0)
+            .and_then(|balances| balances.get(key.1));
+        Ok(balance.map(Cow::Borrowed))
+    }
+
+    fn contains_key(&self, key: &(&ContractId, &AssetId)) -> Result<bool, Infallible> {
+        Ok(self
+            .balances
+            .get(key.0)
+            .map(|balances| balances.contains_key(key.1))
+            .unwrap_or(false))
+    }
+}
+
+impl StorageMutate<ContractsAssets<'_>> for LocalStorage {
+    fn insert(
+        &mut self,
+        key: &(&ContractId, &AssetId),
+        value: &Word,
+    ) -> Result<Option<Word>, Infallible> {
+        Ok(self
+            .balances
+            .entry(*key.0)
+            .or_default()
+            .insert(*key.1, *value))
+    }
+
+    fn remove(&mut self, key: &(&ContractId, &AssetId)) -> Result<Option<Word>, Infallible> {
+        Ok(self
+            .balances
+            .get_mut(key.0)
+            .and_then(|balances| balances.remove(key.1)))
+    }
+}
+
+impl MerkleRootStorage<ContractId, ContractsAssets<'_>> for LocalStorage {
+    fn root(&mut self, parent: &ContractId) -> Result<MerkleRoot, Infallible> {
+        // Balances are ordered by asset ID, as they are within the VM's `MemoryStorage`.
+        let balances: Vec<_> = self
+            .balances
+            .get(parent)
+            .into_iter()
+            .flat_map(|balances| balances.values().map(|balance| balance.to_be_bytes()))
+            .collect();
+        Ok(ephemeral_merkle_root(balances.iter()).into())
+    }
+}
+
+impl StorageInspect<ContractsState<'_>> for LocalStorage {
+    type Error = Infallible;
+
+    fn get(&self, key: &(&ContractId, &Bytes32)) -> Result<Option<Cow<'_, Bytes32>>, Infallible> {
+        let value = self
+            .contract_state
+            .get(key.0)
+            .and_then(|state| state.get(key.1));
+        Ok(value.map(Cow::Borrowed))
+    }
+
+    fn contains_key(&self, key: &(&ContractId, &Bytes32)) -> Result<bool, Infallible> {
+        Ok(self
+            .contract_state
+            .get(key.0)
+            .map(|state| state.contains_key(key.1))
+            .unwrap_or(false))
+    }
+}
+
+impl StorageMutate<ContractsState<'_>> for LocalStorage {
+    fn insert(
+        &mut self,
+        key: &(&ContractId, &Bytes32),
+        value: &Bytes32,
+    ) -> Result<Option<Bytes32>, Infallible> {
+        Ok(self
+            .contract_state
+            .entry(*key.0)
+            .or_default()
+            .insert(*key.1, *value))
+    }
+
+    fn remove(&mut self, key: &(&ContractId, &Bytes32)) -> Result<Option<Bytes32>, Infallible> {
+        Ok(self
+            .contract_state
+            .get_mut(key.0)
+            .and_then(|state| state.remove(key.1)))
+    }
+}
+
+impl MerkleRootStorage<ContractId, ContractsState<'_>> for LocalStorage {
+    fn root(&mut self, parent: &ContractId) -> Result<MerkleRoot, Infallible> {
+        // Values are ordered by key, as they are within the VM's `MemoryStorage`.
+        let values: Vec<_> = self
+            .contract_state
+            .get(parent)
+            .into_iter()
+            .flat_map(|state| state.values())
+            .collect();
+        Ok(ephemeral_merkle_root(values.iter()).into())
+    }
+}
+
+impl InterpreterStorage for LocalStorage {
+    type DataError = Infallible;
+
+    fn block_height(&self) -> Result<u32, Infallible> {
+        Ok(self.block_height)
+    }
+
+    fn timestamp(&self, height: u32) -> Result<Word, Infallible> {
+        Ok(GENESIS_TIMESTAMP + Word::from(height) * BLOCK_INTERVAL)
+    }
+
+    fn block_hash(&self, block_height: u32) -> Result<Bytes32, Infallible> {
+        Ok(Hasher::hash(block_height.to_be_bytes()))
+    }
+
+    fn coinbase(&self) -> Result<Address, Infallible> {
+        Ok(Address::zeroed())
+    }
+
+    fn merkle_contract_state_range(
+        &self,
+        id: &ContractId,
+        start_key: &Bytes32,
+        range: Word,
+    ) -> Result<Vec<Option<Cow<'_, Bytes32>>>, Infallible> {
+        let state = self.contract_state.get(id);
+        Ok(key_range(start_key, range)
+            .map(|key| state.and_then(|state| state.get(&key)).map(Cow::Borrowed))
+            .collect())
+    }
+
+    fn merkle_contract_state_insert_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        values: &[Bytes32],
+    ) -> Result<Option<()>, Infallible> {
+        let state = self.contract_state.entry(*contract).or_default();
+        let mut all_set = true;
+        for (key, value) in key_range(start_key, values.len() as Word).zip(values) {
+            all_set &= state.insert(key, *value).is_some();
+        }
+        Ok(all_set.then_some(()))
+    }
+
+    fn merkle_contract_state_remove_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        range: Word,
+    ) -> Result<Option<()>, Infallible> {
+        let state = self.contract_state.entry(*contract).or_default();
+        let mut all_set = true;
+        for key in key_range(start_key, range) {
+            all_set &= state.remove(&key).is_some();
+        }
+        Ok(all_set.then_some(()))
+    }
+}
+
+/// Up to `count` consecutive storage keys, starting at `start_key` and ending early at the
+/// largest possible key.
+fn key_range(start_key: &Bytes32, count: Word) -> impl Iterator<Item = Bytes32> {
+    std::iter::successors(Some(*start_key), |key| {
+        // Increment the key as a big-endian integer.
+        let mut next = **key;
+        for byte in next.iter_mut().rev() {
+            let (incremented, overflowed) = byte.overflowing_add(1);
+            *byte = incremented;
+            if !overflowed {
+                return Some(Bytes32::from(next));
+            }
+        }
+        None
+    })
+    .take(count as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_tx::{Input, Output, TransactionBuilder, TxPointer, UtxoId, Witness};
+    use fuel_vm::consts::{REG_CGAS, REG_FP, REG_ONE, REG_ZERO};
+    use fuel_vm::prelude::{GTFArgs, Opcode};
+
+    /// A path at which no local node state has been persisted yet.
+    fn state_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir()
+            .join("forc-client-test-local-node")
+            .join(format!("{name}.json"));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    fn bytecode(ops: &[Opcode]) -> Vec<u8> {
+        ops.iter()
+            .flat_map(|op| u32::from(*op).to_be_bytes())
+            .collect()
+    }
+
+    /// A transaction deploying a contract which writes `value` to the storage slot keyed by its
+    /// own ID, then either returns or reverts.
+    fn create_contract(value: u32, revert: bool) -> (Transaction, ContractId) {
+        let end = match revert {
+            true => Opcode::RVRT(REG_ONE),
+            false => Opcode::RET(REG_ONE),
+        };
+        let code = bytecode(&[
+            Opcode::MOVI(0x10, value),
+            Opcode::SWW(REG_FP, 0x11, 0x10),
+            end,
+        ]);
+        let contract = Contract::from(code.clone());
+        let state_root = Contract::default_state_root();
+        let contract_id = contract.id(&Salt::zeroed(), &contract.root(), &state_root);
+        let tx = TransactionBuilder::create(Witness::from(code), Salt::zeroed(), vec![])
+            .add_output(Output::contract_created(contract_id, state_root))
+            .finalize_without_signature_as_transaction();
+        (tx, contract_id)
+    }
+
+    /// A transaction calling the given contract.
+    fn call_contract(contract_id: ContractId) -> Transaction {
+        let script = bytecode(&[
+            Opcode::GTF(0x10, REG_ZERO, GTFArgs::ScriptData as u16),
+            Opcode::CALL(0x10, REG_ZERO, REG_ZERO, REG_CGAS),
+            Opcode::RET(REG_ONE),
+        ]);
+        let script_data = contract_id
+            .iter()
+            .copied()
+            .chain([0u8; 16])
+            .collect::<Vec<_>>();
+        TransactionBuilder::script(script, script_data)
+            .gas_limit(ConsensusParameters::DEFAULT.max_gas_per_tx)
+            .add_input(Input::Contract {
+                utxo_id: UtxoId::default(),
+                balance_root: Bytes32::zeroed(),
+                state_root: Bytes32::zeroed(),
+                tx_pointer: TxPointer::default(),
+                contract_id,
+            })
+            .add_output(Output::contract(0, Bytes32::zeroed(), Bytes32::zeroed()))
+            .finalize_without_signature_as_transaction()
+    }
+
+    /// The value the test contract with the given ID wrote to storage, if any.
+    fn stored_value(node: &LocalNode<'_>, contract_id: &ContractId) -> Option<Word> {
+        let key = Bytes32::from(**contract_id);
+        let value = node.storage.contract_state.get(contract_id)?.get(&key)?;
+        Some(Word::from_be_bytes(value[..8].try_into().unwrap()))
+    }
+
+    #[test]
+    fn test_state_persists_across_reload() {
+        let path = state_path("persists");
+        let (create, contract_id) = create_contract(42, false);
+        let mut node_lock = LocalNodeLock::new(Some(&path)).unwrap();
+        let mut node = node_lock.load().unwrap();
+        assert_eq!(node.block_height(), 0);
+        node.transact(create, false).unwrap();
+        assert!(node.contract_exists(&contract_id));
+        drop(node);
+
+        let mut node = node_lock.load().unwrap();
+        assert_eq!(node.block_height(), 1);
+        assert!(node.contract_exists(&contract_id));
+        let receipts = node.transact(call_contract(contract_id), false).unwrap();
+        assert!(receipts
+            .iter()
+            .all(|r| !matches!(r, Receipt::Revert { .. })));
+        drop(node);
+
+        let node = node_lock.load().unwrap();
+        assert_eq!(node.block_height(), 2);
+        assert_eq!(stored_value(&node, &contract_id), Some(42));
+    }
+
+    #[test]
+    fn test_simulate_does_not_persist() {
+        let path = state_path("simulate");
+        let (create, contract_id) = create_contract(42, false);
+        let mut node_lock = LocalNodeLock::new(Some(&path)).unwrap();
+        let mut node = node_lock.load().unwrap();
+        node.transact(create, true).unwrap();
+        assert!(!node.contract_exists(&contract_id));
+        assert_eq!(node.block_height(), 0);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_reverted_tx_keeps_storage_and_advances_block() {
+        let path = state_path("revert");
+        let (create, contract_id) = create_contract(42, true);
+        let mut node_lock = LocalNodeLock::new(Some(&path)).unwrap();
+        let mut node = node_lock.load().unwrap();
+        node.transact(create, false).unwrap();
+        let receipts = node.transact(call_contract(contract_id), false).unwrap();
+        assert!(receipts.iter().any(|r| matches!(r, Receipt::Revert { .. })));
+        drop(node);
+
+        let node = node_lock.load().unwrap();
+        assert_eq!(node.block_height(), 2);
+        assert!(node.contract_exists(&contract_id));
+        assert_eq!(stored_value(&node, &contract_id), None);
+    }
+
+    #[test]
+    fn test_concurrent_loads_are_serialized() {
+        let path = state_path("concurrent");
+        let (create, contract_id) = create_contract(42, false);
+        let mut node_lock = LocalNodeLock::new(Some(&path)).unwrap();
+        let mut node = node_lock.load().unwrap();
+
+        // The other node may only be loaded once this one, and the block it adds, is dropped.
+        let other = {
+            let path = path.clone();
+            std::thread::spawn(move || {
+                let mut node_lock = LocalNodeLock::new(Some(&path)).unwrap();
+                let node = node_lock.load().unwrap();
+                (node.block_height(), node.contract_exists(&contract_id))
+            })
+        };
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        node.transact(create, false).unwrap();
+        drop(node);
+
+        assert_eq!(other.join().unwrap(), (1, true));
+    }
+
+    #[test]
+    fn test_key_range() {
+        let start = Bytes32::from([0xff; 32]);
+        let mut key = [0xff; 32];
+        key[31] = 0xfe;
+        let keys: Vec<_> = key_range(&Bytes32::from(key), 5).collect();
+        assert_eq!(keys, vec![Bytes32::from(key), start]);
+
+        let keys: Vec<_> = key_range(&Bytes32::zeroed(), 3).collect();
+        let mut expected = [0u8; 32];
+        assert_eq!(keys[0], Bytes32::from(expected));
+        expected[31] = 2;
+        assert_eq!(keys[2], Bytes32::from(expected));
+        assert_eq!(key_range(&start, 0).count(), 0);
+    }
+
+    #[test]
+    fn test_merkle_contract_state_ranges() {
+        let contract_id = ContractId::zeroed();
+        let start = Bytes32::zeroed();
+        let values = [Bytes32::from([1; 32]), Bytes32::from([2; 32])];
+        let mut storage = LocalStorage::default();
+
+        // None of the slots were set before the insertion, then all of them were.
+        let inserted = storage.merkle_contract_state_insert_range(&contract_id, &start, &values);
+        assert_eq!(inserted.unwrap(), None);
+        let inserted = storage.merkle_contract_state_insert_range(&contract_id, &start, &values);
+        assert_eq!(inserted.unwrap(), Some(()));
+
+        let range = storage
+            .merkle_contract_state_range(&contract_id, &start, 3)
+            .unwrap();
+        let range: Vec<_> = range.into_iter().map(|v| v.map(Cow::into_owned)).collect();
+        assert_eq!(range, vec![Some(values[0]), Some(values[1]), None]);
+
+        // The third slot was not set, but the first two are removed regardless.
+        let removed = storage.merkle_contract_state_remove_range(&contract_id, &start, 3);
+        assert_eq!(removed.unwrap(), None);
+        let range = storage
+            .merkle_contract_state_range(&contract_id, &start, 2)
+            .unwrap();
+        assert!(range.iter().all(Option::is_none));
+        let removed = storage.merkle_contract_state_remove_range(&contract_id, &start, 0);
+        assert_eq!(removed.unwrap(), Some(()));
+    }
+}
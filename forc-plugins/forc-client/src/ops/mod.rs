@@ -1,4 +1,11 @@
+pub mod call;
 pub mod deploy;
+pub mod keystore;
+pub mod local_node;
 pub mod pkg_util;
+pub mod predicate;
+pub mod receipt_util;
 pub mod run;
+#[cfg(test)]
+pub(crate) mod test_util;
 pub mod tx_util;
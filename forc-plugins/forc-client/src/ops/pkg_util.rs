@@ -1,13 +1,33 @@
+use std::collections::HashSet;
 use std::path::Path;
 
 use anyhow::Result;
 use forc_pkg::{self as pkg, manifest::ManifestFile, BuildOpts, BuildPlan};
-use pkg::{build_with_options, BuiltPackage, PackageManifestFile};
+use pkg::{build_with_options, BuiltPackage, NodeIx, PackageManifestFile};
+
+/// A built member of a workspace, along with its node in the build plan and its manifest.
+pub(crate) type BuiltMember = (NodeIx, PackageManifestFile, BuiltPackage);
 
 pub(crate) fn built_pkgs_with_manifest(
     path: &Path,
     build_opts: BuildOpts,
 ) -> Result<Vec<(PackageManifestFile, BuiltPackage)>> {
+    let (_, built_pkgs) = built_pkgs_with_plan(path, build_opts)?;
+    Ok(built_pkgs
+        .into_iter()
+        .map(|(_, member_manifest, built_pkg)| (member_manifest, built_pkg))
+        .collect())
+}
+
+/// Builds the package or workspace at `path`, producing each built member in order of
+/// compilation, along with its node in the returned build plan.
+///
+/// As members are compiled after their dependencies, any contract dependencies of a member precede
+/// it.
+pub(crate) fn built_pkgs_with_plan(
+    path: &Path,
+    build_opts: BuildOpts,
+) -> Result<(BuildPlan, Vec<BuiltMember>)> {
     let manifest_file = ManifestFile::from_dir(path)?;
     let mut member_manifests = manifest_file.member_manifests()?;
     let lock_path = manifest_file.lock_path()?;
@@ -19,8 +39,12 @@ pub(crate) fn built_pkgs_with_manifest(
     )?;
     let graph = build_plan.graph();
     let mut built_pkgs = build_with_options(build_opts)?.into_members()?;
+    let member_nodes: HashSet<NodeIx> = build_plan.member_nodes().collect();
     let mut pkgs_with_manifest = Vec::new();
-    for member_index in build_plan.member_nodes() {
+    for &member_index in build_plan.compilation_order() {
+        if !member_nodes.contains(&member_index) {
+            continue;
+        }
         let pkg_name = &graph[member_index].name;
         // Check if the currrent member is built.
         //
@@ -30,8 +54,8 @@ pub(crate) fn built_pkgs_with_manifest(
             let member_manifest = member_manifests
                 .remove(pkg_name)
                 .expect("Member manifest file is missing");
-            pkgs_with_manifest.push((member_manifest, built_pkg));
+            pkgs_with_manifest.push((member_index, member_manifest, built_pkg));
         }
     }
-    Ok(pkgs_with_manifest)
+    Ok((build_plan, pkgs_with_manifest))
 }
@@ -0,0 +1,81 @@
+use clap::Parser;
+
+/// Build a predicate project.
+/// Prints the predicate's root along with its encoded predicate data, optionally producing a coin
+/// input that spends from the predicate for use with `forc tx`.
+#[derive(Debug, Default, Parser)]
+#[clap(bin_name = "forc predicate", version)]
+pub struct PredicateCommand {
+    /// Arguments to the predicate's `main` function, encoded as the predicate data.
+    ///
+    /// Each argument is parsed according to the type of its parameter, e.g. `42`, `true`, a hex
+    /// string for a `b256`, `(1, true)` for a struct or tuple and `[1, 2]` for an array.
+    pub args: Vec<String>,
+
+    /// Hex string of raw predicate data. Cannot be used alongside typed arguments.
+    #[clap(short, long, conflicts_with = "args")]
+    pub data: Option<String>,
+
+    /// Path to the project, if not specified, current working directory will be used.
+    #[clap(short, long)]
+    pub path: Option<String>,
+
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    /// Meaning it will only try to use previously downloaded dependencies.
+    #[clap(long = "offline")]
+    pub offline_mode: bool,
+
+    /// Terse mode. Limited warning and error output.
+    #[clap(long = "terse", short = 't')]
+    pub terse_mode: bool,
+
+    /// The directory in which the sway compiler output artifacts are placed.
+    ///
+    /// By default, this is `<project-root>/out`.
+    #[clap(long)]
+    pub output_directory: Option<String>,
+
+    /// Name of the build profile to use.
+    /// If it is not specified, forc will use debug build profile.
+    #[clap(long)]
+    pub build_profile: Option<String>,
+
+    /// Use release build plan. If a custom release plan is not specified, it is implicitly added to the manifest file.
+    ///
+    /// If --build-profile is also provided, forc omits this flag and uses provided build-profile.
+    #[clap(long)]
+    pub release: bool,
+
+    /// Requires that the Forc.lock file is up-to-date. If the lock file is missing, or it
+    /// needs to be updated, Forc will exit with an error
+    #[clap(long)]
+    pub locked: bool,
+
+    #[clap(flatten)]
+    pub coin: Coin,
+}
+
+/// The coin owned by the predicate that is to be spent.
+///
+/// If a UTXO ID is provided, the `forc tx` arguments for a coin input spending it are printed.
+#[derive(Debug, Default, Parser)]
+pub struct Coin {
+    /// Hash of the unspent transaction that created the coin, followed by its output index.
+    #[clap(long, requires = "amount")]
+    pub utxo_id: Option<fuel_tx::UtxoId>,
+    /// Index of transaction output.
+    #[clap(long, default_value = "0")]
+    pub output_ix: u8,
+    /// Amount of coins.
+    #[clap(long)]
+    pub amount: Option<u64>,
+    /// Asset ID of the coins. Defaults to the base asset.
+    #[clap(long)]
+    pub asset_id: Option<fuel_tx::AssetId>,
+    /// Points to the TX whose output is being spent. Includes block height, tx index.
+    #[clap(long)]
+    pub tx_ptr: Option<fuel_tx::TxPointer>,
+    /// UTXO being spent must have been created at least this many blocks ago.
+    #[clap(long, default_value = "0")]
+    pub maturity: u32,
+}
@@ -0,0 +1,226 @@
+use anyhow::{anyhow, bail, Result};
+use forc_pkg::{self as pkg, BuildProfile, PackageManifestFile};
+use forc_util::default_output_directory;
+use pkg::BuiltPackage;
+use std::path::PathBuf;
+use sway_core::language::parsed::TreeType;
+use sway_core::{asm_generation::ProgramABI, BuildTarget};
+use tracing::info;
+
+use crate::ops::pkg_util::built_pkgs_with_manifest;
+use forc_util::abi::encode_main_args;
+
+use super::cmd::PredicateCommand;
+
+/// A built predicate along with the data with which it is to be spent.
+pub struct BuiltPredicate {
+    /// The name of the predicate package.
+    pub name: String,
+    /// The predicate's root, which is the address of the coins and messages the predicate owns.
+    pub address: fuel_tx::Address,
+    /// The encoded predicate data.
+    pub data: Vec<u8>,
+    /// The coin input spending from the predicate, if a coin was specified.
+    pub input: Option<forc_tx::Input>,
+}
+
+/// Builds predicate(s). If given path corresponds to a workspace, all predicate members will be
+/// built.
+///
+/// Upon success, returns each predicate along with its encoded data. The data is also written to
+/// `<name>-predicate-data.bin` alongside the predicate's bytecode in the output directory.
+pub fn predicate(command: PredicateCommand) -> Result<Vec<BuiltPredicate>> {
+    let curr_dir = if let Some(path) = &command.path {
+        PathBuf::from(path)
+    } else {
+        std::env::current_dir().map_err(|e| anyhow!("{:?}", e))?
+    };
+    let build_opts = build_opts_from_cmd(&command);
+    let built_pkgs_with_manifest = built_pkgs_with_manifest(&curr_dir, build_opts)?;
+    let mut predicates = Vec::new();
+    for (member_manifest, built_pkg) in built_pkgs_with_manifest {
+        if member_manifest
+            .check_program_type(vec![TreeType::Predicate])
+            .is_ok()
+        {
+            predicates.push(predicate_pkg(&command, &member_manifest, &built_pkg)?);
+        }
+    }
+    if predicates.is_empty() {
+        bail!("no predicate found at {}", curr_dir.display());
+    }
+
+    Ok(predicates)
+}
+
+pub fn predicate_pkg(
+    command: &PredicateCommand,
+    manifest: &PackageManifestFile,
+    compiled: &BuiltPackage,
+) -> Result<BuiltPredicate> {
+    let name = manifest.project.name.clone();
+    let address = fuel_tx::Input::predicate_owner(&compiled.bytecode);
+    let data = match &command.data {
+        Some(input_data) => {
+            let data = input_data.strip_prefix("0x").unwrap_or(input_data);
+            hex::decode(data).map_err(|e| anyhow!("Invalid predicate data: {}", e))?
+        }
+        None => match &compiled.json_abi_program {
+            ProgramABI::Fuel(program_abi) => encode_main_args(program_abi, &command.args)?,
+            ProgramABI::Evm(_) => bail!("typed predicate arguments require the Fuel build target"),
+        },
+    };
+
+    // Write the data next to the bytecode so that both may be passed to `forc tx`.
+    let output_dir = command
+        .output_directory
+        .as_ref()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| default_output_directory(manifest.dir()).join(profile_name(command)));
+    let bytecode_path = output_dir.join(&name).with_extension("bin");
+    let data_path = output_dir.join(format!("{}-predicate-data.bin", name));
+    std::fs::write(&data_path, &data)?;
+
+    info!("  Predicate address: {:#x}", address);
+    info!("  Predicate data: 0x{}", hex::encode(&data));
+
+    let input = match command.coin.utxo_id {
+        None => None,
+        Some(utxo_id) => {
+            let coin = &command.coin;
+            let amount = coin
+                .amount
+                .ok_or_else(|| anyhow!("the amount of the coin must be specified"))?;
+            let input_coin = forc_tx::InputCoin {
+                utxo_id,
+                output_ix: coin.output_ix,
+                owner: address,
+                amount,
+                asset_id: coin.asset_id.unwrap_or_default(),
+                tx_ptr: coin.tx_ptr.unwrap_or_default(),
+                witness_ix: None,
+                maturity: coin.maturity,
+                predicate: forc_tx::Predicate {
+                    bytecode: Some(bytecode_path),
+                    data: Some(data_path),
+                },
+            };
+            info!("  Input: {}", input_coin_args(&input_coin));
+            Some(forc_tx::Input::Coin(input_coin))
+        }
+    };
+
+    Ok(BuiltPredicate {
+        name,
+        address,
+        data,
+        input,
+    })
+}
+
+/// The `forc tx` arguments for the given coin input.
+fn input_coin_args(coin: &forc_tx::InputCoin) -> String {
+    let mut args = format!(
+        "input coin --utxo-id {:#x} --output-ix {} --owner {:#x} --amount {} --asset-id {:#x} \
+        --tx-ptr {:x} --maturity {}",
+        coin.utxo_id,
+        coin.output_ix,
+        coin.owner,
+        coin.amount,
+        coin.asset_id,
+        coin.tx_ptr,
+        coin.maturity
+    );
+    if let Some(bytecode) = &coin.predicate.bytecode {
+        args.push_str(&format!(" --predicate {}", bytecode.display()));
+    }
+    if let Some(data) = &coin.predicate.data {
+        args.push_str(&format!(" --predicate-data {}", data.display()));
+    }
+    args
+}
+
+/// The name of the build profile selected by the command, which names the output directory.
+fn profile_name(command: &PredicateCommand) -> String {
+    match (&command.build_profile, command.release) {
+        (_, true) => BuildProfile::RELEASE.to_string(),
+        (Some(build_profile), false) => build_profile.clone(),
+        (None, false) => BuildProfile::DEBUG.to_string(),
+    }
+}
+
+fn build_opts_from_cmd(cmd: &PredicateCommand) -> pkg::BuildOpts {
+    let inject_map = std::collections::HashMap::new();
+    pkg::BuildOpts {
+        pkg: pkg::PkgOpts {
+            path: cmd.path.clone(),
+            offline: cmd.offline_mode,
+            terse: cmd.terse_mode,
+            locked: cmd.locked,
+            output_directory: cmd.output_directory.clone(),
+        },
+        print: pkg::PrintOpts::default(),
+        minify: pkg::MinifyOpts::default(),
+        build_target: BuildTarget::default(),
+        build_profile: cmd.build_profile.clone(),
+        release: cmd.release,
+        time_phases: false,
+        binary_outfile: None,
+        debug_outfile: None,
+        tests: false,
+        inject_map,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn predicate_args_command(output_dir: &std::path::Path, args: &[&str]) -> PredicateCommand {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test/data/predicate_args");
+        PredicateCommand {
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            path: Some(path.display().to_string()),
+            offline_mode: true,
+            terse_mode: true,
+            output_directory: Some(output_dir.display().to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_predicate_root_and_data() {
+        let output_dir = std::env::temp_dir().join("forc-client-test-predicate-root-and-data");
+        let command = predicate_args_command(&output_dir, &["42", "(7, true)"]);
+        let predicates = predicate(command).unwrap();
+        assert_eq!(predicates.len(), 1);
+        let built = &predicates[0];
+        assert_eq!(built.name, "predicate_args");
+
+        // The root is that of the bytecode written to the output directory.
+        let bytecode = std::fs::read(output_dir.join("predicate_args.bin")).unwrap();
+        assert_eq!(built.address, fuel_tx::Input::predicate_owner(&bytecode));
+
+        // `limit: u64` followed by `point: Point`, each field occupying a word.
+        let mut expected_data = vec![];
+        expected_data.extend(42u64.to_be_bytes());
+        expected_data.extend(7u64.to_be_bytes());
+        expected_data.extend(1u64.to_be_bytes());
+        assert_eq!(built.data, expected_data);
+        let data_path = output_dir.join("predicate_args-predicate-data.bin");
+        assert_eq!(std::fs::read(data_path).unwrap(), expected_data);
+        std::fs::remove_dir_all(&output_dir).unwrap();
+    }
+
+    #[test]
+    fn test_predicate_invalid_args() {
+        let output_dir = std::env::temp_dir().join("forc-client-test-predicate-invalid-args");
+        let command = predicate_args_command(&output_dir, &["42"]);
+        let err = predicate(command).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "`main` takes 2 argument(s) but 1 were provided"
+        );
+        let _ = std::fs::remove_dir_all(&output_dir);
+    }
+}
@@ -0,0 +1,196 @@
+//! Human readable summaries of the receipts produced by executing a transaction.
+
+use anyhow::Result;
+use forc_pkg::BuiltPackage;
+use fuel_tx::{ContractId, Receipt, Word};
+use std::fs;
+use sway_core::{asm_generation::ProgramABI, source_map::SourceMap};
+use sway_types::span::Position;
+use tracing::info;
+
+use forc_util::abi::ReceiptDecoder;
+
+/// The values passed to `revert` by the standard library to signal particular failures, along
+/// with a description of each. These mirror the constants of `std::error_signals`.
+const ERROR_SIGNALS: &[(Word, &str)] = &[
+    (
+        0xffff_ffff_ffff_0000,
+        "FAILED_REQUIRE_SIGNAL: a call to `std::revert::require` failed",
+    ),
+    (
+        0xffff_ffff_ffff_0001,
+        "FAILED_TRANSFER_TO_ADDRESS_SIGNAL: a call to `std::token::transfer_to_address` failed",
+    ),
+    (
+        0xffff_ffff_ffff_0002,
+        "FAILED_SEND_MESSAGE_SIGNAL: a call to `std::message::send_message` failed",
+    ),
+    (
+        0xffff_ffff_ffff_0003,
+        "FAILED_STORAGE_ARRAY_INDEX_SIGNAL: an index into an array in storage was out of bounds",
+    ),
+];
+
+/// Print the values logged and returned by the given package, decoded via its ABI, followed by
+/// the reason and source location of any revert or panic.
+///
+/// `id` is the ID of the package's program as it appears in the receipts, i.e. the contract ID of
+/// a contract or `ContractId::zeroed()` for a script. Logs, reverts and panics of other contracts
+/// are still reported, but cannot be decoded or mapped back to their source.
+pub(crate) fn print_receipt_summary(
+    compiled: &BuiltPackage,
+    id: &ContractId,
+    receipts: &[Receipt],
+) -> Result<()> {
+    let decoder = match &compiled.json_abi_program {
+        ProgramABI::Fuel(program_abi) => Some(ReceiptDecoder::from_program_abi(program_abi)?),
+        ProgramABI::Evm(_) => None,
+    };
+    print_decoded_receipts(decoder.as_ref(), Some(compiled.source_map()), id, receipts)
+}
+
+/// Print the values logged and returned by the program with the given ID, followed by the reason
+/// for any revert or panic.
+///
+/// Values are only decoded if a `decoder` for the program is given, and reverts and panics are
+/// only mapped back to their source if the program's `source_map` is given.
+pub(crate) fn print_decoded_receipts(
+    decoder: Option<&ReceiptDecoder>,
+    source_map: Option<&SourceMap>,
+    id: &ContractId,
+    receipts: &[Receipt],
+) -> Result<()> {
+    if let Some(decoder) = decoder {
+        for log in decoder.decode_logs(id, receipts)? {
+            info!("Log: {}", log);
+        }
+        if let Some(value) = decoder.decode_return(id, receipts)? {
+            info!("Return value: {}", value);
+        }
+    }
+    for receipt in receipts {
+        let (failure, receipt_id, pc, is) = match receipt {
+            Receipt::Revert {
+                id: receipt_id,
+                ra,
+                pc,
+                is,
+            } => (describe_revert(*ra), receipt_id, *pc, *is),
+            Receipt::Panic {
+                id: receipt_id,
+                reason,
+                pc,
+                is,
+                ..
+            } => (
+                format!("Panicked: {:?}", reason.reason()),
+                receipt_id,
+                *pc,
+                *is,
+            ),
+            _ => continue,
+        };
+        let location = match source_map {
+            Some(source_map) if receipt_id == id => source_location(source_map, pc, is),
+            _ => None,
+        };
+        match location {
+            Some(location) => info!("{} at {}", failure, location),
+            None if *receipt_id == ContractId::zeroed() => info!("{} in script", failure),
+            None => info!("{} in contract {}", failure, receipt_id),
+        }
+    }
+    Ok(())
+}
+
+/// Describe the value passed to `revert`, naming it if it is one of the standard library's error
+/// signals.
+fn describe_revert(code: Word) -> String {
+    match ERROR_SIGNALS.iter().find(|(signal, _)| *signal == code) {
+        Some((_, description)) => format!("Reverted with {:#x} ({})", code, description),
+        None => format!("Reverted with {}", code),
+    }
+}
+
+/// Map the program counter of an instruction of a program to `<path>:<line>:<column>`.
+///
+/// `is` is the address at which the program's bytecode begins.
+fn source_location(source_map: &SourceMap, pc: Word, is: Word) -> Option<String> {
+    let instruction_ix = usize::try_from(pc.checked_sub(is)? / 4).ok()?;
+    let (path, range) = source_map.addr_to_span(instruction_ix)?;
+    let line_col = fs::read_to_string(&path)
+        .ok()
+        .and_then(|source| Position::new(source.into(), range.start))
+        .map(|position| position.line_col());
+    let location = match line_col {
+        Some((line, col)) => format!("{}:{}:{}", path.display(), line, col),
+        None => format!("{} (byte offset {})", path.display(), range.start),
+    };
+    Some(location)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{path::PathBuf, sync::Arc};
+    use sway_types::Span;
+
+    #[test]
+    fn test_error_signals_match_std() {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../../sway-lib-std/src/error_signals.sw");
+        let source = fs::read_to_string(path).unwrap();
+        let std_signals: Vec<_> = source
+            .lines()
+            .filter_map(|line| line.strip_prefix("pub const "))
+            .map(|decl| {
+                let (name, value) = decl.trim_end_matches(';').split_once(" = ").unwrap();
+                let value = value.trim_start_matches("0x").replace('_', "");
+                (Word::from_str_radix(&value, 16).unwrap(), name.to_string())
+            })
+            .collect();
+        assert_eq!(std_signals.len(), ERROR_SIGNALS.len());
+        for ((signal, name), (expected_signal, description)) in
+            std_signals.iter().zip(ERROR_SIGNALS)
+        {
+            assert_eq!(signal, expected_signal);
+            assert!(description.starts_with(&format!("{name}: ")));
+        }
+    }
+
+    #[test]
+    fn test_describe_revert() {
+        assert_eq!(describe_revert(42), "Reverted with 42");
+        assert_eq!(
+            describe_revert(0xffff_ffff_ffff_0000),
+            "Reverted with 0xffffffffffff0000 \
+            (FAILED_REQUIRE_SIGNAL: a call to `std::revert::require` failed)"
+        );
+    }
+
+    #[test]
+    fn test_source_location() {
+        let path =
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test/data/predicate_args/src/main.sw");
+        let source: Arc<str> = fs::read_to_string(&path).unwrap().into();
+        let start = source.find("fn main").unwrap();
+        let span = Span::new(
+            source.clone(),
+            start,
+            start + 7,
+            Some(Arc::new(path.clone())),
+        )
+        .unwrap();
+        let mut source_map = SourceMap::new();
+        source_map.insert(3, &span);
+
+        // The program counter is relative to the start of the program's bytecode.
+        let is = 0x1000;
+        assert_eq!(
+            source_location(&source_map, is + 3 * 4, is),
+            Some(format!("{}:8:1", path.display()))
+        );
+        assert_eq!(source_location(&source_map, is + 2 * 4, is), None);
+        assert_eq!(source_location(&source_map, is - 4, is), None);
+    }
+}
@@ -1,5 +1,8 @@
 use clap::Parser;
 use fuel_gql_client::fuel_crypto::SecretKey;
+use std::path::PathBuf;
+
+use crate::ops::keystore::Signing;
 
 /// Run script project.
 /// Crafts a script transaction then sends it to a running node.
@@ -10,6 +13,15 @@ pub struct RunCommand {
     #[clap(short, long)]
     pub data: Option<String>,
 
+    /// Arguments to the script's `main` function, encoded according to the script's ABI.
+    ///
+    /// Either a single JSON object mapping parameter names to values, e.g.
+    /// `--args '{"amount": 42, "to": "0x..."}'`, or one `name=value` pair per parameter, e.g.
+    /// `--args amount=42 --args to=0x...`. Values are given as JSON; structs are objects, enums
+    /// are `{"Variant": value}` or `"Variant"`, and arrays and tuples are arrays.
+    #[clap(long, multiple_occurrences = true, conflicts_with = "data")]
+    pub args: Vec<String>,
+
     /// Path to the project, if not specified, current working directory will be used.
     #[clap(short, long)]
     pub path: Option<String>,
@@ -126,10 +138,27 @@ pub struct RunCommand {
     #[clap(long)]
     pub simulate: bool,
 
-    /// Do not sign the transaction
-    #[clap(long)]
-    pub unsigned: bool,
+    #[clap(flatten)]
+    pub signing: Signing,
 
     /// Set the key to be used for signing.
-    pub signing_key: Option<SecretKey>,
+    ///
+    /// Deprecated, use `--signing-key` instead.
+    #[clap(
+        value_name = "SIGNING_KEY",
+        conflicts_with_all = &["signing-key", "account", "local"]
+    )]
+    pub positional_signing_key: Option<SecretKey>,
+
+    /// Run the script against an in-process VM rather than a node. The state of the VM is
+    /// persisted between invocations, so that contracts deployed by `forc deploy --local` may be
+    /// called.
+    #[clap(long)]
+    pub local: bool,
+
+    /// The file in which the state of the in-process VM is persisted.
+    ///
+    /// By default, this is `~/.forc/local/state.json`.
+    #[clap(long, requires = "local")]
+    pub local_state: Option<PathBuf>,
 }
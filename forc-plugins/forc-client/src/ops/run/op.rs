@@ -8,12 +8,15 @@ use pkg::BuiltPackage;
 use std::time::Duration;
 use std::{path::PathBuf, str::FromStr};
 use sway_core::language::parsed::TreeType;
-use sway_core::BuildTarget;
+use sway_core::{asm_generation::ProgramABI, BuildTarget};
 use tokio::time::timeout;
 use tracing::info;
 
+use crate::ops::local_node::LocalNodeLock;
 use crate::ops::pkg_util::built_pkgs_with_manifest;
+use crate::ops::receipt_util::print_receipt_summary;
 use crate::ops::tx_util::{TransactionBuilderExt, TxParameters, TX_SUBMIT_TIMEOUT_MS};
+use forc_util::abi::encode_named_main_args;
 
 use super::cmd::RunCommand;
 
@@ -56,16 +59,24 @@ pub async fn run_pkg(
     manifest: &PackageManifestFile,
     compiled: &BuiltPackage,
 ) -> Result<RanScript> {
-    let input_data = command.data.as_deref().unwrap_or("");
-    let data = input_data.strip_prefix("0x").unwrap_or(input_data);
-    let script_data = hex::decode(data).expect("Invalid hex");
+    let program_abi = match &compiled.json_abi_program {
+        ProgramABI::Fuel(program_abi) => Some(program_abi),
+        ProgramABI::Evm(_) => None,
+    };
+    let script_data = match (&command.data, program_abi) {
+        (Some(data), _) => {
+            let data = data.strip_prefix("0x").unwrap_or(data);
+            hex::decode(data).map_err(|e| anyhow!("Invalid hex script data: {}", e))?
+        }
+        (None, Some(program_abi)) if !command.args.is_empty() => {
+            encode_named_main_args(program_abi, &command.args)?
+        }
+        (None, None) if !command.args.is_empty() => {
+            bail!("typed script arguments require the Fuel build target")
+        }
+        (None, _) => vec![],
+    };
 
-    let node_url = command
-        .node_url
-        .as_deref()
-        .or_else(|| manifest.network.as_ref().map(|nw| &nw.url[..]))
-        .unwrap_or(NODE_URL);
-    let client = FuelClient::new(node_url)?;
     let contract_ids = command
         .contract
         .as_ref()
@@ -76,10 +87,35 @@ pub async fn run_pkg(
                 .map_err(|e| anyhow!("Failed to parse contract id: {}", e))
         })
         .collect::<Result<Vec<ContractId>>>()?;
-    let tx = TransactionBuilder::script(compiled.bytecode.clone(), script_data)
-        .params(TxParameters::new(command.gas_limit, command.gas_price))
-        .add_contracts(contract_ids)
-        .finalize_signed(client.clone(), command.unsigned, command.signing_key)
+    let mut tx = TransactionBuilder::script(compiled.bytecode.clone(), script_data);
+    tx.params(TxParameters::new(command.gas_limit, command.gas_price))
+        .add_contracts(contract_ids);
+
+    if command.local {
+        let tx = tx.finalize_without_signature_as_transaction();
+        if command.dry_run {
+            info!("{:?}", tx);
+            return Ok(RanScript { receipts: vec![] });
+        }
+        let mut node_lock = LocalNodeLock::new(command.local_state.as_deref())?;
+        let mut node = node_lock.load()?;
+        let receipts = node.transact(tx, command.simulate)?;
+        info!("{}", format_log_receipts(&receipts, command.pretty_print)?);
+        print_receipt_summary(compiled, &ContractId::zeroed(), &receipts)?;
+        return Ok(RanScript { receipts });
+    }
+
+    let node_url = command
+        .node_url
+        .as_deref()
+        .or_else(|| manifest.network.as_ref().map(|nw| &nw.url[..]))
+        .unwrap_or(NODE_URL);
+    let client = FuelClient::new(node_url)?;
+    let signing_key = command
+        .signing
+        .signing_key_or_positional(command.positional_signing_key)?;
+    let tx = tx
+        .finalize_signed(client.clone(), command.signing.unsigned, signing_key)
         .await?;
     if command.dry_run {
         info!("{:?}", tx);
@@ -87,11 +123,12 @@ pub async fn run_pkg(
     } else {
         let receipts =
             try_send_tx(node_url, &tx.into(), command.pretty_print, command.simulate).await?;
+        print_receipt_summary(compiled, &ContractId::zeroed(), &receipts)?;
         Ok(RanScript { receipts })
     }
 }
 
-async fn try_send_tx(
+pub(crate) async fn try_send_tx(
     node_url: &str,
     tx: &Transaction,
     pretty_print: bool,
@@ -0,0 +1,31 @@
+//! Helpers shared by the tests of the operations.
+
+use std::{fs, path::Path, path::PathBuf};
+
+/// Copy the project or workspace of the given name within `test/data` to a fresh temporary
+/// directory named `dest`, so that the outputs and deployments written by building and deploying it
+/// do not end up in the source tree.
+pub(crate) fn copy_test_project(name: &str, dest: &str) -> PathBuf {
+    let src = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test/data")
+        .join(name);
+    let dest = std::env::temp_dir().join("forc-client-test").join(dest);
+    let _ = fs::remove_dir_all(&dest);
+    copy_dir(&src, &dest);
+    dest
+}
+
+fn copy_dir(src: &Path, dest: &Path) {
+    fs::create_dir_all(dest).unwrap();
+    for entry in fs::read_dir(src).unwrap() {
+        let entry = entry.unwrap();
+        let path = entry.path();
+        if entry.file_type().unwrap().is_dir() {
+            if entry.file_name() != "out" {
+                copy_dir(&path, &dest.join(entry.file_name()));
+            }
+        } else {
+            fs::copy(&path, dest.join(entry.file_name())).unwrap();
+        }
+    }
+}
@@ -0,0 +1,26 @@
+contract;
+
+struct Point {
+    x: u64,
+    y: bool,
+}
+
+abi CallContract {
+    fn increment(value: u64) -> u64;
+    fn flip(point: Point, offset: u64) -> Point;
+}
+
+impl CallContract for Contract {
+    fn increment(value: u64) -> u64 {
+        __log(value);
+        __add(value, 1)
+    }
+
+    fn flip(point: Point, offset: u64) -> Point {
+        let y = if point.y { false } else { true };
+        Point {
+            x: __add(point.x, offset),
+            y,
+        }
+    }
+}
@@ -0,0 +1,11 @@
+contract;
+
+abi Callee {
+    fn value() -> u64;
+}
+
+impl Callee for Contract {
+    fn value() -> u64 {
+        42
+    }
+}
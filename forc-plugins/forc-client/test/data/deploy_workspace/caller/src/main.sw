@@ -0,0 +1,11 @@
+contract;
+
+abi Caller {
+    fn callee_id() -> b256;
+}
+
+impl Caller for Contract {
+    fn callee_id() -> b256 {
+        callee::CONTRACT_ID
+    }
+}
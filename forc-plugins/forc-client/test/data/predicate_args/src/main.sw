@@ -0,0 +1,10 @@
+predicate;
+
+struct Point {
+    x: u64,
+    y: bool,
+}
+
+fn main(limit: u64, point: Point) -> bool {
+    point.y && point.x < limit
+}
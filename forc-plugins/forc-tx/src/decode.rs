@@ -0,0 +1,415 @@
+//! Decoding serialized transactions and printing them in a readable form.
+
+use crate::Format;
+use anyhow::{bail, Context};
+use fuel_tx::{field::*, UniqueIdentifier};
+use std::fmt;
+
+/// Decode a transaction serialized in the given format, or in the format inferred from its
+/// contents if `None`.
+pub fn decode(bytes: &[u8], format: Option<Format>) -> anyhow::Result<fuel_tx::Transaction> {
+    let format = format.unwrap_or_else(|| infer_format(bytes));
+    match format {
+        Format::Json => {
+            serde_json::from_slice(bytes).context("failed to parse transaction from JSON")
+        }
+        Format::Hex => {
+            let hex = std::str::from_utf8(bytes).context("hex transaction is not valid UTF-8")?;
+            let hex: String = hex.split_whitespace().collect();
+            let hex = hex.strip_prefix("0x").unwrap_or(&hex);
+            decode_binary(&hex::decode(hex).context("failed to decode hex transaction")?)
+        }
+        Format::Binary => decode_binary(bytes),
+    }
+}
+
+/// Infer the format of a serialized transaction: JSON if it is an object, hex if it contains only
+/// hex digits and whitespace (optionally prefixed with `0x`) and otherwise binary.
+fn infer_format(bytes: &[u8]) -> Format {
+    let trimmed = match std::str::from_utf8(bytes) {
+        Ok(s) => s.trim(),
+        Err(_) => return Format::Binary,
+    };
+    if trimmed.starts_with('{') {
+        Format::Json
+    } else if !trimmed.is_empty()
+        && trimmed
+            .strip_prefix("0x")
+            .unwrap_or(trimmed)
+            .chars()
+            .all(|c| c.is_ascii_hexdigit() || c.is_whitespace())
+    {
+        Format::Hex
+    } else {
+        Format::Binary
+    }
+}
+
+fn decode_binary(bytes: &[u8]) -> anyhow::Result<fuel_tx::Transaction> {
+    let (len, tx) = fuel_tx::Transaction::try_from_bytes(bytes)
+        .context("failed to decode transaction from bytes")?;
+    if len != bytes.len() {
+        bail!(
+            "found {} trailing bytes after the {} byte transaction",
+            bytes.len() - len,
+            len
+        );
+    }
+    Ok(tx)
+}
+
+/// Displays a transaction as an indented tree of its fields, inputs, outputs and witnesses, along
+/// with its ID and the disassembly of any script or predicates.
+pub struct TxTree<'a>(pub &'a fuel_tx::Transaction);
+
+impl fmt::Display for TxTree<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tx = self.0;
+        writeln!(f, "id: {:#x}", tx.id())?;
+        match tx {
+            fuel_tx::Transaction::Script(script) => {
+                writeln!(f, "script:")?;
+                writeln!(f, "  gas price: {}", script.gas_price())?;
+                writeln!(f, "  gas limit: {}", script.gas_limit())?;
+                writeln!(f, "  maturity: {}", script.maturity())?;
+                writeln!(f, "  receipts root: {:#x}", script.receipts_root())?;
+                writeln!(f, "  script: {} bytes", script.script().len())?;
+                write_disassembly(f, 4, script.script())?;
+                writeln!(f, "  script data: {}", hex(script.script_data()))?;
+                write_inputs(f, script.inputs())?;
+                write_outputs(f, script.outputs())?;
+                write_witnesses(f, script.witnesses())
+            }
+            fuel_tx::Transaction::Create(create) => {
+                writeln!(f, "create:")?;
+                writeln!(f, "  gas price: {}", create.gas_price())?;
+                writeln!(f, "  gas limit: {}", create.gas_limit())?;
+                writeln!(f, "  maturity: {}", create.maturity())?;
+                writeln!(f, "  bytecode length: {} words", create.bytecode_length())?;
+                writeln!(
+                    f,
+                    "  bytecode witness index: {}",
+                    create.bytecode_witness_index()
+                )?;
+                writeln!(f, "  salt: {:#x}", create.salt())?;
+                if let Some(witness) = create
+                    .witnesses()
+                    .get(*create.bytecode_witness_index() as usize)
+                {
+                    let contract = fuel_tx::Contract::from(witness.as_vec().as_slice());
+                    let state_root =
+                        fuel_tx::Contract::initial_state_root(create.storage_slots().iter());
+                    let contract_id = contract.id(create.salt(), &contract.root(), &state_root);
+                    writeln!(f, "  contract id: {:#x}", contract_id)?;
+                }
+                writeln!(f, "  storage slots:")?;
+                for slot in create.storage_slots() {
+                    writeln!(f, "    {:#x}: {:#x}", slot.key(), slot.value())?;
+                }
+                write_inputs(f, create.inputs())?;
+                write_outputs(f, create.outputs())?;
+                write_witnesses(f, create.witnesses())
+            }
+            fuel_tx::Transaction::Mint(mint) => {
+                writeln!(f, "mint:")?;
+                writeln!(f, "  tx pointer: {}", tx_pointer(mint.tx_pointer()))?;
+                write_outputs(f, mint.outputs())
+            }
+        }
+    }
+}
+
+fn write_inputs(f: &mut fmt::Formatter<'_>, inputs: &[fuel_tx::Input]) -> fmt::Result {
+    writeln!(f, "  inputs:")?;
+    for (ix, input) in inputs.iter().enumerate() {
+        match input {
+            fuel_tx::Input::CoinSigned {
+                utxo_id,
+                owner,
+                amount,
+                asset_id,
+                tx_pointer: ptr,
+                witness_index,
+                maturity,
+            } => {
+                writeln!(f, "    {ix}: coin")?;
+                writeln!(f, "      utxo id: {:#x}", utxo_id)?;
+                writeln!(f, "      owner: {:#x}", owner)?;
+                writeln!(f, "      amount: {}", amount)?;
+                writeln!(f, "      asset id: {:#x}", asset_id)?;
+                writeln!(f, "      tx pointer: {}", tx_pointer(ptr))?;
+                writeln!(f, "      witness index: {}", witness_index)?;
+                writeln!(f, "      maturity: {}", maturity)?;
+            }
+            fuel_tx::Input::CoinPredicate {
+                utxo_id,
+                owner,
+                amount,
+                asset_id,
+                tx_pointer: ptr,
+                maturity,
+                predicate,
+                predicate_data,
+            } => {
+                writeln!(f, "    {ix}: coin")?;
+                writeln!(f, "      utxo id: {:#x}", utxo_id)?;
+                writeln!(f, "      owner: {:#x}", owner)?;
+                writeln!(f, "      amount: {}", amount)?;
+                writeln!(f, "      asset id: {:#x}", asset_id)?;
+                writeln!(f, "      tx pointer: {}", tx_pointer(ptr))?;
+                writeln!(f, "      maturity: {}", maturity)?;
+                write_predicate(f, predicate, predicate_data)?;
+            }
+            fuel_tx::Input::Contract {
+                utxo_id,
+                balance_root,
+                state_root,
+                tx_pointer: ptr,
+                contract_id,
+            } => {
+                writeln!(f, "    {ix}: contract")?;
+                writeln!(f, "      utxo id: {:#x}", utxo_id)?;
+                writeln!(f, "      balance root: {:#x}", balance_root)?;
+                writeln!(f, "      state root: {:#x}", state_root)?;
+                writeln!(f, "      tx pointer: {}", tx_pointer(ptr))?;
+                writeln!(f, "      contract id: {:#x}", contract_id)?;
+            }
+            fuel_tx::Input::MessageSigned {
+                message_id,
+                sender,
+                recipient,
+                amount,
+                nonce,
+                witness_index,
+                data,
+            } => {
+                writeln!(f, "    {ix}: message")?;
+                writeln!(f, "      message id: {:#x}", message_id)?;
+                writeln!(f, "      sender: {:#x}", sender)?;
+                writeln!(f, "      recipient: {:#x}", recipient)?;
+                writeln!(f, "      amount: {}", amount)?;
+                writeln!(f, "      nonce: {}", nonce)?;
+                writeln!(f, "      witness index: {}", witness_index)?;
+                writeln!(f, "      data: {}", hex(data))?;
+            }
+            fuel_tx::Input::MessagePredicate {
+                message_id,
+                sender,
+                recipient,
+                amount,
+                nonce,
+                data,
+                predicate,
+                predicate_data,
+            } => {
+                writeln!(f, "    {ix}: message")?;
+                writeln!(f, "      message id: {:#x}", message_id)?;
+                writeln!(f, "      sender: {:#x}", sender)?;
+                writeln!(f, "      recipient: {:#x}", recipient)?;
+                writeln!(f, "      amount: {}", amount)?;
+                writeln!(f, "      nonce: {}", nonce)?;
+                writeln!(f, "      data: {}", hex(data))?;
+                write_predicate(f, predicate, predicate_data)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_predicate(f: &mut fmt::Formatter<'_>, predicate: &[u8], data: &[u8]) -> fmt::Result {
+    writeln!(f, "      predicate: {} bytes", predicate.len())?;
+    write_disassembly(f, 8, predicate)?;
+    writeln!(f, "      predicate data: {}", hex(data))
+}
+
+fn write_outputs(f: &mut fmt::Formatter<'_>, outputs: &[fuel_tx::Output]) -> fmt::Result {
+    writeln!(f, "  outputs:")?;
+    for (ix, output) in outputs.iter().enumerate() {
+        match output {
+            fuel_tx::Output::Coin {
+                to,
+                amount,
+                asset_id,
+            } => {
+                writeln!(f, "    {ix}: coin")?;
+                writeln!(f, "      to: {:#x}", to)?;
+                writeln!(f, "      amount: {}", amount)?;
+                writeln!(f, "      asset id: {:#x}", asset_id)?;
+            }
+            fuel_tx::Output::Contract {
+                input_index,
+                balance_root,
+                state_root,
+            } => {
+                writeln!(f, "    {ix}: contract")?;
+                writeln!(f, "      input index: {}", input_index)?;
+                writeln!(f, "      balance root: {:#x}", balance_root)?;
+                writeln!(f, "      state root: {:#x}", state_root)?;
+            }
+            fuel_tx::Output::Message { recipient, amount } => {
+                writeln!(f, "    {ix}: message")?;
+                writeln!(f, "      recipient: {:#x}", recipient)?;
+                writeln!(f, "      amount: {}", amount)?;
+            }
+            fuel_tx::Output::Change {
+                to,
+                amount,
+                asset_id,
+            } => {
+                writeln!(f, "    {ix}: change")?;
+                writeln!(f, "      to: {:#x}", to)?;
+                writeln!(f, "      amount: {}", amount)?;
+                writeln!(f, "      asset id: {:#x}", asset_id)?;
+            }
+            fuel_tx::Output::Variable {
+                to,
+                amount,
+                asset_id,
+            } => {
+                writeln!(f, "    {ix}: variable")?;
+                writeln!(f, "      to: {:#x}", to)?;
+                writeln!(f, "      amount: {}", amount)?;
+                writeln!(f, "      asset id: {:#x}", asset_id)?;
+            }
+            fuel_tx::Output::ContractCreated {
+                contract_id,
+                state_root,
+            } => {
+                writeln!(f, "    {ix}: contract created")?;
+                writeln!(f, "      contract id: {:#x}", contract_id)?;
+                writeln!(f, "      state root: {:#x}", state_root)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_witnesses(f: &mut fmt::Formatter<'_>, witnesses: &[fuel_tx::Witness]) -> fmt::Result {
+    writeln!(f, "  witnesses:")?;
+    for (ix, witness) in witnesses.iter().enumerate() {
+        writeln!(f, "    {ix}: {}", hex(witness.as_vec()))?;
+    }
+    Ok(())
+}
+
+/// Write the disassembly of the given bytecode, one instruction per line along with its byte
+/// offset and raw bytes, in the same form as `forc parse-bytecode`.
+///
+/// Any data section within the bytecode is disassembled as if it were instructions.
+fn write_disassembly(f: &mut fmt::Formatter<'_>, indent: usize, bytecode: &[u8]) -> fmt::Result {
+    for (ix, raw) in bytecode.chunks(fuel_asm::Opcode::LEN).enumerate() {
+        let raw_hex = raw
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let op = match fuel_asm::Opcode::from_bytes(raw) {
+            Ok(op) => format!("{:?}", op),
+            Err(_) => "(incomplete)".to_string(),
+        };
+        writeln!(
+            f,
+            "{:indent$}{:>6}  {:<11}  {}",
+            "",
+            ix * fuel_asm::Opcode::LEN,
+            raw_hex,
+            op,
+            indent = indent
+        )?;
+    }
+    Ok(())
+}
+
+fn tx_pointer(ptr: &fuel_tx::TxPointer) -> String {
+    format!(
+        "block height {}, tx index {}",
+        ptr.block_height(),
+        ptr.tx_index()
+    )
+}
+
+fn hex(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+#[cfg(test)]
+fn test_script() -> fuel_tx::Transaction {
+    let script = [
+        fuel_asm::Opcode::ADDI(0x10, 0x00, 42),
+        fuel_asm::Opcode::RET(0x10),
+    ]
+    .into_iter()
+    .collect::<Vec<u8>>();
+    let input = fuel_tx::Input::Contract {
+        utxo_id: Default::default(),
+        balance_root: Default::default(),
+        state_root: Default::default(),
+        tx_pointer: fuel_tx::TxPointer::new(3, 1),
+        contract_id: Default::default(),
+    };
+    let output = fuel_tx::Output::Contract {
+        input_index: 0,
+        balance_root: Default::default(),
+        state_root: Default::default(),
+    };
+    fuel_tx::Transaction::script(
+        1,
+        1_000_000,
+        0,
+        script,
+        vec![1, 2, 3],
+        vec![input],
+        vec![output],
+        vec![vec![0xAB, 0xCD].into()],
+    )
+    .into()
+}
+
+#[cfg(test)]
+fn test_script_bytes() -> Vec<u8> {
+    use std::io::Read;
+    let mut tx = test_script();
+    let mut bytes = vec![0; 4096];
+    let len = tx.read(&mut bytes).unwrap();
+    bytes.truncate(len);
+    bytes
+}
+
+#[test]
+fn test_decode_binary() {
+    let bytes = test_script_bytes();
+    assert_eq!(decode(&bytes, None).unwrap(), test_script());
+    assert_eq!(decode(&bytes, Some(Format::Binary)).unwrap(), test_script());
+}
+
+#[test]
+fn test_decode_hex() {
+    let hex = hex(&test_script_bytes());
+    assert_eq!(decode(hex.as_bytes(), None).unwrap(), test_script());
+    let unprefixed = format!("{}\n", &hex[2..]);
+    assert_eq!(decode(unprefixed.as_bytes(), None).unwrap(), test_script());
+}
+
+#[test]
+fn test_decode_json() {
+    let json = serde_json::to_string_pretty(&test_script()).unwrap();
+    assert_eq!(decode(json.as_bytes(), None).unwrap(), test_script());
+}
+
+#[test]
+fn test_decode_trailing_bytes() {
+    let mut bytes = test_script_bytes();
+    bytes.extend_from_slice(&[0; 8]);
+    let err = decode(&bytes, Some(Format::Binary)).unwrap_err();
+    assert!(err.to_string().contains("8 trailing bytes"), "{}", err);
+}
+
+#[test]
+fn test_display_tree() {
+    let tree = TxTree(&test_script()).to_string();
+    assert!(tree.starts_with(&format!("id: {:#x}\nscript:\n", test_script().id())));
+    assert!(tree.contains("ADDI(16, 0, 42)"), "{}", tree);
+    assert!(tree.contains("tx pointer: block height 3, tx index 1"));
+    assert!(tree.contains("script data: 0x010203"));
+    assert!(tree.contains("    0: 0xabcd"));
+}
@@ -1,10 +1,13 @@
-//! A simple tool for constructing transactions from the command line.
+//! A simple tool for constructing and decoding transactions from the command line.
 
 use anyhow::{bail, Context};
 use clap::Parser;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
+pub mod decode;
+pub mod template;
+
 /// The top-level `forc tx` command.
 #[derive(Debug, Parser, Deserialize, Serialize)]
 #[clap(about, version)]
@@ -15,17 +18,20 @@ pub struct Command {
     pub tx: Transaction,
 }
 
-/// Construct a transaction.
+/// Construct a transaction, or decode an existing one.
 #[derive(Debug, Parser, Deserialize, Serialize)]
 #[clap(name = "transaction")]
 pub enum Transaction {
     Create(Create),
     Script(Script),
     Mint(Mint),
+    Decode(Decode),
+    Template(Template),
 }
 
 /// Construct a `Create` transaction for deploying a contract.
 #[derive(Debug, Parser, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct Create {
     #[clap(flatten)]
     pub gas: Gas,
@@ -40,6 +46,7 @@ pub struct Create {
     pub bytecode: PathBuf,
     /// Witness index of contract bytecode to create.
     #[clap(long, default_value_t = 0)]
+    #[serde(default)]
     pub bytecode_witness_index: u8,
     /// Path to a JSON file with a list of storage slots to initialize (key, value).
     #[clap(long)]
@@ -48,28 +55,34 @@ pub struct Create {
     ///
     /// Can be specified multiple times.
     #[clap(long = "witness", multiple = true, max_values = 255)]
+    #[serde(default)]
     pub witnesses: Vec<String>,
     // Inputs and outputs must follow all other arguments and are parsed separately.
     #[clap(skip)]
+    #[serde(default)]
     pub inputs: Vec<Input>,
     // Inputs and outputs must follow all other arguments and are parsed separately.
     #[clap(skip)]
+    #[serde(default)]
     pub outputs: Vec<Output>,
 }
 
 /// Construct a `Mint` transaction for emulating a block producer.
 #[derive(Debug, Parser, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct Mint {
     /// The location of the `Mint` transaction in the block.
     #[clap(long)]
     pub tx_ptr: fuel_tx::TxPointer,
     // Outputs must follow all other arguments and are parsed separately.
     #[clap(skip)]
+    #[serde(default)]
     pub outputs: Vec<Output>,
 }
 
 /// Construct a `Script` transaction for running a script.
 #[derive(Debug, Parser, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct Script {
     #[clap(flatten)]
     pub gas: Gas,
@@ -89,17 +102,70 @@ pub struct Script {
     ///
     /// Can be specified multiple times.
     #[clap(long = "witness", multiple = true, max_values = 255)]
+    #[serde(default)]
     pub witnesses: Vec<String>,
     // Inputs and outputs must follow all other arguments and are parsed separately.
     #[clap(skip)]
+    #[serde(default)]
     pub inputs: Vec<Input>,
     // Inputs and outputs must follow all other arguments and are parsed separately.
     #[clap(skip)]
+    #[serde(default)]
     pub outputs: Vec<Output>,
 }
 
+/// Decode a serialized transaction and print its fields, inputs, outputs and witnesses along with
+/// its ID and the disassembly of its script and predicates.
+///
+/// If an output path is given, the decoded transaction is written there as JSON instead.
+#[derive(Debug, Parser, Deserialize, Serialize)]
+pub struct Decode {
+    /// Path to the serialized transaction. If not specified, it is read from stdin.
+    pub path: Option<PathBuf>,
+    /// The format of the serialized transaction.
+    ///
+    /// If not specified, it is inferred from the contents: JSON if it is an object, hex if it
+    /// consists only of hex digits (optionally prefixed with `0x`) and otherwise binary.
+    #[clap(long, arg_enum)]
+    pub format: Option<Format>,
+}
+
+/// The format of a serialized transaction.
+#[derive(Clone, Copy, Debug, clap::ArgEnum, Deserialize, Serialize)]
+pub enum Format {
+    /// The transaction as produced by `forc tx`.
+    Json,
+    /// Hex-encoded bytes of the canonical binary encoding.
+    Hex,
+    /// Raw bytes of the canonical binary encoding.
+    Binary,
+}
+
+/// Construct a transaction from a TOML or JSON template.
+///
+/// The template describes a `Create`, `Script` or `Mint` transaction using the same fields as their
+/// command line arguments, e.g. a `[Script]` table with `bytecode`, `data` and `gas` entries and
+/// `[[Script.inputs]]` tables, and is checked against the same rules. Relative paths are relative
+/// to the template's directory.
+///
+/// Placeholders of the form `${NAME}` are replaced by the value given with `--var NAME=VALUE`, or
+/// otherwise by the environment variable `NAME`. A literal `$` is written as `$$`. Values of
+/// placeholders within double-quoted strings are escaped, and those of other placeholders must be a
+/// number, boolean or hex string, so that they can't alter the template's structure.
+#[derive(Debug, Parser, Deserialize, Serialize)]
+pub struct Template {
+    /// Path to the template, either a `.toml` or a `.json` file.
+    pub path: PathBuf,
+    /// The value of a placeholder in the template, given as `NAME=VALUE`.
+    ///
+    /// Can be specified multiple times.
+    #[clap(long = "var", multiple_occurrences = true)]
+    pub vars: Vec<String>,
+}
+
 /// Flag set for specifying gas price and limit.
 #[derive(Debug, Parser, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct Gas {
     /// Gas price for the transaction.
     ///
@@ -121,6 +187,7 @@ pub enum Input {
 }
 
 #[derive(Debug, Parser, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct InputCoin {
     /// Hash of the unspent transaction.
     #[clap(long)]
@@ -147,10 +214,12 @@ pub struct InputCoin {
     #[clap(long)]
     pub maturity: u32,
     #[clap(flatten)]
+    #[serde(default)]
     pub predicate: Predicate,
 }
 
 #[derive(Debug, Parser, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct InputContract {
     /// Hash of the unspent transaction.
     #[clap(long)]
@@ -173,6 +242,7 @@ pub struct InputContract {
 }
 
 #[derive(Debug, Parser, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct InputMessage {
     /// The message ID as described here.
     #[clap(long)]
@@ -196,11 +266,13 @@ pub struct InputMessage {
     #[clap(long)]
     pub witness_ix: Option<u8>,
     #[clap(flatten)]
+    #[serde(default)]
     pub predicate: Predicate,
 }
 
 /// Grouped arguments related to an input's predicate.
-#[derive(Debug, Parser, Deserialize, Serialize)]
+#[derive(Debug, Default, Parser, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct Predicate {
     /// The predicate bytecode.
     #[clap(long = "predicate")]
@@ -234,6 +306,7 @@ pub enum Output {
 }
 
 #[derive(Debug, Parser, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct OutputCoin {
     /// Hash of the unspent transaction.
     #[clap(long)]
@@ -247,6 +320,7 @@ pub struct OutputCoin {
 }
 
 #[derive(Debug, Parser, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct OutputContract {
     /// Index of input contract.
     #[clap(long)]
@@ -260,6 +334,7 @@ pub struct OutputContract {
 }
 
 #[derive(Debug, Parser, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct OutputMessage {
     /// The address of the message recipient.
     #[clap(long)]
@@ -270,6 +345,7 @@ pub struct OutputMessage {
 }
 
 #[derive(Debug, Parser, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct OutputChange {
     /// Receiving address or predicate root.
     #[clap(long)]
@@ -283,6 +359,7 @@ pub struct OutputChange {
 }
 
 #[derive(Debug, Parser, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct OutputVariable {
     /// Receiving address or predicate root.
     #[clap(long)]
@@ -296,6 +373,7 @@ pub struct OutputVariable {
 }
 
 #[derive(Debug, Parser, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct OutputContractCreated {
     /// Contract ID
     #[clap(long)]
@@ -328,16 +406,23 @@ impl Command {
                 Transaction::Mint(_) => {
                     bail!("Found argument 'input' which isn't valid for a Mint transaction");
                 }
+                Transaction::Decode(_) | Transaction::Template(_) => {
+                    bail!("Found argument 'input' which isn't valid when decoding a transaction or using a template");
+                }
             }
             Ok(())
         }
 
-        fn push_output(cmd: &mut Transaction, output: Output) {
+        fn push_output(cmd: &mut Transaction, output: Output) -> anyhow::Result<()> {
             match cmd {
                 Transaction::Create(ref mut create) => create.outputs.push(output),
                 Transaction::Script(ref mut script) => script.outputs.push(output),
                 Transaction::Mint(ref mut mint) => mint.outputs.push(output),
+                Transaction::Decode(_) | Transaction::Template(_) => {
+                    bail!("Found argument 'output' which isn't valid when decoding a transaction or using a template");
+                }
             }
+            Ok(())
         }
 
         let mut args = args.into_iter().peekable();
@@ -362,7 +447,7 @@ impl Command {
                 OUTPUT => {
                     let output =
                         Output::try_parse_from(args_til_next).context("failed to parse output")?;
-                    push_output(&mut cmd.tx, output)
+                    push_output(&mut cmd.tx, output)?
                 }
                 arg => bail!("unexpected argument {arg}, expected 'input' or 'output'"),
             }
@@ -384,11 +469,32 @@ impl TryFrom<Transaction> for fuel_tx::Transaction {
             Transaction::Create(create) => Self::Create(<_>::try_from(create)?),
             Transaction::Script(script) => Self::Script(<_>::try_from(script)?),
             Transaction::Mint(mint) => Self::Mint(mint.into()),
+            Transaction::Decode(decode) => <_>::try_from(decode)?,
+            Transaction::Template(template) => {
+                <_>::try_from(template::load(&template.path, &template.vars)?)?
+            }
         };
         Ok(tx)
     }
 }
 
+impl TryFrom<Decode> for fuel_tx::Transaction {
+    type Error = anyhow::Error;
+    fn try_from(decode: Decode) -> Result<Self, Self::Error> {
+        let bytes = match decode.path {
+            Some(path) => std::fs::read(&path)
+                .with_context(|| format!("failed to read transaction from {}", path.display()))?,
+            None => {
+                let mut bytes = vec![];
+                std::io::Read::read_to_end(&mut std::io::stdin(), &mut bytes)
+                    .context("failed to read transaction from stdin")?;
+                bytes
+            }
+        };
+        decode::decode(&bytes, decode.format)
+    }
+}
+
 impl TryFrom<Create> for fuel_tx::Create {
     type Error = anyhow::Error;
     fn try_from(create: Create) -> Result<Self, Self::Error> {
@@ -1,7 +1,11 @@
 fn main() {
     let cmd = forc_tx::Command::try_parse().unwrap();
+    let decoding = matches!(cmd.tx, forc_tx::Transaction::Decode(_));
     let tx = fuel_tx::Transaction::try_from(cmd.tx).unwrap();
     match cmd.output_path {
+        None if decoding => {
+            print!("{}", forc_tx::decode::TxTree(&tx));
+        }
         None => {
             let string = serde_json::to_string_pretty(&tx).unwrap();
             println!("{}", string);
@@ -0,0 +1,354 @@
+//! Constructing transactions from TOML or JSON templates.
+
+use crate::{Input, Transaction};
+use anyhow::{anyhow, bail, Context};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+/// Load the transaction described by the template at the given path.
+///
+/// Each `${NAME}` placeholder within the template is replaced by the value given for `NAME` in
+/// `vars` as `NAME=VALUE`, or otherwise by the value of the environment variable `NAME`, as
+/// described by [substitute]. Relative paths within the template are relative to the directory
+/// containing it.
+pub fn load(path: &Path, vars: &[String]) -> anyhow::Result<Transaction> {
+    let vars = parse_vars(vars)?;
+    let template = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read template {}", path.display()))?;
+    let contents = substitute(&template, |name| {
+        vars.get(name).cloned().or_else(|| std::env::var(name).ok())
+    })?;
+    let mut tx: Transaction = match path.extension().and_then(|ext| ext.to_str()) {
+        // `toml` can't deserialize enums with fields, so the template is converted to JSON first.
+        Some("toml") => toml::from_str::<toml::Value>(&contents)
+            .map_err(anyhow::Error::from)
+            .and_then(|value| Ok(serde_json::from_str(&serde_json::to_string(&value)?)?))
+            .with_context(|| format!("failed to parse template {}", path.display()))?,
+        Some("json") => serde_json::from_str(&contents)
+            .with_context(|| format!("failed to parse template {}", path.display()))?,
+        _ => bail!(
+            "unknown format of template {}, expected a `.toml` or `.json` file",
+            path.display()
+        ),
+    };
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    tx.resolve_paths(dir)?;
+    Ok(tx)
+}
+
+/// Parse variables given as `NAME=VALUE`.
+fn parse_vars(vars: &[String]) -> anyhow::Result<BTreeMap<String, String>> {
+    vars.iter()
+        .map(|var| {
+            let (name, value) = var.split_once('=').ok_or_else(|| {
+                anyhow!("expected a variable of the form NAME=VALUE, found {var:?}")
+            })?;
+            Ok((name.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Replace each `${NAME}` placeholder in the template by the value produced by `lookup`. A literal
+/// `$` may be written as `$$`.
+///
+/// Within a double-quoted string, a value is escaped as the contents of the string, which is the
+/// same in TOML and JSON. Elsewhere, a value must be a scalar such as a number, boolean or hex
+/// string. Either way, a value can't change the structure of the template. Placeholders within TOML
+/// comments are left as they are.
+///
+/// Fails with a list of every placeholder for which `lookup` produced no value.
+pub fn substitute(
+    template: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> anyhow::Result<String> {
+    let mut output = String::with_capacity(template.len());
+    let mut missing = vec![];
+    let mut in_string = false;
+    let mut in_comment = false;
+    let mut rest = template;
+    while let Some(c) = rest.chars().next() {
+        if in_comment {
+            in_comment = c != '\n';
+        } else if in_string && c == '\\' {
+            // Copy the escape sequence as is, so that an escaped quote doesn't end the string.
+            let len = 1 + rest[1..].chars().next().map_or(0, char::len_utf8);
+            output.push_str(&rest[..len]);
+            rest = &rest[len..];
+            continue;
+        } else if c == '"' {
+            in_string = !in_string;
+        } else if c == '#' && !in_string {
+            in_comment = true;
+        } else if let Some(after) = rest.strip_prefix("$$") {
+            output.push('$');
+            rest = after;
+            continue;
+        } else if let Some(after) = rest.strip_prefix("${") {
+            let end = after
+                .find('}')
+                .ok_or_else(|| anyhow!("unterminated placeholder in template"))?;
+            let name = &after[..end];
+            if !is_valid_name(name) {
+                bail!("invalid placeholder name {name:?} in template");
+            }
+            match lookup(name) {
+                Some(value) if in_string => output.push_str(&escape(&value)),
+                Some(value) if is_scalar(&value) => output.push_str(&value),
+                Some(value) => bail!(
+                    "the value {value:?} of placeholder `{name}` is not a number, boolean or hex \
+                    string, so the placeholder must be within a double-quoted string"
+                ),
+                None => missing.push(name.to_string()),
+            }
+            rest = &after[end + 1..];
+            continue;
+        }
+        output.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    if !missing.is_empty() {
+        missing.sort();
+        missing.dedup();
+        bail!(
+            "no value for placeholder(s) {} in template, set them with `--var NAME=VALUE` or \
+            as environment variables",
+            missing
+                .iter()
+                .map(|name| format!("`{name}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    Ok(output)
+}
+
+/// Escape the value as the contents of a double-quoted TOML or JSON string, so that a value
+/// containing a quote, backslash or newline can't end the string or add to the template.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Whether the value may be substituted outside of a string, e.g. `42`, `true` or `0x01`.
+fn is_scalar(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '+' | '-'))
+}
+
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+impl Transaction {
+    /// Make the relative paths to the files referenced by the transaction relative to `dir`.
+    fn resolve_paths(&mut self, dir: &Path) -> anyhow::Result<()> {
+        let resolve = |path: &mut PathBuf| {
+            if path.is_relative() {
+                *path = dir.join(&path);
+            }
+        };
+        let inputs = match self {
+            Transaction::Create(create) => {
+                resolve(&mut create.bytecode);
+                resolve(&mut create.storage_slots);
+                &mut create.inputs
+            }
+            Transaction::Script(script) => {
+                resolve(&mut script.bytecode);
+                resolve(&mut script.data);
+                &mut script.inputs
+            }
+            Transaction::Mint(_) => return Ok(()),
+            Transaction::Decode(_) | Transaction::Template(_) => {
+                bail!("a template may only describe a `Create`, `Script` or `Mint` transaction")
+            }
+        };
+        for input in inputs {
+            let predicate = match input {
+                Input::Coin(coin) => &mut coin.predicate,
+                Input::Message(msg) => {
+                    resolve(&mut msg.msg_data);
+                    &mut msg.predicate
+                }
+                Input::Contract(_) => continue,
+            };
+            predicate.bytecode.iter_mut().for_each(resolve);
+            predicate.data.iter_mut().for_each(resolve);
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_substitute() {
+    let vars: BTreeMap<_, _> = [("CONTRACT_ID", "0x01"), ("AMOUNT", "100")]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    let lookup = |name: &str| vars.get(name).cloned();
+    assert_eq!(
+        substitute("id = \"${CONTRACT_ID}\"\namount = ${AMOUNT}", lookup).unwrap(),
+        "id = \"0x01\"\namount = 100"
+    );
+    assert_eq!(
+        substitute("$$AMOUNT $ ${AMOUNT}", lookup).unwrap(),
+        "$AMOUNT $ 100"
+    );
+    let err = substitute("${B} ${A} ${B} ${AMOUNT}", lookup).unwrap_err();
+    assert!(err.to_string().contains("`A`, `B`"), "{}", err);
+    assert!(substitute("${AMOUNT", lookup).is_err());
+    assert!(substitute("${1X}", lookup).is_err());
+}
+
+#[test]
+fn test_substitute_unquoted() {
+    let lookup = |name: &str| match name {
+        "INJECTED" => Some("1, price = 5".to_string()),
+        "QUOTE" => Some(r#"a"b"#.to_string()),
+        _ => Some("100".to_string()),
+    };
+    // Values within strings are escaped, and escaped quotes within the template don't end them.
+    assert_eq!(
+        substitute(r#"a = "\"${QUOTE}" b = "${INJECTED}""#, lookup).unwrap(),
+        r#"a = "\"a\"b" b = "1, price = 5""#
+    );
+    // Values outside of strings must be scalars.
+    assert_eq!(
+        substitute("gas = { price = 0, limit = ${GAS_LIMIT} }", lookup).unwrap(),
+        "gas = { price = 0, limit = 100 }"
+    );
+    let err = substitute("gas = { price = 0, limit = ${INJECTED} }", lookup).unwrap_err();
+    assert!(err.to_string().contains("`INJECTED`"), "{}", err);
+    assert!(substitute(r#"{"limit": ${INJECTED}}"#, lookup).is_err());
+    assert!(substitute("limit = ${QUOTE}", lookup).is_err());
+    // A quote within a comment doesn't start a string, and placeholders within comments are kept.
+    assert!(substitute("# \"\nlimit = ${INJECTED}", lookup).is_err());
+    assert_eq!(
+        substitute("# ${INJECTED}\nlimit = ${GAS_LIMIT}", lookup).unwrap(),
+        "# ${INJECTED}\nlimit = 100"
+    );
+}
+
+#[test]
+fn test_escape() {
+    assert_eq!(escape("0x01"), "0x01");
+    assert_eq!(escape(r#"a"b\c"#), r#"a\"b\\c"#);
+    assert_eq!(escape("a\nb\tc\u{1}"), r#"a\nb\tc\u0001"#);
+}
+
+#[test]
+fn test_parse_vars() {
+    let vars = parse_vars(&["A=1".to_string(), "B=x=y".to_string()]).unwrap();
+    assert_eq!(vars["A"], "1");
+    assert_eq!(vars["B"], "x=y");
+    assert!(parse_vars(&["A".to_string()]).is_err());
+}
+
+#[test]
+fn test_load_script_template() {
+    let dir = std::env::temp_dir().join(format!("forc-tx-template-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("script.toml");
+    let template = r#"
+        [Script]
+        bytecode = "out/debug/my-script.bin"
+        data = "/abs/my-script.dat"
+        receipts_root = "0000000000000000000000000000000000000000000000000000000000000000"
+        maturity = 0
+        gas = { price = 0, limit = ${GAS_LIMIT} }
+
+        [[Script.inputs]]
+        [Script.inputs.Contract]
+        utxo_id = { tx_id = "0000000000000000000000000000000000000000000000000000000000000000", output_index = 0 }
+        output_ix = 0
+        balance_root = "0000000000000000000000000000000000000000000000000000000000000000"
+        state_root = "0000000000000000000000000000000000000000000000000000000000000000"
+        tx_ptr = { block_height = 0, tx_index = 0 }
+        contract_id = "${CONTRACT_ID}"
+
+        [[Script.outputs]]
+        [Script.outputs.Contract]
+        input_ix = 0
+        balance_root = "0000000000000000000000000000000000000000000000000000000000000000"
+        state_root = "0000000000000000000000000000000000000000000000000000000000000000"
+    "#;
+    std::fs::write(&path, template).unwrap();
+    let contract_id = fuel_tx::ContractId::from([7; 32]);
+    let vars = vec![
+        "GAS_LIMIT=100".to_string(),
+        format!("CONTRACT_ID={contract_id}"),
+    ];
+    let tx = load(&path, &vars).unwrap();
+    let script = match tx {
+        Transaction::Script(script) => script,
+        tx => panic!("expected a script, found {tx:?}"),
+    };
+    assert_eq!(script.bytecode, dir.join("out/debug/my-script.bin"));
+    assert_eq!(script.data, PathBuf::from("/abs/my-script.dat"));
+    assert_eq!(script.gas.limit, 100);
+    assert!(script.witnesses.is_empty());
+    match &script.inputs[..] {
+        [Input::Contract(contract)] => assert_eq!(contract.contract_id, contract_id),
+        inputs => panic!("expected a single contract input, found {inputs:?}"),
+    }
+    assert_eq!(script.outputs.len(), 1);
+
+    // Unknown fields are rejected, as they are on the command line.
+    std::fs::write(
+        &path,
+        template.replace("maturity = 0", "maturity = 0\nmaturty = 0"),
+    )
+    .unwrap();
+    assert!(load(&path, &vars).is_err());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_load_template_with_quoted_value() {
+    let dir = std::env::temp_dir().join(format!("forc-tx-template-quoted-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let receipts_root = "0".repeat(64);
+    let toml_template = format!(
+        "[Script]\nbytecode = \"${{BYTECODE}}\"\ndata = \"/abs/my-script.dat\"\n\
+        receipts_root = \"{receipts_root}\"\nmaturity = 0\ngas = {{ price = 0, limit = 100 }}\n"
+    );
+    let json_template = format!(
+        r#"{{"Script": {{"bytecode": "${{BYTECODE}}", "data": "/abs/my-script.dat",
+        "receipts_root": "{receipts_root}", "maturity": 0, "gas": {{"price": 0, "limit": 100}}}}}}"#
+    );
+    // A value that would otherwise end the string and add to the template.
+    let bytecode = r#"/abs/my"script.bin", maturity = 1, "x"#;
+    let vars = vec![format!("BYTECODE={bytecode}")];
+    for (file_name, template) in [
+        ("script.toml", toml_template),
+        ("script.json", json_template),
+    ] {
+        let path = dir.join(file_name);
+        std::fs::write(&path, template).unwrap();
+        let script = match load(&path, &vars).unwrap() {
+            Transaction::Script(script) => script,
+            tx => panic!("expected a script, found {tx:?}"),
+        };
+        assert_eq!(script.bytecode, PathBuf::from(bytecode));
+        assert_eq!(script.maturity, 0);
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+}
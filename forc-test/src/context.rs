@@ -0,0 +1,310 @@
+//! Configuration of the block and transaction within which a test is executed.
+
+use crate::{literal_u64, test_attribute_args};
+use fuel_tx as tx;
+use fuel_vm::{
+    fuel_storage::{Mappable, MerkleRoot, MerkleRootStorage, StorageInspect, StorageMutate},
+    storage::{InterpreterStorage, MemoryStorage},
+};
+use std::{borrow::Cow, str::FromStr};
+use sway_ast::Literal;
+use sway_core::language::ty::TyFunctionDeclaration;
+use sway_types::constants::{
+    TEST_BLOCK_HEIGHT_NAME, TEST_INPUT_COIN_NAME, TEST_OUTPUT_COIN_NAME,
+    TEST_OUTPUT_VARIABLES_NAME, TEST_SENDER_NAME, TEST_TIMESTAMP_NAME,
+};
+use tx::{Address, AssetId, Bytes32, ContractId, Word};
+
+/// The number of seconds between consecutive blocks, used to derive the timestamps of the blocks
+/// preceding a block with a requested timestamp. Matches the interval assumed by `MemoryStorage`.
+const BLOCK_INTERVAL: Word = 10;
+
+/// The block and transaction context requested via the arguments of a test's `#[test]` attribute.
+///
+/// Anything that is not requested is left as it is for tests without any context.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TestContext {
+    /// The height of the block in which the test is executed, via `block_height = <height>`.
+    pub(crate) block_height: Option<u32>,
+    /// The TAI64 timestamp of the block in which the test is executed, as returned by
+    /// `std::block::timestamp`, via `timestamp = <timestamp>`.
+    pub(crate) timestamp: Option<Word>,
+    /// The owner of all coin inputs of the test transaction, via `sender = "0x<address>"`.
+    pub(crate) sender: Option<Address>,
+    /// The amount and asset of each additional coin input, via
+    /// `input_coin = "<amount>[:0x<asset_id>]"`.
+    pub(crate) input_coins: Vec<(Word, AssetId)>,
+    /// The outputs of the test transaction, via `output_coin = "0x<to>:<amount>[:0x<asset_id>]"`
+    /// and `output_variables = <count>`, in the order in which they are declared.
+    pub(crate) outputs: Vec<tx::Output>,
+}
+
+/// The storage against which a test is executed.
+///
+/// Wraps the package's `MemoryStorage`, only overriding the current block height and the
+/// timestamps of blocks if requested by the test.
+#[derive(Clone, Debug)]
+pub(crate) struct TestStorage {
+    storage: MemoryStorage,
+    block_height: Option<u32>,
+    timestamp: Option<Word>,
+}
+
+impl TestContext {
+    /// Collect the context requested by the arguments of the given test's `#[test]` attribute.
+    ///
+    /// The test transaction has a contract output for each of the `contract_count` contracts that
+    /// the test may call, which count towards its maximum outputs along with those requested.
+    pub(crate) fn from_test_decl(
+        test_function_decl: &TyFunctionDeclaration,
+        contract_count: usize,
+    ) -> anyhow::Result<Self> {
+        let test_name = &test_function_decl.name;
+        let contract_output_count = contract_count as Word;
+        let mut context = TestContext::default();
+        for arg in test_attribute_args(test_function_decl) {
+            let value = arg.value.as_ref();
+            match arg.name.as_str() {
+                TEST_BLOCK_HEIGHT_NAME => {
+                    let height = value
+                        .and_then(literal_u64)
+                        .and_then(|height| u32::try_from(height).ok())
+                        .ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "Expected a `u32` block height, i.e. \
+                                `{TEST_BLOCK_HEIGHT_NAME} = <height>`, for test: {test_name}."
+                            )
+                        })?;
+                    context.block_height = Some(height);
+                }
+                TEST_TIMESTAMP_NAME => {
+                    let timestamp = value.and_then(literal_u64).ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Expected a `u64` TAI64 timestamp, i.e. \
+                            `{TEST_TIMESTAMP_NAME} = <timestamp>`, for test: {test_name}."
+                        )
+                    })?;
+                    context.timestamp = Some(timestamp);
+                }
+                TEST_SENDER_NAME => {
+                    let sender = literal_str(value)
+                        .and_then(|s| Address::from_str(s).ok())
+                        .ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "Expected a hex encoded address, i.e. \
+                                `{TEST_SENDER_NAME} = \"0x<address>\"`, for test: {test_name}."
+                            )
+                        })?;
+                    context.sender = Some(sender);
+                }
+                TEST_INPUT_COIN_NAME => {
+                    let coin = literal_str(value).and_then(parse_coin).ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Expected a coin, i.e. \
+                            `{TEST_INPUT_COIN_NAME} = \"<amount>[:0x<asset_id>]\"`, \
+                            for test: {test_name}."
+                        )
+                    })?;
+                    context.input_coins.push(coin);
+                }
+                TEST_OUTPUT_COIN_NAME => {
+                    let output = literal_str(value)
+                        .and_then(|s| {
+                            let (to, coin) = s.split_once(':')?;
+                            let to = Address::from_str(to).ok()?;
+                            let (amount, asset_id) = parse_coin(coin)?;
+                            Some(tx::Output::coin(to, amount, asset_id))
+                        })
+                        .ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "Expected a recipient and a coin, i.e. \
+                                `{TEST_OUTPUT_COIN_NAME} = \"0x<to>:<amount>[:0x<asset_id>]\"`, \
+                                for test: {test_name}."
+                            )
+                        })?;
+                    check_output_count(
+                        contract_output_count + context.outputs.len() as Word + 1,
+                        test_name,
+                    )?;
+                    context.outputs.push(output);
+                }
+                TEST_OUTPUT_VARIABLES_NAME => {
+                    let count = value.and_then(literal_u64).ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Expected a number of variable outputs, i.e. \
+                            `{TEST_OUTPUT_VARIABLES_NAME} = <count>`, for test: {test_name}."
+                        )
+                    })?;
+                    check_output_count(
+                        (contract_output_count + context.outputs.len() as Word)
+                            .saturating_add(count),
+                        test_name,
+                    )?;
+                    let variable = tx::Output::variable(Address::zeroed(), 0, AssetId::zeroed());
+                    for _ in 0..count {
+                        context.outputs.push(variable);
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(context)
+    }
+
+    /// Wrap the given storage, applying the requested block height and timestamp.
+    pub(crate) fn storage(&self, storage: MemoryStorage) -> TestStorage {
+        TestStorage {
+            storage,
+            block_height: self.block_height,
+            timestamp: self.timestamp,
+        }
+    }
+}
+
+/// Ensure that the test transaction may have the given number of outputs.
+fn check_output_count(count: Word, test_name: &impl std::fmt::Display) -> anyhow::Result<()> {
+    let max_outputs = tx::ConsensusParameters::DEFAULT.max_outputs;
+    if count > max_outputs {
+        anyhow::bail!(
+            "Too many outputs for test: {test_name}. A transaction may have at most \
+            {max_outputs} outputs."
+        );
+    }
+    Ok(())
+}
+
+/// The string value of an attribute argument, if it is a string literal.
+fn literal_str(value: Option<&Literal>) -> Option<&str> {
+    match value {
+        Some(Literal::String(lit_string)) => Some(lit_string.parsed.as_str()),
+        _ => None,
+    }
+}
+
+/// Parse a coin of the form `<amount>[:0x<asset_id>]`, defaulting to the base asset.
+fn parse_coin(s: &str) -> Option<(Word, AssetId)> {
+    let (amount, asset_id) = match s.split_once(':') {
+        Some((amount, asset_id)) => (amount, AssetId::from_str(asset_id).ok()?),
+        None => (s, AssetId::BASE),
+    };
+    let amount = amount.replace('_', "").parse().ok()?;
+    Some((amount, asset_id))
+}
+
+impl<M: Mappable> StorageInspect<M> for TestStorage
+where
+    MemoryStorage: StorageInspect<M>,
+{
+    type Error = <MemoryStorage as StorageInspect<M>>::Error;
+
+    fn get(&self, key: &M::Key) -> Result<Option<Cow<'_, M::GetValue>>, Self::Error> {
+        self.storage.get(key)
+    }
+
+    fn contains_key(&self, key: &M::Key) -> Result<bool, Self::Error> {
+        self.storage.contains_key(key)
+    }
+}
+
+impl<M: Mappable> StorageMutate<M> for TestStorage
+where
+    MemoryStorage: StorageMutate<M>,
+{
+    fn insert(
+        &mut self,
+        key: &M::Key,
+        value: &M::SetValue,
+    ) -> Result<Option<M::GetValue>, Self::Error> {
+        self.storage.insert(key, value)
+    }
+
+    fn remove(&mut self, key: &M::Key) -> Result<Option<M::GetValue>, Self::Error> {
+        self.storage.remove(key)
+    }
+}
+
+impl<K, M: Mappable> MerkleRootStorage<K, M> for TestStorage
+where
+    MemoryStorage: MerkleRootStorage<K, M>,
+{
+    fn root(&mut self, key: &K) -> Result<MerkleRoot, Self::Error> {
+        self.storage.root(key)
+    }
+}
+
+impl InterpreterStorage for TestStorage {
+    type DataError = <MemoryStorage as InterpreterStorage>::DataError;
+
+    fn block_height(&self) -> Result<u32, Self::DataError> {
+        match self.block_height {
+            Some(block_height) => Ok(block_height),
+            None => self.storage.block_height(),
+        }
+    }
+
+    fn timestamp(&self, height: u32) -> Result<Word, Self::DataError> {
+        match self.timestamp {
+            // Preceding blocks are assumed to have been produced at a regular interval.
+            Some(timestamp) => {
+                let blocks_ago = self.block_height()?.saturating_sub(height);
+                Ok(timestamp.saturating_sub(Word::from(blocks_ago) * BLOCK_INTERVAL))
+            }
+            None => self.storage.timestamp(height),
+        }
+    }
+
+    fn block_hash(&self, block_height: u32) -> Result<Bytes32, Self::DataError> {
+        self.storage.block_hash(block_height)
+    }
+
+    fn coinbase(&self) -> Result<Address, Self::DataError> {
+        self.storage.coinbase()
+    }
+
+    fn merkle_contract_state_range(
+        &self,
+        id: &ContractId,
+        start_key: &Bytes32,
+        range: Word,
+    ) -> Result<Vec<Option<Cow<'_, Bytes32>>>, Self::DataError> {
+        self.storage
+            .merkle_contract_state_range(id, start_key, range)
+    }
+
+    fn merkle_contract_state_insert_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        values: &[Bytes32],
+    ) -> Result<Option<()>, Self::DataError> {
+        self.storage
+            .merkle_contract_state_insert_range(contract, start_key, values)
+    }
+
+    fn merkle_contract_state_remove_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        range: Word,
+    ) -> Result<Option<()>, Self::DataError> {
+        self.storage
+            .merkle_contract_state_remove_range(contract, start_key, range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_output_count;
+
+    #[test]
+    fn test_check_output_count() {
+        assert!(check_output_count(0, &"test_foo").is_ok());
+        assert!(check_output_count(255, &"test_foo").is_ok());
+        let err = check_output_count(256, &"test_foo").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Too many outputs for test: test_foo. A transaction may have at most 255 outputs."
+        );
+        assert!(check_output_count(u64::MAX, &"test_foo").is_err());
+    }
+}
@@ -0,0 +1,322 @@
+//! Line coverage of Sway source files, collected from the instructions executed by tests.
+
+use crate::{report::xml_escape, Tested, TestedPackage};
+use forc_pkg::BuiltPackage;
+use fuel_tx as tx;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The name of the lcov file written by `Coverage::write_reports`.
+pub const LCOV_FILE_NAME: &str = "lcov.info";
+
+/// The name of the HTML summary written by `Coverage::write_reports`.
+pub const HTML_FILE_NAME: &str = "index.html";
+
+/// The number of tests by which each source line was executed, keyed by file path and then by
+/// 1-based line number.
+///
+/// Lines that map to instructions that were never executed are present with a count of `0`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Coverage {
+    pub files: BTreeMap<PathBuf, BTreeMap<usize, u64>>,
+}
+
+/// An instruction executed by a test, as the contract within which it was executed, or `None`
+/// for the test script, and its byte offset within the bytecode of that program.
+pub(crate) type ExecutedInstruction = (Option<tx::ContractId>, u64);
+
+/// Maps the instructions executed by a test to the source lines of the package under test and the
+/// contracts deployed for it.
+///
+/// Only lines within the source files of the package that each program was built from are mapped,
+/// so that the coverage of dependencies such as the standard library is not reported.
+pub(crate) struct SourceLines {
+    /// The source line of each instruction of the test script, keyed by instruction index.
+    script: HashMap<usize, (PathBuf, usize)>,
+    /// The source lines of each deployed contract, keyed by instruction index.
+    contracts: HashMap<tx::ContractId, HashMap<usize, (PathBuf, usize)>>,
+}
+
+impl Coverage {
+    /// Collect the coverage of all tested packages within the given package or workspace.
+    pub fn from_tested(tested: &Tested) -> Self {
+        let mut coverage = Self::default();
+        let pkgs: Vec<&TestedPackage> = match tested {
+            Tested::Package(pkg) => vec![pkg],
+            Tested::Workspace(pkgs) => pkgs.iter().collect(),
+        };
+        for pkg_coverage in pkgs.into_iter().filter_map(|pkg| pkg.coverage.as_ref()) {
+            coverage.merge(pkg_coverage);
+        }
+        coverage
+    }
+
+    /// Add the line counts of `other` to this coverage.
+    pub fn merge(&mut self, other: &Coverage) {
+        for (path, lines) in &other.files {
+            let file = self.files.entry(path.clone()).or_default();
+            for (line, count) in lines {
+                *file.entry(*line).or_default() += count;
+            }
+        }
+    }
+
+    /// Take the greater of the line counts of this coverage and `other`, so that a line executed by
+    /// several runs of the same test is counted once.
+    pub fn union(&mut self, other: &Coverage) {
+        for (path, lines) in &other.files {
+            let file = self.files.entry(path.clone()).or_default();
+            for (line, count) in lines {
+                let total = file.entry(*line).or_default();
+                *total = (*total).max(*count);
+            }
+        }
+    }
+
+    /// The number of lines within the given file that were executed and the total number of lines
+    /// within the file that map to instructions.
+    pub fn file_summary(lines: &BTreeMap<usize, u64>) -> (usize, usize) {
+        let hit = lines.values().filter(|count| **count > 0).count();
+        (hit, lines.len())
+    }
+
+    /// The number of executed lines and the total number of lines across all files.
+    pub fn summary(&self) -> (usize, usize) {
+        self.files
+            .values()
+            .map(Self::file_summary)
+            .fold((0, 0), |(hit, total), (h, t)| (hit + h, total + t))
+    }
+
+    /// Format the coverage as an lcov tracefile.
+    pub fn to_lcov(&self) -> String {
+        let mut lcov = String::new();
+        for (path, lines) in &self.files {
+            let (hit, total) = Self::file_summary(lines);
+            lcov.push_str("TN:\n");
+            lcov.push_str(&format!("SF:{}\n", path.display()));
+            for (line, count) in lines {
+                lcov.push_str(&format!("DA:{line},{count}\n"));
+            }
+            lcov.push_str(&format!("LF:{total}\nLH:{hit}\nend_of_record\n"));
+        }
+        lcov
+    }
+
+    /// Format the coverage as a standalone HTML page with a summary table followed by the source
+    /// of each file, with executed and unexecuted lines highlighted.
+    pub fn to_html(&self) -> anyhow::Result<String> {
+        let (hit, total) = self.summary();
+        let mut html = String::new();
+        writeln!(html, "<!DOCTYPE html>")?;
+        writeln!(html, "<html>\n<head>\n<meta charset=\"utf-8\">")?;
+        writeln!(html, "<title>forc test coverage</title>")?;
+        writeln!(
+            html,
+            "<style>\
+            body {{ font-family: sans-serif; }} \
+            table {{ border-collapse: collapse; }} \
+            td, th {{ padding: 2px 12px; text-align: left; }} \
+            pre {{ line-height: 1.3; }} \
+            .hit {{ background: #ddffdd; }} \
+            .miss {{ background: #ffdddd; }} \
+            .count {{ color: #888; display: inline-block; width: 6em; }}\
+            </style>"
+        )?;
+        writeln!(html, "</head>\n<body>")?;
+        writeln!(
+            html,
+            "<h1>Coverage: {hit}/{total} lines ({})</h1>",
+            format_percent(hit, total)
+        )?;
+        writeln!(html, "<table>")?;
+        writeln!(
+            html,
+            "<tr><th>file</th><th>lines</th><th>coverage</th></tr>"
+        )?;
+        for (ix, (path, lines)) in self.files.iter().enumerate() {
+            let (hit, total) = Self::file_summary(lines);
+            writeln!(
+                html,
+                "<tr><td><a href=\"#file{ix}\">{}</a></td><td>{hit}/{total}</td><td>{}</td></tr>",
+                xml_escape(&path.display().to_string()),
+                format_percent(hit, total)
+            )?;
+        }
+        writeln!(html, "</table>")?;
+        for (ix, (path, lines)) in self.files.iter().enumerate() {
+            let path_str = xml_escape(&path.display().to_string());
+            writeln!(html, "<h2 id=\"file{ix}\">{path_str}</h2>")?;
+            let source = fs::read_to_string(path).map_err(|e| {
+                anyhow::anyhow!("failed to read source file {}: {e}", path.display())
+            })?;
+            writeln!(html, "<pre>")?;
+            for (line_ix, line) in source.lines().enumerate() {
+                let (class, count) = match lines.get(&(line_ix + 1)) {
+                    Some(0) => ("miss", "0".to_string()),
+                    Some(count) => ("hit", count.to_string()),
+                    None => ("", String::new()),
+                };
+                writeln!(
+                    html,
+                    "<span class=\"{class}\"><span class=\"count\">{:>4} {count}</span>{}</span>",
+                    line_ix + 1,
+                    xml_escape(line)
+                )?;
+            }
+            writeln!(html, "</pre>")?;
+        }
+        writeln!(html, "</body>\n</html>")?;
+        Ok(html)
+    }
+
+    /// Write the lcov tracefile and HTML summary into the given directory.
+    pub fn write_reports(&self, dir: &Path) -> anyhow::Result<()> {
+        fs::create_dir_all(dir).map_err(|e| {
+            anyhow::anyhow!("failed to create coverage directory {}: {e}", dir.display())
+        })?;
+        fs::write(dir.join(LCOV_FILE_NAME), self.to_lcov())?;
+        fs::write(dir.join(HTML_FILE_NAME), self.to_html()?)?;
+        Ok(())
+    }
+}
+
+impl SourceLines {
+    /// Map the instructions of the given test script and deployed contracts to their source lines.
+    pub(crate) fn new(
+        script: &BuiltPackage,
+        contracts: &[(tx::ContractId, &BuiltPackage)],
+    ) -> Self {
+        let mut line_starts = HashMap::new();
+        let script_lines = instruction_lines(script, &mut line_starts);
+        let contracts = contracts
+            .iter()
+            .map(|(contract_id, built)| (*contract_id, instruction_lines(built, &mut line_starts)))
+            .collect();
+        Self {
+            script: script_lines,
+            contracts,
+        }
+    }
+
+    /// Every source line that maps to an instruction, each with a count of `0`.
+    pub(crate) fn instrumented(&self) -> Coverage {
+        let mut coverage = Coverage::default();
+        let all_lines = self
+            .script
+            .values()
+            .chain(self.contracts.values().flat_map(|lines| lines.values()));
+        for (path, line) in all_lines {
+            coverage
+                .files
+                .entry(path.clone())
+                .or_default()
+                .insert(*line, 0);
+        }
+        coverage
+    }
+
+    /// The source lines of the given executed instructions, each with a count of `1`.
+    pub(crate) fn covered(&self, executed: &[ExecutedInstruction]) -> Coverage {
+        let lines: BTreeSet<&(PathBuf, usize)> = executed
+            .iter()
+            .filter_map(|(context, offset)| {
+                let instruction_ix = usize::try_from(offset / 4).ok()?;
+                let lines = match context {
+                    None => &self.script,
+                    Some(contract_id) => self.contracts.get(contract_id)?,
+                };
+                lines.get(&instruction_ix)
+            })
+            .collect();
+        let mut coverage = Coverage::default();
+        for (path, line) in lines {
+            coverage
+                .files
+                .entry(path.clone())
+                .or_default()
+                .insert(*line, 1);
+        }
+        coverage
+    }
+}
+
+/// Map each instruction of the given package that originates from the package's own source files
+/// to the 1-based line on which its span begins.
+///
+/// `line_starts` caches the byte offset at which each line begins for every file read so far.
+fn instruction_lines(
+    built: &BuiltPackage,
+    line_starts: &mut HashMap<PathBuf, Option<Vec<usize>>>,
+) -> HashMap<usize, (PathBuf, usize)> {
+    let pkg_dir = built.manifest_file.dir();
+    built
+        .source_map()
+        .iter()
+        .filter(|(_, path, _)| path.starts_with(pkg_dir))
+        .filter_map(|(instruction_ix, path, range)| {
+            let starts = line_starts.entry(path.clone()).or_insert_with(|| {
+                let source = fs::read_to_string(&path).ok()?;
+                let newlines = source.match_indices('\n').map(|(ix, _)| ix + 1);
+                Some(std::iter::once(0).chain(newlines).collect())
+            });
+            let line = match starts.as_ref()?.binary_search(&range.start) {
+                Ok(ix) => ix + 1,
+                Err(ix) => ix,
+            };
+            Some((instruction_ix, (path, line)))
+        })
+        .collect()
+}
+
+/// Format the proportion of executed lines as a percentage, or `-` if there are no lines.
+pub fn format_percent(hit: usize, total: usize) -> String {
+    match total {
+        0 => "-".to_string(),
+        _ => format!("{:.1}%", hit as f64 * 100.0 / total as f64),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Coverage;
+    use std::path::PathBuf;
+
+    fn coverage(lines: &[(usize, u64)]) -> Coverage {
+        let mut coverage = Coverage::default();
+        coverage.files.insert(
+            PathBuf::from("/src/main.sw"),
+            lines.iter().cloned().collect(),
+        );
+        coverage
+    }
+
+    #[test]
+    fn test_merge_and_lcov() {
+        let mut total = coverage(&[(1, 0), (2, 0), (4, 0)]);
+        total.merge(&coverage(&[(1, 1), (2, 1)]));
+        total.merge(&coverage(&[(1, 1)]));
+        assert_eq!(total.summary(), (2, 3));
+        assert_eq!(
+            total.to_lcov(),
+            "TN:\nSF:/src/main.sw\nDA:1,2\nDA:2,1\nDA:4,0\nLF:3\nLH:2\nend_of_record\n"
+        );
+    }
+
+    #[test]
+    fn test_union() {
+        let mut test = coverage(&[(1, 1)]);
+        test.union(&coverage(&[(1, 1), (2, 1)]));
+        test.union(&coverage(&[(1, 0), (4, 0)]));
+        let mut total = coverage(&[(1, 1)]);
+        total.merge(&test);
+        assert_eq!(
+            total.to_lcov(),
+            "TN:\nSF:/src/main.sw\nDA:1,2\nDA:2,1\nDA:4,0\nLF:3\nLH:2\nend_of_record\n"
+        );
+    }
+}
@@ -0,0 +1,511 @@
+//! Generation, encoding and shrinking of random arguments for test functions that take parameters.
+
+use crate::{Coverage, TestResult};
+use fuel_abi_types::program_abi::{ProgramABI, TypeApplication};
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
+use std::fmt;
+
+/// The number of cases executed for each test function that takes parameters by default.
+pub const DEFAULT_FUZZ_RUNS: usize = 256;
+
+/// The seed from which test function arguments are generated by default.
+pub const DEFAULT_FUZZ_SEED: u64 = 0x7E57;
+
+/// The maximum number of additional cases executed while shrinking a failing input.
+const MAX_SHRINK_RUNS: usize = 1024;
+
+/// The configuration for executing test functions that take parameters.
+#[derive(Clone, Copy, Debug)]
+pub struct FuzzConfig {
+    /// The number of cases with randomly generated arguments to execute for each test.
+    pub runs: usize,
+    /// The seed from which the arguments of each test are generated.
+    pub seed: u64,
+}
+
+/// The outcome of executing a test function with randomly generated arguments.
+#[derive(Clone, Debug, Serialize)]
+pub struct FuzzOutcome {
+    /// The number of cases executed, including those executed while shrinking a failing input.
+    pub runs: usize,
+    /// The seed from which the arguments were generated.
+    pub seed: u64,
+    /// The smallest arguments found for which the test fails, if any.
+    pub counterexample: Option<String>,
+}
+
+/// The type of a test function parameter, as described by the JSON ABI.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum ArgType {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    B256,
+    Struct(String, Vec<(String, ArgType)>),
+    Tuple(Vec<ArgType>),
+    Array(Box<ArgType>, usize),
+}
+
+/// A value with which a test function may be called.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum ArgValue {
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    B256([u8; 32]),
+    Struct(String, Vec<(String, ArgValue)>),
+    Tuple(Vec<ArgValue>),
+    Array(Vec<ArgValue>),
+}
+
+/// The named arguments with which a single test case is executed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct TestArgs(pub(crate) Vec<(String, ArgValue)>);
+
+impl Default for FuzzConfig {
+    fn default() -> Self {
+        Self {
+            runs: DEFAULT_FUZZ_RUNS,
+            seed: DEFAULT_FUZZ_SEED,
+        }
+    }
+}
+
+/// Collect the parameters of the test function with the given name from its JSON ABI.
+pub(crate) fn test_params(
+    test_abi: &ProgramABI,
+    test_name: &str,
+) -> anyhow::Result<Vec<(String, ArgType)>> {
+    let function = test_abi
+        .functions
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("Missing JSON ABI for test {test_name}"))?;
+    let params = function
+        .inputs
+        .iter()
+        .map(|input| Ok((input.name.clone(), ArgType::from_abi(test_abi, input)?)))
+        .collect::<anyhow::Result<_>>()
+        .map_err(|e: anyhow::Error| {
+            anyhow::anyhow!("Unable to generate arguments for test {test_name}: {e}")
+        })?;
+    Ok(params)
+}
+
+/// Execute a test that takes the given parameters with `config.runs` sets of random arguments.
+///
+/// `run_case` executes the test with the given encoded arguments. Upon the first failing case,
+/// the failing arguments are shrunk and the result of the smallest failing case is returned.
+/// Otherwise, the result of the last case is returned. In both cases the returned duration is the
+/// total across all cases executed, and the returned coverage counts each line executed by any
+/// case once, as for a test without parameters.
+pub(crate) fn run_fuzz_test(
+    params: &[(String, ArgType)],
+    config: &FuzzConfig,
+    mut run_case: impl FnMut(Vec<u8>) -> anyhow::Result<TestResult>,
+) -> anyhow::Result<TestResult> {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(config.seed);
+    let mut duration = std::time::Duration::ZERO;
+    let mut coverage: Option<Coverage> = None;
+    let mut record = |result: &TestResult| {
+        duration += result.duration;
+        if let Some(case_coverage) = &result.coverage {
+            coverage
+                .get_or_insert_with(Coverage::default)
+                .union(case_coverage);
+        }
+    };
+    let mut runs = 0;
+    let mut last_result = None;
+    while runs < config.runs.max(1) {
+        let args = generate_args(params, &mut rng);
+        let result = run_case(args.encode())?;
+        runs += 1;
+        record(&result);
+        if !result.passed() {
+            let mut failing_result = result;
+            let (args, shrink_runs) = shrink_args(args, |candidate| {
+                let result = run_case(candidate.encode())?;
+                record(&result);
+                let fails = !result.passed();
+                if fails {
+                    failing_result = result;
+                }
+                Ok(fails)
+            })?;
+            failing_result.duration = duration;
+            failing_result.coverage = coverage;
+            failing_result.fuzz = Some(FuzzOutcome {
+                runs: runs + shrink_runs,
+                seed: config.seed,
+                counterexample: Some(args.to_string()),
+            });
+            return Ok(failing_result);
+        }
+        last_result = Some(result);
+    }
+    let mut result = last_result.expect("at least one case is executed");
+    result.duration = duration;
+    result.coverage = coverage;
+    result.fuzz = Some(FuzzOutcome {
+        runs,
+        seed: config.seed,
+        counterexample: None,
+    });
+    Ok(result)
+}
+
+/// Generate random arguments for the given parameters.
+pub(crate) fn generate_args(params: &[(String, ArgType)], rng: &mut impl Rng) -> TestArgs {
+    TestArgs(
+        params
+            .iter()
+            .map(|(name, ty)| (name.clone(), ty.generate(rng)))
+            .collect(),
+    )
+}
+
+/// Repeatedly replace `args` with a simpler set of arguments for which `fails` holds, until no
+/// simpler failing arguments can be found.
+///
+/// Returns the simplest failing arguments found along with the number of cases executed.
+pub(crate) fn shrink_args(
+    mut args: TestArgs,
+    mut fails: impl FnMut(&TestArgs) -> anyhow::Result<bool>,
+) -> anyhow::Result<(TestArgs, usize)> {
+    let mut runs = 0;
+    'shrink: while runs < MAX_SHRINK_RUNS {
+        for candidate in args.shrink() {
+            runs += 1;
+            if fails(&candidate)? {
+                args = candidate;
+                continue 'shrink;
+            }
+            if runs >= MAX_SHRINK_RUNS {
+                break;
+            }
+        }
+        break;
+    }
+    Ok((args, runs))
+}
+
+impl ArgType {
+    fn from_abi(test_abi: &ProgramABI, type_application: &TypeApplication) -> anyhow::Result<Self> {
+        let type_decl = test_abi
+            .types
+            .iter()
+            .find(|type_decl| type_decl.type_id == type_application.type_id)
+            .ok_or_else(|| {
+                anyhow::anyhow!("missing type declaration for {}", type_application.name)
+            })?;
+        let type_field = type_decl.type_field.as_str();
+        let is_generic = matches!(&type_application.type_arguments, Some(args) if !args.is_empty())
+            || matches!(&type_decl.type_parameters, Some(params) if !params.is_empty());
+        if is_generic {
+            anyhow::bail!("generic type `{type_field}` is not supported");
+        }
+        let components = || -> anyhow::Result<Vec<(String, ArgType)>> {
+            type_decl
+                .components
+                .iter()
+                .flatten()
+                .map(|component| {
+                    Ok((
+                        component.name.clone(),
+                        ArgType::from_abi(test_abi, component)?,
+                    ))
+                })
+                .collect()
+        };
+        let ty = match type_field {
+            "bool" => ArgType::Bool,
+            "u8" => ArgType::U8,
+            "u16" => ArgType::U16,
+            "u32" => ArgType::U32,
+            "u64" => ArgType::U64,
+            "b256" => ArgType::B256,
+            _ if type_field.starts_with("struct ") => {
+                let name = type_field.trim_start_matches("struct ").to_string();
+                ArgType::Struct(name, components()?)
+            }
+            _ if type_field.starts_with('(') => {
+                ArgType::Tuple(components()?.into_iter().map(|(_, ty)| ty).collect())
+            }
+            _ if type_field.starts_with('[') => {
+                let len = type_field
+                    .trim_end_matches(']')
+                    .rsplit("; ")
+                    .next()
+                    .and_then(|len| len.parse().ok())
+                    .ok_or_else(|| anyhow::anyhow!("invalid array type `{type_field}`"))?;
+                let elem_ty = components()?
+                    .into_iter()
+                    .next()
+                    .map(|(_, ty)| ty)
+                    .ok_or_else(|| anyhow::anyhow!("invalid array type `{type_field}`"))?;
+                ArgType::Array(Box::new(elem_ty), len)
+            }
+            _ => anyhow::bail!("type `{type_field}` is not supported"),
+        };
+        Ok(ty)
+    }
+
+    /// Generate a random value of this type.
+    ///
+    /// Integers are occasionally generated at their boundaries as these values tend to be the
+    /// most likely to expose bugs.
+    fn generate(&self, rng: &mut impl Rng) -> ArgValue {
+        fn int<T: Copy>(rng: &mut impl Rng, edges: [T; 3]) -> T
+        where
+            rand::distributions::Standard: rand::distributions::Distribution<T>,
+        {
+            match rng.gen_range(0..8) {
+                0 => edges[rng.gen_range(0..edges.len())],
+                _ => rng.gen(),
+            }
+        }
+        match self {
+            ArgType::Bool => ArgValue::Bool(rng.gen()),
+            ArgType::U8 => ArgValue::U8(int(rng, [0, 1, u8::MAX])),
+            ArgType::U16 => ArgValue::U16(int(rng, [0, 1, u16::MAX])),
+            ArgType::U32 => ArgValue::U32(int(rng, [0, 1, u32::MAX])),
+            ArgType::U64 => ArgValue::U64(int(rng, [0, 1, u64::MAX])),
+            ArgType::B256 => ArgValue::B256(rng.gen()),
+            ArgType::Struct(name, fields) => ArgValue::Struct(
+                name.clone(),
+                fields
+                    .iter()
+                    .map(|(name, ty)| (name.clone(), ty.generate(rng)))
+                    .collect(),
+            ),
+            ArgType::Tuple(elems) => {
+                ArgValue::Tuple(elems.iter().map(|ty| ty.generate(rng)).collect())
+            }
+            ArgType::Array(elem, len) => {
+                ArgValue::Array((0..*len).map(|_| elem.generate(rng)).collect())
+            }
+        }
+    }
+}
+
+impl ArgValue {
+    /// Append the encoding of this value to `buf`.
+    ///
+    /// Values are encoded in the same layout in which the VM reads them from memory: integers
+    /// and booleans each occupy a single big-endian word while composite values are the
+    /// concatenation of their elements.
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            ArgValue::Bool(b) => buf.extend((*b as u64).to_be_bytes()),
+            ArgValue::U8(n) => buf.extend((*n as u64).to_be_bytes()),
+            ArgValue::U16(n) => buf.extend((*n as u64).to_be_bytes()),
+            ArgValue::U32(n) => buf.extend((*n as u64).to_be_bytes()),
+            ArgValue::U64(n) => buf.extend(n.to_be_bytes()),
+            ArgValue::B256(bytes) => buf.extend(bytes),
+            ArgValue::Struct(_, fields) => fields.iter().for_each(|(_, v)| v.encode(buf)),
+            ArgValue::Tuple(elems) | ArgValue::Array(elems) => {
+                elems.iter().for_each(|v| v.encode(buf))
+            }
+        }
+    }
+
+    /// Values that are simpler than this one, from simplest to least simple.
+    fn shrink(&self) -> Vec<ArgValue> {
+        // Try zero first, then successively smaller steps down from `n` so that the shrunk value
+        // converges on the boundary of the failure in a logarithmic number of steps.
+        fn int(n: u64) -> Vec<u64> {
+            let mut candidates = vec![];
+            if n > 0 {
+                candidates.push(0);
+            }
+            let mut delta = n / 2;
+            while delta > 0 {
+                candidates.push(n - delta);
+                delta /= 2;
+            }
+            candidates.dedup();
+            candidates
+        }
+        match self {
+            ArgValue::Bool(b) => match b {
+                true => vec![ArgValue::Bool(false)],
+                false => vec![],
+            },
+            ArgValue::U8(n) => int(*n as u64)
+                .into_iter()
+                .map(|n| ArgValue::U8(n as u8))
+                .collect(),
+            ArgValue::U16(n) => int(*n as u64)
+                .into_iter()
+                .map(|n| ArgValue::U16(n as u16))
+                .collect(),
+            ArgValue::U32(n) => int(*n as u64)
+                .into_iter()
+                .map(|n| ArgValue::U32(n as u32))
+                .collect(),
+            ArgValue::U64(n) => int(*n).into_iter().map(ArgValue::U64).collect(),
+            ArgValue::B256(bytes) => match bytes.iter().all(|b| *b == 0) {
+                true => vec![],
+                false => vec![ArgValue::B256([0; 32])],
+            },
+            ArgValue::Struct(name, fields) => shrink_fields(fields)
+                .into_iter()
+                .map(|fields| ArgValue::Struct(name.clone(), fields))
+                .collect(),
+            ArgValue::Tuple(elems) => shrink_elems(elems)
+                .into_iter()
+                .map(ArgValue::Tuple)
+                .collect(),
+            ArgValue::Array(elems) => shrink_elems(elems)
+                .into_iter()
+                .map(ArgValue::Array)
+                .collect(),
+        }
+    }
+}
+
+/// Shrink one element at a time, leaving the rest unchanged.
+fn shrink_elems(elems: &[ArgValue]) -> Vec<Vec<ArgValue>> {
+    let mut candidates = vec![];
+    for (ix, elem) in elems.iter().enumerate() {
+        for shrunk in elem.shrink() {
+            let mut candidate = elems.to_vec();
+            candidate[ix] = shrunk;
+            candidates.push(candidate);
+        }
+    }
+    candidates
+}
+
+/// Shrink one named field at a time, leaving the rest unchanged.
+fn shrink_fields(fields: &[(String, ArgValue)]) -> Vec<Vec<(String, ArgValue)>> {
+    let (names, values): (Vec<_>, Vec<_>) = fields.iter().cloned().unzip();
+    shrink_elems(&values)
+        .into_iter()
+        .map(|values| names.iter().cloned().zip(values).collect())
+        .collect()
+}
+
+impl TestArgs {
+    /// Encode the arguments as the script data from which a test entry reads its parameters.
+    pub(crate) fn encode(&self) -> Vec<u8> {
+        let mut buf = vec![];
+        self.0.iter().for_each(|(_, value)| value.encode(&mut buf));
+        buf
+    }
+
+    fn shrink(&self) -> Vec<TestArgs> {
+        shrink_fields(&self.0).into_iter().map(TestArgs).collect()
+    }
+}
+
+impl fmt::Display for ArgValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgValue::Bool(b) => write!(f, "{b}"),
+            ArgValue::U8(n) => write!(f, "{n}"),
+            ArgValue::U16(n) => write!(f, "{n}"),
+            ArgValue::U32(n) => write!(f, "{n}"),
+            ArgValue::U64(n) => write!(f, "{n}"),
+            ArgValue::B256(bytes) => write!(f, "0x{}", hex::encode(bytes)),
+            ArgValue::Struct(name, fields) => {
+                write!(f, "{name} {{ ")?;
+                for (ix, (field_name, value)) in fields.iter().enumerate() {
+                    if ix > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{field_name}: {value}")?;
+                }
+                write!(f, " }}")
+            }
+            ArgValue::Tuple(elems) => {
+                write!(f, "(")?;
+                for (ix, value) in elems.iter().enumerate() {
+                    if ix > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                if elems.len() == 1 {
+                    write!(f, ",")?;
+                }
+                write!(f, ")")
+            }
+            ArgValue::Array(elems) => {
+                write!(f, "[")?;
+                for (ix, value) in elems.iter().enumerate() {
+                    if ix > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl fmt::Display for TestArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (ix, (name, value)) in self.0.iter().enumerate() {
+            if ix > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{name} = {value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{shrink_args, ArgValue, TestArgs};
+
+    #[test]
+    fn test_encode_args() {
+        let args = TestArgs(vec![
+            ("a".to_string(), ArgValue::U8(1)),
+            (
+                "p".to_string(),
+                ArgValue::Struct(
+                    "Point".to_string(),
+                    vec![
+                        ("x".to_string(), ArgValue::U64(2)),
+                        ("ok".to_string(), ArgValue::Bool(true)),
+                    ],
+                ),
+            ),
+        ]);
+        let mut expected = vec![];
+        expected.extend(1u64.to_be_bytes());
+        expected.extend(2u64.to_be_bytes());
+        expected.extend(1u64.to_be_bytes());
+        assert_eq!(args.encode(), expected);
+        assert_eq!(args.to_string(), "a = 1, p = Point { x: 2, ok: true }");
+    }
+
+    #[test]
+    fn test_shrink_args_to_minimal_failure() {
+        let args = TestArgs(vec![
+            ("a".to_string(), ArgValue::U64(1_000_000)),
+            ("b".to_string(), ArgValue::Bool(true)),
+        ]);
+        // Fails whenever `a` is at least 100, regardless of `b`.
+        let (shrunk, _) = shrink_args(args, |args| {
+            Ok(matches!(args.0[0].1, ArgValue::U64(a) if a >= 100))
+        })
+        .unwrap();
+        assert_eq!(
+            shrunk,
+            TestArgs(vec![
+                ("a".to_string(), ArgValue::U64(100)),
+                ("b".to_string(), ArgValue::Bool(false)),
+            ])
+        );
+    }
+}
@@ -0,0 +1,133 @@
+//! Gas usage snapshots for comparing the gas used by tests between runs.
+
+use crate::{Tested, TestedPackage};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, path::Path};
+
+/// The gas used by each test, keyed first by package name and then by test name.
+///
+/// Serialized as JSON so that snapshots can be committed and diffed alongside the tests.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub struct GasSnapshot {
+    pub packages: BTreeMap<String, BTreeMap<String, u64>>,
+}
+
+/// A test whose gas usage increased beyond the allowed tolerance relative to a snapshot.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GasRegression {
+    pub pkg_name: String,
+    pub test_name: String,
+    /// The gas used by the test as recorded in the snapshot.
+    pub expected: u64,
+    /// The gas used by the test in the current run.
+    pub actual: u64,
+}
+
+impl GasSnapshot {
+    /// Collect the gas used by each test within the given tested package or workspace.
+    pub fn from_tested(tested: &Tested) -> Self {
+        let mut snapshot = Self::default();
+        match tested {
+            Tested::Package(pkg) => snapshot.insert_pkg(pkg),
+            Tested::Workspace(pkgs) => pkgs.iter().for_each(|pkg| snapshot.insert_pkg(pkg)),
+        }
+        snapshot
+    }
+
+    fn insert_pkg(&mut self, pkg: &TestedPackage) {
+        let tests = pkg
+            .tests
+            .iter()
+            .map(|test| (test.name.clone(), test.gas_used))
+            .collect();
+        self.packages.insert(pkg.built.pkg_name.clone(), tests);
+    }
+
+    /// Load a snapshot from the JSON file at the given path.
+    pub fn from_file(path: &Path) -> anyhow::Result<Self> {
+        let contents = fs::read_to_string(path).map_err(|e| {
+            anyhow::anyhow!("failed to read gas snapshot {}: {}", path.display(), e)
+        })?;
+        let snapshot = serde_json::from_str(&contents).map_err(|e| {
+            anyhow::anyhow!("failed to parse gas snapshot {}: {}", path.display(), e)
+        })?;
+        Ok(snapshot)
+    }
+
+    /// Write this snapshot to the given path as pretty-printed JSON.
+    pub fn to_file(&self, path: &Path) -> anyhow::Result<()> {
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(path, contents).map_err(|e| {
+            anyhow::anyhow!("failed to write gas snapshot {}: {}", path.display(), e)
+        })?;
+        Ok(())
+    }
+
+    /// Compare the `current` gas usage against this snapshot.
+    ///
+    /// Returns every test whose gas usage grew by more than `tolerance_pct` percent of the value
+    /// recorded in this snapshot. Tests that are missing from this snapshot are ignored.
+    pub fn regressions(&self, current: &GasSnapshot, tolerance_pct: f64) -> Vec<GasRegression> {
+        let mut regressions = vec![];
+        for (pkg_name, tests) in &current.packages {
+            let expected_tests = match self.packages.get(pkg_name) {
+                Some(tests) => tests,
+                None => continue,
+            };
+            for (test_name, &actual) in tests {
+                let expected = match expected_tests.get(test_name) {
+                    Some(&expected) => expected,
+                    None => continue,
+                };
+                let allowed = expected as f64 * (1.0 + tolerance_pct / 100.0);
+                if actual as f64 > allowed {
+                    regressions.push(GasRegression {
+                        pkg_name: pkg_name.clone(),
+                        test_name: test_name.clone(),
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        }
+        regressions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GasRegression, GasSnapshot};
+
+    fn snapshot(tests: &[(&str, u64)]) -> GasSnapshot {
+        let tests = tests
+            .iter()
+            .map(|(name, gas)| (name.to_string(), *gas))
+            .collect();
+        let mut snapshot = GasSnapshot::default();
+        snapshot.packages.insert("pkg".to_string(), tests);
+        snapshot
+    }
+
+    #[test]
+    fn test_gas_regressions_beyond_tolerance() {
+        let old = snapshot(&[("a", 100), ("b", 100), ("c", 100)]);
+        let new = snapshot(&[("a", 90), ("b", 105), ("c", 111), ("d", 1000)]);
+        let regressions = old.regressions(&new, 10.0);
+        assert_eq!(
+            regressions,
+            vec![GasRegression {
+                pkg_name: "pkg".to_string(),
+                test_name: "c".to_string(),
+                expected: 100,
+                actual: 111,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_gas_regressions_zero_tolerance() {
+        let old = snapshot(&[("a", 100)]);
+        assert!(old.regressions(&snapshot(&[("a", 100)]), 0.0).is_empty());
+        assert_eq!(old.regressions(&snapshot(&[("a", 101)]), 0.0).len(), 1);
+    }
+}
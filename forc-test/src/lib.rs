@@ -1,13 +1,47 @@
-use std::{collections::HashSet, fs, path::PathBuf, sync::Arc};
+mod context;
+mod coverage;
+mod fuzz;
+mod gas;
+mod report;
 
+pub use coverage::{format_percent, Coverage, HTML_FILE_NAME, LCOV_FILE_NAME};
+pub use fuzz::{FuzzConfig, FuzzOutcome, DEFAULT_FUZZ_RUNS, DEFAULT_FUZZ_SEED};
+pub use gas::{GasRegression, GasSnapshot};
+pub use report::{PackageTestReport, TestCaseReport, TestReport};
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use context::TestContext;
+use coverage::{ExecutedInstruction, SourceLines};
 use forc_pkg as pkg;
 use fuel_tx as tx;
-use fuel_vm::{self as vm, prelude::Opcode};
+use fuel_vm::{self as vm, prelude::Opcode, storage::InterpreterStorage};
 use pkg::{Built, BuiltPackage};
 use rand::{distributions::Standard, prelude::Distribution, Rng, SeedableRng};
-use sway_core::{language::ty::TyFunctionDeclaration, transform::AttributeKind, BuildTarget};
-use sway_types::{Span, Spanned};
-use tx::{AssetId, TxPointer, UtxoId};
+use rayon::prelude::*;
+use serde::Serialize;
+use sway_ast::Literal;
+use sway_core::{
+    language::ty::TyFunctionDeclaration,
+    transform::{AttributeArg, AttributeKind},
+    BuildTarget,
+};
+use sway_types::{
+    constants::{
+        TEST_BLOCK_HEIGHT_NAME, TEST_INPUT_COIN_NAME, TEST_OUTPUT_COIN_NAME,
+        TEST_OUTPUT_VARIABLES_NAME, TEST_SENDER_NAME, TEST_SHOULD_LOG_DATA_NAME,
+        TEST_SHOULD_LOG_NAME, TEST_SHOULD_REVERT_NAME, TEST_STORAGE_SLOTS_NAME,
+        TEST_TIMESTAMP_NAME,
+    },
+    Span, Spanned,
+};
+use tracing::info;
+use tx::{AssetId, IntoChecked, TxPointer, UtxoId};
 use vm::prelude::SecretKey;
 
 /// The result of a `forc test` invocation.
@@ -23,6 +57,9 @@ pub struct TestedPackage {
     pub built: Box<pkg::BuiltPackage>,
     /// The resulting `ProgramState` after executing the test.
     pub tests: Vec<TestResult>,
+    /// The number of tests that executed each source line of the package, if coverage was
+    /// requested.
+    pub coverage: Option<Coverage>,
 }
 
 #[derive(Debug)]
@@ -48,15 +85,39 @@ pub struct TestResult {
     pub condition: TestPassCondition,
     /// Emitted `Recipt`s during the execution of the test.
     pub logs: Vec<fuel_tx::Receipt>,
+    /// The logs that must be emitted, in order, during the execution of the test for it to pass.
+    pub expected_logs: Vec<ExpectedLog>,
+    /// The gas used during the execution of the test, as reported by the `ScriptResult` receipt.
+    pub gas_used: u64,
+    /// For tests that take parameters, the outcome of executing the test with random arguments.
+    ///
+    /// In this case the rest of the result describes the smallest failing case if any case
+    /// failed, or the last case otherwise.
+    pub fuzz: Option<FuzzOutcome>,
+    /// The source lines executed by the test, if coverage was requested.
+    pub coverage: Option<Coverage>,
 }
 
 /// The possible conditions for a test result to be considered "passing".
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum TestPassCondition {
-    ShouldRevert,
+    /// The test must revert, optionally with the given revert code.
+    ShouldRevert(Option<u64>),
     ShouldNotRevert,
 }
 
+/// A log that a test asserts is emitted during its execution.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExpectedLog {
+    /// A `Log` receipt whose `ra` register value is the given value, requested via
+    /// `#[test(should_log = <value>)]`.
+    Log(u64),
+    /// A `LogData` receipt whose data is the given bytes, requested via
+    /// `#[test(should_log_data = "0x<hex>")]`.
+    LogData(Vec<u8>),
+}
+
 /// A package or a workspace that has been built, ready for test execution.
 pub enum BuiltTests {
     Package(PackageTests),
@@ -70,7 +131,7 @@ pub enum BuiltTests {
 #[derive(Debug)]
 pub enum PackageTests {
     Contract(ContractToTest),
-    NonContract(pkg::BuiltPackage),
+    NonContract(NonContractToTest),
 }
 
 /// A built contract ready for test execution.
@@ -81,6 +142,27 @@ pub enum PackageTests {
 pub struct ContractToTest {
     pub tests_included: pkg::BuiltPackage,
     pub tests_excluded: pkg::BuiltPackage,
+    /// The contract dependencies to deploy before executing the tests.
+    pub contract_dependencies: Vec<ContractDependencyToTest>,
+}
+
+/// A built library, script or predicate ready for test execution.
+#[derive(Debug)]
+pub struct NonContractToTest {
+    pub built: pkg::BuiltPackage,
+    /// The contract dependencies to deploy before executing the tests.
+    pub contract_dependencies: Vec<ContractDependencyToTest>,
+}
+
+/// A contract dependency (direct or transitive) of a package under test.
+///
+/// Contract dependencies are deployed with their declared `salt` before the tests of the dependent
+/// package are executed. Tests can refer to the deployed contract through the dependency's
+/// `CONTRACT_ID` constant, e.g. `abi(MyAbi, my_dep::CONTRACT_ID)`.
+#[derive(Debug)]
+pub struct ContractDependencyToTest {
+    pub built: pkg::BuiltPackage,
+    pub salt: tx::Salt,
 }
 
 /// The set of options provided to the `test` function.
@@ -106,6 +188,27 @@ pub struct Opts {
     pub time_phases: bool,
 }
 
+/// Used to control the number of test runners used to execute tests in parallel.
+///
+/// The number of runners can be specified with `Manual`, or left for forc-test to decide with
+/// `Auto`, in which case one runner per available CPU is used.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum TestRunnerCount {
+    Manual(usize),
+    #[default]
+    Auto,
+}
+
+/// A filter used to select the tests to run by name.
+#[derive(Clone, Debug)]
+pub struct TestFilter<'a> {
+    /// The phrase used to filter test names.
+    pub filter_phrase: &'a str,
+    /// If set, only tests whose name matches `filter_phrase` exactly are selected. Otherwise,
+    /// tests whose name contains `filter_phrase` are selected.
+    pub exact_match: bool,
+}
+
 /// The set of options provided for controlling logs printed for each test.
 #[derive(Default, Clone)]
 pub struct TestPrintOpts {
@@ -126,10 +229,39 @@ struct TxMetadata {
 }
 
 /// The storage and the contract id (if a contract is being tested) for a test.
-#[derive(Debug)]
+///
+/// A `TestSetup` is created once per package and cloned for each test, so that every test runs
+/// against a fresh copy of the storage.
+#[derive(Clone, Debug, Default)]
 struct TestSetup {
     storage: vm::storage::MemoryStorage,
     contract_id: Option<tx::ContractId>,
+    /// The ids of the deployed contract dependencies of the package under test.
+    contract_dependency_ids: Vec<tx::ContractId>,
+}
+
+impl ExpectedLog {
+    /// Whether or not the given receipt is the expected log.
+    pub fn matches(&self, receipt: &tx::Receipt) -> bool {
+        match (self, receipt) {
+            (ExpectedLog::Log(value), tx::Receipt::Log { ra, .. }) => value == ra,
+            (ExpectedLog::LogData(bytes), tx::Receipt::LogData { data, .. }) => bytes == data,
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for ExpectedLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExpectedLog::Log(value) => write!(f, "{TEST_SHOULD_LOG_NAME} = {value}"),
+            ExpectedLog::LogData(bytes) => write!(
+                f,
+                "{TEST_SHOULD_LOG_DATA_NAME} = \"0x{}\"",
+                hex::encode(bytes)
+            ),
+        }
+    }
 }
 
 impl BuiltTests {
@@ -139,16 +271,31 @@ impl BuiltTests {
     /// is a `Contract` so that only that contract is re-built.
     ///
     /// If the `built` is a package, `PackageTests::from_built_pkg` is used.
-    pub(crate) fn from_built(built: Built, opts: Opts) -> anyhow::Result<BuiltTests> {
+    pub(crate) fn from_built(
+        built: Built,
+        opts: Opts,
+        build_plan: &pkg::BuildPlan,
+        built_contract_deps: &HashMap<pkg::NodeIx, BuiltPackage>,
+    ) -> anyhow::Result<BuiltTests> {
         let built = match built {
-            Built::Package(pkg) => BuiltTests::Package(PackageTests::from_built_pkg(*pkg, opts)?),
+            Built::Package(pkg) => BuiltTests::Package(PackageTests::from_built_pkg(
+                *pkg,
+                opts,
+                build_plan,
+                built_contract_deps,
+            )?),
             Built::Workspace(workspace) => {
                 let packages = workspace
                     .into_values()
                     .map(|built_pkg| {
                         let path = built_pkg.manifest_file.dir();
                         let patched_opts = opts.clone().patch_opts(path);
-                        PackageTests::from_built_pkg(built_pkg, patched_opts)
+                        PackageTests::from_built_pkg(
+                            built_pkg,
+                            patched_opts,
+                            build_plan,
+                            built_contract_deps,
+                        )
                     })
                     .collect::<anyhow::Result<_>>()?;
                 BuiltTests::Workspace(packages)
@@ -166,14 +313,32 @@ impl<'a> PackageTests {
     pub(crate) fn built_pkg_with_tests(&'a self) -> &'a BuiltPackage {
         match self {
             PackageTests::Contract(contract) => &contract.tests_included,
-            PackageTests::NonContract(non_contract) => non_contract,
+            PackageTests::NonContract(non_contract) => &non_contract.built,
+        }
+    }
+
+    /// Return the contract dependencies that must be deployed before running the tests.
+    pub(crate) fn contract_dependencies(&self) -> &[ContractDependencyToTest] {
+        match self {
+            PackageTests::Contract(contract) => &contract.contract_dependencies,
+            PackageTests::NonContract(non_contract) => &non_contract.contract_dependencies,
         }
     }
 
     /// Construct a `PackageTests` from `BuiltPackage`.
     ///
     /// If the built package is a `Contract`, this will re-compile the package with tests disabled.
-    fn from_built_pkg(built_pkg: BuiltPackage, opts: Opts) -> anyhow::Result<PackageTests> {
+    ///
+    /// The package's contract dependencies, which were compiled along with the package, are
+    /// collected so that they may be deployed prior to executing the tests.
+    fn from_built_pkg(
+        built_pkg: BuiltPackage,
+        opts: Opts,
+        build_plan: &pkg::BuildPlan,
+        built_contract_deps: &HashMap<pkg::NodeIx, BuiltPackage>,
+    ) -> anyhow::Result<PackageTests> {
+        let contract_dependencies =
+            contract_dependencies(&built_pkg, build_plan, built_contract_deps)?;
         let tree_type = &built_pkg.tree_type;
         let package_test = match tree_type {
             sway_core::language::parsed::TreeType::Contract => {
@@ -184,82 +349,260 @@ impl<'a> PackageTests {
                 let contract_to_test = ContractToTest {
                     tests_included: built_pkg,
                     tests_excluded: pkg_without_tests,
+                    contract_dependencies,
                 };
                 PackageTests::Contract(contract_to_test)
             }
-            _ => PackageTests::NonContract(built_pkg),
+            _ => PackageTests::NonContract(NonContractToTest {
+                built: built_pkg,
+                contract_dependencies,
+            }),
         };
         Ok(package_test)
     }
 
-    /// Run all tests for this package and collect their results.
-    pub(crate) fn run_tests(&self) -> anyhow::Result<TestedPackage> {
+    /// Run all tests for this package that match the given `filter` and collect their results.
+    ///
+    /// Tests are executed in parallel using the given pool of test runners. Each test is executed
+    /// against its own copy of the package's storage, so tests are independent of one another.
+    ///
+    /// Tests that take parameters are executed once for each of the `fuzz_config.runs` sets of
+    /// randomly generated arguments.
+    ///
+    /// If `collect_coverage` is set, the source lines executed by each test are recorded.
+    pub(crate) fn run_tests(
+        &self,
+        test_runners: &rayon::ThreadPool,
+        filter: Option<&TestFilter>,
+        fuzz_config: &FuzzConfig,
+        collect_coverage: bool,
+    ) -> anyhow::Result<TestedPackage> {
         let pkg_with_tests = self.built_pkg_with_tests();
-        // TODO: We can easily parallelise this, but let's wait until testing is stable first.
-        let tests = pkg_with_tests
-            .entries
-            .iter()
-            .filter(|entry| entry.is_test())
-            .map(|entry| {
-                let offset =
-                    u32::try_from(entry.imm).expect("test instruction offset out of range");
-                let name = entry.fn_name.clone();
-                let test_setup = self.setup()?;
-                let (state, duration, receipts) =
-                    exec_test(&pkg_with_tests.bytecode, offset, test_setup);
-
-                // Only retain `Log` and `LogData` receipts.
-                let logs = receipts
-                    .into_iter()
-                    .filter(|receipt| {
-                        matches!(receipt, fuel_tx::Receipt::Log { .. })
-                            || matches!(receipt, fuel_tx::Receipt::LogData { .. })
-                    })
-                    .collect();
-
-                let test_decl_id = entry
-                    .test_decl_id
-                    .clone()
-                    .expect("test entry point is missing declaration id");
-                let span = test_decl_id.span();
-                let test_function_decl = pkg_with_tests
-                    .decl_engine
-                    .get_function(test_decl_id, &span)
-                    .expect("declaration engine is missing function declaration for test");
-                let condition = test_pass_condition(&test_function_decl)?;
-                Ok(TestResult {
-                    name,
-                    duration,
-                    span,
-                    state,
-                    condition,
-                    logs,
+        let manifest_dir = pkg_with_tests.manifest_file.dir();
+        let package_setup = self.setup()?;
+        let source_lines = collect_coverage.then(|| self.source_lines(&package_setup));
+        let tests = test_runners.install(|| {
+            pkg_with_tests
+                .entries
+                .par_iter()
+                .filter(|entry| entry.is_test() && filter_test(filter, &entry.fn_name))
+                .map(|entry| {
+                    let offset =
+                        u32::try_from(entry.imm).expect("test instruction offset out of range");
+                    let name = entry.fn_name.clone();
+                    let test_decl_id = entry
+                        .test_decl_id
+                        .clone()
+                        .expect("test entry point is missing declaration id");
+                    let test_abi = pkg_with_tests.json_abi_tests.get(&*test_decl_id);
+                    let span = test_decl_id.span();
+                    let test_function_decl = pkg_with_tests
+                        .decl_engine
+                        .get_function(test_decl_id, &span)
+                        .expect("declaration engine is missing function declaration for test");
+                    let condition = test_pass_condition(&test_function_decl)?;
+                    let expected_logs = test_expected_logs(&test_function_decl)?;
+                    let storage_slots = test_storage_slots(&test_function_decl, manifest_dir)?;
+                    let context = TestContext::from_test_decl(
+                        &test_function_decl,
+                        package_setup.contract_ids().count(),
+                    )?;
+
+                    // Execute the test with the given script data, from which the test reads its
+                    // arguments (if any).
+                    let run_case = |script_data: Vec<u8>| -> anyhow::Result<TestResult> {
+                        // Each test case starts from a fresh copy of the package's storage,
+                        // optionally seeded with the storage slots requested by the test.
+                        let mut test_setup = package_setup.clone();
+                        if let Some(storage_slots) = &storage_slots {
+                            test_setup.seed_storage(storage_slots)?;
+                        }
+                        let TestExecution {
+                            state,
+                            duration,
+                            receipts,
+                            executed,
+                        } = exec_test(
+                            &pkg_with_tests.bytecode,
+                            offset,
+                            test_setup,
+                            &context,
+                            script_data,
+                            collect_coverage,
+                        )?;
+                        let coverage = source_lines
+                            .as_ref()
+                            .zip(executed)
+                            .map(|(source_lines, executed)| source_lines.covered(&executed));
+
+                        let gas_used = receipts
+                            .iter()
+                            .find_map(|receipt| receipt.gas_used())
+                            .unwrap_or_default();
+
+                        // Only retain `Log` and `LogData` receipts.
+                        let logs = receipts
+                            .into_iter()
+                            .filter(|receipt| {
+                                matches!(receipt, fuel_tx::Receipt::Log { .. })
+                                    || matches!(receipt, fuel_tx::Receipt::LogData { .. })
+                            })
+                            .collect();
+
+                        Ok(TestResult {
+                            name: name.clone(),
+                            duration,
+                            span: span.clone(),
+                            state,
+                            condition: condition.clone(),
+                            logs,
+                            expected_logs: expected_logs.clone(),
+                            gas_used,
+                            fuzz: None,
+                            coverage,
+                        })
+                    };
+
+                    // Tests that take parameters are executed many times with random arguments.
+                    let params = test_abi
+                        .map(|test_abi| fuzz::test_params(test_abi, &name))
+                        .transpose()?;
+                    match params {
+                        Some(params) => fuzz::run_fuzz_test(&params, fuzz_config, run_case),
+                        None => run_case(vec![]),
+                    }
                 })
-            })
-            .collect::<anyhow::Result<_>>()?;
+                .collect::<anyhow::Result<Vec<_>>>()
+        })?;
+        let coverage = source_lines.map(|source_lines| {
+            let mut coverage = source_lines.instrumented();
+            for test_coverage in tests.iter().filter_map(|test| test.coverage.as_ref()) {
+                coverage.merge(test_coverage);
+            }
+            coverage
+        });
         let tested_pkg = TestedPackage {
             built: Box::new(pkg_with_tests.clone()),
             tests,
+            coverage,
         };
         Ok(tested_pkg)
     }
 
-    /// Setup the storage for a test and returns a contract id for testing contracts.
+    /// Setup the storage shared by the tests of this package and returns a contract id for testing
+    /// contracts.
     ///
-    /// For testing contracts, storage returned from this function contains the deployed contract.
-    /// For other types, default storage is returned.
+    /// The storage returned from this function contains all deployed contract dependencies of the
+    /// package. For testing contracts, it also contains the deployed contract itself.
     fn setup(&self) -> anyhow::Result<TestSetup> {
-        match self {
-            PackageTests::Contract(contract_to_test) => {
-                let contract_pkg_without_tests = contract_to_test.tests_excluded.clone();
-                let test_setup = deploy_test_contract(contract_pkg_without_tests)?;
-                Ok(test_setup)
-            }
-            PackageTests::NonContract(_) => Ok(TestSetup {
-                storage: vm::storage::MemoryStorage::default(),
-                contract_id: None,
-            }),
+        let mut test_setup = TestSetup::default();
+        for contract_dependency in self.contract_dependencies() {
+            let built = &contract_dependency.built;
+            let contract_id = test_setup.deploy_contract(built, contract_dependency.salt)?;
+            info!(
+                "      Deploying contract dependency {} with id {:?} for testing",
+                built.pkg_name, contract_id
+            );
+            test_setup.contract_dependency_ids.push(contract_id);
+        }
+        if let PackageTests::Contract(contract_to_test) = self {
+            let contract_pkg_without_tests = &contract_to_test.tests_excluded;
+            let contract_id =
+                test_setup.deploy_contract(contract_pkg_without_tests, tx::Salt::zeroed())?;
+            // TODO: Remove this prompt once https://github.com/FuelLabs/sway/issues/3673 is addressed.
+            info!(
+                "      Deploying contract with id {:?} for testing",
+                contract_id
+            );
+            test_setup.contract_id = Some(contract_id);
         }
+        Ok(test_setup)
+    }
+
+    /// Map the instructions of the tests and of the contracts deployed within the given setup to
+    /// their source lines.
+    fn source_lines(&self, test_setup: &TestSetup) -> SourceLines {
+        let contract_under_test = match self {
+            PackageTests::Contract(contract) => test_setup
+                .contract_id
+                .map(|contract_id| (contract_id, &contract.tests_excluded)),
+            PackageTests::NonContract(_) => None,
+        };
+        // Contract dependencies are deployed in order, so their ids line up with their packages.
+        let contract_dependencies = test_setup
+            .contract_dependency_ids
+            .iter()
+            .cloned()
+            .zip(self.contract_dependencies().iter().map(|dep| &dep.built));
+        let contracts: Vec<_> = contract_under_test
+            .into_iter()
+            .chain(contract_dependencies)
+            .collect();
+        SourceLines::new(self.built_pkg_with_tests(), &contracts)
+    }
+}
+
+impl TestSetup {
+    /// Deploys the given contract into this setup's storage, returning the deployed contract's id.
+    fn deploy_contract(
+        &mut self,
+        built_pkg: &BuiltPackage,
+        salt: tx::Salt,
+    ) -> anyhow::Result<tx::ContractId> {
+        // Obtain the contract id for deployment.
+        let mut storage_slots = built_pkg.storage_slots.clone();
+        storage_slots.sort();
+        let bytecode = built_pkg.bytecode.clone();
+        let contract = tx::Contract::from(bytecode.clone());
+        let root = contract.root();
+        let state_root = tx::Contract::initial_state_root(storage_slots.iter());
+        let contract_id = contract.id(&salt, &root, &state_root);
+
+        // Setup the interpreter for deployment.
+        let params = tx::ConsensusParameters::default();
+        let mut interpreter =
+            vm::interpreter::Interpreter::with_storage(self.storage.clone(), params);
+
+        // Create the deployment transaction.
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0x7E57u64);
+        let metadata: TxMetadata = rng.gen();
+
+        let tx = tx::TransactionBuilder::create(bytecode.into(), salt, storage_slots)
+            .add_unsigned_coin_input(
+                metadata.secret_key,
+                metadata.utxo_id,
+                metadata.amount,
+                metadata.asset_id,
+                metadata.tx_pointer,
+                metadata.maturity,
+            )
+            .add_output(tx::Output::contract_created(contract_id, state_root))
+            .maturity(metadata.maturity)
+            .finalize_checked(metadata.block_height, &params);
+
+        // Deploy the contract.
+        interpreter.transact(tx)?;
+        self.storage = interpreter.as_ref().clone();
+        Ok(contract_id)
+    }
+
+    /// The ids of all contracts deployed within this setup, starting with the contract under test.
+    fn contract_ids(&self) -> impl '_ + Iterator<Item = tx::ContractId> {
+        self.contract_id
+            .into_iter()
+            .chain(self.contract_dependency_ids.iter().cloned())
+    }
+
+    /// Write the given storage slots into the storage of the contract under test.
+    fn seed_storage(&mut self, storage_slots: &[tx::StorageSlot]) -> anyhow::Result<()> {
+        let contract_id = self.contract_id.ok_or_else(|| {
+            anyhow::anyhow!("storage slots can only be provided for tests within contracts")
+        })?;
+        for slot in storage_slots {
+            self.storage
+                .merkle_contract_state_insert(&contract_id, slot.key(), slot.value())?;
+        }
+        Ok(())
     }
 }
 
@@ -312,14 +655,58 @@ impl Opts {
 impl TestResult {
     /// Whether or not the test passed.
     pub fn passed(&self) -> bool {
-        match &self.condition {
-            TestPassCondition::ShouldRevert => {
-                matches!(self.state, vm::state::ProgramState::Revert(_))
+        self.failure_message().is_none()
+    }
+
+    /// The code with which the test reverted, if it reverted.
+    pub fn revert_code(&self) -> Option<u64> {
+        match self.state {
+            vm::state::ProgramState::Revert(code) => Some(code),
+            _ => None,
+        }
+    }
+
+    /// A short description of why the test failed, or `None` if the test passed.
+    ///
+    /// A test fails if its revert behaviour does not match its `TestPassCondition`, or if any of
+    /// its expected logs were not emitted in the expected order.
+    ///
+    /// For tests that take parameters, the message includes the arguments for which it failed.
+    pub fn failure_message(&self) -> Option<String> {
+        let reason = self.failure_reason()?;
+        match &self.fuzz {
+            Some(FuzzOutcome {
+                seed,
+                counterexample: Some(args),
+                ..
+            }) => Some(format!("{reason}, with arguments ({args}) and seed {seed}")),
+            _ => Some(reason),
+        }
+    }
+
+    fn failure_reason(&self) -> Option<String> {
+        match (&self.condition, self.revert_code()) {
+            (TestPassCondition::ShouldRevert(_), None) => {
+                return Some("test did not revert".to_string())
+            }
+            (TestPassCondition::ShouldRevert(Some(expected)), Some(code)) if *expected != code => {
+                return Some(format!(
+                    "test reverted with code {code:#x}, expected {expected:#x}"
+                ))
             }
-            TestPassCondition::ShouldNotRevert => {
-                !matches!(self.state, vm::state::ProgramState::Revert(_))
+            (TestPassCondition::ShouldNotRevert, Some(code)) => {
+                return Some(format!("test reverted with code {code:#x}"))
             }
+            _ => (),
         }
+        // Each expected log must be found after the previously matched one.
+        let mut logs = self.logs.iter();
+        for expected_log in &self.expected_logs {
+            if !logs.any(|log| expected_log.matches(log)) {
+                return Some(format!("expected log {expected_log} was not emitted"));
+            }
+        }
+        None
     }
 
     /// Return `TestDetails` from the span of the function declaring this test.
@@ -344,8 +731,8 @@ impl TestResult {
 }
 
 impl BuiltTests {
-    /// The total number of tests.
-    pub fn test_count(&self) -> usize {
+    /// The total number of tests that match the given `filter`.
+    pub fn test_count(&self, filter: Option<&TestFilter>) -> usize {
         let pkgs: Vec<&PackageTests> = match self {
             BuiltTests::Package(pkg) => vec![pkg],
             BuiltTests::Workspace(workspace) => workspace.iter().collect(),
@@ -355,106 +742,252 @@ impl BuiltTests {
                 pkg.built_pkg_with_tests()
                     .entries
                     .iter()
-                    .filter(|e| e.is_test())
+                    .filter(|e| e.is_test() && filter_test(filter, &e.fn_name))
                     .count()
             })
             .sum()
     }
 
-    /// Run all built tests, return the result.
-    pub fn run(self) -> anyhow::Result<Tested> {
-        run_tests(self)
+    /// Run all built tests that match the given `filter`, return the result.
+    ///
+    /// If `collect_coverage` is set, each tested package's results include the number of tests
+    /// that executed each of its source lines. This requires the `coverage` feature.
+    pub fn run(
+        self,
+        test_runner_count: TestRunnerCount,
+        filter: Option<TestFilter>,
+        fuzz_config: FuzzConfig,
+        collect_coverage: bool,
+    ) -> anyhow::Result<Tested> {
+        if collect_coverage && !cfg!(feature = "coverage") {
+            anyhow::bail!("coverage requires forc-test to be built with the `coverage` feature");
+        }
+        let test_runners = match test_runner_count {
+            TestRunnerCount::Manual(runner_count) => rayon::ThreadPoolBuilder::new()
+                .num_threads(runner_count)
+                .build(),
+            TestRunnerCount::Auto => rayon::ThreadPoolBuilder::new().build(),
+        }?;
+        run_tests(
+            self,
+            &test_runners,
+            filter.as_ref(),
+            &fuzz_config,
+            collect_coverage,
+        )
     }
 }
 
 /// First builds the package or workspace, ready for execution.
 pub fn build(opts: Opts) -> anyhow::Result<BuiltTests> {
     let build_opts = opts.clone().into_build_opts();
-    let built = pkg::build_with_options(build_opts)?;
-    let built_tests = BuiltTests::from_built(built, opts)?;
+    let build_plan = pkg::BuildPlan::from_build_opts(&build_opts)?;
+    let (built, built_contract_deps) = pkg::build_with_options_and_plan(&build_opts, &build_plan)?;
+    let built_tests = BuiltTests::from_built(built, opts, &build_plan, &built_contract_deps)?;
     Ok(built_tests)
 }
 
-/// Deploys the provided contract and returns an interpreter instance ready to be used in test
-/// executions with deployed contract.
-fn deploy_test_contract(built_pkg: BuiltPackage) -> anyhow::Result<TestSetup> {
-    // Obtain the contract id for deployment.
-    let mut storage_slots = built_pkg.storage_slots;
-    storage_slots.sort();
-    let bytecode = built_pkg.bytecode;
-    let contract = tx::Contract::from(bytecode.clone());
-    let root = contract.root();
-    let state_root = tx::Contract::initial_state_root(storage_slots.iter());
-    let salt = tx::Salt::zeroed();
-    let contract_id = contract.id(&salt, &root, &state_root);
-    // TODO: Remove this prompt once https://github.com/FuelLabs/sway/issues/3673 is addressed.
-    println!(
-        "      Deploying contract with id {:?} for testing",
-        contract_id
-    );
-
-    // Setup the interpreter for deployment.
-    let params = tx::ConsensusParameters::default();
-    let storage = vm::storage::MemoryStorage::default();
-    let mut interpreter = vm::interpreter::Interpreter::with_storage(storage, params);
-
-    // Create the deployment transaction.
-    let mut rng = rand::rngs::StdRng::seed_from_u64(0x7E57u64);
-    let metadata: TxMetadata = rng.gen();
-
-    let tx = tx::TransactionBuilder::create(bytecode.into(), salt, storage_slots)
-        .add_unsigned_coin_input(
-            metadata.secret_key,
-            metadata.utxo_id,
-            metadata.amount,
-            metadata.asset_id,
-            metadata.tx_pointer,
-            metadata.maturity,
-        )
-        .add_output(tx::Output::contract_created(contract_id, state_root))
-        .maturity(metadata.maturity)
-        .finalize_checked(metadata.block_height, &params);
-
-    // Deploy the contract.
-    interpreter.transact(tx)?;
-    let storage_after_deploy = interpreter.as_ref();
-    Ok(TestSetup {
-        storage: storage_after_deploy.clone(),
-        contract_id: Some(contract_id),
-    })
+/// Collects the contract dependencies (both direct and transitive) of the given built package.
+///
+/// The dependencies were compiled with the same build profile as the package under test, so their
+/// contract ids match the `CONTRACT_ID` constants injected into the package's namespace.
+fn contract_dependencies(
+    built_pkg: &BuiltPackage,
+    build_plan: &pkg::BuildPlan,
+    built_contract_deps: &HashMap<pkg::NodeIx, BuiltPackage>,
+) -> anyhow::Result<Vec<ContractDependencyToTest>> {
+    let node = build_plan
+        .find_member_index(&built_pkg.pkg_name)
+        .ok_or_else(|| anyhow::anyhow!("Cannot find {} in the build plan", built_pkg.pkg_name))?;
+    build_plan
+        .contract_dependencies(node)
+        .map(|(dep, salt)| {
+            let built = built_contract_deps.get(&dep).cloned().ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Contract dependency {} of {} was not built",
+                    build_plan.graph()[dep].name,
+                    built_pkg.pkg_name
+                )
+            })?;
+            Ok(ContractDependencyToTest { built, salt })
+        })
+        .collect()
 }
 
-fn test_pass_condition(
-    test_function_decl: &TyFunctionDeclaration,
-) -> anyhow::Result<TestPassCondition> {
-    let test_args: HashSet<String> = test_function_decl
+/// The arguments provided to the `#[test]` attribute(s) of the given test function.
+fn test_attribute_args(test_function_decl: &TyFunctionDeclaration) -> Vec<&AttributeArg> {
+    test_function_decl
         .attributes
         .get(&AttributeKind::Test)
         .expect("test declaration is missing test attribute")
         .iter()
-        .flat_map(|attr| attr.args.iter().map(|arg| arg.to_string()))
-        .collect();
+        .flat_map(|attr| attr.args.iter())
+        .collect()
+}
+
+fn test_pass_condition(
+    test_function_decl: &TyFunctionDeclaration,
+) -> anyhow::Result<TestPassCondition> {
+    let test_args = test_attribute_args(test_function_decl);
     let test_name = &test_function_decl.name;
-    if test_args.is_empty() {
-        Ok(TestPassCondition::ShouldNotRevert)
-    } else if test_args.get("should_revert").is_some() {
-        Ok(TestPassCondition::ShouldRevert)
-    } else {
-        anyhow::bail!("Invalid test argument(s) for test: {test_name}.")
+    let mut condition = TestPassCondition::ShouldNotRevert;
+    for arg in test_args {
+        match arg.name.as_str() {
+            TEST_SHOULD_REVERT_NAME => {
+                let expected_code = match &arg.value {
+                    None => None,
+                    Some(value) => Some(literal_u64(value).ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Expected a revert code, i.e. `{TEST_SHOULD_REVERT_NAME} = \"<code>\"`, \
+                            for test: {test_name}."
+                        )
+                    })?),
+                };
+                condition = TestPassCondition::ShouldRevert(expected_code);
+            }
+            TEST_STORAGE_SLOTS_NAME
+            | TEST_SHOULD_LOG_NAME
+            | TEST_SHOULD_LOG_DATA_NAME
+            | TEST_BLOCK_HEIGHT_NAME
+            | TEST_TIMESTAMP_NAME
+            | TEST_SENDER_NAME
+            | TEST_INPUT_COIN_NAME
+            | TEST_OUTPUT_COIN_NAME
+            | TEST_OUTPUT_VARIABLES_NAME => (),
+            _ => anyhow::bail!("Invalid test argument(s) for test: {test_name}."),
+        }
+    }
+    Ok(condition)
+}
+
+/// Collect the logs requested via `#[test(should_log = <value>)]` and
+/// `#[test(should_log_data = "0x<hex>")]`, in the order in which they are declared.
+fn test_expected_logs(
+    test_function_decl: &TyFunctionDeclaration,
+) -> anyhow::Result<Vec<ExpectedLog>> {
+    let test_name = &test_function_decl.name;
+    let mut expected_logs = vec![];
+    for arg in test_attribute_args(test_function_decl) {
+        match arg.name.as_str() {
+            TEST_SHOULD_LOG_NAME => {
+                let value = arg.value.as_ref().and_then(literal_u64).ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Expected a `u64` log value, i.e. `{TEST_SHOULD_LOG_NAME} = <value>`, \
+                        for test: {test_name}."
+                    )
+                })?;
+                expected_logs.push(ExpectedLog::Log(value));
+            }
+            TEST_SHOULD_LOG_DATA_NAME => {
+                let bytes = match &arg.value {
+                    Some(Literal::String(lit_string)) => lit_string
+                        .parsed
+                        .strip_prefix("0x")
+                        .and_then(|hex_str| hex::decode(hex_str).ok()),
+                    _ => None,
+                }
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Expected hex encoded log data, i.e. \
+                        `{TEST_SHOULD_LOG_DATA_NAME} = \"0x<hex>\"`, for test: {test_name}."
+                    )
+                })?;
+                expected_logs.push(ExpectedLog::LogData(bytes));
+            }
+            _ => (),
+        }
+    }
+    Ok(expected_logs)
+}
+
+/// Interpret an attribute argument value as a `u64`.
+///
+/// Accepts either an integer literal or a string containing a decimal or `0x` prefixed hex integer.
+fn literal_u64(literal: &Literal) -> Option<u64> {
+    match literal {
+        Literal::Int(lit_int) => u64::try_from(&lit_int.parsed).ok(),
+        Literal::String(lit_string) => {
+            let s = lit_string.parsed.replace('_', "");
+            match s.strip_prefix("0x") {
+                Some(hex_str) => u64::from_str_radix(hex_str, 16).ok(),
+                None => s.parse().ok(),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Load the storage slots requested via `#[test(storage_slots = "<path>")]`, if any.
+///
+/// The path is expected to point to a JSON storage slots file (in the same format emitted by
+/// `forc build`) and is resolved relative to the package's manifest directory.
+fn test_storage_slots(
+    test_function_decl: &TyFunctionDeclaration,
+    manifest_dir: &Path,
+) -> anyhow::Result<Option<Vec<tx::StorageSlot>>> {
+    let test_name = &test_function_decl.name;
+    let arg = match test_attribute_args(test_function_decl)
+        .into_iter()
+        .find(|arg| arg.name.as_str() == TEST_STORAGE_SLOTS_NAME)
+    {
+        Some(arg) => arg,
+        None => return Ok(None),
+    };
+    let path = match &arg.value {
+        Some(Literal::String(lit_string)) => manifest_dir.join(&lit_string.parsed),
+        _ => anyhow::bail!(
+            "Expected a path to a storage slots file, i.e. \
+            `{TEST_STORAGE_SLOTS_NAME} = \"<path>\"`, for test: {test_name}."
+        ),
+    };
+    let contents = fs::read_to_string(&path).map_err(|e| {
+        anyhow::anyhow!(
+            "failed to read storage slots file {} for test {test_name}: {e}",
+            path.display()
+        )
+    })?;
+    let storage_slots = serde_json::from_str(&contents).map_err(|e| {
+        anyhow::anyhow!(
+            "failed to parse storage slots file {} for test {test_name}: {e}",
+            path.display()
+        )
+    })?;
+    Ok(Some(storage_slots))
+}
+
+impl TestFilter<'_> {
+    /// Whether or not the test with the given name is selected by this filter.
+    fn filter(&self, fn_name: &str) -> bool {
+        if self.exact_match {
+            fn_name == self.filter_phrase
+        } else {
+            fn_name.contains(self.filter_phrase)
+        }
     }
 }
 
+/// Whether or not the test with the given name is selected by the optional `filter`.
+fn filter_test(filter: Option<&TestFilter>, fn_name: &str) -> bool {
+    filter.map(|filter| filter.filter(fn_name)).unwrap_or(true)
+}
+
 /// Build the given package and run its tests, returning the results.
-fn run_tests(built: BuiltTests) -> anyhow::Result<Tested> {
+fn run_tests(
+    built: BuiltTests,
+    test_runners: &rayon::ThreadPool,
+    filter: Option<&TestFilter>,
+    fuzz_config: &FuzzConfig,
+    collect_coverage: bool,
+) -> anyhow::Result<Tested> {
     match built {
         BuiltTests::Package(pkg) => {
-            let tested_pkg = pkg.run_tests()?;
+            let tested_pkg = pkg.run_tests(test_runners, filter, fuzz_config, collect_coverage)?;
             Ok(Tested::Package(Box::new(tested_pkg)))
         }
         BuiltTests::Workspace(workspace) => {
             let tested_pkgs = workspace
                 .into_iter()
-                .map(|pkg| pkg.run_tests())
+                .map(|pkg| pkg.run_tests(test_runners, filter, fuzz_config, collect_coverage))
                 .collect::<anyhow::Result<Vec<TestedPackage>>>()?;
             Ok(Tested::Workspace(tested_pkgs))
         }
@@ -498,40 +1031,83 @@ fn patch_test_bytecode(bytecode: &[u8], test_offset: u32) -> std::borrow::Cow<[u
     std::borrow::Cow::Owned(patched)
 }
 
+/// The outcome of executing a single test.
+struct TestExecution {
+    state: vm::state::ProgramState,
+    duration: std::time::Duration,
+    receipts: Vec<fuel_tx::Receipt>,
+    /// Every instruction executed during the test, if coverage was requested.
+    executed: Option<Vec<ExecutedInstruction>>,
+}
+
 // Execute the test whose entry point is at the given instruction offset as if it were a script.
+//
+// The block and transaction are configured according to the given test context.
+//
+// If `collect_coverage` is set, the location of every instruction executed during the test is
+// also recorded.
 fn exec_test(
     bytecode: &[u8],
     test_offset: u32,
     test_setup: TestSetup,
-) -> (
-    vm::state::ProgramState,
-    std::time::Duration,
-    Vec<fuel_tx::Receipt>,
-) {
+    context: &TestContext,
+    script_data: Vec<u8>,
+    collect_coverage: bool,
+) -> anyhow::Result<TestExecution> {
+    let contract_ids: Vec<tx::ContractId> = test_setup.contract_ids().collect();
     let storage = test_setup.storage;
-    let contract_id = test_setup.contract_id;
 
     // Patch the bytecode to jump to the relevant test.
     let bytecode = patch_test_bytecode(bytecode, test_offset).into_owned();
 
     // Create a transaction to execute the test function.
-    let script_input_data = vec![];
+    let script_input_data = script_data;
     let mut rng = rand::rngs::StdRng::seed_from_u64(0x7E57u64);
     let metadata: TxMetadata = rng.gen();
     let params = tx::ConsensusParameters::default();
-    let mut tx = tx::TransactionBuilder::script(bytecode, script_input_data)
-        .add_unsigned_coin_input(
-            metadata.secret_key,
-            metadata.utxo_id,
-            metadata.amount,
-            metadata.asset_id,
-            metadata.tx_pointer,
-            0,
-        )
-        .gas_limit(tx::ConsensusParameters::DEFAULT.max_gas_per_tx)
-        .maturity(metadata.maturity)
-        .clone();
-    if let Some(contract_id) = contract_id {
+    let mut tx = tx::TransactionBuilder::script(bytecode, script_input_data);
+    tx.gas_limit(tx::ConsensusParameters::DEFAULT.max_gas_per_tx)
+        .maturity(metadata.maturity);
+    // The coin inputs are signed by the test's secret key, unless they are to be owned by a
+    // particular sender, in which case they are left unsigned.
+    let coins = std::iter::once((metadata.amount, metadata.asset_id))
+        .chain(context.input_coins.iter().cloned());
+    for (ix, (amount, asset_id)) in coins.enumerate() {
+        let utxo_id = if ix == 0 { metadata.utxo_id } else { rng.gen() };
+        match context.sender {
+            Some(sender) => tx.add_input(tx::Input::coin_signed(
+                utxo_id,
+                sender,
+                amount,
+                asset_id,
+                metadata.tx_pointer,
+                0,
+                0,
+            )),
+            None => tx.add_unsigned_coin_input(
+                metadata.secret_key,
+                utxo_id,
+                amount,
+                asset_id,
+                metadata.tx_pointer,
+                0,
+            ),
+        };
+    }
+    if context.sender.is_some() {
+        tx.add_witness(tx::Witness::default());
+    }
+    // Add an input and output for each deployed contract, so that tests may call into them. The
+    // coin inputs occupy the first input indices.
+    let coin_input_count = tx.inputs().len();
+    for (ix, contract_id) in contract_ids.into_iter().enumerate() {
+        let input_index = u8::try_from(coin_input_count + ix).map_err(|_| {
+            anyhow::anyhow!(
+                "too many inputs to test: {} coin inputs and {} contracts",
+                coin_input_count,
+                ix + 1
+            )
+        })?;
         tx.add_input(tx::Input::Contract {
             utxo_id: tx::UtxoId::new(tx::Bytes32::zeroed(), 0),
             balance_root: tx::Bytes32::zeroed(),
@@ -540,21 +1116,219 @@ fn exec_test(
             contract_id,
         })
         .add_output(tx::Output::Contract {
-            input_index: 1,
+            input_index,
             balance_root: fuel_tx::Bytes32::zeroed(),
             state_root: tx::Bytes32::zeroed(),
         });
     }
-    let tx = tx.finalize_checked(metadata.block_height, &params);
+    for output in &context.outputs {
+        tx.add_output(*output);
+    }
+    let tx = tx.finalize();
+    let tx = match context.sender {
+        Some(_) => tx.into_checked_basic(metadata.block_height, &params),
+        None => tx.into_checked(metadata.block_height, &params),
+    }
+    .map_err(|e| anyhow::anyhow!("invalid test transaction: {e:?}"))?;
 
+    let storage = context.storage(storage);
     let mut interpreter = vm::interpreter::Interpreter::with_storage(storage, params);
 
     // Execute and return the result.
     let start = std::time::Instant::now();
-    let transition = interpreter.transact(tx).unwrap();
+    let transition = interpreter.transact(tx)?;
     let duration = start.elapsed();
     let state = *transition.state();
     let receipts = transition.receipts().to_vec();
+    let executed = collect_coverage.then(|| executed_instructions(&interpreter));
+
+    Ok(TestExecution {
+        state,
+        duration,
+        receipts,
+        executed,
+    })
+}
+
+/// Every instruction executed by the given interpreter, as recorded by its profiler.
+#[cfg(feature = "coverage")]
+fn executed_instructions<S, Tx>(
+    interpreter: &vm::interpreter::Interpreter<S, Tx>,
+) -> Vec<ExecutedInstruction> {
+    interpreter
+        .profiler()
+        .data()
+        .coverage()
+        .iter()
+        .map(|location| (location.context(), location.offset()))
+        .collect()
+}
+
+#[cfg(not(feature = "coverage"))]
+fn executed_instructions<S, Tx>(
+    _interpreter: &vm::interpreter::Interpreter<S, Tx>,
+) -> Vec<ExecutedInstruction> {
+    unreachable!("coverage is only collected with the `coverage` feature")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        build, filter_test, pkg, FuzzConfig, Opts, TestFilter, TestRunnerCount, Tested,
+        TestedPackage,
+    };
+    use std::path::PathBuf;
 
-    (state, duration, receipts)
+    /// Build and run the tests of the package of the given name within `test/data`.
+    ///
+    /// Outputs are written to a temporary directory rather than the source tree.
+    fn test_package(name: &str, collect_coverage: bool) -> TestedPackage {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("test/data")
+            .join(name);
+        let output_dir = std::env::temp_dir().join("forc-test-test").join(name);
+        let opts = Opts {
+            pkg: pkg::PkgOpts {
+                path: Some(path.display().to_string()),
+                offline: true,
+                terse: true,
+                locked: true,
+                output_directory: Some(output_dir.display().to_string()),
+            },
+            ..Default::default()
+        };
+        let tested = build(opts)
+            .unwrap()
+            .run(
+                TestRunnerCount::Auto,
+                None,
+                FuzzConfig::default(),
+                collect_coverage,
+            )
+            .unwrap();
+        match tested {
+            Tested::Package(tested_pkg) => *tested_pkg,
+            Tested::Workspace(_) => panic!("expected a package"),
+        }
+    }
+
+    #[test]
+    fn test_fuzz_counterexamples() {
+        let tested_pkg = test_package("fuzz_failure", false);
+        let counterexample = |name: &str| {
+            let test = tested_pkg
+                .tests
+                .iter()
+                .find(|test| test.name == name)
+                .unwrap();
+            let fuzz = test.fuzz.as_ref().unwrap();
+            assert_eq!(fuzz.seed, FuzzConfig::default().seed);
+            assert_eq!(test.passed(), fuzz.counterexample.is_none());
+            fuzz.counterexample.clone()
+        };
+        assert_eq!(
+            counterexample("test_visible_points_are_near").as_deref(),
+            Some("p = Point { x: 1000, y: 0, visible: true }")
+        );
+        assert_eq!(
+            counterexample("test_halves_are_small").as_deref(),
+            Some("a = 0, b = 100")
+        );
+        assert_eq!(counterexample("test_halves_are_at_most_half"), None);
+    }
+
+    #[test]
+    fn test_revert_and_log_failures() {
+        let tested_pkg = test_package("revert_failure", false);
+        let failure = |name: &str| {
+            tested_pkg
+                .tests
+                .iter()
+                .find(|test| test.name == name)
+                .unwrap()
+                .failure_message()
+        };
+        assert_eq!(
+            failure("test_does_not_revert").as_deref(),
+            Some("test did not revert")
+        );
+        assert_eq!(
+            failure("test_does_not_revert_with_code").as_deref(),
+            Some("test did not revert")
+        );
+        assert_eq!(
+            failure("test_reverts_with_wrong_code").as_deref(),
+            Some("test reverted with code 0x7, expected 0x2a")
+        );
+        assert_eq!(
+            failure("test_reverts_unexpectedly").as_deref(),
+            Some("test reverted with code 0x3")
+        );
+        assert_eq!(
+            failure("test_log_missing").as_deref(),
+            Some("expected log should_log = 5 was not emitted")
+        );
+        assert_eq!(
+            failure("test_logs_out_of_order").as_deref(),
+            Some("expected log should_log = 1 was not emitted")
+        );
+        assert_eq!(
+            failure("test_log_data_missing").as_deref(),
+            Some(
+                "expected log should_log_data = \
+                \"0x0000000000000000000000000000000000000000000000000000000000000002\" \
+                was not emitted"
+            )
+        );
+        assert!(tested_pkg.tests.iter().all(|test| !test.passed()));
+    }
+
+    #[cfg(feature = "coverage")]
+    #[test]
+    fn test_coverage() {
+        let tested_pkg = test_package("coverage", true);
+        let source =
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test/data/coverage/src/lib.sw");
+        let line = |text: &str| {
+            let ix = std::fs::read_to_string(&source)
+                .unwrap()
+                .lines()
+                .position(|line| line.contains(text))
+                .unwrap();
+            ix + 1
+        };
+        let coverage = tested_pkg.coverage.as_ref().unwrap();
+        let lines = &coverage.files[&source];
+        // Both tests take the `if` branch, and the fuzz test counts once however many cases it runs.
+        assert_eq!(lines.get(&line("if n > 10")), Some(&2));
+        assert_eq!(lines.get(&line("n - 10")), Some(&2));
+        assert_eq!(lines.get(&line("never executed")), Some(&0));
+        assert_eq!(lines.get(&line("classify(n | 16)")), Some(&1));
+    }
+
+    #[test]
+    fn test_filter_contains() {
+        let filter = TestFilter {
+            filter_phrase: "foo",
+            exact_match: false,
+        };
+        assert!(filter_test(Some(&filter), "test_foo"));
+        assert!(filter_test(Some(&filter), "foo"));
+        assert!(!filter_test(Some(&filter), "test_bar"));
+    }
+
+    #[test]
+    fn test_filter_exact_match() {
+        let filter = TestFilter {
+            filter_phrase: "test_foo",
+            exact_match: true,
+        };
+        assert!(filter_test(Some(&filter), "test_foo"));
+        assert!(!filter_test(Some(&filter), "test_foo_bar"));
+    }
+
+    #[test]
+    fn test_no_filter() {
+        assert!(filter_test(None, "test_foo"));
+    }
 }
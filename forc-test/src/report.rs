@@ -0,0 +1,281 @@
+//! Machine-readable reports of test results for consumption by CI tooling.
+
+use crate::{FuzzOutcome, TestPassCondition, TestResult, Tested, TestedPackage};
+use forc_util::abi::ReceiptDecoder;
+use serde::Serialize;
+use std::{fmt::Write, path::PathBuf};
+use sway_core::asm_generation::ProgramABI;
+
+/// A report of the results of a `forc test` invocation.
+#[derive(Debug, Serialize)]
+pub struct TestReport<'a> {
+    pub packages: Vec<PackageTestReport<'a>>,
+}
+
+/// A report of the results of testing a single package.
+#[derive(Debug, Serialize)]
+pub struct PackageTestReport<'a> {
+    pub name: String,
+    pub passed: usize,
+    pub failed: usize,
+    /// The total time taken to execute the package's tests, in seconds.
+    pub duration: f64,
+    pub tests: Vec<TestCaseReport<'a>>,
+}
+
+/// A report of the result of a single test.
+#[derive(Debug, Serialize)]
+pub struct TestCaseReport<'a> {
+    pub name: String,
+    pub file_path: PathBuf,
+    /// The 1-based line number for the test declaration.
+    pub line_number: usize,
+    pub condition: &'a TestPassCondition,
+    pub passed: bool,
+    /// A short description of why the test failed, if it failed.
+    pub failure: Option<String>,
+    /// The time taken to execute the test, in seconds.
+    pub duration: f64,
+    pub gas_used: u64,
+    /// The code passed to `rvrt` in the case that the test reverted.
+    pub revert_code: Option<u64>,
+    /// The values logged during the test, decoded according to the package's ABI.
+    ///
+    /// A value whose type is not in the ABI, e.g. one logged by a library's test, is given as the
+    /// `Log` or `LogData` receipt that logged it.
+    pub logs: Vec<serde_json::Value>,
+    /// For tests that take parameters, the outcome of executing the test with random arguments.
+    pub fuzz: Option<&'a FuzzOutcome>,
+}
+
+impl<'a> TestReport<'a> {
+    /// Collect a report for each test within the given tested package or workspace.
+    pub fn from_tested(tested: &'a Tested) -> anyhow::Result<Self> {
+        let packages = match tested {
+            Tested::Package(pkg) => vec![PackageTestReport::from_tested_pkg(pkg)?],
+            Tested::Workspace(pkgs) => pkgs
+                .iter()
+                .map(PackageTestReport::from_tested_pkg)
+                .collect::<anyhow::Result<_>>()?,
+        };
+        Ok(Self { packages })
+    }
+
+    /// Serialize the report as pretty-printed JSON.
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Serialize the report in the JUnit XML format.
+    ///
+    /// Each package is reported as a `<testsuite>` and each test as a `<testcase>`. Logs are
+    /// included as the test case's `<system-out>`.
+    pub fn to_junit_xml(&self) -> anyhow::Result<String> {
+        let tests: usize = self.packages.iter().map(|pkg| pkg.tests.len()).sum();
+        let failures: usize = self.packages.iter().map(|pkg| pkg.failed).sum();
+        let duration: f64 = self.packages.iter().map(|pkg| pkg.duration).sum();
+        let mut xml = String::new();
+        writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            xml,
+            r#"<testsuites name="forc test" tests="{tests}" failures="{failures}" time="{duration}">"#
+        )?;
+        for pkg in &self.packages {
+            writeln!(
+                xml,
+                r#"  <testsuite name="{}" tests="{}" failures="{}" time="{}">"#,
+                xml_escape(&pkg.name),
+                pkg.tests.len(),
+                pkg.failed,
+                pkg.duration
+            )?;
+            for test in &pkg.tests {
+                writeln!(
+                    xml,
+                    r#"    <testcase name="{}" classname="{}" file="{}" line="{}" time="{}">"#,
+                    xml_escape(&test.name),
+                    xml_escape(&pkg.name),
+                    xml_escape(&test.file_path.display().to_string()),
+                    test.line_number,
+                    test.duration
+                )?;
+                writeln!(xml, "      <properties>")?;
+                writeln!(
+                    xml,
+                    r#"        <property name="gas_used" value="{}"/>"#,
+                    test.gas_used
+                )?;
+                if let Some(fuzz) = test.fuzz {
+                    writeln!(
+                        xml,
+                        r#"        <property name="fuzz_runs" value="{}"/>"#,
+                        fuzz.runs
+                    )?;
+                }
+                if let Some(revert_code) = test.revert_code {
+                    writeln!(
+                        xml,
+                        r#"        <property name="revert_code" value="{revert_code}"/>"#
+                    )?;
+                }
+                writeln!(xml, "      </properties>")?;
+                if let Some(failure) = &test.failure {
+                    writeln!(xml, r#"      <failure message="{}"/>"#, xml_escape(failure))?;
+                }
+                writeln!(
+                    xml,
+                    "      <system-out>{}</system-out>",
+                    xml_escape(&serde_json::to_string(&test.logs)?)
+                )?;
+                writeln!(xml, "    </testcase>")?;
+            }
+            writeln!(xml, "  </testsuite>")?;
+        }
+        writeln!(xml, "</testsuites>")?;
+        Ok(xml)
+    }
+}
+
+impl<'a> PackageTestReport<'a> {
+    fn from_tested_pkg(pkg: &'a TestedPackage) -> anyhow::Result<Self> {
+        let decoder = match &pkg.built.json_abi_program {
+            ProgramABI::Fuel(program_abi) => Some(ReceiptDecoder::from_program_abi(program_abi)?),
+            ProgramABI::Evm(_) => None,
+        };
+        let tests = pkg
+            .tests
+            .iter()
+            .map(|test| TestCaseReport::from_test_result(test, decoder.as_ref()))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let passed = tests.iter().filter(|test| test.passed).count();
+        Ok(Self {
+            name: pkg.built.pkg_name.clone(),
+            passed,
+            failed: tests.len() - passed,
+            duration: tests.iter().map(|test| test.duration).sum(),
+            tests,
+        })
+    }
+}
+
+impl<'a> TestCaseReport<'a> {
+    fn from_test_result(
+        test: &'a TestResult,
+        decoder: Option<&ReceiptDecoder>,
+    ) -> anyhow::Result<Self> {
+        let details = test.details()?;
+        let failure = test.failure_message();
+        Ok(Self {
+            name: test.name.clone(),
+            file_path: details.file_path.to_path_buf(),
+            line_number: details.line_number + 1,
+            condition: &test.condition,
+            passed: failure.is_none(),
+            failure,
+            duration: test.duration.as_secs_f64(),
+            gas_used: test.gas_used,
+            revert_code: test.revert_code(),
+            logs: decode_logs(&test.logs, decoder)?,
+            fuzz: test.fuzz.as_ref(),
+        })
+    }
+}
+
+/// Decode the values logged by the given receipts, falling back to the JSON of the receipt for
+/// values whose type is unknown or whose bytes do not match their type.
+fn decode_logs(
+    receipts: &[fuel_tx::Receipt],
+    decoder: Option<&ReceiptDecoder>,
+) -> anyhow::Result<Vec<serde_json::Value>> {
+    let receipts_json = forc_util::log_receipts_to_json(receipts)?;
+    let logs = receipts
+        .iter()
+        .zip(receipts_json.as_array().into_iter().flatten())
+        .filter(|(receipt, _)| {
+            matches!(
+                receipt,
+                fuel_tx::Receipt::Log { .. } | fuel_tx::Receipt::LogData { .. }
+            )
+        })
+        .map(
+            |(receipt, receipt_json)| match decoder.and_then(|d| d.decode_log(receipt)) {
+                Some(Ok(value)) => value,
+                Some(Err(_)) | None => receipt_json.clone(),
+            },
+        )
+        .collect();
+    Ok(logs)
+}
+
+/// Escape the characters that may not appear verbatim within XML text or attribute values.
+pub(crate) fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_logs, xml_escape};
+    use forc_util::abi::ReceiptDecoder;
+    use fuel_abi_types::program_abi::ProgramABI;
+    use serde_json::json;
+
+    #[test]
+    fn test_decode_logs() {
+        let type_app = |type_id: usize| json!({"name": "", "type": type_id, "typeArguments": null});
+        let abi: ProgramABI = serde_json::from_value(json!({
+            "types": [
+                {"typeId": 0, "type": "u64", "components": null, "typeParameters": null},
+                {"typeId": 1, "type": "b256", "components": null, "typeParameters": null},
+            ],
+            "functions": [],
+            "loggedTypes": [
+                {"logId": 0, "loggedType": type_app(0)},
+                {"logId": 1, "loggedType": type_app(1)},
+            ],
+        }))
+        .unwrap();
+        let decoder = ReceiptDecoder::from_program_abi(&abi).unwrap();
+        let id = fuel_tx::ContractId::zeroed();
+        let log_data = |log_id, data| {
+            fuel_tx::Receipt::log_data(id, 0, log_id, 0, Default::default(), data, 0, 0)
+        };
+        let receipts = [
+            fuel_tx::Receipt::log(id, 42, 0, 0, 0, 0, 0),
+            // The bytes of a `b256` are too short, so the receipt itself is reported.
+            log_data(1, vec![1, 2, 3]),
+            // There is no type with this log ID.
+            fuel_tx::Receipt::log(id, 7, 2, 0, 0, 0, 0),
+            fuel_tx::Receipt::ret(id, 0, 0, 0),
+        ];
+        let receipts_json = forc_util::log_receipts_to_json(&receipts).unwrap();
+        let logs = decode_logs(&receipts, Some(&decoder)).unwrap();
+        assert_eq!(
+            logs,
+            vec![
+                json!(42),
+                receipts_json[1].clone(),
+                receipts_json[2].clone()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_xml_escape() {
+        assert_eq!(xml_escape("plain"), "plain");
+        assert_eq!(
+            xml_escape(r#"{"a":"<b>&'c'"}"#),
+            "{&quot;a&quot;:&quot;&lt;b&gt;&amp;&apos;c&apos;&quot;}"
+        );
+    }
+}
@@ -0,0 +1,21 @@
+library coverage;
+
+pub fn classify(n: u64) -> u64 {
+    if n > 10 {
+        n - 10
+    } else {
+        n * 3 + 7 // never executed
+    }
+}
+
+#[test]
+fn test_classify_large() {
+    let class = classify(11);
+    assert(class == 1);
+}
+
+#[test]
+fn test_classify_fuzzed(n: u64) {
+    let class = classify(n | 16);
+    assert(class >= 6);
+}
@@ -0,0 +1,22 @@
+library fuzz_failure;
+
+struct Point {
+    x: u64,
+    y: u8,
+    visible: bool,
+}
+
+#[test]
+fn test_visible_points_are_near(p: Point) {
+    assert(!p.visible || p.x < 1000);
+}
+
+#[test]
+fn test_halves_are_small(a: u64, b: u64) {
+    assert(a / 2 + b / 2 < 50);
+}
+
+#[test]
+fn test_halves_are_at_most_half(a: u64) {
+    assert(a / 2 <= a);
+}
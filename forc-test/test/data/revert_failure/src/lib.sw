@@ -0,0 +1,41 @@
+library revert_failure;
+
+use std::logging::log;
+
+#[test(should_revert)]
+fn test_does_not_revert() {
+    log(1);
+}
+
+#[test(should_revert = "42")]
+fn test_does_not_revert_with_code() {
+    log(1);
+}
+
+#[test(should_revert = "42")]
+fn test_reverts_with_wrong_code() {
+    revert(7);
+}
+
+#[test]
+fn test_reverts_unexpectedly() {
+    revert(3);
+}
+
+#[test(should_log = 1, should_log = 5)]
+fn test_log_missing() {
+    log(1);
+    log(2);
+}
+
+#[test(should_log = 3, should_log = 1)]
+fn test_logs_out_of_order() {
+    log(1);
+    log(2);
+    log(3);
+}
+
+#[test(should_log_data = "0x0000000000000000000000000000000000000000000000000000000000000002")]
+fn test_log_data_missing() {
+    log(0x0000000000000000000000000000000000000000000000000000000000000001);
+}
@@ -0,0 +1,830 @@
+use anyhow::{anyhow, bail, Context, Result};
+use fuel_abi_types::program_abi::ProgramABI;
+use fuel_tx::{ContractId, Receipt};
+use fuels_core::{
+    abi_decoder::ABIDecoder, abi_encoder::ABIEncoder,
+    code_gen::function_selector::resolve_fn_selector, tokenizer::Tokenizer, StringToken, Token,
+};
+use fuels_types::{
+    enum_variants::EnumVariants, param_types::ParamType, TypeApplication, TypeDeclaration,
+};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Encode the given arguments to the `main` function of the program described by `program_abi`.
+///
+/// See [AbiFunction::encode_args].
+pub fn encode_main_args(program_abi: &ProgramABI, args: &[String]) -> Result<Vec<u8>> {
+    AbiFunction::from_program_abi(program_abi, "main")?.encode_args(args)
+}
+
+/// Encode the given named arguments to the `main` function of the program described by
+/// `program_abi`.
+///
+/// See [AbiFunction::encode_named_args].
+pub fn encode_named_main_args(program_abi: &ProgramABI, args: &[String]) -> Result<Vec<u8>> {
+    AbiFunction::from_program_abi(program_abi, "main")?.encode_named_args(args)
+}
+
+/// Decodes the values returned and logged by a program according to its ABI.
+pub struct ReceiptDecoder {
+    /// The output type of the function called, by default the program's `main` function if it has
+    /// one.
+    output: Option<ParamType>,
+    logged_types: HashMap<u64, ParamType>,
+}
+
+impl ReceiptDecoder {
+    pub fn from_program_abi(program_abi: &ProgramABI) -> Result<Self> {
+        let abi = SdkAbi::from_program_abi(program_abi)?;
+        let output = abi
+            .function("main")
+            .map(|main| abi.param_type(&main.output))
+            .transpose()?;
+        Ok(Self {
+            output,
+            logged_types: abi.logged_types()?,
+        })
+    }
+
+    /// Decode return values as the output of the given function, rather than of `main`.
+    pub fn with_function(self, function: &AbiFunction) -> Self {
+        Self {
+            output: Some(function.output.clone()),
+            ..self
+        }
+    }
+
+    /// Decode the last value returned by the program with the given ID, if it returned a value.
+    /// The ID of a script is `ContractId::zeroed()`.
+    pub fn decode_return(&self, id: &ContractId, receipts: &[Receipt]) -> Result<Option<Value>> {
+        let output = match &self.output {
+            None | Some(ParamType::Unit) => return Ok(None),
+            Some(output) => output,
+        };
+        let bytes = receipts.iter().rev().find_map(|receipt| match receipt {
+            Receipt::Return {
+                id: return_id, val, ..
+            } if return_id == id => Some(val.to_be_bytes().to_vec()),
+            Receipt::ReturnData {
+                id: return_id,
+                data,
+                ..
+            } if return_id == id => Some(data.clone()),
+            _ => None,
+        });
+        bytes.map(|bytes| decode(output, &bytes)).transpose()
+    }
+
+    /// Decode the values logged by the program with the given ID, in the order in which they were
+    /// logged. The ID of a script is `ContractId::zeroed()`.
+    ///
+    /// Values logged by other contracts are not decoded.
+    pub fn decode_logs(&self, id: &ContractId, receipts: &[Receipt]) -> Result<Vec<Value>> {
+        receipts
+            .iter()
+            .filter(|receipt| receipt.id() == Some(id))
+            .filter_map(|receipt| self.decode_log(receipt))
+            .collect()
+    }
+
+    /// Decode the value logged by the given `Log` or `LogData` receipt.
+    ///
+    /// Returns `None` for other receipts, or if the type of the logged value is not in the ABI.
+    pub fn decode_log(&self, receipt: &Receipt) -> Option<Result<Value>> {
+        let (log_id, bytes) = match receipt {
+            Receipt::Log { ra, rb, .. } => (*rb, ra.to_be_bytes().to_vec()),
+            Receipt::LogData { rb, data, .. } => (*rb, data.clone()),
+            _ => return None,
+        };
+        let param_type = self.logged_types.get(&log_id)?;
+        Some(decode(param_type, &bytes))
+    }
+}
+
+/// A function of a program's ABI, e.g. the `main` function of a script or a contract method.
+pub struct AbiFunction {
+    pub name: String,
+    pub params: Vec<(String, ParamType)>,
+    pub output: ParamType,
+}
+
+impl AbiFunction {
+    pub fn from_program_abi(program_abi: &ProgramABI, name: &str) -> Result<Self> {
+        let abi = SdkAbi::from_program_abi(program_abi)?;
+        let function = abi
+            .function(name)
+            .ok_or_else(|| anyhow!("program ABI has no function named `{}`", name))?;
+        let params = function
+            .inputs
+            .iter()
+            .map(|input| Ok((input.name.clone(), abi.param_type(input)?)))
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            name: name.to_string(),
+            params,
+            output: abi.param_type(&function.output)?,
+        })
+    }
+
+    /// The selector by which the function is called as a contract method.
+    pub fn selector(&self) -> [u8; 8] {
+        let param_types: Vec<_> = self.params.iter().map(|(_, ty)| ty.clone()).collect();
+        resolve_fn_selector(&self.name, &param_types)
+    }
+
+    /// Encode the given arguments, one per parameter in order.
+    ///
+    /// Each argument is parsed according to the type of its parameter, e.g. `42`, `true`, a hex
+    /// string for a `b256`, `(1, true)` for a struct or tuple and `[1, 2]` for an array.
+    pub fn encode_args(&self, args: &[String]) -> Result<Vec<u8>> {
+        if self.params.len() != args.len() {
+            bail!(
+                "`{}` takes {} argument(s) but {} were provided",
+                self.name,
+                self.params.len(),
+                args.len()
+            );
+        }
+        let tokens = self
+            .params
+            .iter()
+            .zip(args)
+            .map(|((name, param_type), arg)| {
+                let value = match param_type {
+                    ParamType::B256 => arg.strip_prefix("0x").unwrap_or(arg),
+                    _ => arg,
+                };
+                Tokenizer::tokenize(param_type, value.to_string())
+                    .map_err(|e| anyhow!("invalid value `{}` for `{}`: {}", arg, name, e))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        encode(&tokens)
+    }
+
+    /// Encode the given named arguments.
+    ///
+    /// The arguments are either a single JSON object mapping parameter names to values, e.g.
+    /// `{"a": 42, "b": {"x": 1, "y": true}}`, or `name=value` pairs. The value of a pair is parsed
+    /// as JSON, or is otherwise taken to be a string, e.g. `a=42`, `b={"x": 1, "y": true}` or
+    /// `c=0x01..`.
+    pub fn encode_named_args(&self, args: &[String]) -> Result<Vec<u8>> {
+        let mut values = match args {
+            [arg] if arg.trim_start().starts_with('{') => {
+                serde_json::from_str::<Map<String, Value>>(arg)
+                    .map_err(|e| anyhow!("invalid JSON arguments: {}", e))?
+            }
+            _ => args
+                .iter()
+                .map(|arg| {
+                    let (name, value) = arg.split_once('=').ok_or_else(|| {
+                        anyhow!(
+                            "expected an argument of the form `name=value`, found `{}`",
+                            arg
+                        )
+                    })?;
+                    let value = serde_json::from_str(value)
+                        .unwrap_or_else(|_| Value::String(value.to_string()));
+                    Ok((name.to_string(), value))
+                })
+                .collect::<Result<_>>()?,
+        };
+        let tokens = self
+            .params
+            .iter()
+            .map(|(name, param_type)| {
+                let value = values
+                    .remove(name)
+                    .ok_or_else(|| anyhow!("missing argument `{}`", name))?;
+                json_to_token(param_type, &value)
+                    .with_context(|| format!("invalid value `{}` for `{}`", value, name))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if let Some(name) = values.keys().next() {
+            bail!("`{}` has no parameter named `{}`", self.name, name);
+        }
+        encode(&tokens)
+    }
+}
+
+/// A program's ABI in the SDK's representation, along with a lookup of its types by ID.
+struct SdkAbi {
+    abi: fuels_types::ProgramABI,
+    type_lookup: HashMap<usize, TypeDeclaration>,
+}
+
+impl SdkAbi {
+    fn from_program_abi(program_abi: &ProgramABI) -> Result<Self> {
+        // The SDK's ABI types share the JSON representation of those produced by the compiler.
+        let abi: fuels_types::ProgramABI =
+            serde_json::from_value(serde_json::to_value(program_abi)?)?;
+        let type_lookup = abi
+            .types
+            .iter()
+            .map(|decl| (decl.type_id, decl.clone()))
+            .collect();
+        Ok(Self { abi, type_lookup })
+    }
+
+    fn function(&self, name: &str) -> Option<&fuels_types::ABIFunction> {
+        self.abi.functions.iter().find(|f| f.name == name)
+    }
+
+    fn param_type(&self, type_application: &TypeApplication) -> Result<ParamType> {
+        param_type(type_application, &self.type_lookup)
+    }
+
+    /// The type of each value logged by the program, by log ID.
+    fn logged_types(&self) -> Result<HashMap<u64, ParamType>> {
+        self.abi
+            .logged_types
+            .iter()
+            .flatten()
+            .map(|logged_type| {
+                let param_type = self.param_type(&logged_type.application)?;
+                Ok((logged_type.log_id, param_type))
+            })
+            .collect()
+    }
+}
+
+/// Convert the given type to a `ParamType`, keeping the names of structs, fields and variants.
+fn param_type(
+    type_application: &TypeApplication,
+    type_lookup: &HashMap<usize, TypeDeclaration>,
+) -> Result<ParamType> {
+    let param_type = ParamType::try_from_type_application(type_application, type_lookup)?;
+    with_names(param_type, type_application, type_lookup, &[])
+}
+
+/// The SDK's `ParamType` conversion discards the names of structs, fields and enum variants, so
+/// restore them from the declaration of each type, resolving generic type parameters along the
+/// way in the same manner as the SDK.
+fn with_names(
+    param_type: ParamType,
+    type_application: &TypeApplication,
+    type_lookup: &HashMap<usize, TypeDeclaration>,
+    generics: &[(usize, TypeApplication)],
+) -> Result<ParamType> {
+    let type_application = resolve_generics(type_application, generics);
+    let decl = type_lookup
+        .get(&type_application.type_id)
+        .ok_or_else(|| anyhow!("unknown type id {}", type_application.type_id))?;
+    let decl_generics = match &decl.type_parameters {
+        Some(params) if !params.is_empty() => params
+            .iter()
+            .cloned()
+            .zip(type_application.type_arguments.iter().flatten().cloned())
+            .collect(),
+        // Types such as arrays forward the generic parameters of their enclosing type.
+        _ => generics.to_vec(),
+    };
+    let components: Vec<_> = decl.components.iter().flatten().collect();
+    let named_components = |param_types: Vec<(String, ParamType)>| {
+        param_types
+            .into_iter()
+            .zip(&components)
+            .map(|((_, param_type), component)| {
+                let param_type = with_names(param_type, component, type_lookup, &decl_generics)?;
+                Ok((component.name.clone(), param_type))
+            })
+            .collect::<Result<Vec<_>>>()
+    };
+    let declared_name = |prefix: &str| {
+        decl.type_field
+            .strip_prefix(prefix)
+            .unwrap_or(&decl.type_field)
+            .to_string()
+    };
+    let param_type = match param_type {
+        ParamType::Struct {
+            fields, generics, ..
+        } => ParamType::Struct {
+            name: declared_name("struct "),
+            fields: named_components(fields)?,
+            generics,
+        },
+        ParamType::Enum {
+            variants, generics, ..
+        } => ParamType::Enum {
+            name: declared_name("enum "),
+            variants: EnumVariants::new(named_components(variants.variants().clone())?)
+                .map_err(|e| anyhow!("{}", e))?,
+            generics,
+        },
+        ParamType::Array(elem_type, len) => {
+            let elem_application = components
+                .first()
+                .ok_or_else(|| anyhow!("array type `{}` has no element type", decl.type_field))?;
+            let elem_type = with_names(*elem_type, elem_application, type_lookup, &decl_generics)?;
+            ParamType::Array(Box::new(elem_type), len)
+        }
+        ParamType::Vector(elem_type) => {
+            let elem_application = type_application
+                .type_arguments
+                .iter()
+                .flatten()
+                .next()
+                .ok_or_else(|| anyhow!("`Vec` has no element type"))?;
+            let elem_type = with_names(*elem_type, elem_application, type_lookup, generics)?;
+            ParamType::Vector(Box::new(elem_type))
+        }
+        ParamType::Tuple(types) => {
+            let types = types
+                .into_iter()
+                .zip(&components)
+                .map(|(param_type, component)| {
+                    with_names(param_type, component, type_lookup, &decl_generics)
+                })
+                .collect::<Result<_>>()?;
+            ParamType::Tuple(types)
+        }
+        param_type => param_type,
+    };
+    Ok(param_type)
+}
+
+/// Substitute any generic type parameters within the given type with their concrete types.
+fn resolve_generics(
+    type_application: &TypeApplication,
+    generics: &[(usize, TypeApplication)],
+) -> TypeApplication {
+    match generics
+        .iter()
+        .find(|(type_id, _)| *type_id == type_application.type_id)
+    {
+        Some((_, concrete)) => concrete.clone(),
+        None => TypeApplication {
+            type_arguments: type_application.type_arguments.as_ref().map(|args| {
+                args.iter()
+                    .map(|arg| resolve_generics(arg, generics))
+                    .collect()
+            }),
+            ..type_application.clone()
+        },
+    }
+}
+
+fn encode(tokens: &[Token]) -> Result<Vec<u8>> {
+    let encoded =
+        ABIEncoder::encode(tokens).map_err(|e| anyhow!("failed to encode arguments: {}", e))?;
+    Ok(encoded.resolve(0))
+}
+
+fn decode(param_type: &ParamType, bytes: &[u8]) -> Result<Value> {
+    let token = ABIDecoder::decode_single(param_type, bytes)
+        .map_err(|e| anyhow!("failed to decode `{}`: {}", type_name(param_type), e))?;
+    token_to_json(param_type, &token)
+}
+
+/// Convert a JSON value to a token of the given type.
+///
+/// Structs are given as objects, or as arrays of their fields in order. Enums are given as
+/// `{"<variant>": <value>}`, or as `"<variant>"` for variants of unit type.
+fn json_to_token(param_type: &ParamType, value: &Value) -> Result<Token> {
+    let token = match (param_type, value) {
+        (ParamType::Unit, Value::Null) => Token::Unit,
+        (ParamType::Bool, Value::Bool(b)) => Token::Bool(*b),
+        (ParamType::U8, _) => Token::U8(json_u64(value)?.try_into()?),
+        (ParamType::U16, _) => Token::U16(json_u64(value)?.try_into()?),
+        (ParamType::U32, _) => Token::U32(json_u64(value)?.try_into()?),
+        (ParamType::U64, _) => Token::U64(json_u64(value)?),
+        (ParamType::Byte, _) => Token::Byte(json_u64(value)?.try_into()?),
+        (ParamType::B256, Value::String(s)) => {
+            let bytes = hex::decode(s.strip_prefix("0x").unwrap_or(s))?;
+            let bytes = bytes.try_into().map_err(|_| anyhow!("expected 32 bytes"))?;
+            Token::B256(bytes)
+        }
+        (ParamType::String(len), Value::String(s)) => {
+            if s.len() != *len {
+                bail!("expected a string of length {}", len);
+            }
+            Token::String(StringToken::new(s.clone(), *len))
+        }
+        (ParamType::Array(elem_type, len), Value::Array(items)) => {
+            if items.len() != *len {
+                bail!("expected an array of length {}", len);
+            }
+            Token::Array(json_to_tokens(elem_type, items)?)
+        }
+        (ParamType::Vector(elem_type), Value::Array(items)) => {
+            Token::Vector(json_to_tokens(elem_type, items)?)
+        }
+        (ParamType::Tuple(types), Value::Array(items)) if types.len() == items.len() => {
+            let tokens = types
+                .iter()
+                .zip(items)
+                .map(|(param_type, item)| json_to_token(param_type, item))
+                .collect::<Result<_>>()?;
+            Token::Tuple(tokens)
+        }
+        (ParamType::Struct { fields, .. }, Value::Object(map)) if fields.len() == map.len() => {
+            let tokens = fields
+                .iter()
+                .map(|(name, param_type)| {
+                    let value = map
+                        .get(name)
+                        .ok_or_else(|| anyhow!("missing field `{}`", name))?;
+                    json_to_token(param_type, value)
+                })
+                .collect::<Result<_>>()?;
+            Token::Struct(tokens)
+        }
+        (ParamType::Struct { fields, .. }, Value::Array(items)) if fields.len() == items.len() => {
+            let tokens = fields
+                .iter()
+                .zip(items)
+                .map(|((_, param_type), item)| json_to_token(param_type, item))
+                .collect::<Result<_>>()?;
+            Token::Struct(tokens)
+        }
+        (ParamType::Enum { variants, .. }, Value::String(name)) => {
+            enum_token(variants, name, &Value::Null)?
+        }
+        (ParamType::Enum { variants, .. }, Value::Object(map)) if map.len() == 1 => {
+            let (name, value) = map.iter().next().expect("map has one entry");
+            enum_token(variants, name, value)?
+        }
+        _ => bail!("expected a value of type `{}`", type_name(param_type)),
+    };
+    Ok(token)
+}
+
+fn json_to_tokens(param_type: &ParamType, items: &[Value]) -> Result<Vec<Token>> {
+    items
+        .iter()
+        .map(|item| json_to_token(param_type, item))
+        .collect()
+}
+
+/// Integers may be given either as JSON numbers or as decimal or `0x` prefixed hex strings.
+fn json_u64(value: &Value) -> Result<u64> {
+    let int = match value {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) => match s.strip_prefix("0x") {
+            Some(hex_str) => u64::from_str_radix(hex_str, 16).ok(),
+            None => s.parse().ok(),
+        },
+        _ => None,
+    };
+    int.ok_or_else(|| anyhow!("expected an unsigned integer"))
+}
+
+fn enum_token(variants: &EnumVariants, name: &str, value: &Value) -> Result<Token> {
+    let (discriminant, (_, param_type)) = variants
+        .variants()
+        .iter()
+        .enumerate()
+        .find(|(_, (variant_name, _))| variant_name == name)
+        .ok_or_else(|| anyhow!("no variant named `{}`", name))?;
+    let token = json_to_token(param_type, value)?;
+    Ok(Token::Enum(Box::new((
+        discriminant.try_into()?,
+        token,
+        variants.clone(),
+    ))))
+}
+
+/// Convert a token of the given type to JSON, in the same representation accepted by
+/// `json_to_token`.
+fn token_to_json(param_type: &ParamType, token: &Token) -> Result<Value> {
+    let value = match (param_type, token) {
+        (_, Token::Unit) => Value::Null,
+        (_, Token::Bool(b)) => Value::Bool(*b),
+        (_, Token::U8(n)) | (_, Token::Byte(n)) => Value::from(*n),
+        (_, Token::U16(n)) => Value::from(*n),
+        (_, Token::U32(n)) => Value::from(*n),
+        (_, Token::U64(n)) => Value::from(*n),
+        (_, Token::B256(bytes)) => Value::String(format!("0x{}", hex::encode(bytes))),
+        (_, Token::String(s)) => Value::String(
+            s.get_encodable_str()
+                .map_err(|e| anyhow!("{}", e))?
+                .to_string(),
+        ),
+        (ParamType::Array(elem_type, _), Token::Array(tokens))
+        | (ParamType::Vector(elem_type), Token::Vector(tokens)) => Value::Array(
+            tokens
+                .iter()
+                .map(|token| token_to_json(elem_type, token))
+                .collect::<Result<_>>()?,
+        ),
+        (ParamType::Tuple(types), Token::Tuple(tokens)) => Value::Array(
+            types
+                .iter()
+                .zip(tokens)
+                .map(|(param_type, token)| token_to_json(param_type, token))
+                .collect::<Result<_>>()?,
+        ),
+        (ParamType::Struct { fields, .. }, Token::Struct(tokens)) => Value::Object(
+            fields
+                .iter()
+                .zip(tokens)
+                .map(|((name, param_type), token)| {
+                    Ok((name.clone(), token_to_json(param_type, token)?))
+                })
+                .collect::<Result<_>>()?,
+        ),
+        (_, Token::Enum(selector)) => {
+            let (discriminant, token, variants) = &**selector;
+            let (name, param_type) = variants
+                .select_variant(*discriminant)
+                .map_err(|e| anyhow!("{}", e))?;
+            match param_type {
+                ParamType::Unit => Value::String(name.clone()),
+                _ => {
+                    let mut map = Map::new();
+                    map.insert(name.clone(), token_to_json(param_type, token)?);
+                    Value::Object(map)
+                }
+            }
+        }
+        _ => bail!(
+            "unexpected value {} for type `{}`",
+            token,
+            type_name(param_type)
+        ),
+    };
+    Ok(value)
+}
+
+/// The name of the given type, as written in Sway.
+fn type_name(param_type: &ParamType) -> String {
+    match param_type {
+        ParamType::U8 => "u8".to_string(),
+        ParamType::U16 => "u16".to_string(),
+        ParamType::U32 => "u32".to_string(),
+        ParamType::U64 => "u64".to_string(),
+        ParamType::Bool => "bool".to_string(),
+        ParamType::Byte => "byte".to_string(),
+        ParamType::B256 => "b256".to_string(),
+        ParamType::Unit => "()".to_string(),
+        ParamType::Array(elem_type, len) => format!("[{}; {}]", type_name(elem_type), len),
+        ParamType::Vector(elem_type) => format!("Vec<{}>", type_name(elem_type)),
+        ParamType::String(len) => format!("str[{}]", len),
+        ParamType::Struct { name, .. } | ParamType::Enum { name, .. } => name.clone(),
+        ParamType::Tuple(types) => {
+            let names: Vec<_> = types.iter().map(type_name).collect();
+            format!("({})", names.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_tx::Bytes32;
+    use serde_json::json;
+
+    /// The ABI of a script whose `main` function takes a parameter of each kind of type.
+    fn program_abi() -> ProgramABI {
+        let type_app = |type_id: usize| json!({"name": "", "type": type_id, "typeArguments": null});
+        let component = |name: &str, type_id: usize| json!({"name": name, "type": type_id, "typeArguments": null});
+        let decl = |type_id: usize, type_field: &str, components: Value| {
+            json!({
+                "typeId": type_id,
+                "type": type_field,
+                "components": components,
+                "typeParameters": null,
+            })
+        };
+        let mut option = decl(
+            10,
+            "enum Option",
+            json!([component("None", 0), component("Some", 11)]),
+        );
+        option["typeParameters"] = json!([11]);
+        let input = |name: &str, type_id: usize| component(name, type_id);
+        let abi = json!({
+            "types": [
+                decl(0, "()", json!([])),
+                decl(1, "bool", Value::Null),
+                decl(2, "u8", Value::Null),
+                decl(3, "u64", Value::Null),
+                decl(4, "b256", Value::Null),
+                decl(5, "str[3]", Value::Null),
+                decl(6, "[_; 2]", json!([component("__array_element", 3)])),
+                decl(
+                    7,
+                    "(_, _)",
+                    json!([component("__tuple_element", 3), component("__tuple_element", 1)]),
+                ),
+                decl(8, "struct Point", json!([component("x", 3), component("y", 1)])),
+                decl(9, "enum Color", json!([component("Red", 0), component("Green", 3)])),
+                option,
+                decl(11, "generic T", Value::Null),
+            ],
+            "functions": [{
+                "name": "main",
+                "inputs": [
+                    input("a", 1),
+                    input("b", 2),
+                    input("c", 3),
+                    input("d", 4),
+                    input("e", 5),
+                    input("f", 6),
+                    input("g", 7),
+                    input("h", 8),
+                    input("i", 9),
+                    {"name": "j", "type": 10, "typeArguments": [type_app(3)]},
+                ],
+                "output": type_app(8),
+            }],
+            "loggedTypes": [
+                {"logId": 0, "loggedType": type_app(8)},
+                {"logId": 1, "loggedType": type_app(3)},
+            ],
+        });
+        serde_json::from_value(abi).unwrap()
+    }
+
+    fn main_fn() -> AbiFunction {
+        AbiFunction::from_program_abi(&program_abi(), "main").unwrap()
+    }
+
+    /// Decode the given encoded arguments to `main`, as a JSON object keyed by parameter name.
+    fn decode_args(function: &AbiFunction, bytes: &[u8]) -> Value {
+        let param_types = function.params.iter().map(|(_, ty)| ty.clone()).collect();
+        let values = decode(&ParamType::Tuple(param_types), bytes).unwrap();
+        let values = values.as_array().unwrap().iter().cloned();
+        let names = function.params.iter().map(|(name, _)| name.clone());
+        Value::Object(names.zip(values).collect())
+    }
+
+    fn args() -> Value {
+        json!({
+            "a": true,
+            "b": 255,
+            "c": 1337,
+            "d": format!("0x{}", "ab".repeat(32)),
+            "e": "abc",
+            "f": [1, 2],
+            "g": [3, false],
+            "h": {"x": 4, "y": true},
+            "i": {"Green": 5},
+            "j": {"Some": 6},
+        })
+    }
+
+    #[test]
+    fn test_named_args_round_trip() {
+        let function = main_fn();
+        let bytes = function.encode_named_args(&[args().to_string()]).unwrap();
+        assert_eq!(decode_args(&function, &bytes), args());
+
+        // Unit variants are given by name, and structs may be given as arrays of their fields.
+        let mut args = args();
+        args["i"] = json!("Red");
+        args["j"] = json!("None");
+        let pairs: Vec<_> = args
+            .as_object()
+            .unwrap()
+            .iter()
+            .map(|(name, value)| match (name.as_str(), value) {
+                ("h", _) => "h=[4, true]".to_string(),
+                (_, Value::String(s)) => format!("{name}={s}"),
+                _ => format!("{name}={value}"),
+            })
+            .collect();
+        let bytes = function.encode_named_args(&pairs).unwrap();
+        assert_eq!(decode_args(&function, &bytes), args);
+    }
+
+    #[test]
+    fn test_positional_args() {
+        let function = main_fn();
+        let args: Vec<_> = [
+            "true",
+            "255",
+            "1337",
+            &format!("0x{}", "ab".repeat(32)),
+            "abc",
+            "[1, 2]",
+            "(3, false)",
+            "(4, true)",
+            "(1, 5)",
+            "(1, 6)",
+        ]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+        let bytes = function.encode_args(&args).unwrap();
+        assert_eq!(decode_args(&function, &bytes), self::args());
+    }
+
+    #[test]
+    fn test_integers_as_strings() {
+        let param_type = ParamType::U64;
+        let token = json_to_token(&param_type, &json!("0x2a")).unwrap();
+        assert_eq!(token, Token::U64(42));
+        let token = json_to_token(&param_type, &json!("42")).unwrap();
+        assert_eq!(token, Token::U64(42));
+    }
+
+    #[test]
+    fn test_selector() {
+        let function = main_fn();
+        let param_types: Vec<_> = function.params.iter().map(|(_, ty)| ty.clone()).collect();
+        assert_eq!(
+            function.selector(),
+            resolve_fn_selector("main", &param_types)
+        );
+    }
+
+    #[test]
+    fn test_decode_return_and_logs() {
+        let decoder = ReceiptDecoder::from_program_abi(&program_abi()).unwrap();
+        let id = ContractId::zeroed();
+        let other_id = ContractId::from([1; 32]);
+        let point = encode(&[Token::Struct(vec![Token::U64(4), Token::Bool(true)])]).unwrap();
+        let receipts = vec![
+            Receipt::log_data(id, 0, 0, 0, Bytes32::zeroed(), point.clone(), 0, 0),
+            Receipt::log(id, 7, 1, 0, 0, 0, 0),
+            // Values logged by other contracts or with unknown log IDs are skipped.
+            Receipt::log(other_id, 8, 1, 0, 0, 0, 0),
+            Receipt::log(id, 9, 2, 0, 0, 0, 0),
+            Receipt::return_data(id, 0, Bytes32::zeroed(), point, 0, 0),
+        ];
+        let logs = decoder.decode_logs(&id, &receipts).unwrap();
+        assert_eq!(logs, vec![json!({"x": 4, "y": true}), json!(7)]);
+        let returned = decoder.decode_return(&id, &receipts).unwrap();
+        assert_eq!(returned, Some(json!({"x": 4, "y": true})));
+        assert_eq!(decoder.decode_return(&other_id, &receipts).unwrap(), None);
+    }
+
+    /// The full error message, including its context, of encoding the given named arguments.
+    fn named_args_err(args: &[&str]) -> String {
+        let args: Vec<_> = args.iter().map(|arg| arg.to_string()).collect();
+        format!("{:#}", main_fn().encode_named_args(&args).unwrap_err())
+    }
+
+    /// The full error message of encoding `args()` with the given argument replaced.
+    fn named_arg_err(name: &str, value: Value) -> String {
+        let mut args = args();
+        args[name] = value;
+        named_args_err(&[&args.to_string()])
+    }
+
+    #[test]
+    fn test_malformed_args() {
+        let positional: Vec<_> = ["true", "1"].iter().map(|arg| arg.to_string()).collect();
+        assert_eq!(
+            main_fn().encode_args(&positional).unwrap_err().to_string(),
+            "`main` takes 10 argument(s) but 2 were provided"
+        );
+        assert_eq!(
+            named_args_err(&["{\"a\": "]),
+            "invalid JSON arguments: EOF while parsing a value at line 1 column 6"
+        );
+        assert_eq!(
+            named_args_err(&["a"]),
+            "expected an argument of the form `name=value`, found `a`"
+        );
+        assert_eq!(named_args_err(&["a=true"]), "missing argument `b`");
+        let mut extra = args();
+        extra["z"] = json!(1);
+        assert_eq!(
+            named_args_err(&[&extra.to_string()]),
+            "`main` has no parameter named `z`"
+        );
+        assert_eq!(
+            named_arg_err("a", json!(1)),
+            "invalid value `1` for `a`: expected a value of type `bool`"
+        );
+        assert_eq!(
+            named_arg_err("b", json!(256)),
+            "invalid value `256` for `b`: out of range integral type conversion attempted"
+        );
+        assert_eq!(
+            named_arg_err("c", json!(-1)),
+            "invalid value `-1` for `c`: expected an unsigned integer"
+        );
+        assert_eq!(
+            named_arg_err("d", json!("0x01")),
+            "invalid value `\"0x01\"` for `d`: expected 32 bytes"
+        );
+        assert_eq!(
+            named_arg_err("e", json!("abcd")),
+            "invalid value `\"abcd\"` for `e`: expected a string of length 3"
+        );
+        assert_eq!(
+            named_arg_err("f", json!([1])),
+            "invalid value `[1]` for `f`: expected an array of length 2"
+        );
+        assert_eq!(
+            named_arg_err("g", json!([1])),
+            "invalid value `[1]` for `g`: expected a value of type `(u64, bool)`"
+        );
+        assert_eq!(
+            named_arg_err("h", json!({"x": 1, "z": true})),
+            "invalid value `{\"x\":1,\"z\":true}` for `h`: missing field `y`"
+        );
+        assert_eq!(
+            named_arg_err("i", json!("Blue")),
+            "invalid value `\"Blue\"` for `i`: no variant named `Blue`"
+        );
+        assert_eq!(
+            named_arg_err("j", json!({"Some": true})),
+            "invalid value `{\"Some\":true}` for `j`: expected an unsigned integer"
+        );
+    }
+}
@@ -15,12 +15,25 @@ use sway_error::warning::CompileWarning;
 use sway_types::{LineCol, Spanned};
 use sway_utils::constants;
 
+#[cfg(feature = "abi")]
+pub mod abi;
 pub mod restricted;
 
 pub const DEFAULT_OUTPUT_DIRECTORY: &str = "out";
 
 /// Format `Log` and `LogData` receipts.
 pub fn format_log_receipts(receipts: &[fuel_tx::Receipt], pretty_print: bool) -> Result<String> {
+    let receipt_to_json_array = log_receipts_to_json(receipts)?;
+    if pretty_print {
+        Ok(serde_json::to_string_pretty(&receipt_to_json_array)?)
+    } else {
+        Ok(serde_json::to_string(&receipt_to_json_array)?)
+    }
+}
+
+/// Serialize the given receipts to a JSON array, hex-encoding the data of any `LogData` and
+/// `ReturnData` receipts.
+pub fn log_receipts_to_json(receipts: &[fuel_tx::Receipt]) -> Result<serde_json::Value> {
     let mut receipt_to_json_array = serde_json::to_value(receipts)?;
     for (rec_index, receipt) in receipts.iter().enumerate() {
         let rec_value = receipt_to_json_array.get_mut(rec_index).ok_or_else(|| {
@@ -43,11 +56,7 @@ pub fn format_log_receipts(receipts: &[fuel_tx::Receipt], pretty_print: bool) ->
             _ => {}
         }
     }
-    if pretty_print {
-        Ok(serde_json::to_string_pretty(&receipt_to_json_array)?)
-    } else {
-        Ok(serde_json::to_string(&receipt_to_json_array)?)
-    }
+    Ok(receipt_to_json_array)
 }
 
 /// Continually go up in the file tree until a specified file is found.
@@ -174,6 +183,11 @@ pub fn git_checkouts_directory() -> PathBuf {
     user_forc_directory().join("git").join("checkouts")
 }
 
+/// The location at which `forc` will cache packages fetched from registries.
+pub fn registry_cache_directory() -> PathBuf {
+    user_forc_directory().join("registry").join("cache")
+}
+
 pub fn print_on_success(
     terse_mode: bool,
     proj_name: &str,
@@ -3,8 +3,14 @@ use ansi_term::Colour;
 use anyhow::{bail, Result};
 use clap::Parser;
 use forc_pkg as pkg;
-use forc_test::TestedPackage;
-use forc_util::format_log_receipts;
+use forc_test::{
+    format_percent, Coverage, FuzzConfig, GasSnapshot, TestFilter, TestReport, TestRunnerCount,
+    TestedPackage,
+};
+use forc_util::{default_output_directory, find_manifest_dir, format_log_receipts};
+use std::path::{Path, PathBuf};
+use term_table::row::Row;
+use term_table::table_cell::{Alignment, TableCell};
 use tracing::info;
 
 /// Run the Sway unit tests for the current project.
@@ -30,8 +36,23 @@ pub struct Command {
     pub build: cli::shared::Build,
     #[clap(flatten)]
     pub test_print: TestPrintOpts,
+    #[clap(flatten)]
+    pub test_gas: TestGasOpts,
+    #[clap(flatten)]
+    pub test_report: TestReportOpts,
+    #[clap(flatten)]
+    pub test_fuzz: TestFuzzOpts,
+    #[clap(flatten)]
+    pub test_coverage: TestCoverageOpts,
     /// When specified, only tests containing the given string will be executed.
     pub filter: Option<String>,
+    /// When specified, only the test exactly matching the given string will be executed.
+    #[clap(long, requires = "filter")]
+    pub exact: bool,
+    /// Number of threads to utilize when running the tests. By default, this is the number of
+    /// threads available in your system.
+    #[clap(long)]
+    pub test_threads: Option<usize>,
 }
 
 /// The set of options provided for controlling output of a test.
@@ -45,35 +66,351 @@ pub struct TestPrintOpts {
     pub print_logs: bool,
 }
 
+/// The set of options provided for executing tests that take parameters.
+#[derive(Parser, Debug, Clone)]
+pub struct TestFuzzOpts {
+    /// The number of sets of random arguments with which each test that takes parameters is
+    /// executed.
+    #[clap(long = "fuzz-runs", default_value_t = forc_test::DEFAULT_FUZZ_RUNS)]
+    pub fuzz_runs: usize,
+    /// The seed from which the random arguments of tests that take parameters are generated.
+    ///
+    /// A failing test reports the seed with which it failed, so that it may be reproduced.
+    #[clap(long = "fuzz-seed", default_value_t = forc_test::DEFAULT_FUZZ_SEED)]
+    pub fuzz_seed: u64,
+}
+
+/// The set of options provided for measuring the source lines executed by tests.
+#[derive(Parser, Debug, Clone)]
+pub struct TestCoverageOpts {
+    /// Record the source lines executed by the tests, print a summary of the line coverage of
+    /// each file and write an lcov tracefile and an HTML report.
+    ///
+    /// This requires forc to be built with the `coverage` feature.
+    #[clap(long = "coverage")]
+    pub coverage: bool,
+    /// The directory to which the lcov tracefile and HTML report are written.
+    ///
+    /// By default, this is the `coverage` directory within the project's output directory.
+    #[clap(long = "coverage-dir")]
+    pub coverage_dir: Option<PathBuf>,
+}
+
+/// The set of options provided for producing a machine-readable report of the test results.
+#[derive(Parser, Debug, Clone)]
+pub struct TestReportOpts {
+    /// The format in which test results are reported.
+    ///
+    /// The `json` and `junit` formats replace the human-readable per-test output with a report
+    /// written to `--output-file`.
+    #[clap(long = "format", value_enum, default_value_t = TestOutputFormat::Human)]
+    pub format: TestOutputFormat,
+    /// The file to which the `json` or `junit` report is written.
+    ///
+    /// By default, this is `test-report.json` or `test-report.xml` within the project's output
+    /// directory.
+    #[clap(long = "output-file")]
+    pub output_file: Option<PathBuf>,
+}
+
+/// The formats in which `forc test` may report test results.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TestOutputFormat {
+    /// Human-readable output.
+    #[default]
+    Human,
+    /// A JSON report including the name, location, pass condition, duration, gas used, revert
+    /// code and logs of each test.
+    Json,
+    /// A JUnit XML report, as consumed by most CI dashboards.
+    Junit,
+}
+
+/// The set of options provided for reporting and checking the gas used by tests.
+#[derive(Parser, Debug, Clone)]
+pub struct TestGasOpts {
+    /// Print a table of the gas used by each test.
+    #[clap(long = "gas-report")]
+    pub gas_report: bool,
+    /// Write the gas used by each test to the given snapshot file.
+    #[clap(long = "gas-snapshot")]
+    pub gas_snapshot: Option<PathBuf>,
+    /// Compare the gas used by each test against the given snapshot file.
+    ///
+    /// Fails if the gas used by any test increased by more than `--gas-tolerance` percent.
+    #[clap(long = "gas-check")]
+    pub gas_check: Option<PathBuf>,
+    /// The percentage by which a test's gas usage may increase before `--gas-check` fails.
+    #[clap(long = "gas-tolerance", default_value_t = 0.0)]
+    pub gas_tolerance: f64,
+}
+
+impl TestGasOpts {
+    /// Reject a negative or NaN `--gas-tolerance`, and checking against the snapshot being written
+    /// by the same run, which would always pass.
+    fn validate(&self) -> Result<()> {
+        if self.gas_tolerance.is_nan() || self.gas_tolerance < 0.0 {
+            bail!(
+                "`--gas-tolerance` must be a non-negative percentage, found {}",
+                self.gas_tolerance
+            );
+        }
+        if let (Some(snapshot), Some(check)) = (&self.gas_snapshot, &self.gas_check) {
+            let same_file = snapshot == check
+                || matches!(
+                    (snapshot.canonicalize(), check.canonicalize()),
+                    (Ok(snapshot), Ok(check)) if snapshot == check
+                );
+            if same_file {
+                bail!(
+                    "`--gas-snapshot` and `--gas-check` must be given different files, \
+                    as the gas used by the tests would otherwise be checked against itself"
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
 pub(crate) fn exec(cmd: Command) -> Result<()> {
-    if let Some(ref _filter) = cmd.filter {
-        bail!("unit test filter not yet supported");
+    let test_runner_count = match cmd.test_threads {
+        Some(0) => bail!("`--test-threads` must be greater than 0"),
+        Some(runner_count) => TestRunnerCount::Manual(runner_count),
+        None => TestRunnerCount::Auto,
+    };
+
+    if cmd.test_fuzz.fuzz_runs == 0 {
+        bail!("`--fuzz-runs` must be greater than 0");
     }
+    let fuzz_config = FuzzConfig {
+        runs: cmd.test_fuzz.fuzz_runs,
+        seed: cmd.test_fuzz.fuzz_seed,
+    };
 
     let test_print_opts = cmd.test_print.clone();
+    let test_gas_opts = cmd.test_gas.clone();
+    test_gas_opts.validate()?;
+    let test_report_opts = cmd.test_report.clone();
+    if test_report_opts.output_file.is_some() && test_report_opts.format == TestOutputFormat::Human
+    {
+        bail!("`--output-file` requires either `--format json` or `--format junit`");
+    }
+    let report_file = match test_report_opts.format {
+        TestOutputFormat::Human => None,
+        format => Some(report_file_from_cmd(&cmd, format)?),
+    };
+    let coverage_dir = cmd
+        .test_coverage
+        .coverage
+        .then(|| coverage_dir_from_cmd(&cmd))
+        .transpose()?;
+    let test_filter_phrase = cmd.filter.clone();
+    let test_filter = test_filter_phrase.as_ref().map(|filter_phrase| TestFilter {
+        filter_phrase,
+        exact_match: cmd.exact,
+    });
     let opts = opts_from_cmd(cmd);
     let built_tests = forc_test::build(opts)?;
     let start = std::time::Instant::now();
-    info!("   Running {} tests", built_tests.test_count());
-    let tested = built_tests.run()?;
+    let test_count = built_tests.test_count(test_filter.as_ref());
+    let all_test_count = built_tests.test_count(None);
+    let filtered_test_count = all_test_count - test_count;
+    match filtered_test_count {
+        0 => info!("   Running {} tests", test_count),
+        _ => info!(
+            "   Running {} tests, filtered {} tests",
+            test_count, filtered_test_count
+        ),
+    }
+    let tested = built_tests.run(
+        test_runner_count,
+        test_filter,
+        fuzz_config,
+        coverage_dir.is_some(),
+    )?;
     let duration = start.elapsed();
 
-    // Eventually we'll print this in a fancy manner, but this will do for testing.
-    match tested {
-        forc_test::Tested::Workspace(pkgs) => {
-            for pkg in pkgs {
-                let built = &pkg.built.pkg_name;
-                info!("\n   tested -- {built}\n");
-                print_tested_pkg(&pkg, &test_print_opts)?;
+    match test_report_opts.format {
+        // Eventually we'll print this in a fancy manner, but this will do for testing.
+        TestOutputFormat::Human => match &tested {
+            forc_test::Tested::Workspace(pkgs) => {
+                for pkg in pkgs {
+                    let built = &pkg.built.pkg_name;
+                    info!("\n   tested -- {built}\n");
+                    print_tested_pkg(pkg, &test_print_opts)?;
+                }
+                info!("\n   Finished in {:?}", duration);
             }
-            info!("\n   Finished in {:?}", duration);
+            forc_test::Tested::Package(pkg) => print_tested_pkg(pkg, &test_print_opts)?,
+        },
+        format => {
+            let path = report_file
+                .as_deref()
+                .expect("report formats have a report file");
+            write_test_report(&tested, format, path)?
         }
-        forc_test::Tested::Package(pkg) => print_tested_pkg(&pkg, &test_print_opts)?,
+    }
+
+    let gas_snapshot = GasSnapshot::from_tested(&tested);
+    if test_gas_opts.gas_report {
+        print_gas_report(&gas_snapshot);
+    }
+    // Check against the existing snapshot before writing the new one.
+    if let Some(path) = &test_gas_opts.gas_check {
+        check_gas_snapshot(path, &gas_snapshot, test_gas_opts.gas_tolerance)?;
+    }
+    if let Some(path) = &test_gas_opts.gas_snapshot {
+        gas_snapshot.to_file(path)?;
+        info!("\n   Wrote gas snapshot to {}", path.display());
+    }
+
+    if let Some(dir) = &coverage_dir {
+        let coverage = Coverage::from_tested(&tested);
+        print_coverage_report(&coverage);
+        coverage.write_reports(dir)?;
+        info!("\n   Wrote coverage report to {}", dir.display());
+    }
+
+    Ok(())
+}
+
+/// The directory to which coverage reports are written, either as specified or within the output
+/// directory of the project.
+fn coverage_dir_from_cmd(cmd: &Command) -> Result<PathBuf> {
+    if let Some(dir) = &cmd.test_coverage.coverage_dir {
+        return Ok(dir.clone());
+    }
+    Ok(output_dir_from_cmd(cmd)?.join("coverage"))
+}
+
+/// The file to which the test report is written, either as specified or within the output
+/// directory of the project.
+fn report_file_from_cmd(cmd: &Command, format: TestOutputFormat) -> Result<PathBuf> {
+    if let Some(path) = &cmd.test_report.output_file {
+        return Ok(path.clone());
+    }
+    let file_name = match format {
+        TestOutputFormat::Human => unreachable!("human output is not a report"),
+        TestOutputFormat::Json => "test-report.json",
+        TestOutputFormat::Junit => "test-report.xml",
     };
+    Ok(output_dir_from_cmd(cmd)?.join(file_name))
+}
 
+/// The output directory of the project, either as specified or the default.
+fn output_dir_from_cmd(cmd: &Command) -> Result<PathBuf> {
+    let output_dir = match &cmd.build.output_directory {
+        Some(dir) => PathBuf::from(dir),
+        None => {
+            let this_dir = match &cmd.build.path {
+                Some(path) => PathBuf::from(path),
+                None => std::env::current_dir()?,
+            };
+            let manifest_dir = find_manifest_dir(&this_dir).ok_or_else(|| {
+                anyhow::anyhow!("could not find `{}`", sway_utils::MANIFEST_FILE_NAME)
+            })?;
+            default_output_directory(&manifest_dir)
+        }
+    };
+    Ok(output_dir)
+}
+
+fn write_test_report(
+    tested: &forc_test::Tested,
+    format: TestOutputFormat,
+    path: &Path,
+) -> Result<()> {
+    let report = TestReport::from_tested(tested)?;
+    let contents = match format {
+        TestOutputFormat::Human => unreachable!("human output is not a report"),
+        TestOutputFormat::Json => report.to_json()?,
+        TestOutputFormat::Junit => report.to_junit_xml()?,
+    };
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, contents)
+        .map_err(|e| anyhow::anyhow!("failed to write test report {}: {}", path.display(), e))?;
+    info!("\n   Wrote test report to {}", path.display());
     Ok(())
 }
 
+fn print_gas_report(gas_snapshot: &GasSnapshot) {
+    let mut table = term_table::Table::new();
+    table.separate_rows = false;
+    table.style = term_table::TableStyle::thin();
+    table.add_row(Row::new(vec![
+        TableCell::new("package"),
+        TableCell::new("test"),
+        TableCell::new("gas used"),
+    ]));
+    for (pkg_name, tests) in &gas_snapshot.packages {
+        for (test_name, gas_used) in tests {
+            table.add_row(Row::new(vec![
+                TableCell::new(pkg_name),
+                TableCell::new(test_name),
+                TableCell::new_with_alignment(gas_used, 1, Alignment::Right),
+            ]));
+        }
+    }
+    info!("\n   Gas report:\n{}", table.render());
+}
+
+fn print_coverage_report(coverage: &Coverage) {
+    let mut table = term_table::Table::new();
+    table.separate_rows = false;
+    table.style = term_table::TableStyle::thin();
+    table.add_row(Row::new(vec![
+        TableCell::new("file"),
+        TableCell::new("lines"),
+        TableCell::new("coverage"),
+    ]));
+    let rows = coverage
+        .files
+        .iter()
+        .map(|(path, lines)| (path.display().to_string(), Coverage::file_summary(lines)))
+        .chain(std::iter::once(("total".to_string(), coverage.summary())));
+    for (name, (hit, total)) in rows {
+        table.add_row(Row::new(vec![
+            TableCell::new(name),
+            TableCell::new_with_alignment(format!("{hit}/{total}"), 1, Alignment::Right),
+            TableCell::new_with_alignment(format_percent(hit, total), 1, Alignment::Right),
+        ]));
+    }
+    info!("\n   Coverage report:\n{}", table.render());
+}
+
+fn check_gas_snapshot(path: &Path, current: &GasSnapshot, tolerance: f64) -> Result<()> {
+    let expected = GasSnapshot::from_file(path)?;
+    let regressions = expected.regressions(current, tolerance);
+    if regressions.is_empty() {
+        info!(
+            "\n   Gas check: {} against {}",
+            Colour::Green.paint("OK"),
+            path.display()
+        );
+        return Ok(());
+    }
+    info!("\n   gas regressions:");
+    for regression in &regressions {
+        let increase = regression.actual - regression.expected;
+        info!(
+            "      - test {}::{}, {} -> {} (+{})",
+            regression.pkg_name,
+            regression.test_name,
+            regression.expected,
+            regression.actual,
+            increase
+        );
+    }
+    bail!(
+        "gas usage of {} test(s) increased by more than {}% compared to {}",
+        regressions.len(),
+        tolerance,
+        path.display()
+    )
+}
+
 fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> Result<()> {
     let succeeded = pkg.tests.iter().filter(|t| t.passed()).count();
     let failed = pkg.tests.len() - succeeded;
@@ -84,11 +421,17 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> Res
             true => ("ok", Colour::Green),
             false => ("FAILED", Colour::Red),
         };
+        let fuzz_runs = match &test.fuzz {
+            Some(fuzz) => format!(", {} runs", fuzz.runs),
+            None => String::new(),
+        };
         info!(
-            "      test {} ... {} ({:?})",
+            "      test {} ... {} ({:?}, {} gas{})",
             test.name,
             color.paint(state),
-            test.duration
+            test.duration,
+            test.gas_used,
+            fuzz_runs
         );
 
         // If logs are enabled, print them.
@@ -99,9 +442,9 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> Res
         }
 
         // If the test is failing, save details.
-        if !test_passed {
+        if let Some(failure_message) = test.failure_message() {
             let details = test.details()?;
-            failed_test_details.push((test.name.clone(), details));
+            failed_test_details.push((test.name.clone(), details, failure_message));
         }
     }
     let (state, color) = match succeeded == pkg.tests.len() {
@@ -110,12 +453,12 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> Res
     };
     if failed != 0 {
         info!("\n   failures:");
-        for (failed_test_name, failed_test_detail) in failed_test_details {
+        for (failed_test_name, failed_test_detail, failure_message) in failed_test_details {
             let path = &*failed_test_detail.file_path;
             let line_number = failed_test_detail.line_number;
             info!(
-                "      - test {}, {:?}:{} ",
-                failed_test_name, path, line_number
+                "      - test {}, {:?}:{}, {}",
+                failed_test_name, path, line_number, failure_message
             );
         }
         info!("\n");
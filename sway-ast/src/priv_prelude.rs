@@ -1,7 +1,7 @@
 pub use {
     crate::{
         assignable::Assignable,
-        attribute::{Annotated, Attribute, AttributeDecl},
+        attribute::{Annotated, Attribute, AttributeArg, AttributeDecl},
         brackets::{AngleBrackets, Braces, Parens, SquareBrackets},
         dependency::Dependency,
         expr::{
@@ -1,5 +1,8 @@
 use crate::{
-    asm_generation::{register_allocator, AllocatedAbstractInstructionSet, RegisterSequencer},
+    asm_generation::{
+        register_allocator::{self, SpillArea},
+        AllocatedAbstractInstructionSet, RegisterSequencer,
+    },
     asm_lang::{
         allocated_ops::{AllocatedOp, AllocatedOpcode},
         AllocatedAbstractOp, Op, OrganizationalOp, RealizedOp, VirtualOp, VirtualRegister,
@@ -165,35 +168,66 @@ impl AbstractInstructionSet {
     /// algorithm (https://en.wikipedia.org/wiki/Chaitin%27s_algorithm). The individual steps of
     /// the algorithm are thoroughly explained in register_allocator.rs.
     ///
+    /// An error is returned if no assignment can be found, even after spilling every register
+    /// which may be spilled.
+    ///
     pub(crate) fn allocate_registers(
         self,
         register_sequencer: &mut RegisterSequencer,
-    ) -> AllocatedAbstractInstructionSet {
-        // Step 1: Liveness Analysis.
-        let live_out = register_allocator::liveness_analysis(&self.ops);
-
-        // Step 2: Construct the interference graph.
-        let (mut interference_graph, mut reg_to_node_ix) =
-            register_allocator::create_interference_graph(&self.ops, &live_out);
-
-        // Step 3: Remove redundant MOVE instructions using the interference graph.
-        let reduced_ops = register_allocator::coalesce_registers(
-            &self.ops,
-            &mut interference_graph,
-            &mut reg_to_node_ix,
-            register_sequencer,
-        );
-
-        // Step 4: Simplify - i.e. color the interference graph and return a stack that contains
-        // each colorable node and its neighbors.
-        let mut stack = register_allocator::color_interference_graph(&mut interference_graph);
-
-        // Step 5: Use the stack to assign a register for each virtual register.
-        let pool = register_allocator::assign_registers(&mut stack);
-
-        // Step 6: Update all instructions to use the resulting register pool.
+    ) -> Result<AllocatedAbstractInstructionSet, CompileError> {
+        let mut ops = self.ops;
+        let mut spill_area = None;
+        let pool = loop {
+            // Step 1: Liveness Analysis.
+            let live_out = register_allocator::liveness_analysis(&ops);
+
+            // Step 2: Construct the interference graph.
+            let (mut interference_graph, mut reg_to_node_ix) =
+                register_allocator::create_interference_graph(&ops, &live_out);
+
+            // Step 3: Remove redundant MOVE instructions using the interference graph.
+            ops = register_allocator::coalesce_registers(
+                &ops,
+                &mut interference_graph,
+                &mut reg_to_node_ix,
+                register_sequencer,
+            );
+
+            // Step 4: Simplify - i.e. color the interference graph and return a stack that
+            // contains each colorable node and its neighbors.
+            let mut stack =
+                register_allocator::color_interference_graph(&mut interference_graph.clone(), None);
+
+            // Step 5: Use the stack to assign a register for each virtual register. If that
+            // fails, simplify the graph again, this time picking the cheapest nodes to spill
+            // whenever it can't be simplified further.
+            let pool = register_allocator::assign_registers(&mut stack).or_else(|_| {
+                let spill_costs =
+                    register_allocator::compute_spill_costs(&ops, spill_area.as_ref());
+                let mut stack = register_allocator::color_interference_graph(
+                    &mut interference_graph,
+                    Some(&spill_costs),
+                );
+                register_allocator::assign_registers(&mut stack)
+            });
+            match pool {
+                Ok(pool) => break pool,
+                Err(spills) => {
+                    // Step 6: Spill the virtual registers which couldn't be assigned a register
+                    // to the stack, and start over with the rewritten instructions.
+                    let spill_area =
+                        spill_area.get_or_insert_with(|| SpillArea::new(register_sequencer));
+                    ops = register_allocator::spill(&ops, &spills, spill_area, register_sequencer)?;
+                }
+            }
+        };
+        if let Some(spill_area) = spill_area {
+            ops = spill_area.allocate(ops);
+        }
+
+        // Step 7: Update all instructions to use the resulting register pool.
         let mut buf = vec![];
-        for op in &reduced_ops {
+        for op in &ops {
             buf.push(AllocatedAbstractOp {
                 opcode: op.allocate_registers(&pool),
                 comment: op.comment.clone(),
@@ -201,7 +235,7 @@ impl AbstractInstructionSet {
             })
         }
 
-        AllocatedAbstractInstructionSet { ops: buf }
+        Ok(AllocatedAbstractInstructionSet { ops: buf })
     }
 }
 
@@ -256,3 +290,94 @@ impl RealizedAbstractInstructionSet {
         ops
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        asm_generation::compiler_constants,
+        asm_lang::{allocated_ops::AllocatedRegister, ConstantRegister, VirtualImmediate18},
+    };
+    use std::collections::HashMap;
+
+    /// Run straight line code `ops`, returning the value left in the call return value register.
+    fn run(ops: &[AllocatedAbstractOp]) -> u64 {
+        let mut regs: HashMap<AllocatedRegister, u64> = HashMap::new();
+        let mut memory: HashMap<u64, u64> = HashMap::new();
+        let sp = AllocatedRegister::Constant(ConstantRegister::StackPointer);
+        regs.insert(sp.clone(), 0);
+        for op in ops {
+            let get = |reg: &AllocatedRegister| regs.get(reg).copied().unwrap_or_default();
+            match &op.opcode {
+                Either::Left(AllocatedOpcode::MOVI(r, imm)) => {
+                    regs.insert(r.clone(), imm.value as u64);
+                }
+                Either::Left(AllocatedOpcode::MOVE(r1, r2)) => {
+                    regs.insert(r1.clone(), get(r2));
+                }
+                Either::Left(AllocatedOpcode::ADD(r1, r2, r3)) => {
+                    regs.insert(r1.clone(), get(r2) + get(r3));
+                }
+                Either::Left(AllocatedOpcode::CFEI(imm)) => {
+                    regs.insert(sp.clone(), get(&sp) + imm.value as u64);
+                }
+                Either::Left(AllocatedOpcode::LW(r1, r2, imm)) => {
+                    let value = memory[&(get(r2) + imm.value as u64 * 8)];
+                    regs.insert(r1.clone(), value);
+                }
+                Either::Left(AllocatedOpcode::SW(r1, r2, imm)) => {
+                    memory.insert(get(r1) + imm.value as u64 * 8, get(r2));
+                }
+                _ => panic!("Unexpected op: {}", op.opcode),
+            }
+        }
+        regs[&AllocatedRegister::Constant(ConstantRegister::CallReturnValue)]
+    }
+
+    #[test]
+    fn allocate_registers_with_spills() {
+        // Keep more values live at once than there are registers to hold them.
+        let mut register_sequencer = RegisterSequencer::new();
+        let num_values = 2 * compiler_constants::NUM_ALLOCATABLE_REGISTERS;
+        let values = (0..num_values)
+            .map(|_| register_sequencer.next())
+            .collect::<Vec<_>>();
+        let op = |opcode| Op {
+            opcode: Either::Left(opcode),
+            comment: String::new(),
+            owning_span: None,
+        };
+        let mut ops = values
+            .iter()
+            .enumerate()
+            .map(|(ix, reg)| {
+                op(VirtualOp::MOVI(
+                    reg.clone(),
+                    VirtualImmediate18 {
+                        value: ix as u32 + 1,
+                    },
+                ))
+            })
+            .collect::<Vec<_>>();
+        let sum = values.iter().skip(1).fold(values[0].clone(), |acc, reg| {
+            let sum = register_sequencer.next();
+            ops.push(op(VirtualOp::ADD(sum.clone(), acc, reg.clone())));
+            sum
+        });
+        ops.push(op(VirtualOp::MOVE(
+            VirtualRegister::Constant(ConstantRegister::CallReturnValue),
+            sum,
+        )));
+
+        let allocated = AbstractInstructionSet { ops }
+            .allocate_registers(&mut register_sequencer)
+            .unwrap();
+        assert!(allocated
+            .ops
+            .iter()
+            .any(|op| matches!(op.opcode, Either::Left(AllocatedOpcode::SW(..)))));
+
+        let num_values = num_values as u64;
+        assert_eq!(run(&allocated.ops), num_values * (num_values + 1) / 2);
+    }
+}
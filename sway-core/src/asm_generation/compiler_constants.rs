@@ -36,3 +36,7 @@ pub(crate) const SIX_BITS: u64 = 0b11_1111;
 
 /// Some arbitrary values used for error codes.
 pub(crate) const MISMATCHED_SELECTOR_REVERT_CODE: u32 = 123;
+
+/// The value reverted with when an index into an array in storage is out of bounds, which is
+/// `FAILED_STORAGE_ARRAY_INDEX_SIGNAL` in the standard library.
+pub(crate) const STORAGE_ARRAY_INDEX_OUT_OF_BOUNDS_REVERT_CODE: u64 = 0xffff_ffff_ffff_0003;
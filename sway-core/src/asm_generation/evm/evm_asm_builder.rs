@@ -3,22 +3,24 @@ use std::{collections::HashMap, sync::Arc};
 use crate::{
     asm_generation::{
         asm_builder::{AsmBuilder, AsmBuilderResult},
-        from_ir::StateAccessType,
+        from_ir::{aggregate_idcs_to_field_layout, ir_type_size_in_bytes, StateAccessType},
         register_sequencer::RegisterSequencer,
-        ProgramKind,
+        Entry, ProgramKind,
     },
-    asm_lang::Label,
+    asm_lang::{ConstantRegister, Label},
     error::*,
+    fuel_prelude::{fuel_crypto::Hasher, fuel_tx::StorageSlot},
     metadata::MetadataManager,
+    size_bytes_in_words,
 };
 
 use sway_error::error::CompileError;
 use sway_ir::{Context, *};
-use sway_types::Span;
+use sway_types::{Ident, Span, Spanned};
 
 use etk_asm::{asm::Assembler, ops::*};
 
-mod ethabi {
+pub(super) mod ethabi {
     pub use fuel_ethabi::*;
 }
 
@@ -44,12 +46,27 @@ mod ethabi {
 /// The code that is compiled but not stored on the blockchain is thus the code needed
 /// to store the correct code on the blockchain but also any logic that is contained in
 /// a (potential) constructor of the contract.
+///
+/// Scripts have no constructor, their code is run directly when they're called and they return
+/// their result from it.
+///
+/// Sway has no recursion, so every IR value and local variable is given its own statically
+/// allocated slot in memory, after the scratch space and the free memory pointer.  The EVM stack
+/// is only used for temporaries within an instruction and for the return addresses of calls.
 
 pub struct EvmAsmBuilder<'ir> {
-    #[allow(dead_code)]
-    program_kind: ProgramKind,
+    pub(super) program_kind: ProgramKind,
+
+    // The initial storage slots, which are written by the constructor for contracts.
+    storage_slots: Vec<StorageSlot>,
 
-    sections: Vec<EvmAsmSection>,
+    pub(super) sections: Vec<EvmAsmSection>,
+    pub(super) cur_section: EvmAsmSection,
+
+    // The ABI methods of a contract, mapped from their selector to their entry label, or the
+    // entry label of `main` for scripts.
+    pub(super) entries: Vec<([u8; 4], Label)>,
+    pub(super) main_label: Option<Label>,
 
     // Register sequencer dishes out new registers and labels.
     pub(super) reg_seqr: RegisterSequencer,
@@ -57,14 +74,62 @@ pub struct EvmAsmBuilder<'ir> {
     // Label maps are from IR functions or blocks to label name.  Functions have a start and end
     // label.
     pub(super) func_label_map: HashMap<Function, (Label, Label)>,
-    #[allow(dead_code)]
     pub(super) block_label_map: HashMap<Block, Label>,
 
+    // Where each IR value and local variable lives in memory.
+    pub(super) value_map: HashMap<Value, ValueLocation>,
+    pub(super) local_map: HashMap<LocalVar, u64>,
+
+    // The end of the statically allocated memory, which is where the heap starts.
+    mem_top: u64,
+
+    // A shared block which reverts without any data, used for panics like overflows.
+    pub(super) panic_label: Label,
+
     // IR context we're compiling.
-    context: &'ir Context,
+    pub(super) context: &'ir Context,
 
     // Metadata manager for converting metadata to Spans, etc.
-    md_mgr: MetadataManager,
+    pub(super) md_mgr: MetadataManager,
+}
+
+// The first 64 bytes of memory are scratch space.  As per the Solidity conventions, the word at
+// 0x40 is the free memory pointer.  The word at 0x60 holds the address just before the most
+// recent heap allocation, which is what the `hp` register refers to in ASM blocks.
+const SCRATCH_SPACE: u64 = 0x00;
+const FREE_MEMORY_POINTER: u64 = 0x40;
+const HEAP_POINTER: u64 = 0x60;
+const STATIC_MEMORY_START: u64 = 0x80;
+
+// Sway words are 64 bits and are stored big-endian in memory, so they're in the most significant
+// bytes of the 256 bit EVM word loaded from their address.
+const WORD_SHIFT: u8 = 192;
+
+// The addresses of the precompiled contracts we use.
+const SHA256_PRECOMPILE: u8 = 0x02;
+const IDENTITY_PRECOMPILE: u8 = 0x04;
+
+// Jump destinations are relative to the start of the code which is actually executed, i.e., the
+// runtime code for contracts.
+const CODE_START_LABEL: &str = "code_start";
+const CODE_END_LABEL: &str = "code_end";
+
+/// Where the value of an IR value is found at runtime.
+#[derive(Clone, Copy, Debug)]
+pub(super) enum ValueLocation {
+    /// The value is stored in a 32 byte slot at this address.
+    Slot(u64),
+    /// The value is this constant address, e.g., of a local variable.
+    Address(u64),
+}
+
+/// Something which can be pushed onto the stack.
+#[derive(Clone, Copy, Debug)]
+pub(super) enum Operand {
+    Value(Value),
+    /// A word stored in memory at this address, e.g., an ASM block register.
+    Memory(u64),
+    Number(u64),
 }
 
 #[derive(Default, Debug)]
@@ -78,13 +143,153 @@ impl EvmAsmSection {
         Self::default()
     }
 
-    pub fn size(&self) -> u32 {
-        let mut asm = Assembler::new();
-        if asm.push_all(self.ops.clone()).is_err() {
-            panic!("Could not size EVM assembly section");
+    pub fn size(&self) -> Result<u32, CompileError> {
+        assemble(self.ops.clone()).map(|bytes| bytes.len() as u32)
+    }
+
+    pub(super) fn op(&mut self, op: Op<Abstract>) {
+        self.ops.push(AbstractOp::Op(op));
+    }
+
+    pub(super) fn push_number(&mut self, value: u64) {
+        self.push_bytes(&value.to_be_bytes());
+    }
+
+    /// Push a big-endian value with the smallest push which holds it.  Sizing pushes here rather
+    /// than leaving it to the assembler avoids re-assembling everything behind a forward jump.
+    pub(super) fn push_bytes(&mut self, bytes: &[u8]) {
+        let start = bytes
+            .iter()
+            .position(|byte| *byte != 0)
+            .unwrap_or_else(|| bytes.len().saturating_sub(1));
+        let imm = if bytes.is_empty() {
+            &[0]
+        } else {
+            &bytes[start..]
+        };
+        self.ops.push(
+            AbstractOp::with_immediate(Op::push(imm.len() as u32).unwrap(), imm)
+                .expect("immediate is at most 32 bytes"),
+        );
+    }
+
+    pub(super) fn push_abi(&mut self, operation: ethabi::operation::Operation) {
+        self.abi.push(operation);
+    }
+
+    /// Push the address of a label, relative to the start of the executed code.
+    pub(super) fn push_label(&mut self, label: &str) {
+        self.ops.push(AbstractOp::with_expression(
+            Op::Push2(()),
+            Expression::Minus(
+                Box::new(Expression::Terminal(Terminal::Label(label.to_owned()))),
+                Box::new(Expression::Terminal(Terminal::Label(
+                    CODE_START_LABEL.to_owned(),
+                ))),
+            ),
+        ));
+    }
+
+    /// Mark a jump destination.
+    pub(super) fn label(&mut self, label: &str) {
+        self.ops.push(AbstractOp::Label(label.to_owned()));
+        self.op(Op::JumpDest);
+    }
+
+    pub(super) fn jump(&mut self, label: &str) {
+        self.push_label(label);
+        self.op(Op::Jump);
+    }
+
+    /// Jump if the value on the top of the stack is non-zero.
+    pub(super) fn jump_if(&mut self, label: &str) {
+        self.push_label(label);
+        self.op(Op::JumpI);
+    }
+
+    /// Replace the address on the top of the stack with the word it points to.
+    pub(super) fn load_word(&mut self) {
+        self.op(Op::MLoad);
+        self.push_number(WORD_SHIFT as u64);
+        self.op(Op::Shr);
+    }
+
+    /// Store the word below the top of the stack at the address on the top, leaving the rest of
+    /// the 32 bytes in memory untouched.
+    pub(super) fn store_word(&mut self) {
+        self.op(Op::Dup1);
+        self.op(Op::MLoad);
+        self.push_bytes(&[0xff; 24]);
+        self.op(Op::And);
+        self.op(Op::Dup3);
+        self.push_number(WORD_SHIFT as u64);
+        self.op(Op::Shl);
+        self.op(Op::Or);
+        self.op(Op::Swap1);
+        self.op(Op::MStore);
+        self.op(Op::Pop);
+    }
+
+    /// Write some bytes to memory at a constant address.
+    pub(super) fn store_bytes(&mut self, addr: u64, bytes: &[u8]) {
+        for (idx, chunk) in bytes.chunks(32).enumerate() {
+            let chunk_addr = addr + idx as u64 * 32;
+            let mut padded = chunk.to_vec();
+            padded.resize(32, 0);
+            if chunk.len() < 32 {
+                // Keep whatever follows the final partial chunk.
+                self.push_number(chunk_addr);
+                self.op(Op::MLoad);
+                self.push_bytes(&low_bytes_mask(32 - chunk.len()));
+                self.op(Op::And);
+                self.push_bytes(&padded);
+                self.op(Op::Or);
+            } else {
+                self.push_bytes(&padded);
+            }
+            self.push_number(chunk_addr);
+            self.op(Op::MStore);
         }
-        asm.take().len() as u32
     }
+
+    /// Revert with an empty message.
+    pub(super) fn revert_empty(&mut self) {
+        self.push_number(0);
+        self.op(Op::Dup1);
+        self.op(Op::Revert);
+    }
+}
+
+/// Assemble the ops into bytecode.
+pub(crate) fn assemble(ops: Vec<AbstractOp>) -> Result<Vec<u8>, CompileError> {
+    let mut assembler = Assembler::new();
+    match assembler.push_all(ops) {
+        Ok(_) => Ok(assembler.take()),
+        // Only jump destinations are pushed as expressions, with two bytes.
+        Err(etk_asm::asm::Error::ExpressionTooLarge { .. }) => Err(CompileError::EvmUnsupported {
+            feature: "programs with more than 64 KiB of code".to_owned(),
+            span: Span::dummy(),
+        }),
+        Err(e) => Err(CompileError::InternalOwned(e.to_string(), Span::dummy())),
+    }
+}
+
+// A mask for the least significant `n` bytes of a 256 bit word.
+fn low_bytes_mask(n: usize) -> Vec<u8> {
+    let mut mask = vec![0; 32 - n];
+    mask.resize(32, 0xff);
+    mask
+}
+
+// A mask for the most significant `n` bytes of a 256 bit word.
+pub(super) fn high_bytes_mask(n: usize) -> Vec<u8> {
+    let mut mask = vec![0xff; n];
+    mask.resize(32, 0);
+    mask
+}
+
+pub(super) fn label_name(label: &Label) -> String {
+    format!("label{}", label.0)
 }
 
 pub struct EvmAsmBuilderResult {
@@ -109,73 +314,93 @@ impl<'ir> AsmBuilder for EvmAsmBuilder<'ir> {
     }
 }
 
-#[allow(unused_variables)]
-#[allow(dead_code)]
 impl<'ir> EvmAsmBuilder<'ir> {
     pub fn new(
         program_kind: ProgramKind,
-        reg_seqr: RegisterSequencer,
+        storage_slots: &[StorageSlot],
+        mut reg_seqr: RegisterSequencer,
         context: &'ir Context,
     ) -> Self {
-        let mut b = EvmAsmBuilder {
+        let panic_label = reg_seqr.get_label();
+        EvmAsmBuilder {
             program_kind,
+            storage_slots: storage_slots.to_vec(),
             sections: Vec::new(),
+            cur_section: EvmAsmSection::new(),
+            entries: Vec::new(),
+            main_label: None,
             reg_seqr,
             func_label_map: HashMap::new(),
             block_label_map: HashMap::new(),
+            value_map: HashMap::new(),
+            local_map: HashMap::new(),
+            mem_top: STATIC_MEMORY_START,
+            panic_label,
             context,
             md_mgr: MetadataManager::default(),
-        };
-        let s = b.generate_function();
-        b.sections.push(s);
-        b
+        }
     }
 
     pub fn finalize(&self) -> AsmBuilderResult {
-        let mut global_ops = Vec::new();
-        let mut global_abi = Vec::new();
-
-        let mut size = 0;
-        let mut it = self.sections.iter().peekable();
-        while let Some(section) = it.next() {
-            size += section.size();
-            global_ops.append(&mut section.ops.clone());
-            global_abi.append(&mut section.abi.clone());
-
-            if it.peek().is_some() {
-                size += AbstractOp::Op(Op::Invalid).size().unwrap();
-                global_ops.push(AbstractOp::Op(Op::Invalid));
+        let mut abi = Vec::new();
+
+        // Libraries have no code of their own.
+        if self.program_kind == ProgramKind::Library {
+            return AsmBuilderResult::Evm(EvmAsmBuilderResult {
+                ops: Vec::new(),
+                ops_runtime: Vec::new(),
+                abi,
+            });
+        }
+
+        let mut runtime = EvmAsmSection::new();
+        runtime
+            .ops
+            .push(AbstractOp::Label(CODE_START_LABEL.to_owned()));
+        self.setup_free_memory_pointer(&mut runtime);
+        match self.program_kind {
+            ProgramKind::Contract => self.generate_dispatcher(&mut runtime),
+            _ => {
+                if let Some(main_label) = &self.main_label {
+                    runtime.jump(&label_name(main_label));
+                }
             }
         }
 
-        // First generate a dummy ctor section to calculate its size.
-        let dummy = self.generate_constructor(false, size, 0);
+        for section in &self.sections {
+            runtime.ops.append(&mut section.ops.clone());
+            abi.append(&mut section.abi.clone());
+        }
 
-        // Generate the actual ctor section with the correct size..
-        let mut ctor = self.generate_constructor(false, size, dummy.size());
-        ctor.ops.append(&mut global_ops);
-        global_abi.append(&mut ctor.abi);
+        runtime.label(&label_name(&self.panic_label));
+        runtime.revert_empty();
+        runtime
+            .ops
+            .push(AbstractOp::Label(CODE_END_LABEL.to_owned()));
+
+        let ops = if self.program_kind == ProgramKind::Contract {
+            let mut ctor = self.generate_constructor(false);
+            ctor.ops.append(&mut runtime.ops.clone());
+            abi.append(&mut ctor.abi);
+            ctor.ops
+        } else {
+            runtime.ops.clone()
+        };
 
         AsmBuilderResult::Evm(EvmAsmBuilderResult {
-            ops: ctor.ops.clone(),
-            ops_runtime: ctor.ops,
-            abi: global_abi,
+            ops,
+            ops_runtime: runtime.ops,
+            abi,
         })
     }
 
-    fn generate_constructor(
-        &self,
-        is_payable: bool,
-        data_size: u32,
-        data_offset: u32,
-    ) -> EvmAsmSection {
+    fn generate_constructor(&self, is_payable: bool) -> EvmAsmSection {
         // For more details and explanations see:
         // https://medium.com/@hayeah/diving-into-the-ethereum-vm-part-5-the-smart-contract-creation-process-cb7b6133b855.
 
         let mut s = EvmAsmSection::new();
-        self.setup_free_memory_pointer(&mut s);
 
-        if is_payable {
+        if !is_payable {
             // Get the the amount of ETH transferred to the contract by the parent contract,
             // or by a transaction and check for a non-payable contract. Revert if caller
             // sent ether.
@@ -208,32 +433,53 @@ impl<'ir> EvmAsmBuilder<'ir> {
             s.ops.push(AbstractOp::Op(Op::Pop));
         }
 
-        self.copy_contract_code_to_memory(&mut s, data_size, data_offset);
+        self.initialize_storage(&mut s);
+        self.copy_contract_code_to_memory(&mut s);
+
+        s.abi.push(ethabi::operation::Operation::Constructor(
+            ethabi::Constructor { inputs: vec![] },
+        ));
 
         s
     }
 
-    fn copy_contract_code_to_memory(
-        &self,
-        s: &mut EvmAsmSection,
-        data_size: u32,
-        data_offset: u32,
-    ) {
+    fn initialize_storage(&self, s: &mut EvmAsmSection) {
+        // Write the initial value of each storage slot.
+        //   push32 value
+        //   push32 key
+        //   sstore
+        for slot in &self.storage_slots {
+            s.push_bytes(slot.value().as_ref());
+            s.push_bytes(slot.key().as_ref());
+            s.op(Op::SStore);
+        }
+    }
+
+    fn copy_contract_code_to_memory(&self, s: &mut EvmAsmSection) {
         // Copy contract code into memory, and return.
-        //   push1 dataSize
+        //   push2 dataSize
         //   dup1
-        //   push1 dataOffset
+        //   push2 dataOffset
         //   push1 0x00
         //   codecopy
         //   push1 0x00
         //   return
-        s.ops.push(AbstractOp::Push(Imm::from(Terminal::Number(
-            data_size.into(),
-        ))));
+        s.ops.push(AbstractOp::with_expression(
+            Op::Push2(()),
+            Expression::Minus(
+                Box::new(Expression::Terminal(Terminal::Label(
+                    CODE_END_LABEL.to_owned(),
+                ))),
+                Box::new(Expression::Terminal(Terminal::Label(
+                    CODE_START_LABEL.to_owned(),
+                ))),
+            ),
+        ));
         s.ops.push(AbstractOp::new(Op::Dup1).unwrap());
-        s.ops.push(AbstractOp::Push(Imm::from(Terminal::Number(
-            data_offset.into(),
-        ))));
+        s.ops.push(AbstractOp::Op(Op::with_label(
+            Op::Push2(()),
+            CODE_START_LABEL,
+        )));
         s.ops
             .push(AbstractOp::with_immediate(Op::Push1(()), &[0x00]).unwrap());
         s.ops.push(AbstractOp::Op(Op::CodeCopy));
@@ -242,30 +488,33 @@ impl<'ir> EvmAsmBuilder<'ir> {
         s.ops.push(AbstractOp::Op(Op::Return));
     }
 
-    fn generate_function(&mut self) -> EvmAsmSection {
-        let mut s = EvmAsmSection::new();
-
-        // push1 0x80 # selector("conduct_auto(uint256,uint256,uint256)")
-        // push1 0x40
-        // mstore
-        // push1 0x00
-        // dup1
-        // revert
-        s.ops
-            .push(AbstractOp::with_immediate(Op::Push1(()), &[0x80]).unwrap());
-        s.ops
-            .push(AbstractOp::with_immediate(Op::Push1(()), &[0x40]).unwrap());
-        s.ops.push(AbstractOp::new(Op::MStore).unwrap());
-        s.ops
-            .push(AbstractOp::with_immediate(Op::Push1(()), &[0x00]).unwrap());
-        s.ops.push(AbstractOp::new(Op::Dup1).unwrap());
-        s.ops.push(AbstractOp::new(Op::Revert).unwrap());
-
-        s.abi.push(ethabi::operation::Operation::Constructor(
-            ethabi::Constructor { inputs: vec![] },
-        ));
+    fn generate_dispatcher(&self, s: &mut EvmAsmSection) {
+        // None of the ABI methods are payable.
+        s.op(Op::CallValue);
+        s.jump_if(&label_name(&self.panic_label));
 
-        s
+        // Put the selector from the first 4 bytes of the call data into the scratch space and
+        // jump to the method with a matching selector, or revert if there isn't one.
+        //   push1 0x00
+        //   calldataload
+        //   push1 0xe0
+        //   shr
+        //   push1 0x00
+        //   mstore
+        s.push_number(0);
+        s.op(Op::CallDataLoad);
+        s.push_number(224);
+        s.op(Op::Shr);
+        s.push_number(SCRATCH_SPACE);
+        s.op(Op::MStore);
+        for (selector, label) in &self.entries {
+            s.push_bytes(selector);
+            s.push_number(SCRATCH_SPACE);
+            s.op(Op::MLoad);
+            s.op(Op::Eq);
+            s.jump_if(&label_name(label));
+        }
+        s.jump(&label_name(&self.panic_label));
     }
 
     fn setup_free_memory_pointer(&self, s: &mut EvmAsmSection) {
@@ -273,26 +522,158 @@ impl<'ir> EvmAsmBuilder<'ir> {
         //
         // The "free memory pointer" is stored at position 0x40 in memory.
         // The first 64 bytes of memory can be used as "scratch space" for short-term allocation.
-        // The 32 bytes after the free memory pointer (i.e., starting at 0x60) are meant to be
-        // zero permanently and is used as the initial value for empty dynamic memory arrays.
-        // This means that the allocatable memory starts at 0x80, which is the initial value
-        // of the free memory pointer.
+        // The 32 bytes after the free memory pointer (i.e., starting at 0x60) are used for the
+        // heap pointer.  All the statically allocated memory comes next, so the allocatable
+        // memory starts after it.
         //
-        //   push1 0x80
+        //   push2 heapStart
         //   push1 0x40
         //   mstore
-        s.ops
-            .push(AbstractOp::with_immediate(Op::Push1(()), &[0x80]).unwrap());
-        s.ops
-            .push(AbstractOp::with_immediate(Op::Push1(()), &[0x40]).unwrap());
-        s.ops.push(AbstractOp::new(Op::MStore).unwrap());
+        //   push2 heapStart-1
+        //   push1 0x60
+        //   mstore
+        s.push_number(self.mem_top);
+        s.push_number(FREE_MEMORY_POINTER);
+        s.op(Op::MStore);
+        s.push_number(self.mem_top - 1);
+        s.push_number(HEAP_POINTER);
+        s.op(Op::MStore);
     }
 
-    fn empty_span() -> Span {
+    pub(super) fn empty_span() -> Span {
         let msg = "unknown source location";
         Span::new(Arc::from(msg), 0, msg.len(), None).unwrap()
     }
 
+    pub(super) fn val_span(&mut self, value: &Value) -> Span {
+        self.md_mgr
+            .val_to_span(self.context, *value)
+            .unwrap_or_else(Self::empty_span)
+    }
+
+    pub(super) fn is_copy_type(&self, ty: &Type) -> bool {
+        ty.is_unit(self.context) || ty.is_bool(self.context) || ty.is_uint(self.context)
+    }
+
+    /// Allocate some memory which is never freed, rounded up to whole EVM words.
+    pub(super) fn alloc_static(&mut self, size_in_bytes: u64) -> u64 {
+        let addr = self.mem_top;
+        self.mem_top += size_in_bytes.div_ceil(32).max(1) * 32;
+        addr
+    }
+
+    /// Get the address of the slot for a value, allocating it if needed.
+    pub(super) fn value_slot(&mut self, value: &Value) -> u64 {
+        match self.value_map.get(value) {
+            Some(ValueLocation::Slot(addr)) => *addr,
+            Some(ValueLocation::Address(_)) => {
+                unreachable!("Value with a constant address does not have a slot.")
+            }
+            None => {
+                let addr = self.alloc_static(32);
+                self.value_map.insert(*value, ValueLocation::Slot(addr));
+                addr
+            }
+        }
+    }
+
+    /// Save the value on the top of the stack as the value for `instr_val`.
+    pub(super) fn save_value(&mut self, instr_val: &Value) {
+        let addr = self.value_slot(instr_val);
+        self.cur_section.push_number(addr);
+        self.cur_section.op(Op::MStore);
+    }
+
+    pub(super) fn push_value(&mut self, value: &Value) {
+        if let Some(constant) = value
+            .get_constant(self.context)
+            .or_else(|| value.get_configurable(self.context))
+        {
+            self.push_constant(constant);
+            return;
+        }
+
+        match self.value_map.get(value).copied() {
+            Some(ValueLocation::Address(addr)) => self.cur_section.push_number(addr),
+            _ => {
+                let addr = self.value_slot(value);
+                self.cur_section.push_number(addr);
+                self.cur_section.op(Op::MLoad);
+            }
+        }
+    }
+
+    fn push_constant(&mut self, constant: &Constant) {
+        match &constant.value {
+            ConstantValue::Undef | ConstantValue::Unit => self.cur_section.push_number(0),
+            ConstantValue::Bool(b) => self.cur_section.push_number(u64::from(*b)),
+            ConstantValue::Uint(u) => self.cur_section.push_number(*u),
+            _ => {
+                // Aggregates are written to their own memory at every use, as they may be
+                // modified.
+                let bytes = Entry::from_constant(self.context, constant, None).to_bytes();
+                let addr = self.alloc_static(bytes.len() as u64);
+                self.cur_section.store_bytes(addr, &bytes);
+                self.cur_section.push_number(addr);
+            }
+        }
+    }
+
+    pub(super) fn push_operand(&mut self, operand: Operand) {
+        match operand {
+            Operand::Value(value) => self.push_value(&value),
+            Operand::Memory(addr) => {
+                self.cur_section.push_number(addr);
+                self.cur_section.op(Op::MLoad);
+            }
+            Operand::Number(value) => self.cur_section.push_number(value),
+        }
+    }
+
+    /// Push a value which is an address, offset by a number of bytes.
+    fn push_value_with_offset(&mut self, value: &Value, offset_in_bytes: u64) {
+        self.push_value(value);
+        if offset_in_bytes > 0 {
+            self.cur_section.push_number(offset_in_bytes);
+            self.cur_section.op(Op::Add);
+        }
+    }
+
+    /// Copy `len` bytes from `src` to `dst`, using the identity precompile.
+    pub(super) fn copy_memory(&mut self, dst: Operand, src: Operand, len: Operand) {
+        //   push len
+        //   push dst
+        //   push len
+        //   push src
+        //   push1 0x04
+        //   gas
+        //   staticcall
+        //   pop
+        self.push_operand(len);
+        self.push_operand(dst);
+        self.push_operand(len);
+        self.push_operand(src);
+        self.cur_section.push_number(IDENTITY_PRECOMPILE as u64);
+        self.cur_section.op(Op::Gas);
+        self.cur_section.op(Op::StaticCall);
+        self.cur_section.op(Op::Pop);
+    }
+
+    /// Panic if the value on the top of the stack doesn't fit in a word, leaving it on the stack.
+    fn check_word_overflow(&mut self) {
+        self.cur_section.op(Op::Dup1);
+        self.cur_section.push_number(u64::MAX);
+        self.cur_section.op(Op::Lt);
+        self.cur_section.jump_if(&label_name(&self.panic_label));
+    }
+
+    /// Panic if the operand is zero, before using it as a divisor.
+    fn check_divisor(&mut self, divisor: Operand) {
+        self.push_operand(divisor);
+        self.cur_section.op(Op::IsZero);
+        self.cur_section.jump_if(&label_name(&self.panic_label));
+    }
+
     pub(super) fn compile_instruction(
         &mut self,
         instr_val: &Value,
@@ -339,7 +720,12 @@ impl<'ir> EvmAsmBuilder<'ir> {
                     asset_id,
                     gas,
                     ..
-                } => self.compile_contract_call(instr_val, params, coins, asset_id, gas),
+                } => check!(
+                    self.compile_contract_call(instr_val, params, coins, asset_id, gas),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                ),
                 Instruction::ExtractElement {
                     array,
                     ty,
@@ -348,14 +734,97 @@ impl<'ir> EvmAsmBuilder<'ir> {
                 Instruction::ExtractValue {
                     aggregate, indices, ..
                 } => self.compile_extract_value(instr_val, aggregate, indices),
-                Instruction::FuelVm(fuel_vm_instr) => {
-                    errors.push(CompileError::Internal(
-                        "Value not an instruction.",
-                        self.md_mgr
-                            .val_to_span(self.context, *instr_val)
-                            .unwrap_or_else(Self::empty_span),
-                    ));
-                }
+                Instruction::FuelVm(fuel_vm_instr) => match fuel_vm_instr {
+                    FuelVmInstruction::GetStorageKey => {
+                        check!(
+                            self.compile_get_storage_key(instr_val),
+                            return err(warnings, errors),
+                            warnings,
+                            errors
+                        )
+                    }
+                    FuelVmInstruction::Gtf { index, tx_field_id } => check!(
+                        self.compile_gtf(instr_val, index, *tx_field_id),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    ),
+                    FuelVmInstruction::Log {
+                        log_val,
+                        log_ty,
+                        log_id,
+                    } => self.compile_log(instr_val, log_val, log_ty, log_id),
+                    FuelVmInstruction::ReadRegister(reg) => check!(
+                        self.compile_read_register(instr_val, reg),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    ),
+                    FuelVmInstruction::Revert(revert_val) => {
+                        self.compile_revert(instr_val, revert_val)
+                    }
+                    FuelVmInstruction::Smo {
+                        recipient_and_message,
+                        message_size,
+                        output_index,
+                        coins,
+                    } => check!(
+                        self.compile_smo(
+                            instr_val,
+                            recipient_and_message,
+                            message_size,
+                            output_index,
+                            coins,
+                        ),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    ),
+                    FuelVmInstruction::StateLoadQuadWord {
+                        load_val,
+                        key,
+                        number_of_slots,
+                    } => check!(
+                        self.compile_state_access_quad_word(
+                            instr_val,
+                            load_val,
+                            key,
+                            number_of_slots,
+                            StateAccessType::Read,
+                        ),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    ),
+                    FuelVmInstruction::StateLoadWord(key) => check!(
+                        self.compile_state_load_word(instr_val, key),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    ),
+                    FuelVmInstruction::StateStoreQuadWord {
+                        stored_val,
+                        key,
+                        number_of_slots,
+                    } => check!(
+                        self.compile_state_access_quad_word(
+                            instr_val,
+                            stored_val,
+                            key,
+                            number_of_slots,
+                            StateAccessType::Write,
+                        ),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    ),
+                    FuelVmInstruction::StateStoreWord { stored_val, key } => check!(
+                        self.compile_state_store_word(instr_val, stored_val, key),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    ),
+                },
                 Instruction::GetLocal(local_var) => self.compile_get_local(instr_val, local_var),
                 Instruction::InsertElement {
                     array,
@@ -402,9 +871,7 @@ impl<'ir> EvmAsmBuilder<'ir> {
         } else {
             errors.push(CompileError::Internal(
                 "Value not an instruction.",
-                self.md_mgr
-                    .val_to_span(self.context, *instr_val)
-                    .unwrap_or_else(Self::empty_span),
+                self.val_span(instr_val),
             ));
         }
         ok((), warnings, errors)
@@ -416,15 +883,435 @@ impl<'ir> EvmAsmBuilder<'ir> {
         asm: &AsmBlock,
         asm_args: &[AsmArg],
     ) -> CompileResult<()> {
-        todo!();
+        let mut warnings = Vec::new();
+        let mut errors = Vec::new();
+
+        // Each register gets its own slot in memory.
+        let mut inline_reg_map = HashMap::new();
+        for AsmArg { name, initializer } in asm_args {
+            let reg_addr = self.alloc_static(32);
+            if let Some(init_val) = initializer {
+                self.push_value(init_val);
+                self.cur_section.push_number(reg_addr);
+                self.cur_section.op(Op::MStore);
+            }
+            inline_reg_map.insert(name.as_str().to_owned(), reg_addr);
+        }
+
+        let asm_block = asm.get_content(self.context);
+        for op in &asm_block.body {
+            let op_span = self
+                .md_mgr
+                .md_to_span(self.context, op.metadata)
+                .unwrap_or_else(Self::empty_span);
+            check!(
+                self.compile_asm_op(op, &inline_reg_map, op_span),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
+        }
+
+        // Now save the designated asm return register as the value of the block, but only if it
+        // was named.
+        if let Some(ret_reg_name) = &asm_block.return_name {
+            let ret_reg = check!(
+                Self::asm_operand(&inline_reg_map, ret_reg_name),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
+            self.push_operand(ret_reg);
+            self.save_value(instr_val);
+        }
+
+        ok((), warnings, errors)
+    }
+
+    // Registers declared by the ASM block are read from their slots, and only a few of the
+    // constant registers make sense on the EVM.
+    fn asm_operand(inline_reg_map: &HashMap<String, u64>, name: &Ident) -> CompileResult<Operand> {
+        if let Some(reg_addr) = inline_reg_map.get(name.as_str()) {
+            return ok(Operand::Memory(*reg_addr), vec![], vec![]);
+        }
+        match ConstantRegister::parse_register_name(name.as_str()) {
+            Some(ConstantRegister::Zero) => ok(Operand::Number(0), vec![], vec![]),
+            Some(ConstantRegister::One) => ok(Operand::Number(1), vec![], vec![]),
+            Some(ConstantRegister::HeapPointer) => {
+                ok(Operand::Memory(HEAP_POINTER), vec![], vec![])
+            }
+            Some(_) => err(
+                vec![],
+                vec![CompileError::EvmUnsupported {
+                    feature: format!("the `{name}` register"),
+                    span: name.span(),
+                }],
+            ),
+            None => err(
+                vec![],
+                vec![CompileError::UnknownRegister {
+                    span: name.span(),
+                    initialized_registers: inline_reg_map
+                        .keys()
+                        .cloned()
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                }],
+            ),
+        }
+    }
+
+    fn asm_dest_register(
+        inline_reg_map: &HashMap<String, u64>,
+        name: &Ident,
+    ) -> CompileResult<u64> {
+        match inline_reg_map.get(name.as_str()) {
+            Some(reg_addr) => ok(*reg_addr, vec![], vec![]),
+            None if ConstantRegister::parse_register_name(name.as_str()).is_some() => err(
+                vec![],
+                vec![CompileError::EvmUnsupported {
+                    feature: format!("writing to the `{name}` register"),
+                    span: name.span(),
+                }],
+            ),
+            None => err(
+                vec![],
+                vec![CompileError::UnknownRegister {
+                    span: name.span(),
+                    initialized_registers: inline_reg_map
+                        .keys()
+                        .cloned()
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                }],
+            ),
+        }
+    }
+
+    fn asm_immediate(op: &AsmInstruction, op_span: &Span) -> CompileResult<u64> {
+        match &op.immediate {
+            None => err(
+                vec![],
+                vec![CompileError::MissingImmediate {
+                    span: op_span.clone(),
+                }],
+            ),
+            Some(imm) => match imm.as_str()[1..].parse::<u64>() {
+                Ok(value) => ok(value, vec![], vec![]),
+                Err(_) => err(
+                    vec![],
+                    vec![CompileError::InvalidImmediateValue { span: imm.span() }],
+                ),
+            },
+        }
+    }
+
+    fn compile_asm_op(
+        &mut self,
+        op: &AsmInstruction,
+        inline_reg_map: &HashMap<String, u64>,
+        op_span: Span,
+    ) -> CompileResult<()> {
+        let mut warnings = Vec::new();
+        let mut errors = Vec::new();
+
+        let op_name = op.name.as_str();
+
+        // The number of registers and whether an immediate is expected.
+        let (num_regs, has_imm) = match op_name {
+            "add" | "sub" | "mul" | "div" | "mod" | "exp" | "and" | "or" | "xor" | "sll"
+            | "srl" | "eq" | "lt" | "gt" | "k256" | "s256" | "mcp" | "srw" | "sww" => (3, false),
+            "addi" | "subi" | "muli" | "divi" | "modi" | "expi" | "andi" | "ori" | "xori"
+            | "slli" | "srli" | "lw" | "sw" | "lb" | "sb" | "mcpi" => (2, true),
+            "meq" | "srwq" | "swwq" | "log" | "logd" => (4, false),
+            "not" | "move" => (2, false),
+            "movi" => (1, true),
+            "aloc" | "rvrt" | "bhei" => (1, false),
+            "noop" => (0, false),
+            _ => {
+                errors.push(CompileError::EvmUnsupported {
+                    feature: format!("the `{op_name}` opcode"),
+                    span: op.name.span(),
+                });
+                return err(warnings, errors);
+            }
+        };
+
+        if op.args.len() != num_regs {
+            errors.push(CompileError::IncorrectNumberOfAsmRegisters {
+                span: op_span,
+                expected: num_regs,
+                received: op.args.len(),
+            });
+            return err(warnings, errors);
+        }
+
+        let imm = if has_imm {
+            check!(
+                Self::asm_immediate(op, &op_span),
+                return err(warnings, errors),
+                warnings,
+                errors
+            )
+        } else {
+            0
+        };
+
+        // Resolve the source operands; the first register is the destination for most ops.
+        let mut regs = Vec::new();
+        for arg in &op.args {
+            regs.push(check!(
+                Self::asm_operand(inline_reg_map, arg),
+                return err(warnings, errors),
+                warnings,
+                errors
+            ));
+        }
+        let dest = |idx: usize| Self::asm_dest_register(inline_reg_map, &op.args[idx]);
+
+        match op_name {
+            "add" | "sub" | "mul" | "div" | "mod" | "exp" | "and" | "or" | "xor" | "sll"
+            | "srl" | "eq" | "lt" | "gt" | "addi" | "subi" | "muli" | "divi" | "modi" | "expi"
+            | "andi" | "ori" | "xori" | "slli" | "srli" => {
+                let rhs = if has_imm {
+                    Operand::Number(imm)
+                } else {
+                    regs[2]
+                };
+                let base_name = if has_imm {
+                    &op_name[..op_name.len() - 1]
+                } else {
+                    op_name
+                };
+                let dest = check!(dest(0), return err(warnings, errors), warnings, errors);
+
+                if base_name == "div" || base_name == "mod" {
+                    self.check_divisor(rhs);
+                }
+                self.push_operand(rhs);
+                self.push_operand(regs[1]);
+                match base_name {
+                    "add" => self.cur_section.op(Op::Add),
+                    "sub" => self.cur_section.op(Op::Sub),
+                    "mul" => self.cur_section.op(Op::Mul),
+                    "div" => self.cur_section.op(Op::Div),
+                    "mod" => self.cur_section.op(Op::Mod),
+                    "exp" => self.cur_section.op(Op::Exp),
+                    "and" => self.cur_section.op(Op::And),
+                    "or" => self.cur_section.op(Op::Or),
+                    "xor" => self.cur_section.op(Op::Xor),
+                    "eq" => self.cur_section.op(Op::Eq),
+                    "lt" => self.cur_section.op(Op::Lt),
+                    "gt" => self.cur_section.op(Op::Gt),
+                    // The shift amount is expected on the top of the stack.
+                    "sll" => {
+                        self.cur_section.op(Op::Swap1);
+                        self.cur_section.op(Op::Shl);
+                        self.cur_section.push_number(u64::MAX);
+                        self.cur_section.op(Op::And);
+                    }
+                    "srl" => {
+                        self.cur_section.op(Op::Swap1);
+                        self.cur_section.op(Op::Shr);
+                    }
+                    _ => unreachable!("Unexpected ASM arithmetic op."),
+                }
+                if matches!(base_name, "add" | "sub" | "mul" | "exp") {
+                    self.check_word_overflow();
+                }
+                self.cur_section.push_number(dest);
+                self.cur_section.op(Op::MStore);
+            }
+            "not" => {
+                let dest = check!(dest(0), return err(warnings, errors), warnings, errors);
+                self.push_operand(regs[1]);
+                self.cur_section.op(Op::Not);
+                self.cur_section.push_number(u64::MAX);
+                self.cur_section.op(Op::And);
+                self.cur_section.push_number(dest);
+                self.cur_section.op(Op::MStore);
+            }
+            "move" | "movi" => {
+                let dest = check!(dest(0), return err(warnings, errors), warnings, errors);
+                self.push_operand(if op_name == "move" {
+                    regs[1]
+                } else {
+                    Operand::Number(imm)
+                });
+                self.cur_section.push_number(dest);
+                self.cur_section.op(Op::MStore);
+            }
+            "lw" | "lb" => {
+                let dest = check!(dest(0), return err(warnings, errors), warnings, errors);
+                self.push_operand(regs[1]);
+                let offset = if op_name == "lw" { imm * 8 } else { imm };
+                self.cur_section.push_number(offset);
+                self.cur_section.op(Op::Add);
+                if op_name == "lw" {
+                    self.cur_section.load_word();
+                } else {
+                    self.cur_section.op(Op::MLoad);
+                    self.cur_section.push_number(248);
+                    self.cur_section.op(Op::Shr);
+                }
+                self.cur_section.push_number(dest);
+                self.cur_section.op(Op::MStore);
+            }
+            "sw" | "sb" => {
+                self.push_operand(regs[1]);
+                self.push_operand(regs[0]);
+                let offset = if op_name == "sw" { imm * 8 } else { imm };
+                self.cur_section.push_number(offset);
+                self.cur_section.op(Op::Add);
+                if op_name == "sw" {
+                    self.cur_section.store_word();
+                } else {
+                    self.cur_section.op(Op::MStore8);
+                }
+            }
+            "mcp" => self.copy_memory(regs[0], regs[1], regs[2]),
+            "mcpi" => self.copy_memory(regs[0], regs[1], Operand::Number(imm)),
+            "meq" => {
+                // Compare the hashes of both regions.
+                let dest = check!(dest(0), return err(warnings, errors), warnings, errors);
+                self.push_operand(regs[3]);
+                self.push_operand(regs[1]);
+                self.cur_section.op(Op::Keccak256);
+                self.push_operand(regs[3]);
+                self.push_operand(regs[2]);
+                self.cur_section.op(Op::Keccak256);
+                self.cur_section.op(Op::Eq);
+                self.cur_section.push_number(dest);
+                self.cur_section.op(Op::MStore);
+            }
+            "k256" => {
+                self.push_operand(regs[2]);
+                self.push_operand(regs[1]);
+                self.cur_section.op(Op::Keccak256);
+                self.push_operand(regs[0]);
+                self.cur_section.op(Op::MStore);
+            }
+            "s256" => {
+                //   push 32
+                //   push dst
+                //   push len
+                //   push src
+                //   push1 0x02
+                //   gas
+                //   staticcall
+                //   pop
+                self.cur_section.push_number(32);
+                self.push_operand(regs[0]);
+                self.push_operand(regs[2]);
+                self.push_operand(regs[1]);
+                self.cur_section.push_number(SHA256_PRECOMPILE as u64);
+                self.cur_section.op(Op::Gas);
+                self.cur_section.op(Op::StaticCall);
+                self.cur_section.op(Op::Pop);
+            }
+            "aloc" => {
+                // Bump the free memory pointer and point the heap pointer just before the new
+                // allocation.
+                self.cur_section.push_number(FREE_MEMORY_POINTER);
+                self.cur_section.op(Op::MLoad);
+                self.cur_section.push_number(1);
+                self.cur_section.op(Op::Dup2);
+                self.cur_section.op(Op::Sub);
+                self.cur_section.push_number(HEAP_POINTER);
+                self.cur_section.op(Op::MStore);
+                self.push_operand(regs[0]);
+                self.cur_section.op(Op::Add);
+                self.cur_section.push_number(FREE_MEMORY_POINTER);
+                self.cur_section.op(Op::MStore);
+            }
+            "srw" | "sww" | "srwq" | "swwq" => {
+                // There is no way to tell an unset slot from a slot set to zero on the EVM, so
+                // every slot is reported as being set.
+                let set_reg = check!(dest(1), return err(warnings, errors), warnings, errors);
+                match op_name {
+                    "srw" => {
+                        let dest = check!(dest(0), return err(warnings, errors), warnings, errors);
+                        self.push_operand(regs[2]);
+                        self.cur_section.op(Op::MLoad);
+                        self.cur_section.op(Op::SLoad);
+                        self.cur_section.push_number(WORD_SHIFT as u64);
+                        self.cur_section.op(Op::Shr);
+                        self.cur_section.push_number(dest);
+                        self.cur_section.op(Op::MStore);
+                    }
+                    "sww" => {
+                        self.push_operand(regs[2]);
+                        self.cur_section.push_number(WORD_SHIFT as u64);
+                        self.cur_section.op(Op::Shl);
+                        self.push_operand(regs[0]);
+                        self.cur_section.op(Op::MLoad);
+                        self.cur_section.op(Op::SStore);
+                    }
+                    "srwq" => {
+                        self.state_access_loop(regs[0], regs[2], regs[3], StateAccessType::Read)
+                    }
+                    _ => self.state_access_loop(regs[2], regs[0], regs[3], StateAccessType::Write),
+                }
+                self.cur_section.push_number(1);
+                self.cur_section.push_number(set_reg);
+                self.cur_section.op(Op::MStore);
+            }
+            "log" => {
+                //   push ra
+                //   push1 0x00
+                //   mstore
+                //   push rd
+                //   push rc
+                //   push rb
+                //   push1 0x20
+                //   push1 0x00
+                //   log3
+                self.push_operand(regs[0]);
+                self.cur_section.push_number(SCRATCH_SPACE);
+                self.cur_section.op(Op::MStore);
+                self.push_operand(regs[3]);
+                self.push_operand(regs[2]);
+                self.push_operand(regs[1]);
+                self.cur_section.push_number(32);
+                self.cur_section.push_number(SCRATCH_SPACE);
+                self.cur_section.op(Op::Log3);
+            }
+            "logd" => {
+                //   push rb
+                //   push rd
+                //   push rc
+                //   log1
+                self.push_operand(regs[1]);
+                self.push_operand(regs[3]);
+                self.push_operand(regs[2]);
+                self.cur_section.op(Op::Log1);
+            }
+            "rvrt" => self.revert_with(regs[0]),
+            "bhei" => {
+                let dest = check!(dest(0), return err(warnings, errors), warnings, errors);
+                self.cur_section.op(Op::Number);
+                self.cur_section.push_number(dest);
+                self.cur_section.op(Op::MStore);
+            }
+            "noop" => (),
+            _ => unreachable!("Unexpected ASM op."),
+        }
+
+        ok((), warnings, errors)
     }
 
     fn compile_addr_of(&mut self, instr_val: &Value, arg: &Value) {
-        todo!();
+        self.push_value(arg);
+        self.save_value(instr_val);
     }
 
     fn compile_bitcast(&mut self, instr_val: &Value, bitcast_val: &Value, to_type: &Type) {
-        todo!();
+        self.push_value(bitcast_val);
+        if to_type.is_bool(self.context) {
+            self.cur_section.op(Op::IsZero);
+            self.cur_section.op(Op::IsZero);
+        }
+        self.save_value(instr_val);
     }
 
     fn compile_binary_op(
@@ -434,15 +1321,34 @@ impl<'ir> EvmAsmBuilder<'ir> {
         arg1: &Value,
         arg2: &Value,
     ) {
-        todo!();
+        if let BinaryOpKind::Div = op {
+            self.check_divisor(Operand::Value(*arg2));
+        }
+        self.push_value(arg2);
+        self.push_value(arg1);
+        match op {
+            BinaryOpKind::Add => self.cur_section.op(Op::Add),
+            BinaryOpKind::Sub => self.cur_section.op(Op::Sub),
+            BinaryOpKind::Mul => self.cur_section.op(Op::Mul),
+            BinaryOpKind::Div => self.cur_section.op(Op::Div),
+        }
+        if !matches!(op, BinaryOpKind::Div) {
+            self.check_word_overflow();
+        }
+        self.save_value(instr_val);
     }
 
     fn compile_branch(&mut self, to_block: &BranchToWithArgs) {
-        todo!();
+        self.compile_branch_to_phi_value(to_block);
+        let label = self.block_to_label(&to_block.block);
+        self.cur_section.jump(&label_name(&label));
     }
 
     fn compile_cast_ptr(&mut self, instr_val: &Value, val: &Value, ty: &Type, offs: u64) {
-        todo!();
+        // Like for the Fuel backend, this indexes by the size of the type.
+        let offset_in_bytes = ir_type_size_in_bytes(self.context, ty) * offs;
+        self.push_value_with_offset(val, offset_in_bytes);
+        self.save_value(instr_val);
     }
 
     fn compile_cmp(
@@ -452,7 +1358,12 @@ impl<'ir> EvmAsmBuilder<'ir> {
         lhs_value: &Value,
         rhs_value: &Value,
     ) {
-        todo!();
+        self.push_value(rhs_value);
+        self.push_value(lhs_value);
+        match pred {
+            Predicate::Equal => self.cur_section.op(Op::Eq),
+        }
+        self.save_value(instr_val);
     }
 
     fn compile_conditional_branch(
@@ -461,23 +1372,53 @@ impl<'ir> EvmAsmBuilder<'ir> {
         true_block: &BranchToWithArgs,
         false_block: &BranchToWithArgs,
     ) -> CompileResult<()> {
-        todo!();
+        let true_label = label_name(&self.block_to_label(&true_block.block));
+        let false_label = label_name(&self.block_to_label(&false_block.block));
+
+        // If the true block takes args then they need their own path.
+        let true_edge_label = if true_block.args.is_empty() {
+            true_label.clone()
+        } else {
+            label_name(&self.reg_seqr.get_label())
+        };
+
+        self.push_value(cond_value);
+        self.cur_section.jump_if(&true_edge_label);
+
+        self.compile_branch_to_phi_value(false_block);
+        self.cur_section.jump(&false_label);
+
+        if !true_block.args.is_empty() {
+            self.cur_section.label(&true_edge_label);
+            self.compile_branch_to_phi_value(true_block);
+            self.cur_section.jump(&true_label);
+        }
+
+        ok((), vec![], vec![])
     }
 
     fn compile_branch_to_phi_value(&mut self, to_block: &BranchToWithArgs) {
-        todo!();
+        // Push all the args before writing any of them, as they may refer to the block args
+        // themselves.
+        for arg in &to_block.args {
+            self.push_value(arg);
+        }
+        for idx in (0..to_block.args.len()).rev() {
+            let block_arg = to_block.block.get_arg(self.context, idx).unwrap();
+            self.save_value(&block_arg);
+        }
     }
 
     #[allow(clippy::too_many_arguments)]
     fn compile_contract_call(
         &mut self,
         instr_val: &Value,
-        params: &Value,
-        coins: &Value,
-        asset_id: &Value,
-        gas: &Value,
-    ) {
-        todo!();
+        _params: &Value,
+        _coins: &Value,
+        _asset_id: &Value,
+        _gas: &Value,
+    ) -> CompileResult<()> {
+        self.unsupported("contract calls", instr_val)
     }
 
     fn compile_extract_element(
@@ -487,23 +1428,83 @@ impl<'ir> EvmAsmBuilder<'ir> {
         ty: &Type,
         index_val: &Value,
     ) {
-        todo!();
+        let elem_type = ty.get_array_elem_type(self.context).unwrap();
+        self.push_element_address(array, &elem_type, index_val);
+        if self.is_copy_type(&elem_type) {
+            self.cur_section.load_word();
+        }
+        self.save_value(instr_val);
+    }
+
+    // Push the address of an array element.  We could put the OOB check here, though like for
+    // the Fuel backend it would be too wasteful.
+    fn push_element_address(&mut self, array: &Value, elem_type: &Type, index_val: &Value) {
+        let elem_size = ir_type_size_in_bytes(self.context, elem_type);
+        self.push_value(index_val);
+        self.cur_section.push_number(elem_size);
+        self.cur_section.op(Op::Mul);
+        self.push_value(array);
+        self.cur_section.op(Op::Add);
     }
 
     fn compile_extract_value(&mut self, instr_val: &Value, aggregate_val: &Value, indices: &[u64]) {
-        todo!();
+        let ((extract_offset, _), field_type) = aggregate_idcs_to_field_layout(
+            self.context,
+            &aggregate_val.get_type(self.context).unwrap(),
+            indices,
+        );
+        self.push_value_with_offset(aggregate_val, extract_offset * 8);
+        if self.is_copy_type(&field_type) {
+            self.cur_section.load_word();
+        }
+        self.save_value(instr_val);
     }
 
     fn compile_get_storage_key(&mut self, instr_val: &Value) -> CompileResult<()> {
-        todo!();
+        let warnings = Vec::new();
+        let mut errors = Vec::new();
+
+        let storage_slot_to_hash = match self.md_mgr.val_to_storage_key(self.context, *instr_val) {
+            Some(state_idx) => {
+                format!(
+                    "{}{}",
+                    sway_utils::constants::STORAGE_DOMAIN_SEPARATOR,
+                    state_idx
+                )
+            }
+            None => {
+                errors.push(CompileError::Internal(
+                    "State index for __get_storage_key is not available as a metadata",
+                    self.val_span(instr_val),
+                ));
+                return err(warnings, errors);
+            }
+        };
+
+        let hashed_storage_slot = Hasher::hash(storage_slot_to_hash);
+        let key_addr = self.alloc_static(32);
+        self.cur_section
+            .store_bytes(key_addr, hashed_storage_slot.as_ref());
+        self.value_map
+            .insert(*instr_val, ValueLocation::Address(key_addr));
+
+        ok((), warnings, errors)
     }
 
     fn compile_get_local(&mut self, instr_val: &Value, local_var: &LocalVar) {
-        todo!();
+        // Locals are statically allocated, so their address is known.
+        let addr = self.local_map[local_var];
+        self.value_map
+            .insert(*instr_val, ValueLocation::Address(addr));
     }
 
-    fn compile_gtf(&mut self, instr_val: &Value, index: &Value, tx_field_id: u64) {
-        todo!();
+    fn compile_gtf(
+        &mut self,
+        instr_val: &Value,
+        _index: &Value,
+        _tx_field_id: u64,
+    ) -> CompileResult<()> {
+        self.unsupported("transaction fields", instr_val)
     }
 
     fn compile_insert_element(
@@ -514,7 +1515,28 @@ impl<'ir> EvmAsmBuilder<'ir> {
         value: &Value,
         index_val: &Value,
     ) {
-        todo!();
+        let elem_type = ty.get_array_elem_type(self.context).unwrap();
+        if self.is_copy_type(&elem_type) {
+            self.push_value(value);
+            self.push_element_address(array, &elem_type, index_val);
+            self.cur_section.store_word();
+        } else {
+            // Save the element address to the instruction slot before copying to it.
+            let elem_size = ir_type_size_in_bytes(self.context, &elem_type);
+            self.push_element_address(array, &elem_type, index_val);
+            let elem_addr = self.value_slot(instr_val);
+            self.cur_section.push_number(elem_addr);
+            self.cur_section.op(Op::MStore);
+            self.copy_memory(
+                Operand::Memory(elem_addr),
+                Operand::Value(*value),
+                Operand::Number(elem_size),
+            );
+        }
+
+        // The result is the array itself.
+        self.push_value(array);
+        self.save_value(instr_val);
     }
 
     fn compile_insert_value(
@@ -524,104 +1546,270 @@ impl<'ir> EvmAsmBuilder<'ir> {
         value: &Value,
         indices: &[u64],
     ) {
-        todo!();
+        let ((mut insert_offs, field_size_in_bytes), field_type) = aggregate_idcs_to_field_layout(
+            self.context,
+            &aggregate_val.get_type(self.context).unwrap(),
+            indices,
+        );
+
+        let value_type = value.get_type(self.context).unwrap();
+        let value_size_in_bytes = ir_type_size_in_bytes(self.context, &value_type);
+        let value_size_in_words = size_bytes_in_words!(value_size_in_bytes);
+
+        // Account for the padding if the final field type is a union and the value we're trying to
+        // insert is smaller than the size of the union (i.e. we're inserting a small variant).
+        if field_type.is_union(self.context) {
+            let field_size_in_words = size_bytes_in_words!(field_size_in_bytes);
+            assert!(field_size_in_words >= value_size_in_words);
+            insert_offs += field_size_in_words - value_size_in_words;
+        }
+
+        if self.is_copy_type(&value_type) {
+            self.push_value(value);
+            self.push_value_with_offset(aggregate_val, insert_offs * 8);
+            self.cur_section.store_word();
+        } else {
+            // Save the field address to the instruction slot before copying to it.
+            self.push_value_with_offset(aggregate_val, insert_offs * 8);
+            let field_addr = self.value_slot(instr_val);
+            self.cur_section.push_number(field_addr);
+            self.cur_section.op(Op::MStore);
+            self.copy_memory(
+                Operand::Memory(field_addr),
+                Operand::Value(*value),
+                Operand::Number(value_size_in_bytes),
+            );
+        }
+
+        // The result is the aggregate itself.
+        self.push_value(aggregate_val);
+        self.save_value(instr_val);
     }
 
     fn compile_int_to_ptr(&mut self, instr_val: &Value, int_to_ptr_val: &Value) {
-        todo!();
+        self.push_value(int_to_ptr_val);
+        self.save_value(instr_val);
     }
 
     fn compile_load(&mut self, instr_val: &Value, src_val: &Value) -> CompileResult<()> {
-        todo!();
+        // Copy types are loaded, otherwise the value is the address itself.
+        let load_type = instr_val.get_type(self.context).unwrap();
+        self.push_value(src_val);
+        if self.is_copy_type(&load_type) {
+            self.cur_section.load_word();
+        }
+        self.save_value(instr_val);
+        ok((), Vec::new(), Vec::new())
     }
 
     fn compile_mem_copy(
         &mut self,
-        instr_val: &Value,
+        _instr_val: &Value,
         dst_val: &Value,
         src_val: &Value,
         byte_len: u64,
     ) {
-        todo!();
+        self.copy_memory(
+            Operand::Value(*dst_val),
+            Operand::Value(*src_val),
+            Operand::Number(byte_len),
+        );
     }
 
-    fn compile_log(&mut self, instr_val: &Value, log_val: &Value, log_ty: &Type, log_id: &Value) {
-        todo!();
+    fn compile_log(&mut self, _instr_val: &Value, log_val: &Value, log_ty: &Type, log_id: &Value) {
+        self.push_value(log_id);
+        if self.is_copy_type(log_ty) {
+            // Log the value from the scratch space.
+            self.push_value(log_val);
+            self.cur_section.push_number(SCRATCH_SPACE);
+            self.cur_section.op(Op::MStore);
+            self.cur_section.push_number(32);
+            self.cur_section.push_number(SCRATCH_SPACE);
+        } else {
+            let size_in_bytes = ir_type_size_in_bytes(self.context, log_ty);
+            self.cur_section.push_number(size_in_bytes);
+            self.push_value(log_val);
+        }
+        self.cur_section.op(Op::Log1);
     }
 
-    fn compile_read_register(&mut self, instr_val: &Value, reg: &sway_ir::Register) {
-        todo!();
+    fn compile_read_register(
+        &mut self,
+        instr_val: &Value,
+        reg: &sway_ir::Register,
+    ) -> CompileResult<()> {
+        self.unsupported(
+            &format!("reading the `{reg:?}` register").to_lowercase(),
+            instr_val,
+        )
     }
 
-    fn compile_ret_from_entry(&mut self, instr_val: &Value, ret_val: &Value, ret_type: &Type) {
-        todo!();
+    fn compile_revert(&mut self, _instr_val: &Value, revert_val: &Value) {
+        self.revert_with(Operand::Value(*revert_val));
     }
 
-    fn compile_revert(&mut self, instr_val: &Value, revert_val: &Value) {
-        todo!();
+    // Revert with the code as an ABI encoded `uint256`.
+    fn revert_with(&mut self, revert_code: Operand) {
+        self.push_operand(revert_code);
+        self.cur_section.push_number(SCRATCH_SPACE);
+        self.cur_section.op(Op::MStore);
+        self.cur_section.push_number(32);
+        self.cur_section.push_number(SCRATCH_SPACE);
+        self.cur_section.op(Op::Revert);
     }
 
     fn compile_smo(
         &mut self,
         instr_val: &Value,
-        recipient_and_message: &Value,
-        message_size: &Value,
-        output_index: &Value,
-        coins: &Value,
-    ) {
-        todo!();
+        _recipient_and_message: &Value,
+        _message_size: &Value,
+        _output_index: &Value,
+        _coins: &Value,
+    ) -> CompileResult<()> {
+        self.unsupported("sending messages", instr_val)
     }
 
     fn compile_state_access_quad_word(
         &mut self,
-        instr_val: &Value,
+        _instr_val: &Value,
         val: &Value,
         key: &Value,
         number_of_slots: &Value,
         access_type: StateAccessType,
     ) -> CompileResult<()> {
-        todo!();
+        self.state_access_loop(
+            Operand::Value(*val),
+            Operand::Value(*key),
+            Operand::Value(*number_of_slots),
+            access_type,
+        );
+        ok((), Vec::new(), Vec::new())
+    }
+
+    // Read or write a number of consecutive storage slots, starting at the key stored at
+    // `key_addr`, from or to consecutive 32 byte words at `mem_addr`.
+    fn state_access_loop(
+        &mut self,
+        mem_addr: Operand,
+        key_addr: Operand,
+        number_of_slots: Operand,
+        access_type: StateAccessType,
+    ) {
+        let loop_label = label_name(&self.reg_seqr.get_label());
+        let done_label = label_name(&self.reg_seqr.get_label());
+
+        // The slot index is kept on the stack.
+        self.cur_section.push_number(0);
+        self.cur_section.label(&loop_label);
+        self.cur_section.op(Op::Dup1);
+        self.push_operand(number_of_slots);
+        self.cur_section.op(Op::Gt);
+        self.cur_section.op(Op::IsZero);
+        self.cur_section.jump_if(&done_label);
+
+        match access_type {
+            StateAccessType::Read => {
+                self.cur_section.op(Op::Dup1);
+                self.push_operand(key_addr);
+                self.cur_section.op(Op::MLoad);
+                self.cur_section.op(Op::Add);
+                self.cur_section.op(Op::SLoad);
+                self.cur_section.op(Op::Dup2);
+                self.cur_section.push_number(32);
+                self.cur_section.op(Op::Mul);
+                self.push_operand(mem_addr);
+                self.cur_section.op(Op::Add);
+                self.cur_section.op(Op::MStore);
+            }
+            StateAccessType::Write => {
+                self.cur_section.op(Op::Dup1);
+                self.cur_section.push_number(32);
+                self.cur_section.op(Op::Mul);
+                self.push_operand(mem_addr);
+                self.cur_section.op(Op::Add);
+                self.cur_section.op(Op::MLoad);
+                self.cur_section.op(Op::Dup2);
+                self.push_operand(key_addr);
+                self.cur_section.op(Op::MLoad);
+                self.cur_section.op(Op::Add);
+                self.cur_section.op(Op::SStore);
+            }
+        }
+
+        self.cur_section.push_number(1);
+        self.cur_section.op(Op::Add);
+        self.cur_section.jump(&loop_label);
+        self.cur_section.label(&done_label);
+        self.cur_section.op(Op::Pop);
     }
 
     fn compile_state_load_word(&mut self, instr_val: &Value, key: &Value) -> CompileResult<()> {
-        todo!();
+        // Words are stored in the most significant bytes of a slot, like in memory.
+        self.push_value(key);
+        self.cur_section.op(Op::MLoad);
+        self.cur_section.op(Op::SLoad);
+        self.cur_section.push_number(WORD_SHIFT as u64);
+        self.cur_section.op(Op::Shr);
+        self.save_value(instr_val);
+        ok((), Vec::new(), Vec::new())
     }
 
     fn compile_state_store_word(
         &mut self,
-        instr_val: &Value,
+        _instr_val: &Value,
         store_val: &Value,
         key: &Value,
     ) -> CompileResult<()> {
-        todo!();
+        self.push_value(store_val);
+        self.cur_section.push_number(WORD_SHIFT as u64);
+        self.cur_section.op(Op::Shl);
+        self.push_value(key);
+        self.cur_section.op(Op::MLoad);
+        self.cur_section.op(Op::SStore);
+        ok((), Vec::new(), Vec::new())
     }
 
     fn compile_store(
         &mut self,
-        instr_val: &Value,
+        _instr_val: &Value,
         dst_val: &Value,
         stored_val: &Value,
     ) -> CompileResult<()> {
-        todo!();
-    }
-
-    pub(super) fn func_to_labels(&mut self, func: &Function) -> (Label, Label) {
-        self.func_label_map.get(func).cloned().unwrap_or_else(|| {
-            let labels = (self.reg_seqr.get_label(), self.reg_seqr.get_label());
-            self.func_label_map.insert(*func, labels);
-            labels
-        })
-    }
-
-    pub fn compile_function(&mut self, function: Function) -> CompileResult<()> {
-        ok((), vec![], vec![])
+        // Copy types are stored, otherwise the whole value is copied.
+        let stored_type = stored_val.get_type(self.context).unwrap();
+        if self.is_copy_type(&stored_type) {
+            self.push_value(stored_val);
+            self.push_value(dst_val);
+            self.cur_section.store_word();
+        } else {
+            let size_in_bytes = ir_type_size_in_bytes(self.context, &stored_type);
+            self.copy_memory(
+                Operand::Value(*dst_val),
+                Operand::Value(*stored_val),
+                Operand::Number(size_in_bytes),
+            );
+        }
+        ok((), Vec::new(), Vec::new())
     }
 
-    pub(super) fn compile_call(&mut self, instr_val: &Value, function: &Function, args: &[Value]) {
-        todo!();
+    pub(super) fn unsupported(&mut self, feature: &str, instr_val: &Value) -> CompileResult<()> {
+        err(
+            vec![],
+            vec![CompileError::EvmUnsupported {
+                feature: feature.to_owned(),
+                span: self.val_span(instr_val),
+            }],
+        )
     }
 
-    pub(super) fn compile_ret_from_call(&mut self, instr_val: &Value, ret_val: &Value) {
-        todo!();
+    pub(super) fn block_to_label(&mut self, block: &Block) -> Label {
+        match self.block_label_map.get(block) {
+            Some(label) => label.to_owned(),
+            None => {
+                let label = self.reg_seqr.get_label();
+                self.block_label_map.insert(*block, label);
+                label
+            }
+        }
     }
 }
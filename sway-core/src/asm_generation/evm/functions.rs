@@ -0,0 +1,434 @@
+use std::collections::HashSet;
+
+use crate::{
+    asm_generation::{
+        from_ir::{aggregate_idcs_to_field_layout, ir_type_size_in_bytes},
+        Entry, ProgramKind,
+    },
+    asm_lang::Label,
+    error::*,
+};
+
+use sway_error::error::CompileError;
+use sway_ir::*;
+
+use etk_asm::ops::Op;
+
+use super::{
+    evm_asm_builder::{ethabi, high_bytes_mask, label_name, ValueLocation},
+    EvmAsmBuilder,
+};
+
+/// A summary of the adopted calling convention:
+///
+/// - Every IR value has its own statically allocated slot in memory, including the function
+///   args.  This is safe as Sway doesn't allow recursion.
+/// - The caller writes the args directly into the slots for the callee's args.
+/// - The return address is pushed onto the stack and is the only thing on the stack within the
+///   callee, between instructions.
+/// - The callee returns by swapping its return value below the return address and jumping to it,
+///   leaving the return value on the stack for the caller to save.
+///
+/// Entries, i.e., the ABI methods of contracts and the `main` function of scripts, decode their
+/// args from the call data instead, using the Solidity ABI.  Each arg is made up of one or more
+/// static 32 byte words, and so are their return values, which are returned with RETURN.
+impl<'ir> EvmAsmBuilder<'ir> {
+    pub(super) fn compile_call(&mut self, instr_val: &Value, function: &Function, args: &[Value]) {
+        // Write the args into the callee's arg slots.
+        let arg_vals = function
+            .args_iter(self.context)
+            .map(|(_, arg_val)| *arg_val)
+            .collect::<Vec<_>>();
+        for (arg_val, callee_arg_val) in args.iter().zip(arg_vals.iter()) {
+            self.push_value(arg_val);
+            self.save_value(callee_arg_val);
+        }
+
+        // Push the return address and jump to the callee, which leaves the return value on the
+        // stack.
+        let ret_label = label_name(&self.reg_seqr.get_label());
+        let (start_label, _) = self.func_to_labels(function);
+        self.cur_section.push_label(&ret_label);
+        self.cur_section.jump(&label_name(&start_label));
+        self.cur_section.label(&ret_label);
+        self.save_value(instr_val);
+    }
+
+    pub(super) fn compile_ret_from_call(&mut self, _instr_val: &Value, ret_val: &Value) {
+        //   push ret_val
+        //   swap1
+        //   jump
+        self.push_value(ret_val);
+        self.cur_section.op(Op::Swap1);
+        self.cur_section.op(Op::Jump);
+    }
+
+    pub(super) fn compile_ret_from_entry(
+        &mut self,
+        _instr_val: &Value,
+        ret_val: &Value,
+        ret_type: &Type,
+    ) {
+        if ret_type.is_unit(self.context) {
+            self.cur_section.op(Op::Stop);
+            return;
+        }
+
+        // Encode the return value into a buffer and return it.
+        let abi_words = self.abi_words(ret_type);
+        let out_size = abi_words.len() as u64 * 32;
+        let out_addr = self.alloc_static(out_size);
+        if self.is_copy_type(ret_type) {
+            self.push_value(ret_val);
+            self.cur_section.push_number(out_addr);
+            self.cur_section.op(Op::MStore);
+        } else {
+            for (idx, abi_word) in abi_words.into_iter().enumerate() {
+                self.push_value(ret_val);
+                match abi_word {
+                    AbiWord::Word(offset) => {
+                        self.cur_section.push_number(offset);
+                        self.cur_section.op(Op::Add);
+                        self.cur_section.load_word();
+                    }
+                    AbiWord::Bytes(offset, len) => {
+                        self.cur_section.push_number(offset);
+                        self.cur_section.op(Op::Add);
+                        self.cur_section.op(Op::MLoad);
+                        if len < 32 {
+                            self.cur_section.push_bytes(&high_bytes_mask(len as usize));
+                            self.cur_section.op(Op::And);
+                        }
+                    }
+                }
+                self.cur_section.push_number(out_addr + idx as u64 * 32);
+                self.cur_section.op(Op::MStore);
+            }
+        }
+        self.cur_section.push_number(out_size);
+        self.cur_section.push_number(out_addr);
+        self.cur_section.op(Op::Return);
+    }
+
+    pub(super) fn func_to_labels(&mut self, func: &Function) -> (Label, Label) {
+        self.func_label_map.get(func).cloned().unwrap_or_else(|| {
+            let labels = (self.reg_seqr.get_label(), self.reg_seqr.get_label());
+            self.func_label_map.insert(*func, labels);
+            labels
+        })
+    }
+
+    pub fn compile_function(&mut self, function: Function) -> CompileResult<()> {
+        let mut warnings = Vec::new();
+        let mut errors = Vec::new();
+
+        let func_is_entry = function.is_entry(self.context);
+        let is_main =
+            function.get_name(self.context) == sway_types::constants::DEFAULT_ENTRY_POINT_FN_NAME;
+
+        // Libraries have no code of their own, and tests can't be run on the EVM.
+        if self.program_kind == ProgramKind::Library
+            || (func_is_entry && !function.has_selector(self.context) && !is_main)
+        {
+            return ok((), warnings, errors);
+        }
+
+        if self.program_kind == ProgramKind::Predicate {
+            let span = self
+                .md_mgr
+                .md_to_span(self.context, function.get_metadata(self.context))
+                .unwrap_or_else(Self::empty_span);
+            errors.push(CompileError::EvmUnsupported {
+                feature: "predicates".into(),
+                span,
+            });
+            return err(warnings, errors);
+        }
+
+        let abi = if func_is_entry {
+            Some(check!(
+                self.function_abi(function),
+                return err(warnings, errors),
+                warnings,
+                errors
+            ))
+        } else {
+            None
+        };
+
+        // Insert a function label.
+        let (start_label, _) = self.func_to_labels(&function);
+        self.cur_section.label(&label_name(&start_label));
+
+        if func_is_entry {
+            self.compile_entry_args(function);
+        }
+        self.init_locals(function);
+
+        // Compile instructions.
+        for block in function.block_iter(self.context) {
+            let label = self.block_to_label(&block);
+            self.cur_section.label(&label_name(&label));
+
+            for instr_val in block.instruction_iter(self.context) {
+                check!(
+                    self.compile_instruction(&instr_val, func_is_entry),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
+            }
+        }
+
+        if let Some(abi) = abi {
+            if is_main {
+                self.main_label = Some(start_label);
+            } else {
+                self.entries.push((abi.short_signature(), start_label));
+            }
+            self.cur_section
+                .push_abi(ethabi::operation::Operation::Function(abi));
+        }
+
+        let section = std::mem::take(&mut self.cur_section);
+        self.sections.push(section);
+
+        ok((), warnings, errors)
+    }
+
+    fn init_locals(&mut self, function: Function) {
+        // Locals are statically allocated, and the ones with an initialiser are written on entry
+        // to the function.
+        for (_name, ptr) in function.locals_iter(self.context) {
+            let ptr_ty = ptr.get_type(self.context);
+            let addr = self.alloc_static(ir_type_size_in_bytes(self.context, &ptr_ty));
+            self.local_map.insert(*ptr, addr);
+            if let Some(constant) = ptr.get_initializer(self.context) {
+                let bytes = Entry::from_constant(self.context, constant, None).to_bytes();
+                self.cur_section.store_bytes(addr, &bytes);
+            }
+        }
+    }
+
+    // Decode the args for an entry from the call data.  The first 4 bytes are the selector for
+    // contracts.
+    fn compile_entry_args(&mut self, function: Function) {
+        let mut calldata_offset = if self.program_kind == ProgramKind::Contract {
+            4
+        } else {
+            0
+        };
+
+        let args = function
+            .args_iter(self.context)
+            .map(|(_, arg_val)| *arg_val)
+            .collect::<Vec<_>>();
+        for arg_val in args {
+            let arg_type = arg_val.get_type(self.context).unwrap();
+            if self.is_copy_type(&arg_type) {
+                self.cur_section.push_number(calldata_offset);
+                self.cur_section.op(Op::CallDataLoad);
+                self.save_value(&arg_val);
+                calldata_offset += 32;
+                continue;
+            }
+
+            let arg_addr = self.alloc_static(ir_type_size_in_bytes(self.context, &arg_type));
+            self.value_map
+                .insert(arg_val, ValueLocation::Address(arg_addr));
+            for abi_word in self.abi_words(&arg_type) {
+                match abi_word {
+                    AbiWord::Word(offset) => {
+                        self.cur_section.push_number(calldata_offset);
+                        self.cur_section.op(Op::CallDataLoad);
+                        self.cur_section.push_number(arg_addr + offset);
+                        self.cur_section.store_word();
+                    }
+                    AbiWord::Bytes(offset, len) => {
+                        //   push len
+                        //   push calldata_offset
+                        //   push addr
+                        //   calldatacopy
+                        self.cur_section.push_number(len);
+                        self.cur_section.push_number(calldata_offset);
+                        self.cur_section.push_number(arg_addr + offset);
+                        self.cur_section.op(Op::CallDataCopy);
+                    }
+                }
+                calldata_offset += 32;
+            }
+        }
+    }
+
+    // The words making up the ABI encoding of a type, which must be supported by `abi_type()`.
+    fn abi_words(&self, ty: &Type) -> Vec<AbiWord> {
+        let mut abi_words = Vec::new();
+        self.append_abi_words(ty, 0, &mut abi_words);
+        abi_words
+    }
+
+    fn append_abi_words(&self, ty: &Type, offset: u64, abi_words: &mut Vec<AbiWord>) {
+        match ty.get_content(self.context) {
+            TypeContent::B256 => abi_words.push(AbiWord::Bytes(offset, 32)),
+            TypeContent::String(len) => abi_words.push(AbiWord::Bytes(offset, *len)),
+            TypeContent::Array(elem_type, count) => {
+                let elem_size = ir_type_size_in_bytes(self.context, elem_type);
+                for idx in 0..*count {
+                    self.append_abi_words(elem_type, offset + idx * elem_size, abi_words);
+                }
+            }
+            TypeContent::Struct(field_types) => {
+                for idx in 0..field_types.len() {
+                    let ((field_offs, _), field_type) =
+                        aggregate_idcs_to_field_layout(self.context, ty, &[idx as u64]);
+                    self.append_abi_words(&field_type, offset + field_offs * 8, abi_words);
+                }
+            }
+            _ => abi_words.push(AbiWord::Word(offset)),
+        }
+    }
+
+    // The Solidity ABI type for a Sway type.  Unions have no equivalent, and neither do strings
+    // which don't fit in `bytes32`.
+    fn abi_type(&self, ty: &Type) -> Option<ethabi::ParamType> {
+        Some(match ty.get_content(self.context) {
+            TypeContent::Bool => ethabi::ParamType::Bool,
+            TypeContent::Uint(bits) => ethabi::ParamType::Uint(*bits as usize),
+            TypeContent::B256 => ethabi::ParamType::FixedBytes(32),
+            TypeContent::String(len) if *len > 0 && *len <= 32 => {
+                ethabi::ParamType::FixedBytes(*len as usize)
+            }
+            TypeContent::Array(elem_type, count) => {
+                ethabi::ParamType::FixedArray(Box::new(self.abi_type(elem_type)?), *count as usize)
+            }
+            TypeContent::Struct(field_types) => ethabi::ParamType::Tuple(
+                field_types
+                    .iter()
+                    .map(|field_type| self.abi_type(field_type))
+                    .collect::<Option<_>>()?,
+            ),
+            _ => return None,
+        })
+    }
+
+    fn abi_param(
+        &mut self,
+        function: Function,
+        name: &str,
+        ty: &Type,
+    ) -> CompileResult<ethabi::Param> {
+        match self.abi_type(ty) {
+            Some(kind) => ok(
+                ethabi::Param {
+                    name: name.to_owned(),
+                    kind,
+                    internal_type: None,
+                },
+                vec![],
+                vec![],
+            ),
+            None => err(
+                vec![],
+                vec![CompileError::EvmUnsupported {
+                    feature: format!("the type `{}` in the ABI", ty.as_string(self.context)),
+                    span: self
+                        .md_mgr
+                        .md_to_span(self.context, function.get_metadata(self.context))
+                        .unwrap_or_else(Self::empty_span),
+                }],
+            ),
+        }
+    }
+
+    fn function_abi(&mut self, function: Function) -> CompileResult<ethabi::Function> {
+        let mut warnings = Vec::new();
+        let mut errors = Vec::new();
+
+        let mut inputs = Vec::new();
+        for (name, arg_val) in function.args_iter(self.context) {
+            let arg_type = arg_val.get_type(self.context).unwrap();
+            inputs.push(check!(
+                self.abi_param(function, name, &arg_type),
+                return err(warnings, errors),
+                warnings,
+                errors
+            ));
+        }
+
+        let ret_type = function.get_return_type(self.context);
+        let outputs = if ret_type.is_unit(self.context) {
+            vec![]
+        } else {
+            vec![check!(
+                self.abi_param(function, "", &ret_type),
+                return err(warnings, errors),
+                warnings,
+                errors
+            )]
+        };
+
+        #[allow(deprecated)]
+        let abi = ethabi::Function {
+            name: function.get_name(self.context).to_owned(),
+            inputs,
+            outputs,
+            constant: None,
+            state_mutability: self.state_mutability(function),
+        };
+
+        ok(abi, warnings, errors)
+    }
+
+    // Find whether a function, or anything it calls, reads or writes the contract state.
+    fn state_mutability(&self, function: Function) -> ethabi::StateMutability {
+        let mut visited = HashSet::new();
+        let mut to_visit = vec![function];
+        let mut reads_state = false;
+        while let Some(function) = to_visit.pop() {
+            if !visited.insert(function) {
+                continue;
+            }
+            for (_, instr_val) in function.instruction_iter(self.context) {
+                match instr_val.get_instruction(self.context) {
+                    Some(Instruction::Call(callee, _)) => to_visit.push(*callee),
+                    Some(Instruction::FuelVm(
+                        FuelVmInstruction::StateStoreWord { .. }
+                        | FuelVmInstruction::StateStoreQuadWord { .. }
+                        | FuelVmInstruction::Log { .. },
+                    )) => return ethabi::StateMutability::NonPayable,
+                    Some(Instruction::FuelVm(
+                        FuelVmInstruction::StateLoadWord(_)
+                        | FuelVmInstruction::StateLoadQuadWord { .. },
+                    )) => reads_state = true,
+                    Some(Instruction::AsmBlock(asm, _)) => {
+                        for op in &asm.get_content(self.context).body {
+                            match op.name.as_str() {
+                                "sww" | "swwq" | "log" | "logd" => {
+                                    return ethabi::StateMutability::NonPayable
+                                }
+                                "srw" | "srwq" => reads_state = true,
+                                _ => (),
+                            }
+                        }
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        if reads_state {
+            ethabi::StateMutability::View
+        } else {
+            ethabi::StateMutability::Pure
+        }
+    }
+}
+
+/// The layout of a value which is ABI encoded as a single 32 byte word.
+#[derive(Clone, Copy, Debug)]
+enum AbiWord {
+    /// A Sway word at this byte offset, encoded as a right aligned `uintN` or `bool`.
+    Word(u64),
+    /// A number of bytes at this byte offset, encoded as left aligned `bytesN`.
+    Bytes(u64, u64),
+}
@@ -1,2 +1,3 @@
 mod evm_asm_builder;
 pub use evm_asm_builder::*;
+mod functions;
@@ -1,11 +1,10 @@
 use super::instruction_set::InstructionSet;
-use super::{DataSection, ProgramABI, ProgramKind};
+use super::{evm, DataSection, ProgramABI, ProgramKind};
 use crate::asm_lang::allocated_ops::{AllocatedOp, AllocatedOpcode};
 use crate::decl_engine::DeclId;
 use crate::error::*;
 use crate::source_map::SourceMap;
 
-use etk_asm::asm::Assembler;
 use sway_error::error::CompileError;
 use sway_types::span::Span;
 
@@ -52,24 +51,17 @@ impl FinalizedAsm {
             InstructionSet::Fuel { ops } => {
                 to_bytecode_mut(ops, &mut self.data_section, source_map)
             }
-            InstructionSet::Evm { ops } => {
-                let mut assembler = Assembler::new();
-                if let Err(e) = assembler.push_all(ops.clone()) {
-                    err(
-                        vec![],
-                        vec![CompileError::InternalOwned(e.to_string(), Span::dummy())],
-                    )
-                } else {
-                    ok(
-                        CompiledBytecode {
-                            bytecode: assembler.take(),
-                            config_const_offsets: BTreeMap::new(),
-                        },
-                        vec![],
-                        vec![],
-                    )
-                }
-            }
+            InstructionSet::Evm { ops } => match evm::assemble(ops.clone()) {
+                Ok(bytecode) => ok(
+                    CompiledBytecode {
+                        bytecode,
+                        config_const_offsets: BTreeMap::new(),
+                    },
+                    vec![],
+                    vec![],
+                ),
+                Err(error) => err(vec![], vec![error]),
+            },
         }
     }
 }
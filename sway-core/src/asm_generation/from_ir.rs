@@ -9,13 +9,17 @@ use super::{
     DataId, DataSection,
 };
 
-use crate::{err, ok, BuildConfig, BuildTarget, CompileResult, CompileWarning};
+use crate::{
+    err, fuel_prelude::fuel_tx::StorageSlot, ok, BuildConfig, BuildTarget, CompileResult,
+    CompileWarning,
+};
 
 use sway_error::error::CompileError;
 use sway_ir::*;
 
 pub fn compile_ir_to_asm(
     ir: &Context,
+    storage_slots: &[StorageSlot],
     build_config: Option<&BuildConfig>,
 ) -> CompileResult<FinalizedAsm> {
     // Eventually when we get this 'correct' with no hacks we'll want to compile all the modules
@@ -29,7 +33,13 @@ pub fn compile_ir_to_asm(
 
     let module = ir.module_iter().next().unwrap();
     let final_program = check!(
-        compile_module_to_asm(RegisterSequencer::new(), ir, module, build_config),
+        compile_module_to_asm(
+            RegisterSequencer::new(),
+            ir,
+            module,
+            storage_slots,
+            build_config
+        ),
         return err(warnings, errors),
         warnings,
         errors
@@ -59,6 +69,7 @@ fn compile_module_to_asm(
     reg_seqr: RegisterSequencer,
     context: &Context,
     module: Module,
+    storage_slots: &[StorageSlot],
     build_config: Option<&BuildConfig>,
 ) -> CompileResult<FinalProgram> {
     let kind = match module.get_kind(context) {
@@ -80,7 +91,7 @@ fn compile_module_to_asm(
             reg_seqr,
             context,
         )),
-        BuildTarget::EVM => Box::new(EvmAsmBuilder::new(kind, reg_seqr, context)),
+        BuildTarget::EVM => Box::new(EvmAsmBuilder::new(kind, storage_slots, reg_seqr, context)),
     };
 
     // Pre-create labels for all functions before we generate other code, so we can call them
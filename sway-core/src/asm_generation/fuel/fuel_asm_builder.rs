@@ -808,12 +808,12 @@ impl<'ir> FuelAsmBuilder<'ir> {
         if self.is_copy_type(&field_type) {
             if extract_offset > compiler_constants::TWELVE_BITS {
                 let offset_reg = self.reg_seqr.next();
-                self.number_to_reg(extract_offset, &offset_reg, owning_span.clone());
+                self.number_to_reg(extract_offset * 8, &offset_reg, owning_span.clone());
                 self.cur_bytecode.push(Op {
                     opcode: Either::Left(VirtualOp::ADD(
                         offset_reg.clone(),
-                        base_reg.clone(),
                         base_reg,
+                        offset_reg.clone(),
                     )),
                     comment: "add array base to offset".into(),
                     owning_span: owning_span.clone(),
@@ -1041,10 +1041,43 @@ impl<'ir> FuelAsmBuilder<'ir> {
             });
         } else {
             // Element size is larger than 8; we switch to bytewise offsets and sizes and use MCP.
+            let elem_index_offs_reg = self.reg_seqr.next();
             if elem_size > compiler_constants::TWELVE_BITS {
-                todo!("array element size bigger than 4k")
+                let size_data_id = self
+                    .data_section
+                    .insert_data_value(Entry::new_word(elem_size, None, None));
+                let size_reg = self.reg_seqr.next();
+                self.cur_bytecode.push(Op {
+                    opcode: Either::Left(VirtualOp::LWDataId(size_reg.clone(), size_data_id)),
+                    owning_span: owning_span.clone(),
+                    comment: "loading element size for relative offset".into(),
+                });
+                self.cur_bytecode.push(Op {
+                    opcode: Either::Left(VirtualOp::MUL(
+                        elem_index_offs_reg.clone(),
+                        index_reg,
+                        size_reg,
+                    )),
+                    comment: "insert_element relative offset".into(),
+                    owning_span: owning_span.clone(),
+                });
+                self.cur_bytecode.push(Op {
+                    opcode: Either::Left(VirtualOp::ADD(
+                        elem_index_offs_reg.clone(),
+                        base_reg.clone(),
+                        elem_index_offs_reg.clone(),
+                    )),
+                    comment: "insert_element absolute offset".into(),
+                    owning_span: owning_span.clone(),
+                });
+                self.copy_bytes(
+                    elem_index_offs_reg,
+                    insert_reg,
+                    elem_size,
+                    "insert_element store value",
+                    owning_span,
+                );
             } else {
-                let elem_index_offs_reg = self.reg_seqr.next();
                 self.cur_bytecode.push(Op {
                     opcode: Either::Left(VirtualOp::MULI(
                         elem_index_offs_reg.clone(),
@@ -1124,19 +1157,19 @@ impl<'ir> FuelAsmBuilder<'ir> {
         if self.is_copy_type(&value_type) {
             if insert_offs > compiler_constants::TWELVE_BITS {
                 let insert_offs_reg = self.reg_seqr.next();
-                self.number_to_reg(insert_offs, &insert_offs_reg, owning_span.clone());
+                self.number_to_reg(insert_offs * 8, &insert_offs_reg, owning_span.clone());
                 self.cur_bytecode.push(Op {
                     opcode: Either::Left(VirtualOp::ADD(
+                        insert_offs_reg.clone(),
                         base_reg.clone(),
-                        base_reg.clone(),
-                        insert_offs_reg,
+                        insert_offs_reg.clone(),
                     )),
                     comment: "insert_value absolute offset".into(),
                     owning_span: owning_span.clone(),
                 });
                 self.cur_bytecode.push(Op {
                     opcode: Either::Left(VirtualOp::SW(
-                        base_reg.clone(),
+                        insert_offs_reg,
                         insert_reg,
                         VirtualImmediate12 { value: 0 },
                     )),
@@ -1160,6 +1193,15 @@ impl<'ir> FuelAsmBuilder<'ir> {
             let offs_reg = self.reg_seqr.next();
             if insert_offs * 8 > compiler_constants::TWELVE_BITS {
                 self.number_to_reg(insert_offs * 8, &offs_reg, owning_span.clone());
+                self.cur_bytecode.push(Op {
+                    opcode: Either::Left(VirtualOp::ADD(
+                        offs_reg.clone(),
+                        base_reg.clone(),
+                        offs_reg.clone(),
+                    )),
+                    comment: format!("get struct field(s) {} offset", indices_str),
+                    owning_span: owning_span.clone(),
+                });
             } else {
                 self.cur_bytecode.push(Op {
                     opcode: either::Either::Left(VirtualOp::ADDI(
@@ -1174,13 +1216,13 @@ impl<'ir> FuelAsmBuilder<'ir> {
                 });
             }
             if value_size_in_bytes > compiler_constants::TWELVE_BITS {
-                let size_reg = self.reg_seqr.next();
-                self.number_to_reg(value_size_in_bytes, &size_reg, owning_span.clone());
-                self.cur_bytecode.push(Op {
-                    opcode: Either::Left(VirtualOp::MCP(offs_reg, insert_reg, size_reg)),
-                    comment: "store struct field value".into(),
+                self.copy_bytes(
+                    offs_reg,
+                    insert_reg,
+                    value_size_in_bytes,
+                    "store struct field value",
                     owning_span,
-                });
+                );
             } else {
                 self.cur_bytecode.push(Op {
                     opcode: Either::Left(VirtualOp::MCPI(
@@ -1315,6 +1357,17 @@ impl<'ir> FuelAsmBuilder<'ir> {
         let dst_reg = self.value_to_register(dst_val);
         let src_reg = self.value_to_register(src_val);
 
+        if byte_len > compiler_constants::TWELVE_BITS {
+            self.copy_bytes(
+                dst_reg,
+                src_reg,
+                byte_len,
+                "copy memory with mem_copy",
+                owning_span,
+            );
+            return;
+        }
+
         let len_reg = self.reg_seqr.next();
         self.cur_bytecode.push(Op {
             opcode: Either::Left(VirtualOp::MOVI(
@@ -1841,21 +1894,13 @@ impl<'ir> FuelAsmBuilder<'ir> {
                         }
 
                         if store_size_in_words * 8 > compiler_constants::TWELVE_BITS {
-                            let size_reg = self.reg_seqr.next();
-                            self.number_to_reg(
+                            self.copy_bytes(
+                                dest_offs_reg,
+                                stored_reg,
                                 store_size_in_words * 8,
-                                &size_reg,
-                                owning_span.clone(),
-                            );
-                            self.cur_bytecode.push(Op {
-                                opcode: Either::Left(VirtualOp::MCP(
-                                    dest_offs_reg,
-                                    stored_reg,
-                                    size_reg,
-                                )),
-                                comment: "store value".into(),
+                                "store value",
                                 owning_span,
-                            });
+                            );
                         } else {
                             self.cur_bytecode.push(Op {
                                 opcode: Either::Left(VirtualOp::MCPI(
@@ -2017,7 +2062,16 @@ impl<'ir> FuelAsmBuilder<'ir> {
         span: Option<Span>,
     ) {
         if offset > compiler_constants::TWENTY_FOUR_BITS {
-            todo!("Absolutely giant arrays.");
+            // Too big to build from immediates cheaply, so load it from the data section.
+            let offset_data_id = self
+                .data_section
+                .insert_data_value(Entry::new_word(offset, None, None));
+            self.cur_bytecode.push(Op {
+                opcode: Either::Left(VirtualOp::LWDataId(offset_reg.clone(), offset_data_id)),
+                comment: "load extract offset".into(),
+                owning_span: span,
+            });
+            return;
         }
 
         // Use bitwise ORs and SHIFTs to crate a 24 bit value in a register.
@@ -2054,6 +2108,90 @@ impl<'ir> FuelAsmBuilder<'ir> {
         });
     }
 
+    /// Copy `byte_len` bytes from the address in `src_reg` to the address in `dst_reg`.
+    ///
+    /// The VM charges for the memory written by a single copy exponentially in the number of
+    /// pages written, so anything bigger than fits in an `MCPI` is copied a chunk smaller than a
+    /// page at a time, in a loop.
+    pub(super) fn copy_bytes(
+        &mut self,
+        dst_reg: VirtualRegister,
+        src_reg: VirtualRegister,
+        byte_len: u64,
+        comment: &str,
+        span: Option<Span>,
+    ) {
+        // The largest word aligned chunk which fits in an immediate.
+        let chunk_size = compiler_constants::TWELVE_BITS & !7;
+        let mcpi = |dst_reg: &VirtualRegister, src_reg: &VirtualRegister, size: u64| Op {
+            opcode: Either::Left(VirtualOp::MCPI(
+                dst_reg.clone(),
+                src_reg.clone(),
+                VirtualImmediate12 { value: size as u16 },
+            )),
+            comment: comment.into(),
+            owning_span: span.clone(),
+        };
+
+        if byte_len <= compiler_constants::TWELVE_BITS {
+            self.cur_bytecode.push(mcpi(&dst_reg, &src_reg, byte_len));
+            return;
+        }
+
+        // Copy the addresses as they're advanced by each chunk.
+        let dst_chunk_reg = self.reg_seqr.next();
+        let src_chunk_reg = self.reg_seqr.next();
+        self.cur_bytecode.push(Op::register_move(
+            dst_chunk_reg.clone(),
+            dst_reg,
+            "copy destination address",
+            span.clone(),
+        ));
+        self.cur_bytecode.push(Op::register_move(
+            src_chunk_reg.clone(),
+            src_reg,
+            "copy source address",
+            span.clone(),
+        ));
+        let count_reg = self.reg_seqr.next();
+        self.number_to_reg(byte_len / chunk_size, &count_reg, span.clone());
+
+        let loop_label = self.reg_seqr.get_label();
+        self.cur_bytecode.push(Op::unowned_jump_label(loop_label));
+        self.cur_bytecode
+            .push(mcpi(&dst_chunk_reg, &src_chunk_reg, chunk_size));
+        for reg in [&dst_chunk_reg, &src_chunk_reg] {
+            self.cur_bytecode.push(Op {
+                opcode: Either::Left(VirtualOp::ADDI(
+                    reg.clone(),
+                    reg.clone(),
+                    VirtualImmediate12 {
+                        value: chunk_size as u16,
+                    },
+                )),
+                comment: "advance to next chunk".into(),
+                owning_span: span.clone(),
+            });
+        }
+        self.cur_bytecode.push(Op {
+            opcode: Either::Left(VirtualOp::SUBI(
+                count_reg.clone(),
+                count_reg.clone(),
+                VirtualImmediate12 { value: 1 },
+            )),
+            comment: "count down chunks".into(),
+            owning_span: span.clone(),
+        });
+        self.cur_bytecode
+            .push(Op::jump_if_not_zero(count_reg, loop_label));
+
+        let remainder = byte_len % chunk_size;
+        if remainder != 0 {
+            self.cur_bytecode
+                .push(mcpi(&dst_chunk_reg, &src_chunk_reg, remainder));
+        }
+    }
+
     pub(super) fn func_to_labels(&mut self, func: &Function) -> (Label, Label) {
         self.func_label_map.get(func).cloned().unwrap_or_else(|| {
             let labels = (self.reg_seqr.get_label(), self.reg_seqr.get_label());
@@ -11,6 +11,7 @@ use crate::{
 };
 
 use sway_ir::*;
+use sway_types::Span;
 
 use either::Either;
 
@@ -18,8 +19,9 @@ use super::FuelAsmBuilder;
 
 /// A summary of the adopted calling convention:
 ///
-/// - Function arguments are passed left to right in the reserved registers.  Extra args are passed
-///   on the stack.
+/// - Function arguments are passed left to right in the reserved registers.  If there are more
+///   args than registers then all but the first few are passed in a block on the stack instead, a
+///   word each, and the address of the block is passed in the last args register.
 /// - The return value is returned in $retv.
 /// - The return address is passed in $reta.
 /// - All other general purpose registers must be preserved.
@@ -34,6 +36,7 @@ use super::FuelAsmBuilder;
 ///
 /// - Caller:
 ///   - Place function args into $rarg0 - $rargN and if necessary the stack.
+///   - Place the address of any args on the stack into the last args register.
 ///   - Allocate the return value on the stack if it's a reference type.
 ///   - Place the return address into $reta
 ///   - Jump to function address.
@@ -52,21 +55,56 @@ use super::FuelAsmBuilder;
 impl<'ir> FuelAsmBuilder<'ir> {
     pub(super) fn compile_call(&mut self, instr_val: &Value, function: &Function, args: &[Value]) {
         // Put the args into the args registers.
-        for (idx, arg_val) in args.iter().enumerate() {
-            if idx < compiler_constants::NUM_ARG_REGISTERS as usize {
+        let num_reg_args = num_args_in_registers(args.len());
+        for (idx, arg_val) in args.iter().enumerate().take(num_reg_args) {
+            let arg_reg = self.value_to_register(arg_val);
+            self.cur_bytecode.push(Op::register_move(
+                VirtualRegister::Constant(ConstantRegister::ARG_REGS[idx]),
+                arg_reg,
+                format!("pass arg {idx}"),
+                self.md_mgr.val_to_span(self.context, *arg_val),
+            ));
+        }
+
+        // Put any remaining args into a block on the stack and pass its address in the last args
+        // register.  The stack can only be extended by a 24 bit immediate at a time, so enormous
+        // blocks are allocated in chunks.
+        let call_span = self.md_mgr.val_to_span(self.context, *instr_val);
+        let stack_args_size = (args.len() - num_reg_args) as u64 * 8;
+        if stack_args_size != 0 {
+            let stack_args_reg = self.reg_seqr.next();
+            self.cur_bytecode.push(Op::register_move(
+                stack_args_reg.clone(),
+                VirtualRegister::Constant(ConstantRegister::StackPointer),
+                "save the address of args on the stack",
+                call_span.clone(),
+            ));
+            for chunk_size in stack_chunk_sizes(stack_args_size) {
+                self.cur_bytecode.push(Op {
+                    opcode: Either::Left(VirtualOp::CFEI(VirtualImmediate24 {
+                        value: chunk_size as u32,
+                    })),
+                    comment: format!("allocate {chunk_size} bytes for args"),
+                    owning_span: call_span.clone(),
+                });
+            }
+            for (offset, (idx, arg_val)) in args.iter().enumerate().skip(num_reg_args).enumerate() {
                 let arg_reg = self.value_to_register(arg_val);
-                self.cur_bytecode.push(Op::register_move(
-                    VirtualRegister::Constant(ConstantRegister::ARG_REGS[idx]),
-                    arg_reg,
-                    format!("pass arg {idx}"),
-                    self.md_mgr.val_to_span(self.context, *arg_val),
-                ));
-            } else {
-                todo!(
-                    "can't do more than {} args yet",
-                    compiler_constants::NUM_ARG_REGISTERS
-                );
+                let arg_span = self.md_mgr.val_to_span(self.context, *arg_val);
+                let (addr_reg, offset) =
+                    self.stack_arg_address(&stack_args_reg, offset as u64, arg_span.clone());
+                self.cur_bytecode.push(Op {
+                    opcode: Either::Left(VirtualOp::SW(addr_reg, arg_reg, offset)),
+                    comment: format!("pass arg {idx}"),
+                    owning_span: arg_span,
+                });
             }
+            self.cur_bytecode.push(Op::register_move(
+                VirtualRegister::Constant(ConstantRegister::ARG_REGS[num_reg_args]),
+                stack_args_reg,
+                "pass the address of args on the stack",
+                call_span.clone(),
+            ));
         }
 
         // Set a new return address.
@@ -87,6 +125,17 @@ impl<'ir> FuelAsmBuilder<'ir> {
         });
         self.cur_bytecode.push(Op::unowned_jump_label(ret_label));
 
+        // Free any args passed on the stack.
+        for chunk_size in stack_chunk_sizes(stack_args_size) {
+            self.cur_bytecode.push(Op {
+                opcode: Either::Left(VirtualOp::CFSI(VirtualImmediate24 {
+                    value: chunk_size as u32,
+                })),
+                comment: format!("free {chunk_size} bytes for args"),
+                owning_span: call_span.clone(),
+            });
+        }
+
         // Save the return value.
         let ret_reg = self.reg_seqr.next();
         self.cur_bytecode.push(Op {
@@ -171,7 +220,13 @@ impl<'ir> FuelAsmBuilder<'ir> {
         }
 
         if func_is_entry {
-            self.compile_external_args(function)
+            // Tests are always executed as scripts, so test entries read their arguments from the
+            // script data regardless of the kind of program they are declared within.
+            let program_kind = match test_decl_id {
+                Some(_) => ProgramKind::Script,
+                None => self.program_kind,
+            };
+            self.compile_external_args(function, program_kind)
         } else {
             // Make copies of the arg registers.
             self.compile_fn_call_args(function)
@@ -264,8 +319,10 @@ impl<'ir> FuelAsmBuilder<'ir> {
 
     fn compile_fn_call_args(&mut self, function: Function) {
         // The first n args are passed in registers, but the rest arrive on the stack.
+        let num_reg_args = num_args_in_registers(function.args_iter(self.context).count());
+        let mut stack_args_reg = None;
         for (idx, (_, arg_val)) in function.args_iter(self.context).enumerate() {
-            if idx < compiler_constants::NUM_ARG_REGISTERS as usize {
+            if idx < num_reg_args {
                 // Make a copy of the args in case we make calls and need to use the arg registers.
                 let arg_copy_reg = self.reg_seqr.next();
                 self.cur_bytecode.push(Op::register_move(
@@ -278,16 +335,40 @@ impl<'ir> FuelAsmBuilder<'ir> {
                 // Remember our arg copy.
                 self.reg_map.insert(*arg_val, arg_copy_reg);
             } else {
-                todo!(
-                    "can't do more than {} args yet",
-                    compiler_constants::NUM_ARG_REGISTERS
+                // Load the arg from the stack, whose address is passed in the last args register.
+                let stack_args_reg = stack_args_reg
+                    .get_or_insert_with(|| {
+                        let stack_args_reg = self.reg_seqr.next();
+                        self.cur_bytecode.push(Op::register_move(
+                            stack_args_reg.clone(),
+                            VirtualRegister::Constant(ConstantRegister::ARG_REGS[num_reg_args]),
+                            "save the address of args on the stack",
+                            None,
+                        ));
+                        stack_args_reg
+                    })
+                    .clone();
+                let arg_copy_reg = self.reg_seqr.next();
+                let arg_span = self.md_mgr.val_to_span(self.context, *arg_val);
+                let (addr_reg, offset) = self.stack_arg_address(
+                    &stack_args_reg,
+                    (idx - num_reg_args) as u64,
+                    arg_span.clone(),
                 );
+                self.cur_bytecode.push(Op {
+                    opcode: Either::Left(VirtualOp::LW(arg_copy_reg.clone(), addr_reg, offset)),
+                    comment: format!("load arg {idx}"),
+                    owning_span: arg_span,
+                });
+
+                // Remember our arg copy.
+                self.reg_map.insert(*arg_val, arg_copy_reg);
             }
         }
     }
 
     // Handle loading the arguments of a contract call
-    fn compile_external_args(&mut self, function: Function) {
+    fn compile_external_args(&mut self, function: Function, program_kind: ProgramKind) {
         match function.args_iter(self.context).count() {
             // Nothing to do if there are no arguments
             0 => (),
@@ -297,11 +378,11 @@ impl<'ir> FuelAsmBuilder<'ir> {
             1 => {
                 let (_, val) = function.args_iter(self.context).next().unwrap();
                 let single_arg_reg = self.value_to_register(val);
-                match self.program_kind {
+                match program_kind {
                     ProgramKind::Contract => self.read_args_base_from_frame(&single_arg_reg),
                     ProgramKind::Library => (), // Nothing to do here
                     ProgramKind::Script | ProgramKind::Predicate => {
-                        if let ProgramKind::Predicate = self.program_kind {
+                        if let ProgramKind::Predicate = program_kind {
                             self.read_args_base_from_predicate_data(&single_arg_reg);
                         } else {
                             self.read_args_base_from_script_data(&single_arg_reg);
@@ -329,7 +410,7 @@ impl<'ir> FuelAsmBuilder<'ir> {
             // Otherwise, the args are bundled together and pointed to by the base register.
             _ => {
                 let args_base_reg = self.reg_seqr.next();
-                match self.program_kind {
+                match program_kind {
                     ProgramKind::Contract => self.read_args_base_from_frame(&args_base_reg),
                     ProgramKind::Library => return, // Nothing to do here
                     ProgramKind::Predicate => {
@@ -411,6 +492,38 @@ impl<'ir> FuelAsmBuilder<'ir> {
         }
     }
 
+    /// The register and word offset with which to address the arg `word_offset` words into the
+    /// block of args on the stack at `stack_args_reg`.  Args beyond the reach of a 12 bit
+    /// immediate offset are addressed by computing their address in a new register.
+    fn stack_arg_address(
+        &mut self,
+        stack_args_reg: &VirtualRegister,
+        word_offset: u64,
+        span: Option<Span>,
+    ) -> (VirtualRegister, VirtualImmediate12) {
+        if word_offset <= compiler_constants::TWELVE_BITS {
+            return (
+                stack_args_reg.clone(),
+                VirtualImmediate12 {
+                    value: word_offset as u16,
+                },
+            );
+        }
+
+        let addr_reg = self.reg_seqr.next();
+        self.number_to_reg(word_offset * 8, &addr_reg, span.clone());
+        self.cur_bytecode.push(Op {
+            opcode: Either::Left(VirtualOp::ADD(
+                addr_reg.clone(),
+                stack_args_reg.clone(),
+                addr_reg.clone(),
+            )),
+            comment: "get address of arg on the stack".into(),
+            owning_span: span,
+        });
+        (addr_reg, VirtualImmediate12 { value: 0 })
+    }
+
     // Read the argument(s) base from the call frame.
     fn read_args_base_from_frame(&mut self, reg: &VirtualRegister) {
         self.cur_bytecode.push(Op {
@@ -641,16 +754,15 @@ impl<'ir> FuelAsmBuilder<'ir> {
             None,
         ));
 
+        // The stack can only be extended by a 24 bit immediate at a time, so enormous amounts of
+        // locals are allocated in chunks.
         let locals_size = stack_base * 8;
-        if locals_size != 0 {
-            if locals_size > compiler_constants::TWENTY_FOUR_BITS {
-                todo!("Enormous stack usage for locals.");
-            }
+        for chunk_size in stack_chunk_sizes(locals_size) {
             self.cur_bytecode.push(Op {
                 opcode: Either::Left(VirtualOp::CFEI(VirtualImmediate24 {
-                    value: locals_size as u32,
+                    value: chunk_size as u32,
                 })),
-                comment: format!("allocate {} bytes for locals", locals_size),
+                comment: format!("allocate {} bytes for locals", chunk_size),
                 owning_span: None,
             });
         }
@@ -662,15 +774,12 @@ impl<'ir> FuelAsmBuilder<'ir> {
             .locals_ctxs
             .pop()
             .expect("Calls guaranteed to save locals context.");
-        if locals_size != 0 {
-            if locals_size > compiler_constants::TWENTY_FOUR_BITS {
-                todo!("Enormous stack usage for locals.");
-            }
+        for chunk_size in stack_chunk_sizes(locals_size) {
             self.cur_bytecode.push(Op {
                 opcode: Either::Left(VirtualOp::CFSI(VirtualImmediate24 {
-                    value: locals_size as u32,
+                    value: chunk_size as u32,
                 })),
-                comment: format!("free {} bytes for locals", locals_size),
+                comment: format!("free {} bytes for locals", chunk_size),
                 owning_span: None,
             });
         }
@@ -680,3 +789,44 @@ impl<'ir> FuelAsmBuilder<'ir> {
         &self.locals_ctxs.last().expect("No locals").1
     }
 }
+
+/// The number of args passed in registers to a function with `num_args` args.  If there are more
+/// args than args registers then the last register holds the address of the rest instead.
+fn num_args_in_registers(num_args: usize) -> usize {
+    let num_arg_registers = compiler_constants::NUM_ARG_REGISTERS as usize;
+    if num_args > num_arg_registers {
+        num_arg_registers - 1
+    } else {
+        num_args
+    }
+}
+
+/// Split `size` bytes of stack into chunks small enough to allocate or free with a single `CFEI`
+/// or `CFSI`.
+pub(crate) fn stack_chunk_sizes(size: u64) -> impl Iterator<Item = u64> {
+    // Keep each chunk word aligned.
+    let max_chunk_size = compiler_constants::TWENTY_FOUR_BITS & !7;
+    (0..size)
+        .step_by(max_chunk_size as usize)
+        .map(move |offset| (size - offset).min(max_chunk_size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stack_chunks() {
+        let max_chunk_size = compiler_constants::TWENTY_FOUR_BITS & !7;
+        let chunks = |size| stack_chunk_sizes(size).collect::<Vec<_>>();
+
+        assert!(chunks(0).is_empty());
+        assert_eq!(chunks(8), vec![8]);
+        assert_eq!(chunks(max_chunk_size), vec![max_chunk_size]);
+        assert_eq!(chunks(max_chunk_size + 1), vec![max_chunk_size, 1]);
+        assert_eq!(
+            chunks(2 * max_chunk_size + 8),
+            vec![max_chunk_size, max_chunk_size, 8]
+        );
+    }
+}
@@ -1,10 +1,17 @@
 use crate::{
     asm_generation::{
-        register_sequencer::RegisterSequencer, RegisterAllocationStatus, RegisterPool,
+        compiler_constants, fuel::stack_chunk_sizes, register_sequencer::RegisterSequencer,
+        RegisterAllocationStatus, RegisterPool,
+    },
+    asm_lang::{
+        virtual_register::*, Op, OrganizationalOp, VirtualImmediate12, VirtualImmediate18,
+        VirtualImmediate24, VirtualOp,
     },
-    asm_lang::{virtual_register::*, Op, VirtualOp},
 };
 
+use sway_error::error::CompileError;
+use sway_types::Span;
+
 use std::collections::{BTreeSet, HashMap};
 
 use either::Either;
@@ -324,6 +331,35 @@ pub(crate) fn coalesce_registers(
     reduced_ops
 }
 
+/// Given a list of instructions `ops`, estimate the cost of spilling each virtual register to the
+/// stack as the number of instructions which would have to reload or store it, which is the
+/// number of instructions which use or define it.
+///
+/// The registers introduced by a previous round of spilling, as well as the base register of the
+/// spill area, may not be spilled and are given an infinite cost.
+///
+pub(crate) fn compute_spill_costs(
+    ops: &[Op],
+    spill_area: Option<&SpillArea>,
+) -> HashMap<VirtualRegister, f64> {
+    let mut spill_costs: HashMap<VirtualRegister, f64> = HashMap::new();
+    for op in ops {
+        for reg in op.registers() {
+            if matches!(reg, VirtualRegister::Virtual(_)) {
+                *spill_costs.entry(reg.clone()).or_default() += 1.0;
+            }
+        }
+    }
+
+    if let Some(spill_area) = spill_area {
+        for reg in spill_area.unspillable_registers() {
+            spill_costs.insert(reg.clone(), f64::INFINITY);
+        }
+    }
+
+    spill_costs
+}
+
 /// Given an interference graph and a integer k, figure out if the graph k-colorable. Graph
 /// coloring is an NP-complete problem, but the algorithm below is a simple stack based
 /// approximation that relies on the fact that any node n in the graph that has fewer than k
@@ -334,26 +370,64 @@ pub(crate) fn coalesce_registers(
 /// 1. Pick any node n such that degree(n) < k and put it on the stack along with its neighbors.
 /// 2. Remove node n and all its edges from the graph
 ///    - This may make some new nodes have fewer than k neighbours which is nice.
-/// 3. If some vertex n still has k or more neighbors, then the graph is not k colorable, and we
-///    have to spill. For now, we will just error out, but we may be able to do better in the
-///    future.
+/// 3. If every remaining node n has k or more neighbors, then the graph may not be k colorable,
+///    and we pick a node to potentially spill. This is the node with the lowest spill cost
+///    relative to its degree, i.e. one which is cheap to spill and whose removal simplifies the
+///    graph the most. It is put on the stack and removed from the graph like any other.
 /// ===============================================================================================
 ///
-/// As we don't implement spilling just yet, I've modified the algorithm above to assume k=infinity
-/// and moving the colorability checking until the register assignment phase. The reason for this
-/// is that the algorithm above can be too conservative and may bail our early even though a valid
-/// assignment is actually available. We can revisit this decision when decide to implement
-/// spilling.
+/// Here k is the number of allocatable registers. A node picked in step 3 isn't necessarily
+/// spilled, as its neighbours may end up sharing registers. Whether it is actually spilled is
+/// only decided during the register assignment phase, as the algorithm above can be too
+/// conservative otherwise.
+///
+/// Without any `spill_costs` the algorithm assumes k=infinity, simply removing the nodes in
+/// order. This is usually enough to find an assignment, in which case no registers need to be
+/// considered for spilling.
 ///
 pub(crate) fn color_interference_graph(
     interference_graph: &mut InterferenceGraph,
+    spill_costs: Option<&HashMap<VirtualRegister, f64>>,
 ) -> Vec<(VirtualRegister, BTreeSet<VirtualRegister>)> {
+    let k = match spill_costs {
+        Some(_) => compiler_constants::NUM_ALLOCATABLE_REGISTERS as usize,
+        None => usize::MAX,
+    };
     let mut stack: Vec<(VirtualRegister, BTreeSet<VirtualRegister>)> = vec![];
 
-    while let Some(node) = interference_graph.node_indices().next() {
-        let neighbors = interference_graph
-            .neighbors(node)
-            .map(|n| interference_graph[n].clone())
+    // The current degree of each node in the graph.
+    let mut degrees: HashMap<NodeIndex, usize> = interference_graph
+        .node_indices()
+        .map(|node| (node, interference_graph.neighbors(node).count()))
+        .collect();
+
+    while !degrees.is_empty() {
+        let node = interference_graph
+            .node_indices()
+            .find(|node| degrees[node] < k)
+            .unwrap_or_else(|| {
+                let spill_priority = |node: &NodeIndex| {
+                    spill_costs
+                        .and_then(|spill_costs| spill_costs.get(&interference_graph[*node]))
+                        .copied()
+                        .unwrap_or_default()
+                        / degrees[node] as f64
+                };
+                interference_graph
+                    .node_indices()
+                    .min_by(|a, b| spill_priority(a).total_cmp(&spill_priority(b)))
+                    .expect("Graph must not be empty")
+            });
+
+        let neighbors = interference_graph.neighbors(node).collect::<Vec<_>>();
+        for neighbor in &neighbors {
+            *degrees.get_mut(neighbor).expect("Node must exist") -= 1;
+        }
+        degrees.remove(&node);
+
+        let neighbors = neighbors
+            .iter()
+            .map(|n| interference_graph[*n].clone())
             .collect();
         stack.push((
             interference_graph
@@ -372,10 +446,14 @@ pub(crate) fn color_interference_graph(
 /// the neighbors of v (available from the stack) and the list of virtual registers already used by
 /// r (available in the used_by field) is empty.
 ///
+/// If no register is available to some virtual registers then they must be spilled, and they are
+/// returned as the error.
+///
 pub(crate) fn assign_registers(
     stack: &mut Vec<(VirtualRegister, BTreeSet<VirtualRegister>)>,
-) -> RegisterPool {
+) -> Result<RegisterPool, Vec<VirtualRegister>> {
     let mut pool = RegisterPool::init();
+    let mut spills = vec![];
     while let Some((reg, neighbors)) = stack.pop() {
         if matches!(reg, VirtualRegister::Virtual(_)) {
             let available =
@@ -388,15 +466,455 @@ pub(crate) fn assign_registers(
             if let Some(RegisterAllocationStatus { reg: _, used_by }) = available {
                 used_by.insert(reg.clone());
             } else {
-                // Error out for now if no available register is found
-                unimplemented!(
-                    "The allocator cannot resolve a register mapping for this program. \
-                     This is a temporary artifact of the extremely early stage version \
-                     of this language. Try to lower the number of variables you use."
-                );
+                spills.push(reg);
             }
         }
     }
 
-    pool
+    if spills.is_empty() {
+        Ok(pool)
+    } else {
+        Err(spills)
+    }
+}
+
+/// The area of a function's stack frame to which virtual registers are spilled, allocated after
+/// the registers used by the function have been saved and before its locals.
+pub(crate) struct SpillArea {
+    /// Holds the address of the spill area throughout the function.
+    base_reg: VirtualRegister,
+    /// The word-sized slot in which each spilled register is kept.
+    slots: Vec<VirtualRegister>,
+    /// The registers introduced to reload and store spilled registers.
+    temps: BTreeSet<VirtualRegister>,
+}
+
+impl SpillArea {
+    pub(crate) fn new(register_sequencer: &mut RegisterSequencer) -> Self {
+        SpillArea {
+            base_reg: register_sequencer.next(),
+            slots: vec![],
+            temps: BTreeSet::new(),
+        }
+    }
+
+    /// The registers which may not be spilled, as doing so would free up no registers.
+    fn unspillable_registers(&self) -> impl Iterator<Item = &VirtualRegister> {
+        std::iter::once(&self.base_reg).chain(self.temps.iter())
+    }
+
+    fn size_in_bytes(&self) -> u64 {
+        self.slots.len() as u64 * 8
+    }
+
+    /// The index of the instruction at which the spill area is allocated: just after the label
+    /// starting the function and, unless it is an entry, the saving of its registers.
+    fn allocation_index(ops: &[Op]) -> usize {
+        let mut ix = 0;
+        if matches!(
+            ops.first().map(|op| &op.opcode),
+            Some(Either::Right(OrganizationalOp::Label(_)))
+        ) {
+            ix += 1;
+        }
+        if matches!(
+            ops.get(ix).map(|op| &op.opcode),
+            Some(Either::Right(OrganizationalOp::PushAll(_)))
+        ) {
+            ix += 1;
+        }
+        ix
+    }
+
+    /// Insert the instructions which allocate the spill area on the stack on entry to the
+    /// function, and free it again before the function restores its saved registers.
+    pub(crate) fn allocate(&self, ops: Vec<Op>) -> Vec<Op> {
+        // The stack can only be extended by a 24 bit immediate at a time, so an enormous spill
+        // area is allocated in chunks.
+        let size = self.size_in_bytes();
+        let stack_ops = |opcode: fn(VirtualImmediate24) -> VirtualOp, action: &str| {
+            stack_chunk_sizes(size)
+                .map(|chunk_size| Op {
+                    opcode: Either::Left(opcode(VirtualImmediate24 {
+                        value: chunk_size as u32,
+                    })),
+                    comment: format!("{} {} bytes for spilled registers", action, chunk_size),
+                    owning_span: None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let allocation_ix = Self::allocation_index(&ops) + 1;
+        let mut allocated_ops = Vec::with_capacity(ops.len() + 2);
+        for (ix, op) in ops.into_iter().enumerate() {
+            if matches!(op.opcode, Either::Right(OrganizationalOp::PopAll(_))) {
+                allocated_ops.extend(stack_ops(VirtualOp::CFSI, "free"));
+            }
+            allocated_ops.push(op);
+            if ix + 1 == allocation_ix {
+                allocated_ops.extend(stack_ops(VirtualOp::CFEI, "allocate"));
+            }
+        }
+
+        allocated_ops
+    }
+
+    /// The register and word offset with which to address `slot`, along with the instructions
+    /// which compute that register. Slots beyond the reach of a 12 bit immediate offset from the
+    /// base of the spill area are addressed by computing their address in a new temporary
+    /// register.
+    fn slot_address(
+        &mut self,
+        slot: u64,
+        register_sequencer: &mut RegisterSequencer,
+    ) -> (Vec<Op>, VirtualRegister, VirtualImmediate12) {
+        if slot <= compiler_constants::TWELVE_BITS {
+            return (
+                vec![],
+                self.base_reg.clone(),
+                VirtualImmediate12 { value: slot as u16 },
+            );
+        }
+
+        let addr_reg = register_sequencer.next();
+        let mut ops = number_to_reg(slot * 8, &addr_reg);
+        ops.push(Op {
+            opcode: Either::Left(VirtualOp::ADD(
+                addr_reg.clone(),
+                self.base_reg.clone(),
+                addr_reg.clone(),
+            )),
+            comment: "get spill slot address".into(),
+            owning_span: None,
+        });
+        self.temps.insert(addr_reg.clone());
+        (ops, addr_reg, VirtualImmediate12 { value: 0 })
+    }
+}
+
+/// The instructions which put `value` in `reg`, using `MOVI` if it fits in its immediate, or
+/// shifting it into the register 12 bits at a time otherwise.
+fn number_to_reg(value: u64, reg: &VirtualRegister) -> Vec<Op> {
+    if value <= compiler_constants::EIGHTEEN_BITS {
+        return vec![Op {
+            opcode: Either::Left(VirtualOp::MOVI(
+                reg.clone(),
+                VirtualImmediate18 {
+                    value: value as u32,
+                },
+            )),
+            comment: "get spill slot offset".into(),
+            owning_span: None,
+        }];
+    }
+
+    let mut ops = number_to_reg(value >> 12, reg);
+    ops.push(Op {
+        opcode: Either::Left(VirtualOp::SLLI(
+            reg.clone(),
+            reg.clone(),
+            VirtualImmediate12 { value: 12 },
+        )),
+        comment: "shift spill slot offset".into(),
+        owning_span: None,
+    });
+    ops.push(Op {
+        opcode: Either::Left(VirtualOp::ORI(
+            reg.clone(),
+            reg.clone(),
+            VirtualImmediate12 {
+                value: (value & compiler_constants::TWELVE_BITS) as u16,
+            },
+        )),
+        comment: "get spill slot offset low bits".into(),
+        owning_span: None,
+    });
+    ops
+}
+
+/// Given a list of instructions `ops` and the virtual registers `spills` which could not be
+/// assigned a register, generate a new list of instructions where each spilled register is kept
+/// in a slot of the function's spill area instead.
+///
+/// Each instruction using a spilled register is preceded by a load of its slot into a new
+/// temporary register, and each instruction defining a spilled register is followed by a store
+/// of a new temporary register to its slot. The live range of each temporary spans at most a
+/// couple of instructions, so the interference graph built from the new list of instructions is
+/// easier to color.
+///
+/// If none of the `spills` may be spilled, as they were all introduced by a previous round of
+/// spilling, then spilling can't make any progress and an error is returned.
+///
+pub(crate) fn spill(
+    ops: &[Op],
+    spills: &[VirtualRegister],
+    spill_area: &mut SpillArea,
+    register_sequencer: &mut RegisterSequencer,
+) -> Result<Vec<Op>, CompileError> {
+    let spillable = spills
+        .iter()
+        .filter(|reg| spill_area.unspillable_registers().all(|r| r != *reg))
+        .collect::<BTreeSet<_>>();
+    if spillable.is_empty() {
+        // Point at the first instruction involving one of the registers, if it came from source.
+        let span = ops
+            .iter()
+            .filter(|op| op.registers().iter().any(|reg| spills.contains(reg)))
+            .find_map(|op| op.owning_span.clone())
+            .unwrap_or_else(Span::dummy);
+        return Err(CompileError::RegisterAllocationFailed { span });
+    }
+
+    let mut spilled_ops = Vec::with_capacity(ops.len());
+    if spill_area.slots.is_empty() {
+        // Save the address of the spill area, which is allocated right after this instruction.
+        let allocation_ix = SpillArea::allocation_index(ops);
+        spilled_ops.extend_from_slice(&ops[..allocation_ix]);
+        spilled_ops.push(Op::register_move(
+            spill_area.base_reg.clone(),
+            VirtualRegister::Constant(ConstantRegister::StackPointer),
+            "save spill area base register",
+            None,
+        ));
+        spilled_ops.extend_from_slice(&ops[allocation_ix..]);
+    } else {
+        spilled_ops.extend_from_slice(ops);
+    }
+
+    let slots = spillable
+        .into_iter()
+        .map(|reg| {
+            let slot = spill_area.slots.len() as u64;
+            spill_area.slots.push(reg.clone());
+            (reg.clone(), slot)
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut new_ops = Vec::with_capacity(spilled_ops.len());
+    for op in spilled_ops {
+        let uses = op
+            .use_registers()
+            .into_iter()
+            .filter(|reg| slots.contains_key(reg))
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        let defs = op
+            .def_registers()
+            .into_iter()
+            .filter(|reg| slots.contains_key(reg))
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        if uses.is_empty() && defs.is_empty() {
+            new_ops.push(op);
+            continue;
+        }
+
+        let temps = uses
+            .union(&defs)
+            .map(|reg| (reg.clone(), register_sequencer.next()))
+            .collect::<HashMap<_, _>>();
+        for reg in &uses {
+            let (addr_ops, addr_reg, offset) =
+                spill_area.slot_address(slots[reg], register_sequencer);
+            new_ops.extend(addr_ops);
+            new_ops.push(Op {
+                opcode: Either::Left(VirtualOp::LW(temps[reg].clone(), addr_reg, offset)),
+                comment: format!("reload spilled register {}", reg),
+                owning_span: None,
+            });
+        }
+        new_ops.push(op.update_register(&temps));
+        for reg in &defs {
+            let (addr_ops, addr_reg, offset) =
+                spill_area.slot_address(slots[reg], register_sequencer);
+            new_ops.extend(addr_ops);
+            new_ops.push(Op {
+                opcode: Either::Left(VirtualOp::SW(addr_reg, temps[reg].clone(), offset)),
+                comment: format!("spill register {}", reg),
+                owning_span: None,
+            });
+        }
+        spill_area.temps.extend(temps.into_values());
+    }
+
+    Ok(new_ops)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn op(opcode: VirtualOp) -> Op {
+        Op {
+            opcode: Either::Left(opcode),
+            comment: String::new(),
+            owning_span: None,
+        }
+    }
+
+    fn movi(reg: &VirtualRegister, value: u32) -> Op {
+        op(VirtualOp::MOVI(reg.clone(), VirtualImmediate18 { value }))
+    }
+
+    fn add(dst: &VirtualRegister, lhs: &VirtualRegister, rhs: &VirtualRegister) -> Op {
+        op(VirtualOp::ADD(dst.clone(), lhs.clone(), rhs.clone()))
+    }
+
+    fn opcodes(ops: &[Op]) -> Vec<String> {
+        ops.iter().map(|op| op.opcode.to_string()).collect()
+    }
+
+    #[test]
+    fn spill_costs() {
+        let mut register_sequencer = RegisterSequencer::new();
+        let a = register_sequencer.next();
+        let b = register_sequencer.next();
+        let one = VirtualRegister::Constant(ConstantRegister::One);
+        let ops = vec![movi(&a, 1), add(&b, &a, &a), add(&b, &b, &one)];
+
+        let costs = compute_spill_costs(&ops, None);
+        assert_eq!(costs.len(), 2);
+        assert_eq!(costs[&a], 2.0);
+        assert_eq!(costs[&b], 2.0);
+
+        // Once `a` is spilled, the registers introduced to keep it in the spill area may not be
+        // spilled themselves.
+        let mut spill_area = SpillArea::new(&mut register_sequencer);
+        let ops = spill(
+            &ops,
+            std::slice::from_ref(&a),
+            &mut spill_area,
+            &mut register_sequencer,
+        )
+        .unwrap();
+        let costs = compute_spill_costs(&ops, Some(&spill_area));
+        assert!(!costs.contains_key(&a));
+        assert_eq!(costs[&b], 2.0);
+        assert_eq!(costs.len(), 4);
+        assert!(spill_area
+            .unspillable_registers()
+            .all(|reg| costs[reg] == f64::INFINITY));
+    }
+
+    #[test]
+    fn spill_registers() {
+        let mut register_sequencer = RegisterSequencer::new();
+        let a = register_sequencer.next();
+        let b = register_sequencer.next();
+        let c = register_sequencer.next();
+        let ops = vec![movi(&a, 1), add(&b, &a, &a), add(&c, &b, &a)];
+
+        // Spilling `a` keeps it in the first slot of the spill area, whose address is saved first.
+        let mut spill_area = SpillArea::new(&mut register_sequencer);
+        let ops = spill(&ops, &[a], &mut spill_area, &mut register_sequencer).unwrap();
+        assert_eq!(
+            opcodes(&ops),
+            [
+                "move $r3 $sp",
+                "movi $r4 i1",
+                "sw $r3 $r4 i0",
+                "lw $r5 $r3 i0",
+                "add $r1 $r5 $r5",
+                "lw $r6 $r3 i0",
+                "add $r2 $r1 $r6",
+            ]
+        );
+
+        // Spilling again reuses the spill area, keeping `c` in the next slot.
+        let ops = spill(&ops, &[c], &mut spill_area, &mut register_sequencer).unwrap();
+        assert_eq!(
+            opcodes(&ops[5..]),
+            ["lw $r6 $r3 i0", "add $r7 $r1 $r6", "sw $r3 $r7 i1"]
+        );
+
+        // The spill area is allocated after the registers of a function are saved, and freed
+        // before they're restored.
+        let label = register_sequencer.get_label();
+        let mut ops = ops;
+        ops.insert(0, Op::unowned_jump_label(label));
+        ops.insert(
+            1,
+            Op {
+                opcode: Either::Right(OrganizationalOp::PushAll(label)),
+                comment: String::new(),
+                owning_span: None,
+            },
+        );
+        ops.push(Op {
+            opcode: Either::Right(OrganizationalOp::PopAll(label)),
+            comment: String::new(),
+            owning_span: None,
+        });
+        let ops = spill_area.allocate(ops);
+        assert_eq!(
+            opcodes(&ops[..5]),
+            [".0", "pusha .0", "move $r3 $sp", "cfei i16", "movi $r4 i1"]
+        );
+        assert_eq!(opcodes(&ops[ops.len() - 2..]), ["cfsi i16", "popa .0"]);
+    }
+
+    #[test]
+    fn spill_to_distant_slots() {
+        let mut register_sequencer = RegisterSequencer::new();
+        let a = register_sequencer.next();
+        let b = register_sequencer.next();
+        let ops = vec![movi(&a, 1), add(&b, &a, &a)];
+
+        // Slots beyond the reach of an immediate word offset are addressed using a register.
+        let mut spill_area = SpillArea::new(&mut register_sequencer);
+        spill_area.slots = vec![VirtualRegister::Constant(ConstantRegister::Zero); 5_000];
+        let spilled = spill(
+            &ops,
+            std::slice::from_ref(&a),
+            &mut spill_area,
+            &mut register_sequencer,
+        )
+        .unwrap();
+        assert_eq!(
+            opcodes(&spilled),
+            [
+                "movi $r3 i1",
+                "movi $r4 i40000",
+                "add $r4 $r2 $r4",
+                "sw $r4 $r3 i0",
+                "movi $r6 i40000",
+                "add $r6 $r2 $r6",
+                "lw $r5 $r6 i0",
+                "add $r1 $r5 $r5",
+            ]
+        );
+        assert!(spill_area
+            .temps
+            .contains(&VirtualRegister::Virtual("6".into())));
+
+        // Offsets too big for a `MOVI` are built up 12 bits at a time.
+        spill_area.slots = vec![VirtualRegister::Constant(ConstantRegister::Zero); 300_000];
+        let spilled = spill(&ops, &[a], &mut spill_area, &mut register_sequencer).unwrap();
+        assert_eq!(
+            opcodes(&spilled[1..6]),
+            [
+                "movi $r8 i585",
+                "slli $r8 $r8 i12",
+                "ori $r8 $r8 i3840",
+                "add $r8 $r2 $r8",
+                "sw $r8 $r7 i0",
+            ]
+        );
+    }
+
+    #[test]
+    fn spill_unspillable_registers() {
+        let mut register_sequencer = RegisterSequencer::new();
+        let a = register_sequencer.next();
+        let b = register_sequencer.next();
+        let ops = vec![movi(&a, 1), add(&b, &a, &a)];
+
+        let mut spill_area = SpillArea::new(&mut register_sequencer);
+        let ops = spill(&ops, &[a], &mut spill_area, &mut register_sequencer).unwrap();
+        let temps = spill_area.temps.iter().cloned().collect::<Vec<_>>();
+        assert!(matches!(
+            spill(&ops, &temps, &mut spill_area, &mut register_sequencer),
+            Err(CompileError::RegisterAllocationFailed { .. })
+        ));
+    }
 }
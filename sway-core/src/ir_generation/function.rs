@@ -1,9 +1,12 @@
 use super::{
-    compile::compile_function, convert::*, lexical_map::LexicalMap, storage::get_storage_key,
+    compile::compile_function,
+    convert::*,
+    lexical_map::LexicalMap,
+    storage::{get_storage_key, get_storage_subkey},
     types::*,
 };
 use crate::{
-    asm_generation::from_ir::ir_type_size_in_bytes,
+    asm_generation::{compiler_constants, from_ir::ir_type_size_in_bytes},
     decl_engine::DeclEngine,
     engine_threading::*,
     ir_generation::const_eval::{
@@ -353,6 +356,7 @@ impl<'eng> FnCompiler<'eng> {
                     md_mgr,
                     &access.fields,
                     &access.ix,
+                    &[],
                     span_md_idx,
                 )
             }
@@ -1862,17 +1866,49 @@ impl<'eng> FnCompiler<'eng> {
             return Ok(rhs);
         }
 
-        // Get the type of the access which can be a subfield
-        let access_type = convert_resolved_typeid_no_span(
-            self.type_engine,
-            context,
-            &fields.last().expect("guaranteed by grammar").type_id,
-        )?;
+        // Get the list of indices used to access the storage field, followed by the subkeys of
+        // the array elements and of their subfields, if any. The field indices will be empty if
+        // the storage field type is not a struct.
+        let mut field_idcs = Vec::new();
+        let mut subkeys = Vec::new();
+        let mut curr_type = fields[0].type_id;
+        for (i, field) in fields.iter().enumerate() {
+            if i > 0 {
+                let field_idx = get_indices_for_struct_access(
+                    self.type_engine,
+                    curr_type,
+                    std::slice::from_ref(field),
+                )?[0];
+                (field_idcs, subkeys) = storage_field_path(&field_idcs, &subkeys, field_idx);
+                curr_type = field.type_id;
+            }
 
-        // Get the list of indices used to access the storage field. This will be empty
-        // if the storage field type is not a struct.
-        let base_type = fields[0].type_id;
-        let field_idcs = get_indices_for_struct_access(self.type_engine, base_type, &fields[1..])?;
+            // Then drill down into the array elements, if any.
+            for index_expr in &field.indices {
+                let array_type =
+                    convert_resolved_typeid_no_span(self.type_engine, context, &curr_type)?;
+                let subkey =
+                    self.compile_storage_array_index(context, md_mgr, &array_type, index_expr)?;
+                if let StorageSubkeyIndex::Dynamic(index_val) = subkey {
+                    if index_val.is_diverging(context) {
+                        return Ok(index_val);
+                    }
+                }
+                subkeys.push(subkey);
+                curr_type = match self.type_engine.get(curr_type) {
+                    TypeInfo::Array(elem_type, _) => elem_type.type_id,
+                    _ => {
+                        return Err(CompileError::Internal(
+                            "Indexing into a non-array storage field.",
+                            index_expr.span.clone(),
+                        ))
+                    }
+                };
+            }
+        }
+
+        // Get the type of the access which can be a subfield or an array element
+        let access_type = convert_resolved_typeid_no_span(self.type_engine, context, &curr_type)?;
 
         // Do the actual work. This is a recursive function because we want to drill down
         // to store each primitive type in the storage field in its own storage slot.
@@ -1881,6 +1917,7 @@ impl<'eng> FnCompiler<'eng> {
             md_mgr,
             ix,
             &field_idcs,
+            &subkeys,
             &access_type,
             rhs,
             span_md_idx,
@@ -1941,6 +1978,19 @@ impl<'eng> FnCompiler<'eng> {
         index_expr: &ty::TyExpression,
         span_md_idx: Option<MetadataIndex>,
     ) -> Result<Value, CompileError> {
+        // Elements of arrays in storage are read on their own, rather than reading the whole array
+        // and then indexing into it.
+        if let Some((access, indices)) = get_storage_array_element_access(array_expr, index_expr) {
+            return self.compile_storage_access(
+                context,
+                md_mgr,
+                &access.fields,
+                &access.ix,
+                &indices,
+                span_md_idx,
+            );
+        }
+
         let array_expr_span = array_expr.span.clone();
 
         let array_val = self.compile_expression(context, md_mgr, array_expr)?;
@@ -2280,10 +2330,11 @@ impl<'eng> FnCompiler<'eng> {
         md_mgr: &mut MetadataManager,
         fields: &[ty::TyStorageAccessDescriptor],
         ix: &StateIndex,
+        indices: &[&ty::TyExpression],
         span_md_idx: Option<MetadataIndex>,
     ) -> Result<Value, CompileError> {
         // Get the type of the access which can be a subfield
-        let access_type = convert_resolved_typeid_no_span(
+        let mut access_type = convert_resolved_typeid_no_span(
             self.type_engine,
             context,
             &fields.last().expect("guaranteed by grammar").type_id,
@@ -2295,9 +2346,136 @@ impl<'eng> FnCompiler<'eng> {
         let base_type = fields[0].type_id;
         let field_idcs = get_indices_for_struct_access(self.type_engine, base_type, &fields[1..])?;
 
+        // Then drill down into the array elements, if any.
+        let mut subkeys = Vec::with_capacity(indices.len());
+        for index_expr in indices {
+            let subkey =
+                self.compile_storage_array_index(context, md_mgr, &access_type, index_expr)?;
+            if let StorageSubkeyIndex::Dynamic(index_val) = subkey {
+                if index_val.is_diverging(context) {
+                    return Ok(index_val);
+                }
+            }
+            subkeys.push(subkey);
+            access_type = access_type.get_array_elem_type(context).unwrap();
+        }
+
         // Do the actual work. This is a recursive function because we want to drill down
         // to load each primitive type in the storage field in its own storage slot.
-        self.compile_storage_read(context, md_mgr, ix, &field_idcs, &access_type, span_md_idx)
+        self.compile_storage_read(
+            context,
+            md_mgr,
+            ix,
+            &field_idcs,
+            &subkeys,
+            &access_type,
+            span_md_idx,
+        )
+    }
+
+    /// Compile the index of an element of an array in storage. Constant indices are checked
+    /// against the length of the array and hashed into the storage key at compile time, while
+    /// any other index is checked at runtime, reverting if it is out of bounds.
+    fn compile_storage_array_index(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        array_type: &Type,
+        index_expr: &ty::TyExpression,
+    ) -> Result<StorageSubkeyIndex, CompileError> {
+        if let Ok(Constant {
+            value: ConstantValue::Uint(constant_value),
+            ..
+        }) = compile_constant_expression_to_constant(
+            Engines::new(self.type_engine, self.decl_engine),
+            context,
+            md_mgr,
+            self.module,
+            None,
+            Some(self),
+            index_expr,
+        ) {
+            let count = array_type.get_array_len(context).unwrap();
+            if constant_value >= count {
+                return Err(CompileError::ArrayOutOfBounds {
+                    index: constant_value,
+                    count,
+                    span: index_expr.span.clone(),
+                });
+            }
+            return Ok(StorageSubkeyIndex::Const(constant_value));
+        }
+
+        let index_val = self.compile_expression(context, md_mgr, index_expr)?;
+        if index_val.is_diverging(context) {
+            return Ok(StorageSubkeyIndex::Dynamic(index_val));
+        }
+
+        // Check the index is within the bounds of the array, i.e.
+        // `asm(index: index_val, len: len_val, in_bounds) { lt in_bounds index len; in_bounds }`.
+        let span_md_idx = md_mgr.span_to_md(context, &index_expr.span);
+        let len_val = Constant::get_uint(context, 64, array_type.get_array_len(context).unwrap());
+        let registers = vec![
+            AsmArg {
+                name: Ident::new_no_span("index"),
+                initializer: Some(index_val),
+            },
+            AsmArg {
+                name: Ident::new_no_span("len"),
+                initializer: Some(len_val),
+            },
+            AsmArg {
+                name: Ident::new_no_span("in_bounds"),
+                initializer: None,
+            },
+        ];
+        let body = vec![AsmInstruction {
+            name: Ident::new_no_span("lt"),
+            args: vec![
+                Ident::new_no_span("in_bounds"),
+                Ident::new_no_span("index"),
+                Ident::new_no_span("len"),
+            ],
+            immediate: None,
+            metadata: span_md_idx,
+        }];
+        let bool_ty = Type::get_bool(context);
+        let in_bounds_val = self
+            .current_block
+            .ins(context)
+            .asm_block(
+                registers,
+                body,
+                bool_ty,
+                Some(Ident::new_no_span("in_bounds")),
+            )
+            .add_metadatum(context, span_md_idx);
+
+        let in_bounds_block = self.function.create_block(context, None);
+        let out_of_bounds_block = self.function.create_block(context, None);
+        self.current_block
+            .ins(context)
+            .conditional_branch(
+                in_bounds_val,
+                in_bounds_block,
+                out_of_bounds_block,
+                vec![],
+                vec![],
+            )
+            .add_metadatum(context, span_md_idx);
+
+        let revert_code_val = Constant::get_uint(
+            context,
+            64,
+            compiler_constants::STORAGE_ARRAY_INDEX_OUT_OF_BOUNDS_REVERT_CODE,
+        );
+        out_of_bounds_block
+            .ins(context)
+            .revert(revert_code_val)
+            .add_metadatum(context, span_md_idx);
+
+        self.current_block = in_bounds_block;
+        Ok(StorageSubkeyIndex::Dynamic(index_val))
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -2357,12 +2535,14 @@ impl<'eng> FnCompiler<'eng> {
             .add_metadatum(context, whole_block_span_md_idx))
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn compile_storage_read(
         &mut self,
         context: &mut Context,
         _md_mgr: &mut MetadataManager,
         ix: &StateIndex,
         indices: &[u64],
+        subkeys: &[StorageSubkeyIndex],
         ty: &Type,
         span_md_idx: Option<MetadataIndex>,
     ) -> Result<Value, CompileError> {
@@ -2386,14 +2566,15 @@ impl<'eng> FnCompiler<'eng> {
                     let field_idx = field_idx as u64;
 
                     // Recurse. The base case is for primitive types that fit in a single storage slot.
-                    let mut new_indices = indices.to_owned();
-                    new_indices.push(field_idx);
+                    let (new_indices, new_subkeys) =
+                        storage_field_path(indices, subkeys, field_idx);
 
                     let val_to_insert = self.compile_storage_read(
                         context,
                         _md_mgr,
                         ix,
                         &new_indices,
+                        &new_subkeys,
                         &field_type,
                         span_md_idx,
                     )?;
@@ -2407,47 +2588,53 @@ impl<'eng> FnCompiler<'eng> {
                 }
                 Ok(struct_val)
             }
-            _ => {
-                let storage_key = get_storage_key(ix, indices);
-
-                // New name for the key
-                let mut key_name = format!("{}{}", "key_for_", ix.to_usize());
-                for ix in indices {
-                    key_name = format!("{}_{}", key_name, ix);
-                }
-                let alias_key_name = self.lexical_map.insert(key_name.as_str().to_owned());
-
-                // Local pointer for the key
-                let key_var = self
+            ty if ty.is_array(context) => {
+                let temp_name = self.lexical_map.insert_anon();
+                let array_var = self
                     .function
-                    .new_local_var(context, alias_key_name, Type::get_b256(context), None)
+                    .new_local_var(context, temp_name, *ty, None)
                     .map_err(|ir_error| {
                         CompileError::InternalOwned(ir_error.to_string(), Span::dummy())
                     })?;
-
-                // Const value for the key from the hash
-                let const_key =
-                    convert_literal_to_value(context, &Literal::B256(storage_key.into()))
-                        .add_metadatum(context, span_md_idx);
-
-                // Convert the key pointer to a value using get_ptr
-                let key_val = self
+                let mut array_val = self
                     .current_block
                     .ins(context)
-                    .get_local(key_var)
+                    .get_local(array_var)
                     .add_metadatum(context, span_md_idx);
 
-                // Store the const hash value to the key pointer value
-                self.current_block
-                    .ins(context)
-                    .store(key_val, const_key)
-                    .add_metadatum(context, span_md_idx);
+                let elem_type = ty.get_array_elem_type(context).unwrap();
+                for elem_idx in 0..ty.get_array_len(context).unwrap() {
+                    // Recurse. Each element is in storage slots of its own, like a struct field.
+                    let mut new_subkeys = subkeys.to_owned();
+                    new_subkeys.push(StorageSubkeyIndex::Const(elem_idx));
+
+                    let val_to_insert = self.compile_storage_read(
+                        context,
+                        _md_mgr,
+                        ix,
+                        indices,
+                        &new_subkeys,
+                        &elem_type,
+                        span_md_idx,
+                    )?;
+
+                    // Insert the loaded value to the array at the given index
+                    let index_val = Constant::get_uint(context, 64, elem_idx)
+                        .add_metadatum(context, span_md_idx);
+                    array_val = self
+                        .current_block
+                        .ins(context)
+                        .insert_element(array_val, *ty, val_to_insert, index_val)
+                        .add_metadatum(context, span_md_idx);
+                }
+                Ok(array_val)
+            }
+            _ => {
+                let key_val =
+                    self.compile_storage_key(context, ix, indices, subkeys, span_md_idx)?;
 
                 match ty.get_content(context) {
-                    TypeContent::Array(..) => Err(CompileError::Internal(
-                        "Arrays in storage have not been implemented yet.",
-                        Span::dummy(),
-                    )),
+                    TypeContent::Array(..) => unreachable!("arrays are already handled!"),
                     TypeContent::Slice => Err(CompileError::Internal(
                         "Slices in storage have not been implemented yet.",
                         Span::dummy(),
@@ -2483,6 +2670,7 @@ impl<'eng> FnCompiler<'eng> {
         _md_mgr: &mut MetadataManager,
         ix: &StateIndex,
         indices: &[u64],
+        subkeys: &[StorageSubkeyIndex],
         ty: &Type,
         rhs: Value,
         span_md_idx: Option<MetadataIndex>,
@@ -2494,8 +2682,8 @@ impl<'eng> FnCompiler<'eng> {
                     let field_idx = field_idx as u64;
 
                     // Recurse. The base case is for primitive types that fit in a single storage slot.
-                    let mut new_indices = indices.to_owned();
-                    new_indices.push(field_idx);
+                    let (new_indices, new_subkeys) =
+                        storage_field_path(indices, subkeys, field_idx);
 
                     // Extract the value from the aggregate at the given index
                     let rhs = self
@@ -2509,6 +2697,7 @@ impl<'eng> FnCompiler<'eng> {
                         _md_mgr,
                         ix,
                         &new_indices,
+                        &new_subkeys,
                         &field_type,
                         rhs,
                         span_md_idx,
@@ -2516,47 +2705,41 @@ impl<'eng> FnCompiler<'eng> {
                 }
                 Ok(())
             }
-            _ => {
-                let storage_key = get_storage_key(ix, indices);
-
-                // New name for the key
-                let mut key_name = format!("{}{}", "key_for_", ix.to_usize());
-                for ix in indices {
-                    key_name = format!("{}_{}", key_name, ix);
-                }
-                let alias_key_name = self.lexical_map.insert(key_name.as_str().to_owned());
-
-                // Local pointer for the key
-                let key_var = self
-                    .function
-                    .new_local_var(context, alias_key_name, Type::get_b256(context), None)
-                    .map_err(|ir_error| {
-                        CompileError::InternalOwned(ir_error.to_string(), Span::dummy())
-                    })?;
-
-                // Const value for the key from the hash
-                let const_key =
-                    convert_literal_to_value(context, &Literal::B256(storage_key.into()))
+            ty if ty.is_array(context) => {
+                let elem_type = ty.get_array_elem_type(context).unwrap();
+                for elem_idx in 0..ty.get_array_len(context).unwrap() {
+                    // Recurse. Each element is in storage slots of its own, like a struct field.
+                    let mut new_subkeys = subkeys.to_owned();
+                    new_subkeys.push(StorageSubkeyIndex::Const(elem_idx));
+
+                    // Extract the value from the array at the given index
+                    let index_val = Constant::get_uint(context, 64, elem_idx)
+                        .add_metadatum(context, span_md_idx);
+                    let rhs = self
+                        .current_block
+                        .ins(context)
+                        .extract_element(rhs, *ty, index_val)
                         .add_metadatum(context, span_md_idx);
 
-                // Convert the key pointer to a value using get_ptr
-                let key_val = self
-                    .current_block
-                    .ins(context)
-                    .get_local(key_var)
-                    .add_metadatum(context, span_md_idx);
-
-                // Store the const hash value to the key pointer value
-                self.current_block
-                    .ins(context)
-                    .store(key_val, const_key)
-                    .add_metadatum(context, span_md_idx);
+                    self.compile_storage_write(
+                        context,
+                        _md_mgr,
+                        ix,
+                        indices,
+                        &new_subkeys,
+                        &elem_type,
+                        rhs,
+                        span_md_idx,
+                    )?;
+                }
+                Ok(())
+            }
+            _ => {
+                let key_val =
+                    self.compile_storage_key(context, ix, indices, subkeys, span_md_idx)?;
 
                 match ty.get_content(context) {
-                    TypeContent::Array(..) => Err(CompileError::Internal(
-                        "Arrays in storage have not been implemented yet.",
-                        Span::dummy(),
-                    )),
+                    TypeContent::Array(..) => unreachable!("arrays are already handled!"),
                     TypeContent::Slice => Err(CompileError::Internal(
                         "Slices in storage have not been implemented yet.",
                         Span::dummy(),
@@ -2589,6 +2772,135 @@ impl<'eng> FnCompiler<'eng> {
         }
     }
 
+    /// Compile the storage key of the value at `indices` within the storage field `ix`, or at
+    /// `subkeys` within that if the value is in an array, into a local and return the local.
+    fn compile_storage_key(
+        &mut self,
+        context: &mut Context,
+        ix: &StateIndex,
+        indices: &[u64],
+        subkeys: &[StorageSubkeyIndex],
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        // Hash in as many subkeys as are known at compile time. Any which follow an index which
+        // is only known at runtime are hashed in at runtime.
+        let mut storage_key = get_storage_key(ix, indices);
+        let mut subkeys = subkeys.iter().peekable();
+        while let Some(StorageSubkeyIndex::Const(idx)) = subkeys.peek() {
+            storage_key = get_storage_subkey(&storage_key, *idx);
+            subkeys.next();
+        }
+
+        // New name for the key
+        let mut key_name = format!("{}{}", "key_for_", ix.to_usize());
+        for ix in indices {
+            key_name = format!("{}_{}", key_name, ix);
+        }
+        let alias_key_name = self.lexical_map.insert(key_name.as_str().to_owned());
+
+        // Local pointer for the key
+        let key_var = self
+            .function
+            .new_local_var(context, alias_key_name, Type::get_b256(context), None)
+            .map_err(|ir_error| CompileError::InternalOwned(ir_error.to_string(), Span::dummy()))?;
+
+        // Const value for the key from the hash
+        let const_key = convert_literal_to_value(context, &Literal::B256(storage_key.into()))
+            .add_metadatum(context, span_md_idx);
+
+        // Convert the key pointer to a value using get_ptr
+        let key_val = self
+            .current_block
+            .ins(context)
+            .get_local(key_var)
+            .add_metadatum(context, span_md_idx);
+
+        // Store the const hash value to the key pointer value
+        self.current_block
+            .ins(context)
+            .store(key_val, const_key)
+            .add_metadatum(context, span_md_idx);
+
+        for subkey in subkeys {
+            let index_val = match subkey {
+                StorageSubkeyIndex::Const(idx) => Constant::get_uint(context, 64, *idx),
+                StorageSubkeyIndex::Dynamic(index_val) => *index_val,
+            };
+            self.compile_storage_subkey(context, key_val, index_val, span_md_idx)?;
+        }
+
+        Ok(key_val)
+    }
+
+    /// Replace the storage key in `key_val` with the key of the element or subfield at
+    /// `index_val` within it, i.e. sha256(<key> ++ <index>), the same as `get_storage_subkey`.
+    fn compile_storage_subkey(
+        &mut self,
+        context: &mut Context,
+        key_val: Value,
+        index_val: Value,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<(), CompileError> {
+        // Lay out the key and the index next to each other to be hashed.
+        let b256_ty = Type::get_b256(context);
+        let u64_ty = Type::get_uint64(context);
+        let preimage_ty = Type::new_struct(context, vec![b256_ty, u64_ty]);
+        let temp_name = self.lexical_map.insert_anon();
+        let preimage_var = self
+            .function
+            .new_local_var(context, temp_name, preimage_ty, None)
+            .map_err(|ir_error| CompileError::InternalOwned(ir_error.to_string(), Span::dummy()))?;
+        let mut preimage_val = self
+            .current_block
+            .ins(context)
+            .get_local(preimage_var)
+            .add_metadatum(context, span_md_idx);
+        preimage_val = self
+            .current_block
+            .ins(context)
+            .insert_value(preimage_val, preimage_ty, key_val, vec![0])
+            .add_metadatum(context, span_md_idx);
+        preimage_val = self
+            .current_block
+            .ins(context)
+            .insert_value(preimage_val, preimage_ty, index_val, vec![1])
+            .add_metadatum(context, span_md_idx);
+        let preimage_size_val =
+            Constant::get_uint(context, 64, ir_type_size_in_bytes(context, &preimage_ty));
+
+        // Hash them over the top of the old key.
+        let registers = vec![
+            AsmArg {
+                name: Ident::new_no_span("key"),
+                initializer: Some(key_val),
+            },
+            AsmArg {
+                name: Ident::new_no_span("preimage"),
+                initializer: Some(preimage_val),
+            },
+            AsmArg {
+                name: Ident::new_no_span("size"),
+                initializer: Some(preimage_size_val),
+            },
+        ];
+        let body = vec![AsmInstruction {
+            name: Ident::new_no_span("s256"),
+            args: vec![
+                Ident::new_no_span("key"),
+                Ident::new_no_span("preimage"),
+                Ident::new_no_span("size"),
+            ],
+            immediate: None,
+            metadata: span_md_idx,
+        }];
+        let unit_ty = Type::get_unit(context);
+        self.current_block
+            .ins(context)
+            .asm_block(registers, body, unit_ty, None)
+            .add_metadatum(context, span_md_idx);
+        Ok(())
+    }
+
     fn compile_uint_or_bool_storage_read(
         &mut self,
         context: &mut Context,
@@ -2862,3 +3174,52 @@ impl<'eng> FnCompiler<'eng> {
         Ok(())
     }
 }
+
+/// The index of an element of an array in storage, or of a subfield within such an element. The
+/// storage keys of these are found by hashing the indices into the key of the array, in turn.
+#[derive(Clone, Copy)]
+enum StorageSubkeyIndex {
+    Const(u64),
+    Dynamic(Value),
+}
+
+/// Extend the path to a value in storage with the index of one of its struct fields. The field is
+/// found using its index in the list of subfield indices unless it is within an array, in which
+/// case its index is hashed into the key of the array element as a subkey.
+fn storage_field_path(
+    indices: &[u64],
+    subkeys: &[StorageSubkeyIndex],
+    field_idx: u64,
+) -> (Vec<u64>, Vec<StorageSubkeyIndex>) {
+    let mut new_indices = indices.to_owned();
+    let mut new_subkeys = subkeys.to_owned();
+    if subkeys.is_empty() {
+        new_indices.push(field_idx);
+    } else {
+        new_subkeys.push(StorageSubkeyIndex::Const(field_idx));
+    }
+    (new_indices, new_subkeys)
+}
+
+/// If `array_expr[index_expr]` indexes into an array in storage, possibly through other arrays in
+/// storage as in `storage.a.b[i][j]`, return the storage access and the indices into the arrays,
+/// outermost first.
+fn get_storage_array_element_access<'a>(
+    mut array_expr: &'a ty::TyExpression,
+    index_expr: &'a ty::TyExpression,
+) -> Option<(&'a ty::TyStorageAccess, Vec<&'a ty::TyExpression>)> {
+    let mut indices = vec![index_expr];
+    loop {
+        match &array_expr.expression {
+            ty::TyExpressionVariant::ArrayIndex { prefix, index } => {
+                indices.push(index);
+                array_expr = prefix;
+            }
+            ty::TyExpressionVariant::StorageAccess(access) => {
+                indices.reverse();
+                return Some((access, indices));
+            }
+            _ => return None,
+        }
+    }
+}
@@ -30,6 +30,57 @@ where
     ))
 }
 
+/// Hands out storage keys for the elements of arrays in storage, and for everything nested within
+/// those elements, using the key of the parent and the index of the element or subfield.
+/// Basically returns sha256(<key> ++ <idx>), with the index as a big endian word, so that the key
+/// can also be computed at runtime when the index of an element is not known statically.
+///
+pub(super) fn get_storage_subkey(key: &Bytes32, idx: u64) -> Bytes32 {
+    Hasher::default()
+        .chain(key)
+        .chain(idx.to_be_bytes())
+        .finalize()
+}
+
+/// The location of a value in storage, from which the keys of its storage slots are derived.
+///
+/// Values outside of arrays are found using their state index and the list of subfield indices
+/// leading to them, as in `get_storage_key`. Array elements and their subfields are found by
+/// hashing their indices into the key of their parent, as in `get_storage_subkey`.
+///
+#[derive(Clone)]
+enum StorageLocation<'a> {
+    Path(&'a StateIndex, Vec<usize>),
+    Key(Bytes32),
+}
+
+impl<'a> StorageLocation<'a> {
+    fn key(&self) -> Bytes32 {
+        match self {
+            StorageLocation::Path(ix, indices) => get_storage_key(ix, indices),
+            StorageLocation::Key(key) => *key,
+        }
+    }
+
+    fn field(&self, idx: usize) -> StorageLocation<'a> {
+        match self {
+            StorageLocation::Path(ix, indices) => StorageLocation::Path(
+                ix,
+                indices
+                    .iter()
+                    .cloned()
+                    .chain(std::iter::once(idx))
+                    .collect(),
+            ),
+            StorageLocation::Key(key) => StorageLocation::Key(get_storage_subkey(key, idx as u64)),
+        }
+    }
+
+    fn element(&self, idx: usize) -> StorageLocation<'a> {
+        StorageLocation::Key(get_storage_subkey(&self.key(), idx as u64))
+    }
+}
+
 use uint::construct_uint;
 
 #[allow(
@@ -52,6 +103,8 @@ pub(super) fn add_to_b256(x: Bytes32, y: u64) -> Bytes32 {
 /// generated using the state index and the subfield indices which are recursively built. The
 /// values are generated such that each subfield gets its own storage slot except for enums and
 /// strings which are spread over successive storage slots (use `serialize_to_words` in this case).
+/// Array elements get storage slots of their own, the same as subfields, but their keys are
+/// generated using `get_storage_subkey`.
 ///
 /// This behavior matches the behavior of how storage slots are assigned for storage reads and
 /// writes (i.e. how `state_read_*` and `state_write_*` instructions are generated).
@@ -62,16 +115,29 @@ pub fn serialize_to_storage_slots(
     ix: &StateIndex,
     ty: &Type,
     indices: &[usize],
+) -> Vec<StorageSlot> {
+    serialize_to_storage_slots_at(
+        constant,
+        context,
+        &StorageLocation::Path(ix, indices.to_vec()),
+        ty,
+    )
+}
+
+fn serialize_to_storage_slots_at(
+    constant: &Constant,
+    context: &Context,
+    location: &StorageLocation,
+    ty: &Type,
 ) -> Vec<StorageSlot> {
     match &constant.value {
         ConstantValue::Undef => vec![],
-        ConstantValue::Unit if ty.is_unit(context) => vec![StorageSlot::new(
-            get_storage_key(ix, indices),
-            Bytes32::new([0; 32]),
-        )],
+        ConstantValue::Unit if ty.is_unit(context) => {
+            vec![StorageSlot::new(location.key(), Bytes32::new([0; 32]))]
+        }
         ConstantValue::Bool(b) if ty.is_bool(context) => {
             vec![StorageSlot::new(
-                get_storage_key(ix, indices),
+                location.key(),
                 Bytes32::new(
                     [0; 7]
                         .iter()
@@ -86,7 +152,7 @@ pub fn serialize_to_storage_slots(
         }
         ConstantValue::Uint(n) if ty.is_uint(context) => {
             vec![StorageSlot::new(
-                get_storage_key(ix, indices),
+                location.key(),
                 Bytes32::new(
                     n.to_be_bytes()
                         .iter()
@@ -99,13 +165,17 @@ pub fn serialize_to_storage_slots(
             )]
         }
         ConstantValue::B256(b) if ty.is_b256(context) => {
-            vec![StorageSlot::new(
-                get_storage_key(ix, indices),
-                Bytes32::new(*b),
-            )]
+            vec![StorageSlot::new(location.key(), Bytes32::new(*b))]
         }
-        ConstantValue::Array(_a) if ty.is_array(context) => {
-            unimplemented!("Arrays in storage have not been implemented yet.")
+        ConstantValue::Array(elems) if ty.is_array(context) => {
+            let elem_ty = ty.get_array_elem_type(context).unwrap();
+            elems
+                .iter()
+                .enumerate()
+                .flat_map(|(i, elem)| {
+                    serialize_to_storage_slots_at(elem, context, &location.element(i), &elem_ty)
+                })
+                .collect()
         }
         ConstantValue::Struct(vec) if ty.is_struct(context) => {
             let field_tys = ty.get_field_types(context);
@@ -113,17 +183,7 @@ pub fn serialize_to_storage_slots(
                 .zip(field_tys.iter())
                 .enumerate()
                 .flat_map(|(i, (f, ty))| {
-                    serialize_to_storage_slots(
-                        f,
-                        context,
-                        ix,
-                        ty,
-                        &indices
-                            .iter()
-                            .cloned()
-                            .chain(vec![i].iter().cloned())
-                            .collect::<Vec<usize>>(),
-                    )
+                    serialize_to_storage_slots_at(f, context, &location.field(i), ty)
                 })
                 .collect()
         }
@@ -142,7 +202,7 @@ pub fn serialize_to_storage_slots(
             // First get the keys then get the values
             (0..(ir_type_size_in_bytes(context, ty) + 31) / 32)
                 .into_iter()
-                .map(|i| add_to_b256(get_storage_key(ix, indices), i))
+                .map(|i| add_to_b256(location.key(), i))
                 .zip((0..packed.len() / 4).into_iter().map(|i| {
                     Bytes32::new(
                         Vec::from_iter((0..4).into_iter().flat_map(|j| *packed[4 * i + j]))
@@ -199,8 +259,12 @@ pub fn serialize_to_words(constant: &Constant, context: &Context, ty: &Type) ->
                 )
             }))
         }
-        ConstantValue::Array(_) if ty.is_array(context) => {
-            unimplemented!("Arrays in storage have not been implemented yet.")
+        ConstantValue::Array(elems) if ty.is_array(context) => {
+            let elem_ty = ty.get_array_elem_type(context).unwrap();
+            elems
+                .iter()
+                .flat_map(|elem| serialize_to_words(elem, context, &elem_ty))
+                .collect()
         }
         ConstantValue::Struct(vec) if ty.is_struct(context) => {
             let field_tys = ty.get_field_types(context);
@@ -219,11 +219,20 @@ pub enum ExpressionKind {
 
 /// Represents the left hand side of a reassignment, which could either be a regular variable
 /// expression, denoted by [ReassignmentTarget::VariableExpression], or, a storage field, denoted
-/// by [ReassignmentTarget::StorageField].
+/// by [ReassignmentTarget::StorageField]. Any field of a storage field may be followed by indices
+/// into an array, e.g. `storage.a[i].b[j][k]`.
 #[derive(Debug, Clone)]
 pub enum ReassignmentTarget {
     VariableExpression(Box<Expression>),
-    StorageField(Vec<Ident>),
+    StorageField(Vec<StorageReassignDescriptor>),
+}
+
+/// Describes a single subfield access in the sequence when reassigning to a subfield within
+/// storage, along with the indices of the array elements within it being reassigned, if any.
+#[derive(Debug, Clone)]
+pub struct StorageReassignDescriptor {
+    pub name: Ident,
+    pub indices: Vec<Expression>,
 }
 
 #[derive(Debug, Clone)]
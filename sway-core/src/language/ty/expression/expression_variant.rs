@@ -807,13 +807,13 @@ impl DisplayWithEngines for TyExpressionVariant {
                 format!("reassignment to {}", place)
             }
             TyExpressionVariant::StorageReassignment(storage_reassignment) => {
-                let place: String = {
-                    storage_reassignment
-                        .fields
-                        .iter()
-                        .map(|field| field.name.as_str())
-                        .collect()
-                };
+                let mut place = String::new();
+                for field in &storage_reassignment.fields {
+                    place.push_str(field.name.as_str());
+                    for index in &field.indices {
+                        write!(&mut place, "[{:#?}]", index).unwrap();
+                    }
+                }
                 format!("storage reassignment to {}", place)
             }
             TyExpressionVariant::Return(exp) => {
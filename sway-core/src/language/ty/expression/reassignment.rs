@@ -149,11 +149,13 @@ impl TyStorageReassignment {
 }
 
 /// Describes a single subfield access in the sequence when reassigning to a subfield within
-/// storage.
+/// storage, followed by the indices of the array elements within it being drilled down into, if
+/// the subfield is an array.
 #[derive(Clone, Debug)]
 pub struct TyStorageReassignDescriptor {
     pub name: Ident,
     pub type_id: TypeId,
+    pub indices: Vec<TyExpression>,
     pub(crate) span: Span,
 }
 
@@ -168,5 +170,6 @@ impl PartialEqWithEngines for TyStorageReassignDescriptor {
             && type_engine
                 .get(self.type_id)
                 .eq(&type_engine.get(other.type_id), engines)
+            && self.indices.eq(&other.indices, engines)
     }
 }
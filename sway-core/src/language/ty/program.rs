@@ -13,6 +13,8 @@ use sway_types::*;
 
 use fuel_abi_types::program_abi;
 
+use std::collections::BTreeMap;
+
 #[derive(Debug, Clone)]
 pub struct TyProgram {
     pub kind: TyProgramKind,
@@ -601,6 +603,33 @@ impl TyProgram {
             .flat_map(|(_, submod)| submod.module.test_fns(decl_engine))
             .chain(self.root.test_fns(decl_engine))
     }
+
+    /// Generate a JSON ABI for each test function that takes parameters, so that test runners
+    /// are able to encode the arguments with which these tests are executed.
+    ///
+    /// The ABIs are keyed by the index of each test's declaration, as tests declared in different
+    /// modules may share a name.
+    pub fn generate_json_abi_test_fns(
+        &self,
+        decl_engine: &DeclEngine,
+        type_engine: &TypeEngine,
+    ) -> BTreeMap<usize, program_abi::ProgramABI> {
+        self.test_fns(decl_engine)
+            .filter(|(test_fn, _)| !test_fn.parameters.is_empty())
+            .map(|(test_fn, decl_id)| {
+                let mut types = vec![];
+                let function = test_fn.generate_json_abi_function(type_engine, &mut types);
+                let test_abi = program_abi::ProgramABI {
+                    types,
+                    functions: vec![function],
+                    logged_types: None,
+                    messages_types: None,
+                    configurables: None,
+                };
+                (*decl_id, test_abi)
+            })
+            .collect()
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -508,7 +508,7 @@ pub(crate) fn compile_ast_to_ir_to_asm(
     }
 
     let final_asm = check!(
-        compile_ir_to_asm(&ir, Some(build_config)),
+        compile_ir_to_asm(&ir, &program.storage_slots, Some(build_config)),
         return err(warnings, errors),
         warnings,
         errors
@@ -576,14 +576,6 @@ pub fn inline_function_calls(
             None => {}
         }
 
-        // For now, pending improvements to ASMgen for calls, we must inline any function which has
-        // too many args.
-        if func.args_iter(ctx).count() as u8
-            > crate::asm_generation::compiler_constants::NUM_ARG_REGISTERS
-        {
-            return true;
-        }
-
         // If the function is called only once then definitely inline it.
         if call_counts.get(func).copied().unwrap_or(0) == 1 {
             return true;
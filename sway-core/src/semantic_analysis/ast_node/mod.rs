@@ -16,7 +16,7 @@ use crate::{
 };
 
 use sway_error::{error::CompileError, warning::Warning};
-use sway_types::{span::Span, state::StateIndex, Spanned};
+use sway_types::{integer_bits::IntegerBits, span::Span, state::StateIndex, Spanned};
 
 impl ty::TyAstNode {
     pub(crate) fn type_check(ctx: TypeCheckContext, node: AstNode) -> CompileResult<Self> {
@@ -103,8 +103,8 @@ impl ty::TyAstNode {
 }
 
 pub(crate) fn reassign_storage_subfield(
-    ctx: TypeCheckContext,
-    fields: Vec<Ident>,
+    mut ctx: TypeCheckContext,
+    fields: Vec<StorageReassignDescriptor>,
     rhs: Expression,
     span: Span,
 ) -> CompileResult<ty::TyStorageReassignment> {
@@ -128,75 +128,99 @@ pub(crate) fn reassign_storage_subfield(
         warnings,
         errors
     );
-    let mut type_checked_buf = vec![];
-    let mut fields: Vec<_> = fields.into_iter().rev().collect();
+    let mut type_checked_buf: Vec<ty::TyStorageReassignDescriptor> = vec![];
+    let mut fields = fields.into_iter();
 
-    let first_field = fields.pop().expect("guaranteed by grammar");
+    let first_field = fields.next().expect("guaranteed by grammar");
     let (ix, initial_field_type) = match storage_fields
         .iter()
         .enumerate()
-        .find(|(_, ty::TyStorageField { name, .. })| name == &first_field)
+        .find(|(_, ty::TyStorageField { name, .. })| name == &first_field.name)
     {
         Some((
             ix,
             ty::TyStorageField {
                 type_id: r#type, ..
             },
-        )) => (StateIndex::new(ix), r#type),
+        )) => (StateIndex::new(ix), *r#type),
         None => {
             errors.push(CompileError::StorageFieldDoesNotExist {
-                name: first_field.clone(),
+                name: first_field.name.clone(),
             });
             return err(warnings, errors);
         }
     };
 
-    type_checked_buf.push(ty::TyStorageReassignDescriptor {
-        name: first_field.clone(),
-        type_id: *initial_field_type,
-        span: first_field.span(),
-    });
-
     let update_available_struct_fields = |id: TypeId| match type_engine.get(id) {
         TypeInfo::Struct { fields, .. } => fields,
         _ => vec![],
     };
-    let mut curr_type = *initial_field_type;
-
-    // if the previously iterated type was a struct, put its fields here so we know that,
-    // in the case of a subfield, we can type check the that the subfield exists and its type.
-    let mut available_struct_fields = update_available_struct_fields(*initial_field_type);
+    let mut curr_type = initial_field_type;
 
     // get the initial field's type
-    // make sure the next field exists in that type
-    for field in fields.into_iter().rev() {
-        match available_struct_fields
-            .iter()
-            .find(|x| x.name.as_str() == field.as_str())
-        {
-            Some(struct_field) => {
-                curr_type = struct_field.type_id;
-                type_checked_buf.push(ty::TyStorageReassignDescriptor {
-                    name: field.clone(),
-                    type_id: struct_field.type_id,
-                    span: field.span().clone(),
-                });
-                available_struct_fields = update_available_struct_fields(struct_field.type_id);
-            }
-            None => {
-                let available_fields = available_struct_fields
-                    .iter()
-                    .map(|x| x.name.as_str())
-                    .collect::<Vec<_>>();
-                errors.push(CompileError::FieldNotFound {
-                    field_name: field.clone(),
-                    available_fields: available_fields.join(", "),
-                    struct_name: type_checked_buf.last().unwrap().name.clone(),
-                });
-                return err(warnings, errors);
+    // make sure each next field exists in the type of the field, or array element, before it
+    for (i, field) in std::iter::once(first_field).chain(fields).enumerate() {
+        if i > 0 {
+            // if the previously iterated type was a struct, its fields are available here so we
+            // can type check that the subfield exists and get its type.
+            let available_struct_fields = update_available_struct_fields(curr_type);
+            match available_struct_fields
+                .iter()
+                .find(|x| x.name.as_str() == field.name.as_str())
+            {
+                Some(struct_field) => curr_type = struct_field.type_id,
+                None => {
+                    let available_fields = available_struct_fields
+                        .iter()
+                        .map(|x| x.name.as_str())
+                        .collect::<Vec<_>>();
+                    errors.push(CompileError::FieldNotFound {
+                        field_name: field.name.clone(),
+                        available_fields: available_fields.join(", "),
+                        struct_name: type_checked_buf.last().unwrap().name.clone(),
+                    });
+                    return err(warnings, errors);
+                }
             }
         }
+        let field_type = curr_type;
+
+        // Drill down into the array elements, if any.
+        let mut typed_indices = vec![];
+        for index in field.indices {
+            let elem_type = match type_engine.get(curr_type) {
+                TypeInfo::Array(elem_type, _) => elem_type.type_id,
+                actually => {
+                    errors.push(CompileError::NotIndexable {
+                        name: field.name.to_string(),
+                        span: index.span(),
+                        actually: engines.help_out(actually).to_string(),
+                    });
+                    return err(warnings, errors);
+                }
+            };
+            let type_info_u64 = TypeInfo::UnsignedInteger(IntegerBits::SixtyFour);
+            let ctx = ctx
+                .by_ref()
+                .with_help_text("")
+                .with_type_annotation(type_engine.insert(decl_engine, type_info_u64));
+            typed_indices.push(check!(
+                ty::TyExpression::type_check(ctx, index),
+                return err(warnings, errors),
+                warnings,
+                errors
+            ));
+            curr_type = elem_type;
+        }
+
+        type_checked_buf.push(ty::TyStorageReassignDescriptor {
+            span: field.name.span(),
+            name: field.name,
+            type_id: field_type,
+            indices: typed_indices,
+        });
     }
+
     let ctx = ctx.with_type_annotation(curr_type).with_help_text("");
     let rhs = check!(
         ty::TyExpression::type_check(ctx, rhs),
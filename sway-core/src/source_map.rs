@@ -79,6 +79,13 @@ impl SourceMap {
             (p.to_owned(), sms.range)
         })
     }
+
+    /// Iterate over every mapped opcode index along with its source location.
+    pub fn iter(&self) -> impl '_ + Iterator<Item = (usize, PathBuf, LocationRange)> {
+        self.map
+            .keys()
+            .filter_map(|&pc| self.addr_to_span(pc).map(|(path, range)| (pc, path, range)))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
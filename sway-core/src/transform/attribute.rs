@@ -19,20 +19,35 @@
 //! is
 //!
 //!   #[foo(bar, bar)]
+//!
+//! Args may optionally be given a literal value, e.g.
+//!
+//!   #[foo(bar = "baz")]
 
+use sway_ast::Literal;
 use sway_types::{Ident, Span};
 
 use fuel_abi_types::program_abi;
 
 use std::{collections::HashMap, sync::Arc};
 
+/// An attribute argument has a name (i.e "read", "should_revert"),
+/// an optional literal value and
+/// a span from its declaration.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttributeArg {
+    pub name: Ident,
+    pub value: Option<Literal>,
+    pub span: Span,
+}
+
 /// An attribute has a name (i.e "doc", "storage"),
 /// a vector of possible arguments and
 /// a span from its declaration.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Attribute {
     pub name: Ident,
-    pub args: Vec<Ident>,
+    pub args: Vec<AttributeArg>,
     pub span: Span,
 }
 
@@ -62,7 +77,7 @@ pub(crate) fn generate_json_abi_attributes_map(
                 .flat_map(|(_attr_kind, attrs)| {
                     attrs.iter().map(|attr| program_abi::Attribute {
                         name: attr.name.to_string(),
-                        arguments: attr.args.iter().map(|arg| arg.to_string()).collect(),
+                        arguments: attr.args.iter().map(|arg| arg.name.to_string()).collect(),
                     })
                 })
                 .collect(),
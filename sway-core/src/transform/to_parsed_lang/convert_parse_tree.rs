@@ -472,13 +472,13 @@ fn get_attributed_purity(
     match attributes.get(&AttributeKind::Storage) {
         Some(attrs) if !attrs.is_empty() => {
             for arg in attrs.iter().flat_map(|attr| &attr.args) {
-                match arg.as_str() {
+                match arg.name.as_str() {
                     STORAGE_PURITY_READ_NAME => add_impurity(Purity::Reads, Purity::Writes),
                     STORAGE_PURITY_WRITE_NAME => add_impurity(Purity::Writes, Purity::Reads),
                     _otherwise => {
                         let error = ConvertParseTreeError::InvalidAttributeArgument {
                             attribute: "storage".to_owned(),
-                            span: arg.span(),
+                            span: arg.span.clone(),
                         };
                         return Err(handler.emit_err(error.into()));
                     }
@@ -3387,22 +3387,43 @@ fn assignable_to_reassignment_target(
     engines: Engines<'_>,
     assignable: Assignable,
 ) -> Result<ReassignmentTarget, ErrorEmitted> {
-    let mut idents = Vec::new();
+    // The fields of a storage field, innermost first, each with the indices which follow it.
+    let mut fields: Vec<(&Ident, Vec<&Expr>)> = Vec::new();
+    let mut indices = Vec::new();
     let mut base = &assignable;
     loop {
         match base {
             Assignable::FieldProjection { target, name, .. } => {
-                idents.push(name);
+                fields.push((name, std::mem::take(&mut indices)));
                 base = target;
             }
             Assignable::Var(name) => {
-                if name.as_str() == "storage" {
-                    let idents = idents.into_iter().rev().cloned().collect();
-                    return Ok(ReassignmentTarget::StorageField(idents));
+                if name.as_str() == "storage" && indices.is_empty() {
+                    let fields = fields
+                        .into_iter()
+                        .rev()
+                        .map(|(name, indices)| {
+                            let indices = indices
+                                .into_iter()
+                                .rev()
+                                .map(|index| {
+                                    expr_to_expression(context, handler, engines, index.clone())
+                                })
+                                .collect::<Result<_, _>>()?;
+                            Ok(StorageReassignDescriptor {
+                                name: name.clone(),
+                                indices,
+                            })
+                        })
+                        .collect::<Result<_, _>>()?;
+                    return Ok(ReassignmentTarget::StorageField(fields));
                 }
                 break;
             }
-            Assignable::Index { .. } => break,
+            Assignable::Index { target, arg } => {
+                indices.push(&**arg.get());
+                base = target;
+            }
             Assignable::TupleFieldProjection { .. } => break,
         }
     }
@@ -3581,12 +3602,33 @@ fn item_attrs_to_map(
                 })
             }
 
-            let args = attr
+            let args: Vec<AttributeArg> = attr
                 .args
                 .as_ref()
-                .map(|parens| parens.get().into_iter().cloned().collect())
+                .map(|parens| {
+                    parens
+                        .get()
+                        .into_iter()
+                        .map(|arg| AttributeArg {
+                            name: arg.name.clone(),
+                            value: arg.value.clone(),
+                            span: arg.span(),
+                        })
+                        .collect()
+                })
                 .unwrap_or_else(Vec::new);
 
+            // Only the `test` attribute gives meaning to the values of its args.
+            if name != TEST_ATTRIBUTE_NAME {
+                if let Some(arg) = args.iter().find(|arg| arg.value.is_some()) {
+                    let error = ConvertParseTreeError::AttributeArgumentValueNotAllowed {
+                        attribute: name.to_owned(),
+                        span: arg.span.clone(),
+                    };
+                    return Err(handler.emit_err(error.into()));
+                }
+            }
+
             let attribute = Attribute {
                 name: attr.name.clone(),
                 args,
@@ -71,6 +71,8 @@ pub enum ConvertParseTreeError {
     ContractCallerNamedTypeGenericArg { span: Span },
     #[error("invalid argument for '{attribute}' attribute")]
     InvalidAttributeArgument { attribute: String, span: Span },
+    #[error("the '{attribute}' attribute does not take argument values")]
+    AttributeArgumentValueNotAllowed { attribute: String, span: Span },
     #[error("cannot find type \"{ty_name}\" in this scope")]
     ConstrainedNonExistentType { ty_name: Ident, span: Span },
     #[error("__get_storage_key does not take arguments")]
@@ -144,6 +146,7 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::ContractCallerOneGenericArg { span } => span.clone(),
             ConvertParseTreeError::ContractCallerNamedTypeGenericArg { span } => span.clone(),
             ConvertParseTreeError::InvalidAttributeArgument { span, .. } => span.clone(),
+            ConvertParseTreeError::AttributeArgumentValueNotAllowed { span, .. } => span.clone(),
             ConvertParseTreeError::ConstrainedNonExistentType { span, .. } => span.clone(),
             ConvertParseTreeError::GetStorageKeyTooManyArgs { span, .. } => span.clone(),
             ConvertParseTreeError::RecursiveType { span } => span.clone(),
@@ -360,6 +360,8 @@ pub enum CompileError {
     },
     #[error("Unknown opcode: \"{op_name}\".")]
     UnrecognizedOp { op_name: Ident, span: Span },
+    #[error("The EVM backend does not support {feature}.")]
+    EvmUnsupported { feature: String, span: Span },
     #[error("Cannot infer type for type parameter \"{ty}\". Insufficient type information provided. Try annotating its type.")]
     UnableToInferGeneric { ty: String, span: Span },
     #[error("The generic type parameter \"{ty}\" is unconstrained.")]
@@ -398,6 +400,8 @@ pub enum CompileError {
     UnknownTypeName { name: String, span: Span },
     #[error("Bytecode can only support programs with up to 2^12 words worth of opcodes. Try refactoring into contract calls? This is a temporary error and will be implemented in the future.")]
     TooManyInstructions { span: Span },
+    #[error("The allocator cannot resolve a register mapping for this program. Try to lower the number of variables used within a single expression.")]
+    RegisterAllocationFailed { span: Span },
     #[error(
         "No valid {} file (.{}) was found at {file_path}",
         sway_types::constants::LANGUAGE_NAME,
@@ -800,6 +804,7 @@ impl Spanned for CompileError {
             InvalidAssemblyMismatchedReturn { span, .. } => span.clone(),
             UnknownEnumVariant { span, .. } => span.clone(),
             UnrecognizedOp { span, .. } => span.clone(),
+            EvmUnsupported { span, .. } => span.clone(),
             UnableToInferGeneric { span, .. } => span.clone(),
             UnconstrainedGenericParameter { span, .. } => span.clone(),
             TraitConstraintNotSatisfied { span, .. } => span.clone(),
@@ -814,6 +819,7 @@ impl Spanned for CompileError {
             UnknownTypeName { span, .. } => span.clone(),
             InvalidStrType { span, .. } => span.clone(),
             TooManyInstructions { span, .. } => span.clone(),
+            RegisterAllocationFailed { span } => span.clone(),
             FileNotFound { span, .. } => span.clone(),
             FileCouldNotBeRead { span, .. } => span.clone(),
             ImportMustBeLibrary { span, .. } => span.clone(),
@@ -295,6 +295,8 @@ impl Instruction {
             {
                 ty.get_indexed_type(context, indices)
             }
+            Instruction::InsertElement { ty, .. } => Some(*ty),
+            Instruction::InsertValue { ty, .. } => Some(*ty),
             // Unknown aggregate instruction.  Adding these as we come across them...
             _otherwise => None,
         };
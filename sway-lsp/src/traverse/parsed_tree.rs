@@ -712,15 +712,18 @@ impl<'a> ParsedTree<'a> {
                     ReassignmentTarget::VariableExpression(exp) => {
                         self.handle_expression(exp);
                     }
-                    ReassignmentTarget::StorageField(idents) => {
-                        for ident in idents {
+                    ReassignmentTarget::StorageField(fields) => {
+                        for field in fields {
                             self.tokens.insert(
-                                to_ident_key(ident),
+                                to_ident_key(&field.name),
                                 Token::from_parsed(
                                     AstToken::Reassignment(reassignment.clone()),
                                     SymbolKind::Field,
                                 ),
                             );
+                            for index in &field.indices {
+                                self.handle_expression(index);
+                            }
                         }
                     }
                 }
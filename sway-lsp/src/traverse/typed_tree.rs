@@ -585,6 +585,9 @@ impl<'a> TypedTree<'a> {
                             field.clone(),
                         ));
                     }
+                    for index in &field.indices {
+                        self.handle_expression(index);
+                    }
                 }
                 self.handle_expression(&storage_reassignment.rhs);
             }
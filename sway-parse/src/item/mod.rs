@@ -167,7 +167,7 @@ impl Parse for FnSignature {
 mod tests {
     use super::*;
     use std::sync::Arc;
-    use sway_ast::{AttributeDecl, Item};
+    use sway_ast::{AttributeDecl, Item, Literal};
 
     fn parse_item(input: &str) -> Item {
         let handler = <_>::default();
@@ -191,9 +191,9 @@ mod tests {
                     .map(|att| {
                         (
                             att.name.as_str(),
-                            att.args
-                                .as_ref()
-                                .map(|arg| arg.get().into_iter().map(|a| a.as_str()).collect()),
+                            att.args.as_ref().map(|arg| {
+                                arg.get().into_iter().map(|a| a.name.as_str()).collect()
+                            }),
                         )
                     })
                     .collect()
@@ -402,6 +402,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_attributes_fn_arg_with_value() {
+        let item = parse_item(
+            r#"
+            #[foo(one, two = "three")]
+            fn f() -> bool {
+                false
+            }
+            "#,
+        );
+
+        assert!(matches!(item.value, ItemKind::Fn(_)));
+        assert_eq!(
+            attributes(&item.attribute_list),
+            vec![[("foo", Some(vec!["one", "two"]))]]
+        );
+        let attr = item.attribute_list[0].attribute.get().into_iter().next();
+        let args = attr.unwrap().args.as_ref().unwrap().get();
+        let values: Vec<_> = args.into_iter().map(|arg| arg.value.as_ref()).collect();
+        assert!(values[0].is_none());
+        assert!(matches!(
+            values[1],
+            Some(Literal::String(lit_string)) if lit_string.parsed == "three"
+        ));
+    }
+
     #[test]
     fn parse_attributes_fn_zero_one_and_three_args() {
         let item = parse_item(
@@ -45,6 +45,16 @@ pub const DOC_COMMENT_ATTRIBUTE_NAME: &str = "doc-comment";
 
 /// The attribute used for Sway in-language unit tests.
 pub const TEST_ATTRIBUTE_NAME: &str = "test";
+pub const TEST_SHOULD_REVERT_NAME: &str = "should_revert";
+pub const TEST_STORAGE_SLOTS_NAME: &str = "storage_slots";
+pub const TEST_SHOULD_LOG_NAME: &str = "should_log";
+pub const TEST_SHOULD_LOG_DATA_NAME: &str = "should_log_data";
+pub const TEST_BLOCK_HEIGHT_NAME: &str = "block_height";
+pub const TEST_TIMESTAMP_NAME: &str = "timestamp";
+pub const TEST_SENDER_NAME: &str = "sender";
+pub const TEST_INPUT_COIN_NAME: &str = "input_coin";
+pub const TEST_OUTPUT_COIN_NAME: &str = "output_coin";
+pub const TEST_OUTPUT_VARIABLES_NAME: &str = "output_variables";
 
 /// The valid attribute string used for payable functions.
 pub const PAYABLE_ATTRIBUTE_NAME: &str = "payable";
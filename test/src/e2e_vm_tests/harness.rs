@@ -2,6 +2,7 @@ use anyhow::{bail, Result};
 use colored::Colorize;
 use forc_client::ops::{
     deploy::{cmd::DeployCommand, op::deploy},
+    keystore::Signing,
     run::{cmd::RunCommand, op::run},
 };
 use forc_pkg::{Built, BuiltPackage};
@@ -14,7 +15,7 @@ use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
 use regex::{Captures, Regex};
 use std::{fs, io::Read, path::PathBuf, str::FromStr};
-use sway_core::{asm_generation::ProgramABI, BuildTarget};
+use sway_core::{asm_generation::ProgramABI, language::parsed::TreeType, BuildTarget};
 
 use super::RunConfig;
 
@@ -68,7 +69,10 @@ pub(crate) async fn deploy_contract(file_name: &str, run_config: &RunConfig) ->
         )),
         terse_mode: !run_config.verbose,
         locked: run_config.locked,
-        signing_key: Some(SecretKey::from_str(SECRET_KEY).unwrap()),
+        signing: Signing {
+            signing_key: Some(SecretKey::from_str(SECRET_KEY).unwrap()),
+            ..Default::default()
+        },
         ..Default::default()
     })
     .await
@@ -105,7 +109,10 @@ pub(crate) async fn runs_on_node(
             terse_mode: !run_config.verbose,
             contract: Some(contracts),
             locked: run_config.locked,
-            signing_key: Some(SecretKey::from_str(SECRET_KEY).unwrap()),
+            signing: Signing {
+                signing_key: Some(SecretKey::from_str(SECRET_KEY).unwrap()),
+                ..Default::default()
+            },
             ..Default::default()
         };
         run(command).await.map(|ran_scripts| {
@@ -158,8 +165,39 @@ pub(crate) fn runs_in_vm(
         }
         BuildTarget::EVM => {
             let mut database = revm::InMemoryDB::default();
+            let code_address = if let TreeType::Contract = script.tree_type {
+                // The contract is created by running its init code, which initializes its storage
+                // and returns the runtime code.  The script data is then the call data.
+                let mut env = revm::Env::default();
+                env.tx.transact_to = revm::TransactTo::create();
+                env.tx.data = bytes::Bytes::from(script.bytecode.into_boxed_slice());
+                let mut evm = revm::new();
+                evm.database(&mut database);
+                evm.env = env;
+
+                let result = evm.transact_commit();
+                match result.out {
+                    revm::TransactOut::Create(_, Some(contract_address)) => contract_address,
+                    _ => bail!("Failed to create the contract: {:?}", result.exit_reason),
+                }
+            } else {
+                // The script is deployed as the code of an account, which is then called.
+                let script_address = [0x5c; 20].into();
+                database.insert_account_info(
+                    script_address,
+                    revm::AccountInfo::new(
+                        Default::default(),
+                        0,
+                        revm::Bytecode::new_raw(bytes::Bytes::from(
+                            script.bytecode.into_boxed_slice(),
+                        )),
+                    ),
+                );
+                script_address
+            };
             let mut env = revm::Env::default();
-            env.tx.data = bytes::Bytes::from(script.bytecode.into_boxed_slice());
+            env.tx.transact_to = revm::TransactTo::Call(code_address);
+            env.tx.data = bytes::Bytes::from(script_data.unwrap_or_default());
             let mut evm = revm::new();
             evm.database(&mut database);
             evm.env = env;
@@ -226,7 +264,13 @@ pub(crate) async fn compile_and_run_unit_tests(
             },
             ..Default::default()
         })?;
-        let tested = built_tests.run()?;
+        let test_filter = None;
+        let tested = built_tests.run(
+            forc_test::TestRunnerCount::Auto,
+            test_filter,
+            forc_test::FuzzConfig::default(),
+            false,
+        )?;
 
         match tested {
             forc_test::Tested::Package(tested_pkg) => Ok(vec![*tested_pkg]),
@@ -20,6 +20,7 @@ use std::{
     path::{Path, PathBuf},
     sync::Arc,
 };
+use sway_core::BuildTarget;
 use tokio::sync::Mutex;
 use tracing::Instrument;
 
@@ -57,8 +58,12 @@ impl fmt::Debug for TestResult {
 struct TestDescription {
     name: String,
     category: TestCategory,
+    // The only build target the test is run with, if it can't be run with any of them.
+    build_target: Option<BuildTarget>,
     script_data: Option<Vec<u8>>,
     expected_result: Option<TestResult>,
+    // The data of the logs an EVM run is expected to emit, if they're checked.
+    expected_logs: Option<Vec<Vec<u8>>>,
     contract_paths: Vec<String>,
     validate_abi: bool,
     validate_storage_slots: bool,
@@ -90,8 +95,10 @@ impl TestContext {
         let TestDescription {
             name,
             category,
+            build_target: _,
             script_data,
             expected_result,
+            expected_logs,
             contract_paths,
             validate_abi,
             validate_storage_slots,
@@ -127,6 +134,7 @@ impl TestContext {
                 };
 
                 let result = harness::runs_in_vm(compiled.clone(), script_data)?;
+                let mut logs = None;
                 let result = match result {
                     harness::VMExecutionResult::Fuel(state, receipts) => {
                         match state {
@@ -144,16 +152,26 @@ impl TestContext {
                             ProgramState::Revert(v) => TestResult::Revert(v),
                         }
                     }
-                    harness::VMExecutionResult::Evm(state) => match state.exit_reason {
-                        revm::Return::Continue => todo!(),
-                        revm::Return::Stop => TestResult::Result(0),
-                        revm::Return::Return => todo!(),
-                        revm::Return::SelfDestruct => todo!(),
-                        revm::Return::Revert => TestResult::Revert(0),
-                        _ => {
-                            panic!("EVM exited with unhandled reason: {:?}", state.exit_reason);
+                    harness::VMExecutionResult::Evm(state) => {
+                        logs = Some(
+                            state
+                                .logs
+                                .iter()
+                                .map(|log| log.data.to_vec())
+                                .collect::<Vec<_>>(),
+                        );
+                        match state.exit_reason {
+                            revm::Return::Stop => TestResult::Result(0),
+                            revm::Return::Return => TestResult::Return(evm_output_word(&state.out)),
+                            revm::Return::Revert => TestResult::Revert(evm_output_word(&state.out)),
+                            reason => {
+                                return Err(anyhow!(
+                                    "EVM exited with unhandled reason: {:?}",
+                                    reason
+                                ));
+                            }
                         }
-                    },
+                    }
                 };
 
                 if result != res {
@@ -161,6 +179,18 @@ impl TestContext {
                         "expected: {:?}\nactual: {:?}",
                         res, result
                     )))
+                } else if expected_logs.is_some() && logs != expected_logs {
+                    let to_hex = |logs: Option<Vec<Vec<u8>>>| {
+                        logs.unwrap_or_default()
+                            .iter()
+                            .map(hex::encode)
+                            .collect::<Vec<_>>()
+                    };
+                    Err(anyhow::Error::msg(format!(
+                        "expected logs: {:?}\nactual logs: {:?}",
+                        to_hex(expected_logs),
+                        to_hex(logs)
+                    )))
                 } else {
                     if validate_abi {
                         let (result, out) = run_and_capture_output(|| async {
@@ -363,6 +393,11 @@ pub async fn run(filter_config: &FilterConfig, run_config: &RunConfig) -> Result
     if filter_config.contract_only {
         tests.retain(|t| t.category == TestCategory::RunsWithContract);
     }
+    let other_target_tests = tests.retained(|t| {
+        t.build_target
+            .map(|build_target| build_target == run_config.build_target)
+            .unwrap_or(true)
+    });
     if filter_config.first_only && !tests.is_empty() {
         tests = vec![tests.remove(0)];
     }
@@ -434,6 +469,12 @@ pub async fn run(filter_config: &FilterConfig, run_config: &RunConfig) -> Result
         if !disabled_tests.is_empty() {
             tracing::info!("{} tests were disabled.", disabled_tests.len());
         }
+        if !other_target_tests.is_empty() {
+            tracing::info!(
+                "{} tests are only run with other build targets.",
+                other_target_tests.len()
+            );
+        }
         tracing::warn!(
             "No tests were run. Regex filters filtered out all {} tests.",
             total_number_of_tests
@@ -499,6 +540,21 @@ fn discover_test_configs() -> Result<Vec<TestDescription>> {
     Ok(configs)
 }
 
+/// Returns the last word of the output of an EVM run, which is where an ABI encoded `uint64`
+/// return value or revert code ends up.
+fn evm_output_word(out: &revm::TransactOut) -> u64 {
+    let bytes = match out {
+        revm::TransactOut::None => return 0,
+        revm::TransactOut::Call(bytes) | revm::TransactOut::Create(bytes, _) => bytes,
+    };
+    bytes
+        .iter()
+        .rev()
+        .take(8)
+        .rev()
+        .fold(0, |word, byte| (word << 8) | *byte as u64)
+}
+
 const DIRECTIVE_RX: &str = r"(?m)^\s*#\s*(\w+):\s+(.*)$";
 
 fn build_file_checker(content: &str) -> Result<filecheck::Checker> {
@@ -558,6 +614,16 @@ fn parse_test_toml(path: &Path) -> Result<TestDescription> {
             Some(other) => Err(anyhow!("Unknown category '{}'.", other)),
         })?;
 
+    let build_target = match toml_content.get("build_target") {
+        None => None,
+        Some(build_target_val) => match build_target_val.as_str() {
+            Some("fuel") => Some(BuildTarget::Fuel),
+            Some("evm") => Some(BuildTarget::EVM),
+            None => bail!("Malformed build target '{build_target_val}', should be a string."),
+            Some(other) => bail!("Unknown build target '{}'.", other),
+        },
+    };
+
     // Abort early if we find a FailsToCompile test without any Checker directives.
     if category == TestCategory::FailsToCompile && checker.is_empty() {
         bail!("'fail' tests must contain some FileCheck verification directives.");
@@ -593,6 +659,30 @@ fn parse_test_toml(path: &Path) -> Result<TestDescription> {
         | TestCategory::Disabled => None,
     };
 
+    let expected_logs = match toml_content.get("expected_logs") {
+        None => None,
+        Some(_) if category != TestCategory::Runs || build_target != Some(BuildTarget::EVM) => {
+            bail!("'expected_logs' are only supported for 'run' tests with the 'evm' build target.")
+        }
+        Some(logs) => Some(
+            logs.as_array()
+                .ok_or_else(|| anyhow!("Expected logs must be an array of hex strings."))
+                .and_then(|vals| {
+                    vals.iter()
+                        .map(|val| {
+                            val.as_str()
+                                .ok_or_else(|| anyhow!("Expected logs must be hex strings."))
+                                .and_then(|log| {
+                                    hex::decode(log).map_err(|e| {
+                                        anyhow!("Invalid hex value for 'expected_logs': {}", e)
+                                    })
+                                })
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                })?,
+        ),
+    };
+
     let contract_paths = match toml_content.get("contracts") {
         None => Vec::new(),
         Some(contracts) => contracts
@@ -637,8 +727,10 @@ fn parse_test_toml(path: &Path) -> Result<TestDescription> {
     Ok(TestDescription {
         name,
         category,
+        build_target,
         script_data,
         expected_result,
+        expected_logs,
         contract_paths,
         validate_abi,
         validate_storage_slots,
@@ -0,0 +1,13 @@
+contract;
+
+abi MyContract {
+    #[storage(read)]
+    fn read();
+}
+
+impl MyContract for Contract {
+    // only the `test` attribute takes argument values
+    #[storage(read = "x")]
+    fn read() {
+    }
+}
@@ -0,0 +1,64 @@
+script;
+
+// Only uses what the EVM backend supports, so this can also be run with `--build-target evm`.
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+enum Shape {
+    Square: u64,
+    Rect: (u64, u64),
+}
+
+fn scale(p: Point, factor: u64) -> Point {
+    Point {
+        x: p.x * factor,
+        y: p.y * factor,
+    }
+}
+
+fn sum(values: [u64; 4]) -> u64 {
+    let mut total = 0;
+    let mut i = 0;
+    while i < 4 {
+        total = total + values[i];
+        i = i + 1;
+    }
+    total
+}
+
+fn area(shape: Shape) -> u64 {
+    match shape {
+        Shape::Square(side) => side * side,
+        Shape::Rect((width, height)) => width * height,
+    }
+}
+
+fn main() -> u64 {
+    let p = scale(Point { x: 3, y: 4 }, 2);
+    let values = [p.x, p.y, 5, 6];
+
+    // 6 + 8 + 5 + 6 = 25
+    let total = sum(values);
+
+    // 9 + 10 = 19
+    let areas = area(Shape::Square(3)) + area(Shape::Rect((2, 5)));
+
+    let a: b256 = 0x0000000000000000000000000000000000000000000000000000000000000001;
+    let b: b256 = 0x0000000000000000000000000000000000000000000000000000000000000001;
+    let same = if a == b { 1 } else { 0 };
+
+    let shifted = asm(r1: total, r2: 1, r3) {
+        sll r3 r1 r2;
+        r3: u64
+    };
+
+    if total > 20 && p.x != p.y {
+        // 50 - 6 + 19 + 1 + 50 / 2 - 2 = 87
+        shifted - p.x + areas + same + shifted / 2 - 2
+    } else {
+        0
+    }
+}
@@ -0,0 +1,32 @@
+contract;
+
+// Only uses what the EVM backend supports.
+
+storage {
+    balance: u64 = 10,
+}
+
+abi Vault {
+    #[storage(read)]
+    fn balance() -> u64;
+    #[storage(read, write)]
+    fn withdraw(amount: u64) -> u64;
+}
+
+impl Vault for Contract {
+    #[storage(read)]
+    fn balance() -> u64 {
+        storage.balance
+    }
+
+    #[storage(read, write)]
+    fn withdraw(amount: u64) -> u64 {
+        let balance = storage.balance;
+        if amount > balance {
+            // Revert with the shortfall.
+            __revert(amount - balance);
+        }
+        storage.balance = balance - amount;
+        storage.balance
+    }
+}
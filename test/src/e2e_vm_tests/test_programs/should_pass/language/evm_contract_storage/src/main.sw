@@ -0,0 +1,58 @@
+contract;
+
+// Only uses what the EVM backend supports.  The initial storage values are written by the init
+// code when the contract is created.
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+storage {
+    counter: u64 = 40,
+    owner: b256 = 0x0000000000000000000000000000000000000000000000000000000000000042,
+    origin: Point = Point { x: 1, y: 2 },
+}
+
+abi Counter {
+    #[storage(read)]
+    fn counter() -> u64;
+    #[storage(read, write)]
+    fn increment(amount: u64) -> u64;
+    #[storage(read)]
+    fn owner() -> b256;
+    #[storage(write)]
+    fn move_origin(to: Point);
+    fn is_even(value: u64) -> bool;
+}
+
+impl Counter for Contract {
+    #[storage(read)]
+    fn counter() -> u64 {
+        storage.counter
+    }
+
+    #[storage(read, write)]
+    fn increment(amount: u64) -> u64 {
+        let counter = storage.counter + amount;
+        storage.counter = counter;
+        __log(counter);
+
+        // 42 * 10 + 2 - 1 = 421
+        storage.counter * 10 + storage.origin.y - storage.origin.x
+    }
+
+    #[storage(read)]
+    fn owner() -> b256 {
+        storage.owner
+    }
+
+    #[storage(write)]
+    fn move_origin(to: Point) {
+        storage.origin = to;
+    }
+
+    fn is_even(value: u64) -> bool {
+        value % 2 == 0
+    }
+}
@@ -0,0 +1,49 @@
+script;
+
+// A frame of more than 4 KiB, with elements too big for their size to fit in a 12 bit immediate.
+#[inline(never)]
+fn large_elements() -> u64 {
+    let words = [0; 513];
+    let mut chunks = [words; 4];
+    let mut i = 0;
+    while i < 4 {
+        chunks[i][i * 100] = i + 1;
+        chunks[i][512] = 10;
+        i += 1;
+    }
+
+    let mut sum = 0;
+    i = 0;
+    while i < 4 {
+        sum += chunks[i][i * 100] + chunks[i][512];
+        i += 1;
+    }
+    sum
+}
+
+// A frame of more than 256 KiB.
+#[inline(never)]
+fn large_frame() -> u64 {
+    let row = [1; 64];
+    let block = [row; 64];
+    let mut blocks = [block; 9];
+    let mut i = 0;
+    while i < 9 {
+        blocks[i][i * 7][63 - i] = i * 100;
+        i += 1;
+    }
+
+    let mut sum = 0;
+    i = 0;
+    while i < 9 {
+        sum += blocks[i][i * 7][63 - i] + blocks[i][63][63];
+        i += 1;
+    }
+    sum
+}
+
+fn main() -> u64 {
+    assert(large_elements() == 50);
+    assert(large_frame() == 3609);
+    large_elements() + large_frame()
+}
@@ -0,0 +1,39 @@
+script;
+
+// Functions with more args than there are args registers get the rest of their args on the stack.
+
+#[inline(never)]
+fn weighted_sum(a: u64, b: u64, c: u64, d: u64, e: u64, f: u64, g: u64, h: u64, i: u64) -> u64 {
+    a + b * 2 + c * 3 + d * 4 + e * 5 + f * 6 + g * 7 + h * 8 + i * 9
+}
+
+// Makes a call with args on the stack of its own while using those it was passed.
+#[inline(never)]
+fn nested(a: u64, b: u64, c: u64, d: u64, e: u64, f: u64, g: u64, h: bool) -> u64 {
+    let sum = weighted_sum(g, f, e, d, c, b, a, g, f);
+    if h {
+        sum + g
+    } else {
+        sum
+    }
+}
+
+// Exactly as many args as registers.
+#[inline(never)]
+fn six(a: u64, b: u64, c: u64, d: u64, e: u64, f: u64) -> u64 {
+    a * b + c * d + e * f
+}
+
+fn seven(a: u64, b: u64, c: u64, d: u64, e: u64, f: u64, g: u64) -> u64 {
+    a + b + c + d + e + f + g + six(a, b, c, d, e, f)
+}
+
+fn main() -> u64 {
+    assert(weighted_sum(1, 1, 1, 1, 1, 1, 1, 1, 1) == 45);
+    assert(weighted_sum(0, 0, 0, 0, 0, 0, 0, 0, 10) == 90);
+    assert(nested(1, 2, 3, 4, 5, 6, 7, true) == 201);
+    assert(nested(1, 2, 3, 4, 5, 6, 7, false) == 194);
+    assert(seven(1, 2, 3, 4, 5, 6, 7) == 72);
+    assert(seven(1, 1, 1, 1, 1, 1, 1) == 10);
+    weighted_sum(9, 8, 7, 6, 5, 4, 3, 2, 1)
+}
@@ -0,0 +1,555 @@
+script;
+
+// So many args are passed on the stack that the last of them are beyond the reach of an
+// immediate offset from the address of the block holding them.
+
+#[inline(never)]
+fn pick(
+    a0: u64, a1: u64, a2: u64, a3: u64, a4: u64, a5: u64, a6: u64, a7: u64, a8: u64, a9: u64,
+    a10: u64, a11: u64, a12: u64, a13: u64, a14: u64, a15: u64, a16: u64, a17: u64, a18: u64, a19: u64,
+    a20: u64, a21: u64, a22: u64, a23: u64, a24: u64, a25: u64, a26: u64, a27: u64, a28: u64, a29: u64,
+    a30: u64, a31: u64, a32: u64, a33: u64, a34: u64, a35: u64, a36: u64, a37: u64, a38: u64, a39: u64,
+    a40: u64, a41: u64, a42: u64, a43: u64, a44: u64, a45: u64, a46: u64, a47: u64, a48: u64, a49: u64,
+    a50: u64, a51: u64, a52: u64, a53: u64, a54: u64, a55: u64, a56: u64, a57: u64, a58: u64, a59: u64,
+    a60: u64, a61: u64, a62: u64, a63: u64, a64: u64, a65: u64, a66: u64, a67: u64, a68: u64, a69: u64,
+    a70: u64, a71: u64, a72: u64, a73: u64, a74: u64, a75: u64, a76: u64, a77: u64, a78: u64, a79: u64,
+    a80: u64, a81: u64, a82: u64, a83: u64, a84: u64, a85: u64, a86: u64, a87: u64, a88: u64, a89: u64,
+    a90: u64, a91: u64, a92: u64, a93: u64, a94: u64, a95: u64, a96: u64, a97: u64, a98: u64, a99: u64,
+    a100: u64, a101: u64, a102: u64, a103: u64, a104: u64, a105: u64, a106: u64, a107: u64, a108: u64, a109: u64,
+    a110: u64, a111: u64, a112: u64, a113: u64, a114: u64, a115: u64, a116: u64, a117: u64, a118: u64, a119: u64,
+    a120: u64, a121: u64, a122: u64, a123: u64, a124: u64, a125: u64, a126: u64, a127: u64, a128: u64, a129: u64,
+    a130: u64, a131: u64, a132: u64, a133: u64, a134: u64, a135: u64, a136: u64, a137: u64, a138: u64, a139: u64,
+    a140: u64, a141: u64, a142: u64, a143: u64, a144: u64, a145: u64, a146: u64, a147: u64, a148: u64, a149: u64,
+    a150: u64, a151: u64, a152: u64, a153: u64, a154: u64, a155: u64, a156: u64, a157: u64, a158: u64, a159: u64,
+    a160: u64, a161: u64, a162: u64, a163: u64, a164: u64, a165: u64, a166: u64, a167: u64, a168: u64, a169: u64,
+    a170: u64, a171: u64, a172: u64, a173: u64, a174: u64, a175: u64, a176: u64, a177: u64, a178: u64, a179: u64,
+    a180: u64, a181: u64, a182: u64, a183: u64, a184: u64, a185: u64, a186: u64, a187: u64, a188: u64, a189: u64,
+    a190: u64, a191: u64, a192: u64, a193: u64, a194: u64, a195: u64, a196: u64, a197: u64, a198: u64, a199: u64,
+    a200: u64, a201: u64, a202: u64, a203: u64, a204: u64, a205: u64, a206: u64, a207: u64, a208: u64, a209: u64,
+    a210: u64, a211: u64, a212: u64, a213: u64, a214: u64, a215: u64, a216: u64, a217: u64, a218: u64, a219: u64,
+    a220: u64, a221: u64, a222: u64, a223: u64, a224: u64, a225: u64, a226: u64, a227: u64, a228: u64, a229: u64,
+    a230: u64, a231: u64, a232: u64, a233: u64, a234: u64, a235: u64, a236: u64, a237: u64, a238: u64, a239: u64,
+    a240: u64, a241: u64, a242: u64, a243: u64, a244: u64, a245: u64, a246: u64, a247: u64, a248: u64, a249: u64,
+    a250: u64, a251: u64, a252: u64, a253: u64, a254: u64, a255: u64, a256: u64, a257: u64, a258: u64, a259: u64,
+    a260: u64, a261: u64, a262: u64, a263: u64, a264: u64, a265: u64, a266: u64, a267: u64, a268: u64, a269: u64,
+    a270: u64, a271: u64, a272: u64, a273: u64, a274: u64, a275: u64, a276: u64, a277: u64, a278: u64, a279: u64,
+    a280: u64, a281: u64, a282: u64, a283: u64, a284: u64, a285: u64, a286: u64, a287: u64, a288: u64, a289: u64,
+    a290: u64, a291: u64, a292: u64, a293: u64, a294: u64, a295: u64, a296: u64, a297: u64, a298: u64, a299: u64,
+    a300: u64, a301: u64, a302: u64, a303: u64, a304: u64, a305: u64, a306: u64, a307: u64, a308: u64, a309: u64,
+    a310: u64, a311: u64, a312: u64, a313: u64, a314: u64, a315: u64, a316: u64, a317: u64, a318: u64, a319: u64,
+    a320: u64, a321: u64, a322: u64, a323: u64, a324: u64, a325: u64, a326: u64, a327: u64, a328: u64, a329: u64,
+    a330: u64, a331: u64, a332: u64, a333: u64, a334: u64, a335: u64, a336: u64, a337: u64, a338: u64, a339: u64,
+    a340: u64, a341: u64, a342: u64, a343: u64, a344: u64, a345: u64, a346: u64, a347: u64, a348: u64, a349: u64,
+    a350: u64, a351: u64, a352: u64, a353: u64, a354: u64, a355: u64, a356: u64, a357: u64, a358: u64, a359: u64,
+    a360: u64, a361: u64, a362: u64, a363: u64, a364: u64, a365: u64, a366: u64, a367: u64, a368: u64, a369: u64,
+    a370: u64, a371: u64, a372: u64, a373: u64, a374: u64, a375: u64, a376: u64, a377: u64, a378: u64, a379: u64,
+    a380: u64, a381: u64, a382: u64, a383: u64, a384: u64, a385: u64, a386: u64, a387: u64, a388: u64, a389: u64,
+    a390: u64, a391: u64, a392: u64, a393: u64, a394: u64, a395: u64, a396: u64, a397: u64, a398: u64, a399: u64,
+    a400: u64, a401: u64, a402: u64, a403: u64, a404: u64, a405: u64, a406: u64, a407: u64, a408: u64, a409: u64,
+    a410: u64, a411: u64, a412: u64, a413: u64, a414: u64, a415: u64, a416: u64, a417: u64, a418: u64, a419: u64,
+    a420: u64, a421: u64, a422: u64, a423: u64, a424: u64, a425: u64, a426: u64, a427: u64, a428: u64, a429: u64,
+    a430: u64, a431: u64, a432: u64, a433: u64, a434: u64, a435: u64, a436: u64, a437: u64, a438: u64, a439: u64,
+    a440: u64, a441: u64, a442: u64, a443: u64, a444: u64, a445: u64, a446: u64, a447: u64, a448: u64, a449: u64,
+    a450: u64, a451: u64, a452: u64, a453: u64, a454: u64, a455: u64, a456: u64, a457: u64, a458: u64, a459: u64,
+    a460: u64, a461: u64, a462: u64, a463: u64, a464: u64, a465: u64, a466: u64, a467: u64, a468: u64, a469: u64,
+    a470: u64, a471: u64, a472: u64, a473: u64, a474: u64, a475: u64, a476: u64, a477: u64, a478: u64, a479: u64,
+    a480: u64, a481: u64, a482: u64, a483: u64, a484: u64, a485: u64, a486: u64, a487: u64, a488: u64, a489: u64,
+    a490: u64, a491: u64, a492: u64, a493: u64, a494: u64, a495: u64, a496: u64, a497: u64, a498: u64, a499: u64,
+    a500: u64, a501: u64, a502: u64, a503: u64, a504: u64, a505: u64, a506: u64, a507: u64, a508: u64, a509: u64,
+    a510: u64, a511: u64, a512: u64, a513: u64, a514: u64, a515: u64, a516: u64, a517: u64, a518: u64, a519: u64,
+    a520: u64, a521: u64, a522: u64, a523: u64, a524: u64, a525: u64, a526: u64, a527: u64, a528: u64, a529: u64,
+    a530: u64, a531: u64, a532: u64, a533: u64, a534: u64, a535: u64, a536: u64, a537: u64, a538: u64, a539: u64,
+    a540: u64, a541: u64, a542: u64, a543: u64, a544: u64, a545: u64, a546: u64, a547: u64, a548: u64, a549: u64,
+    a550: u64, a551: u64, a552: u64, a553: u64, a554: u64, a555: u64, a556: u64, a557: u64, a558: u64, a559: u64,
+    a560: u64, a561: u64, a562: u64, a563: u64, a564: u64, a565: u64, a566: u64, a567: u64, a568: u64, a569: u64,
+    a570: u64, a571: u64, a572: u64, a573: u64, a574: u64, a575: u64, a576: u64, a577: u64, a578: u64, a579: u64,
+    a580: u64, a581: u64, a582: u64, a583: u64, a584: u64, a585: u64, a586: u64, a587: u64, a588: u64, a589: u64,
+    a590: u64, a591: u64, a592: u64, a593: u64, a594: u64, a595: u64, a596: u64, a597: u64, a598: u64, a599: u64,
+    a600: u64, a601: u64, a602: u64, a603: u64, a604: u64, a605: u64, a606: u64, a607: u64, a608: u64, a609: u64,
+    a610: u64, a611: u64, a612: u64, a613: u64, a614: u64, a615: u64, a616: u64, a617: u64, a618: u64, a619: u64,
+    a620: u64, a621: u64, a622: u64, a623: u64, a624: u64, a625: u64, a626: u64, a627: u64, a628: u64, a629: u64,
+    a630: u64, a631: u64, a632: u64, a633: u64, a634: u64, a635: u64, a636: u64, a637: u64, a638: u64, a639: u64,
+    a640: u64, a641: u64, a642: u64, a643: u64, a644: u64, a645: u64, a646: u64, a647: u64, a648: u64, a649: u64,
+    a650: u64, a651: u64, a652: u64, a653: u64, a654: u64, a655: u64, a656: u64, a657: u64, a658: u64, a659: u64,
+    a660: u64, a661: u64, a662: u64, a663: u64, a664: u64, a665: u64, a666: u64, a667: u64, a668: u64, a669: u64,
+    a670: u64, a671: u64, a672: u64, a673: u64, a674: u64, a675: u64, a676: u64, a677: u64, a678: u64, a679: u64,
+    a680: u64, a681: u64, a682: u64, a683: u64, a684: u64, a685: u64, a686: u64, a687: u64, a688: u64, a689: u64,
+    a690: u64, a691: u64, a692: u64, a693: u64, a694: u64, a695: u64, a696: u64, a697: u64, a698: u64, a699: u64,
+    a700: u64, a701: u64, a702: u64, a703: u64, a704: u64, a705: u64, a706: u64, a707: u64, a708: u64, a709: u64,
+    a710: u64, a711: u64, a712: u64, a713: u64, a714: u64, a715: u64, a716: u64, a717: u64, a718: u64, a719: u64,
+    a720: u64, a721: u64, a722: u64, a723: u64, a724: u64, a725: u64, a726: u64, a727: u64, a728: u64, a729: u64,
+    a730: u64, a731: u64, a732: u64, a733: u64, a734: u64, a735: u64, a736: u64, a737: u64, a738: u64, a739: u64,
+    a740: u64, a741: u64, a742: u64, a743: u64, a744: u64, a745: u64, a746: u64, a747: u64, a748: u64, a749: u64,
+    a750: u64, a751: u64, a752: u64, a753: u64, a754: u64, a755: u64, a756: u64, a757: u64, a758: u64, a759: u64,
+    a760: u64, a761: u64, a762: u64, a763: u64, a764: u64, a765: u64, a766: u64, a767: u64, a768: u64, a769: u64,
+    a770: u64, a771: u64, a772: u64, a773: u64, a774: u64, a775: u64, a776: u64, a777: u64, a778: u64, a779: u64,
+    a780: u64, a781: u64, a782: u64, a783: u64, a784: u64, a785: u64, a786: u64, a787: u64, a788: u64, a789: u64,
+    a790: u64, a791: u64, a792: u64, a793: u64, a794: u64, a795: u64, a796: u64, a797: u64, a798: u64, a799: u64,
+    a800: u64, a801: u64, a802: u64, a803: u64, a804: u64, a805: u64, a806: u64, a807: u64, a808: u64, a809: u64,
+    a810: u64, a811: u64, a812: u64, a813: u64, a814: u64, a815: u64, a816: u64, a817: u64, a818: u64, a819: u64,
+    a820: u64, a821: u64, a822: u64, a823: u64, a824: u64, a825: u64, a826: u64, a827: u64, a828: u64, a829: u64,
+    a830: u64, a831: u64, a832: u64, a833: u64, a834: u64, a835: u64, a836: u64, a837: u64, a838: u64, a839: u64,
+    a840: u64, a841: u64, a842: u64, a843: u64, a844: u64, a845: u64, a846: u64, a847: u64, a848: u64, a849: u64,
+    a850: u64, a851: u64, a852: u64, a853: u64, a854: u64, a855: u64, a856: u64, a857: u64, a858: u64, a859: u64,
+    a860: u64, a861: u64, a862: u64, a863: u64, a864: u64, a865: u64, a866: u64, a867: u64, a868: u64, a869: u64,
+    a870: u64, a871: u64, a872: u64, a873: u64, a874: u64, a875: u64, a876: u64, a877: u64, a878: u64, a879: u64,
+    a880: u64, a881: u64, a882: u64, a883: u64, a884: u64, a885: u64, a886: u64, a887: u64, a888: u64, a889: u64,
+    a890: u64, a891: u64, a892: u64, a893: u64, a894: u64, a895: u64, a896: u64, a897: u64, a898: u64, a899: u64,
+    a900: u64, a901: u64, a902: u64, a903: u64, a904: u64, a905: u64, a906: u64, a907: u64, a908: u64, a909: u64,
+    a910: u64, a911: u64, a912: u64, a913: u64, a914: u64, a915: u64, a916: u64, a917: u64, a918: u64, a919: u64,
+    a920: u64, a921: u64, a922: u64, a923: u64, a924: u64, a925: u64, a926: u64, a927: u64, a928: u64, a929: u64,
+    a930: u64, a931: u64, a932: u64, a933: u64, a934: u64, a935: u64, a936: u64, a937: u64, a938: u64, a939: u64,
+    a940: u64, a941: u64, a942: u64, a943: u64, a944: u64, a945: u64, a946: u64, a947: u64, a948: u64, a949: u64,
+    a950: u64, a951: u64, a952: u64, a953: u64, a954: u64, a955: u64, a956: u64, a957: u64, a958: u64, a959: u64,
+    a960: u64, a961: u64, a962: u64, a963: u64, a964: u64, a965: u64, a966: u64, a967: u64, a968: u64, a969: u64,
+    a970: u64, a971: u64, a972: u64, a973: u64, a974: u64, a975: u64, a976: u64, a977: u64, a978: u64, a979: u64,
+    a980: u64, a981: u64, a982: u64, a983: u64, a984: u64, a985: u64, a986: u64, a987: u64, a988: u64, a989: u64,
+    a990: u64, a991: u64, a992: u64, a993: u64, a994: u64, a995: u64, a996: u64, a997: u64, a998: u64, a999: u64,
+    a1000: u64, a1001: u64, a1002: u64, a1003: u64, a1004: u64, a1005: u64, a1006: u64, a1007: u64, a1008: u64, a1009: u64,
+    a1010: u64, a1011: u64, a1012: u64, a1013: u64, a1014: u64, a1015: u64, a1016: u64, a1017: u64, a1018: u64, a1019: u64,
+    a1020: u64, a1021: u64, a1022: u64, a1023: u64, a1024: u64, a1025: u64, a1026: u64, a1027: u64, a1028: u64, a1029: u64,
+    a1030: u64, a1031: u64, a1032: u64, a1033: u64, a1034: u64, a1035: u64, a1036: u64, a1037: u64, a1038: u64, a1039: u64,
+    a1040: u64, a1041: u64, a1042: u64, a1043: u64, a1044: u64, a1045: u64, a1046: u64, a1047: u64, a1048: u64, a1049: u64,
+    a1050: u64, a1051: u64, a1052: u64, a1053: u64, a1054: u64, a1055: u64, a1056: u64, a1057: u64, a1058: u64, a1059: u64,
+    a1060: u64, a1061: u64, a1062: u64, a1063: u64, a1064: u64, a1065: u64, a1066: u64, a1067: u64, a1068: u64, a1069: u64,
+    a1070: u64, a1071: u64, a1072: u64, a1073: u64, a1074: u64, a1075: u64, a1076: u64, a1077: u64, a1078: u64, a1079: u64,
+    a1080: u64, a1081: u64, a1082: u64, a1083: u64, a1084: u64, a1085: u64, a1086: u64, a1087: u64, a1088: u64, a1089: u64,
+    a1090: u64, a1091: u64, a1092: u64, a1093: u64, a1094: u64, a1095: u64, a1096: u64, a1097: u64, a1098: u64, a1099: u64,
+    a1100: u64, a1101: u64, a1102: u64, a1103: u64, a1104: u64, a1105: u64, a1106: u64, a1107: u64, a1108: u64, a1109: u64,
+    a1110: u64, a1111: u64, a1112: u64, a1113: u64, a1114: u64, a1115: u64, a1116: u64, a1117: u64, a1118: u64, a1119: u64,
+    a1120: u64, a1121: u64, a1122: u64, a1123: u64, a1124: u64, a1125: u64, a1126: u64, a1127: u64, a1128: u64, a1129: u64,
+    a1130: u64, a1131: u64, a1132: u64, a1133: u64, a1134: u64, a1135: u64, a1136: u64, a1137: u64, a1138: u64, a1139: u64,
+    a1140: u64, a1141: u64, a1142: u64, a1143: u64, a1144: u64, a1145: u64, a1146: u64, a1147: u64, a1148: u64, a1149: u64,
+    a1150: u64, a1151: u64, a1152: u64, a1153: u64, a1154: u64, a1155: u64, a1156: u64, a1157: u64, a1158: u64, a1159: u64,
+    a1160: u64, a1161: u64, a1162: u64, a1163: u64, a1164: u64, a1165: u64, a1166: u64, a1167: u64, a1168: u64, a1169: u64,
+    a1170: u64, a1171: u64, a1172: u64, a1173: u64, a1174: u64, a1175: u64, a1176: u64, a1177: u64, a1178: u64, a1179: u64,
+    a1180: u64, a1181: u64, a1182: u64, a1183: u64, a1184: u64, a1185: u64, a1186: u64, a1187: u64, a1188: u64, a1189: u64,
+    a1190: u64, a1191: u64, a1192: u64, a1193: u64, a1194: u64, a1195: u64, a1196: u64, a1197: u64, a1198: u64, a1199: u64,
+    a1200: u64, a1201: u64, a1202: u64, a1203: u64, a1204: u64, a1205: u64, a1206: u64, a1207: u64, a1208: u64, a1209: u64,
+    a1210: u64, a1211: u64, a1212: u64, a1213: u64, a1214: u64, a1215: u64, a1216: u64, a1217: u64, a1218: u64, a1219: u64,
+    a1220: u64, a1221: u64, a1222: u64, a1223: u64, a1224: u64, a1225: u64, a1226: u64, a1227: u64, a1228: u64, a1229: u64,
+    a1230: u64, a1231: u64, a1232: u64, a1233: u64, a1234: u64, a1235: u64, a1236: u64, a1237: u64, a1238: u64, a1239: u64,
+    a1240: u64, a1241: u64, a1242: u64, a1243: u64, a1244: u64, a1245: u64, a1246: u64, a1247: u64, a1248: u64, a1249: u64,
+    a1250: u64, a1251: u64, a1252: u64, a1253: u64, a1254: u64, a1255: u64, a1256: u64, a1257: u64, a1258: u64, a1259: u64,
+    a1260: u64, a1261: u64, a1262: u64, a1263: u64, a1264: u64, a1265: u64, a1266: u64, a1267: u64, a1268: u64, a1269: u64,
+    a1270: u64, a1271: u64, a1272: u64, a1273: u64, a1274: u64, a1275: u64, a1276: u64, a1277: u64, a1278: u64, a1279: u64,
+    a1280: u64, a1281: u64, a1282: u64, a1283: u64, a1284: u64, a1285: u64, a1286: u64, a1287: u64, a1288: u64, a1289: u64,
+    a1290: u64, a1291: u64, a1292: u64, a1293: u64, a1294: u64, a1295: u64, a1296: u64, a1297: u64, a1298: u64, a1299: u64,
+    a1300: u64, a1301: u64, a1302: u64, a1303: u64, a1304: u64, a1305: u64, a1306: u64, a1307: u64, a1308: u64, a1309: u64,
+    a1310: u64, a1311: u64, a1312: u64, a1313: u64, a1314: u64, a1315: u64, a1316: u64, a1317: u64, a1318: u64, a1319: u64,
+    a1320: u64, a1321: u64, a1322: u64, a1323: u64, a1324: u64, a1325: u64, a1326: u64, a1327: u64, a1328: u64, a1329: u64,
+    a1330: u64, a1331: u64, a1332: u64, a1333: u64, a1334: u64, a1335: u64, a1336: u64, a1337: u64, a1338: u64, a1339: u64,
+    a1340: u64, a1341: u64, a1342: u64, a1343: u64, a1344: u64, a1345: u64, a1346: u64, a1347: u64, a1348: u64, a1349: u64,
+    a1350: u64, a1351: u64, a1352: u64, a1353: u64, a1354: u64, a1355: u64, a1356: u64, a1357: u64, a1358: u64, a1359: u64,
+    a1360: u64, a1361: u64, a1362: u64, a1363: u64, a1364: u64, a1365: u64, a1366: u64, a1367: u64, a1368: u64, a1369: u64,
+    a1370: u64, a1371: u64, a1372: u64, a1373: u64, a1374: u64, a1375: u64, a1376: u64, a1377: u64, a1378: u64, a1379: u64,
+    a1380: u64, a1381: u64, a1382: u64, a1383: u64, a1384: u64, a1385: u64, a1386: u64, a1387: u64, a1388: u64, a1389: u64,
+    a1390: u64, a1391: u64, a1392: u64, a1393: u64, a1394: u64, a1395: u64, a1396: u64, a1397: u64, a1398: u64, a1399: u64,
+    a1400: u64, a1401: u64, a1402: u64, a1403: u64, a1404: u64, a1405: u64, a1406: u64, a1407: u64, a1408: u64, a1409: u64,
+    a1410: u64, a1411: u64, a1412: u64, a1413: u64, a1414: u64, a1415: u64, a1416: u64, a1417: u64, a1418: u64, a1419: u64,
+    a1420: u64, a1421: u64, a1422: u64, a1423: u64, a1424: u64, a1425: u64, a1426: u64, a1427: u64, a1428: u64, a1429: u64,
+    a1430: u64, a1431: u64, a1432: u64, a1433: u64, a1434: u64, a1435: u64, a1436: u64, a1437: u64, a1438: u64, a1439: u64,
+    a1440: u64, a1441: u64, a1442: u64, a1443: u64, a1444: u64, a1445: u64, a1446: u64, a1447: u64, a1448: u64, a1449: u64,
+    a1450: u64, a1451: u64, a1452: u64, a1453: u64, a1454: u64, a1455: u64, a1456: u64, a1457: u64, a1458: u64, a1459: u64,
+    a1460: u64, a1461: u64, a1462: u64, a1463: u64, a1464: u64, a1465: u64, a1466: u64, a1467: u64, a1468: u64, a1469: u64,
+    a1470: u64, a1471: u64, a1472: u64, a1473: u64, a1474: u64, a1475: u64, a1476: u64, a1477: u64, a1478: u64, a1479: u64,
+    a1480: u64, a1481: u64, a1482: u64, a1483: u64, a1484: u64, a1485: u64, a1486: u64, a1487: u64, a1488: u64, a1489: u64,
+    a1490: u64, a1491: u64, a1492: u64, a1493: u64, a1494: u64, a1495: u64, a1496: u64, a1497: u64, a1498: u64, a1499: u64,
+    a1500: u64, a1501: u64, a1502: u64, a1503: u64, a1504: u64, a1505: u64, a1506: u64, a1507: u64, a1508: u64, a1509: u64,
+    a1510: u64, a1511: u64, a1512: u64, a1513: u64, a1514: u64, a1515: u64, a1516: u64, a1517: u64, a1518: u64, a1519: u64,
+    a1520: u64, a1521: u64, a1522: u64, a1523: u64, a1524: u64, a1525: u64, a1526: u64, a1527: u64, a1528: u64, a1529: u64,
+    a1530: u64, a1531: u64, a1532: u64, a1533: u64, a1534: u64, a1535: u64, a1536: u64, a1537: u64, a1538: u64, a1539: u64,
+    a1540: u64, a1541: u64, a1542: u64, a1543: u64, a1544: u64, a1545: u64, a1546: u64, a1547: u64, a1548: u64, a1549: u64,
+    a1550: u64, a1551: u64, a1552: u64, a1553: u64, a1554: u64, a1555: u64, a1556: u64, a1557: u64, a1558: u64, a1559: u64,
+    a1560: u64, a1561: u64, a1562: u64, a1563: u64, a1564: u64, a1565: u64, a1566: u64, a1567: u64, a1568: u64, a1569: u64,
+    a1570: u64, a1571: u64, a1572: u64, a1573: u64, a1574: u64, a1575: u64, a1576: u64, a1577: u64, a1578: u64, a1579: u64,
+    a1580: u64, a1581: u64, a1582: u64, a1583: u64, a1584: u64, a1585: u64, a1586: u64, a1587: u64, a1588: u64, a1589: u64,
+    a1590: u64, a1591: u64, a1592: u64, a1593: u64, a1594: u64, a1595: u64, a1596: u64, a1597: u64, a1598: u64, a1599: u64,
+    a1600: u64, a1601: u64, a1602: u64, a1603: u64, a1604: u64, a1605: u64, a1606: u64, a1607: u64, a1608: u64, a1609: u64,
+    a1610: u64, a1611: u64, a1612: u64, a1613: u64, a1614: u64, a1615: u64, a1616: u64, a1617: u64, a1618: u64, a1619: u64,
+    a1620: u64, a1621: u64, a1622: u64, a1623: u64, a1624: u64, a1625: u64, a1626: u64, a1627: u64, a1628: u64, a1629: u64,
+    a1630: u64, a1631: u64, a1632: u64, a1633: u64, a1634: u64, a1635: u64, a1636: u64, a1637: u64, a1638: u64, a1639: u64,
+    a1640: u64, a1641: u64, a1642: u64, a1643: u64, a1644: u64, a1645: u64, a1646: u64, a1647: u64, a1648: u64, a1649: u64,
+    a1650: u64, a1651: u64, a1652: u64, a1653: u64, a1654: u64, a1655: u64, a1656: u64, a1657: u64, a1658: u64, a1659: u64,
+    a1660: u64, a1661: u64, a1662: u64, a1663: u64, a1664: u64, a1665: u64, a1666: u64, a1667: u64, a1668: u64, a1669: u64,
+    a1670: u64, a1671: u64, a1672: u64, a1673: u64, a1674: u64, a1675: u64, a1676: u64, a1677: u64, a1678: u64, a1679: u64,
+    a1680: u64, a1681: u64, a1682: u64, a1683: u64, a1684: u64, a1685: u64, a1686: u64, a1687: u64, a1688: u64, a1689: u64,
+    a1690: u64, a1691: u64, a1692: u64, a1693: u64, a1694: u64, a1695: u64, a1696: u64, a1697: u64, a1698: u64, a1699: u64,
+    a1700: u64, a1701: u64, a1702: u64, a1703: u64, a1704: u64, a1705: u64, a1706: u64, a1707: u64, a1708: u64, a1709: u64,
+    a1710: u64, a1711: u64, a1712: u64, a1713: u64, a1714: u64, a1715: u64, a1716: u64, a1717: u64, a1718: u64, a1719: u64,
+    a1720: u64, a1721: u64, a1722: u64, a1723: u64, a1724: u64, a1725: u64, a1726: u64, a1727: u64, a1728: u64, a1729: u64,
+    a1730: u64, a1731: u64, a1732: u64, a1733: u64, a1734: u64, a1735: u64, a1736: u64, a1737: u64, a1738: u64, a1739: u64,
+    a1740: u64, a1741: u64, a1742: u64, a1743: u64, a1744: u64, a1745: u64, a1746: u64, a1747: u64, a1748: u64, a1749: u64,
+    a1750: u64, a1751: u64, a1752: u64, a1753: u64, a1754: u64, a1755: u64, a1756: u64, a1757: u64, a1758: u64, a1759: u64,
+    a1760: u64, a1761: u64, a1762: u64, a1763: u64, a1764: u64, a1765: u64, a1766: u64, a1767: u64, a1768: u64, a1769: u64,
+    a1770: u64, a1771: u64, a1772: u64, a1773: u64, a1774: u64, a1775: u64, a1776: u64, a1777: u64, a1778: u64, a1779: u64,
+    a1780: u64, a1781: u64, a1782: u64, a1783: u64, a1784: u64, a1785: u64, a1786: u64, a1787: u64, a1788: u64, a1789: u64,
+    a1790: u64, a1791: u64, a1792: u64, a1793: u64, a1794: u64, a1795: u64, a1796: u64, a1797: u64, a1798: u64, a1799: u64,
+    a1800: u64, a1801: u64, a1802: u64, a1803: u64, a1804: u64, a1805: u64, a1806: u64, a1807: u64, a1808: u64, a1809: u64,
+    a1810: u64, a1811: u64, a1812: u64, a1813: u64, a1814: u64, a1815: u64, a1816: u64, a1817: u64, a1818: u64, a1819: u64,
+    a1820: u64, a1821: u64, a1822: u64, a1823: u64, a1824: u64, a1825: u64, a1826: u64, a1827: u64, a1828: u64, a1829: u64,
+    a1830: u64, a1831: u64, a1832: u64, a1833: u64, a1834: u64, a1835: u64, a1836: u64, a1837: u64, a1838: u64, a1839: u64,
+    a1840: u64, a1841: u64, a1842: u64, a1843: u64, a1844: u64, a1845: u64, a1846: u64, a1847: u64, a1848: u64, a1849: u64,
+    a1850: u64, a1851: u64, a1852: u64, a1853: u64, a1854: u64, a1855: u64, a1856: u64, a1857: u64, a1858: u64, a1859: u64,
+    a1860: u64, a1861: u64, a1862: u64, a1863: u64, a1864: u64, a1865: u64, a1866: u64, a1867: u64, a1868: u64, a1869: u64,
+    a1870: u64, a1871: u64, a1872: u64, a1873: u64, a1874: u64, a1875: u64, a1876: u64, a1877: u64, a1878: u64, a1879: u64,
+    a1880: u64, a1881: u64, a1882: u64, a1883: u64, a1884: u64, a1885: u64, a1886: u64, a1887: u64, a1888: u64, a1889: u64,
+    a1890: u64, a1891: u64, a1892: u64, a1893: u64, a1894: u64, a1895: u64, a1896: u64, a1897: u64, a1898: u64, a1899: u64,
+    a1900: u64, a1901: u64, a1902: u64, a1903: u64, a1904: u64, a1905: u64, a1906: u64, a1907: u64, a1908: u64, a1909: u64,
+    a1910: u64, a1911: u64, a1912: u64, a1913: u64, a1914: u64, a1915: u64, a1916: u64, a1917: u64, a1918: u64, a1919: u64,
+    a1920: u64, a1921: u64, a1922: u64, a1923: u64, a1924: u64, a1925: u64, a1926: u64, a1927: u64, a1928: u64, a1929: u64,
+    a1930: u64, a1931: u64, a1932: u64, a1933: u64, a1934: u64, a1935: u64, a1936: u64, a1937: u64, a1938: u64, a1939: u64,
+    a1940: u64, a1941: u64, a1942: u64, a1943: u64, a1944: u64, a1945: u64, a1946: u64, a1947: u64, a1948: u64, a1949: u64,
+    a1950: u64, a1951: u64, a1952: u64, a1953: u64, a1954: u64, a1955: u64, a1956: u64, a1957: u64, a1958: u64, a1959: u64,
+    a1960: u64, a1961: u64, a1962: u64, a1963: u64, a1964: u64, a1965: u64, a1966: u64, a1967: u64, a1968: u64, a1969: u64,
+    a1970: u64, a1971: u64, a1972: u64, a1973: u64, a1974: u64, a1975: u64, a1976: u64, a1977: u64, a1978: u64, a1979: u64,
+    a1980: u64, a1981: u64, a1982: u64, a1983: u64, a1984: u64, a1985: u64, a1986: u64, a1987: u64, a1988: u64, a1989: u64,
+    a1990: u64, a1991: u64, a1992: u64, a1993: u64, a1994: u64, a1995: u64, a1996: u64, a1997: u64, a1998: u64, a1999: u64,
+    a2000: u64, a2001: u64, a2002: u64, a2003: u64, a2004: u64, a2005: u64, a2006: u64, a2007: u64, a2008: u64, a2009: u64,
+    a2010: u64, a2011: u64, a2012: u64, a2013: u64, a2014: u64, a2015: u64, a2016: u64, a2017: u64, a2018: u64, a2019: u64,
+    a2020: u64, a2021: u64, a2022: u64, a2023: u64, a2024: u64, a2025: u64, a2026: u64, a2027: u64, a2028: u64, a2029: u64,
+    a2030: u64, a2031: u64, a2032: u64, a2033: u64, a2034: u64, a2035: u64, a2036: u64, a2037: u64, a2038: u64, a2039: u64,
+    a2040: u64, a2041: u64, a2042: u64, a2043: u64, a2044: u64, a2045: u64, a2046: u64, a2047: u64, a2048: u64, a2049: u64,
+    a2050: u64, a2051: u64, a2052: u64, a2053: u64, a2054: u64, a2055: u64, a2056: u64, a2057: u64, a2058: u64, a2059: u64,
+    a2060: u64, a2061: u64, a2062: u64, a2063: u64, a2064: u64, a2065: u64, a2066: u64, a2067: u64, a2068: u64, a2069: u64,
+    a2070: u64, a2071: u64, a2072: u64, a2073: u64, a2074: u64, a2075: u64, a2076: u64, a2077: u64, a2078: u64, a2079: u64,
+    a2080: u64, a2081: u64, a2082: u64, a2083: u64, a2084: u64, a2085: u64, a2086: u64, a2087: u64, a2088: u64, a2089: u64,
+    a2090: u64, a2091: u64, a2092: u64, a2093: u64, a2094: u64, a2095: u64, a2096: u64, a2097: u64, a2098: u64, a2099: u64,
+    a2100: u64, a2101: u64, a2102: u64, a2103: u64, a2104: u64, a2105: u64, a2106: u64, a2107: u64, a2108: u64, a2109: u64,
+    a2110: u64, a2111: u64, a2112: u64, a2113: u64, a2114: u64, a2115: u64, a2116: u64, a2117: u64, a2118: u64, a2119: u64,
+    a2120: u64, a2121: u64, a2122: u64, a2123: u64, a2124: u64, a2125: u64, a2126: u64, a2127: u64, a2128: u64, a2129: u64,
+    a2130: u64, a2131: u64, a2132: u64, a2133: u64, a2134: u64, a2135: u64, a2136: u64, a2137: u64, a2138: u64, a2139: u64,
+    a2140: u64, a2141: u64, a2142: u64, a2143: u64, a2144: u64, a2145: u64, a2146: u64, a2147: u64, a2148: u64, a2149: u64,
+    a2150: u64, a2151: u64, a2152: u64, a2153: u64, a2154: u64, a2155: u64, a2156: u64, a2157: u64, a2158: u64, a2159: u64,
+    a2160: u64, a2161: u64, a2162: u64, a2163: u64, a2164: u64, a2165: u64, a2166: u64, a2167: u64, a2168: u64, a2169: u64,
+    a2170: u64, a2171: u64, a2172: u64, a2173: u64, a2174: u64, a2175: u64, a2176: u64, a2177: u64, a2178: u64, a2179: u64,
+    a2180: u64, a2181: u64, a2182: u64, a2183: u64, a2184: u64, a2185: u64, a2186: u64, a2187: u64, a2188: u64, a2189: u64,
+    a2190: u64, a2191: u64, a2192: u64, a2193: u64, a2194: u64, a2195: u64, a2196: u64, a2197: u64, a2198: u64, a2199: u64,
+    a2200: u64, a2201: u64, a2202: u64, a2203: u64, a2204: u64, a2205: u64, a2206: u64, a2207: u64, a2208: u64, a2209: u64,
+    a2210: u64, a2211: u64, a2212: u64, a2213: u64, a2214: u64, a2215: u64, a2216: u64, a2217: u64, a2218: u64, a2219: u64,
+    a2220: u64, a2221: u64, a2222: u64, a2223: u64, a2224: u64, a2225: u64, a2226: u64, a2227: u64, a2228: u64, a2229: u64,
+    a2230: u64, a2231: u64, a2232: u64, a2233: u64, a2234: u64, a2235: u64, a2236: u64, a2237: u64, a2238: u64, a2239: u64,
+    a2240: u64, a2241: u64, a2242: u64, a2243: u64, a2244: u64, a2245: u64, a2246: u64, a2247: u64, a2248: u64, a2249: u64,
+    a2250: u64, a2251: u64, a2252: u64, a2253: u64, a2254: u64, a2255: u64, a2256: u64, a2257: u64, a2258: u64, a2259: u64,
+    a2260: u64, a2261: u64, a2262: u64, a2263: u64, a2264: u64, a2265: u64, a2266: u64, a2267: u64, a2268: u64, a2269: u64,
+    a2270: u64, a2271: u64, a2272: u64, a2273: u64, a2274: u64, a2275: u64, a2276: u64, a2277: u64, a2278: u64, a2279: u64,
+    a2280: u64, a2281: u64, a2282: u64, a2283: u64, a2284: u64, a2285: u64, a2286: u64, a2287: u64, a2288: u64, a2289: u64,
+    a2290: u64, a2291: u64, a2292: u64, a2293: u64, a2294: u64, a2295: u64, a2296: u64, a2297: u64, a2298: u64, a2299: u64,
+    a2300: u64, a2301: u64, a2302: u64, a2303: u64, a2304: u64, a2305: u64, a2306: u64, a2307: u64, a2308: u64, a2309: u64,
+    a2310: u64, a2311: u64, a2312: u64, a2313: u64, a2314: u64, a2315: u64, a2316: u64, a2317: u64, a2318: u64, a2319: u64,
+    a2320: u64, a2321: u64, a2322: u64, a2323: u64, a2324: u64, a2325: u64, a2326: u64, a2327: u64, a2328: u64, a2329: u64,
+    a2330: u64, a2331: u64, a2332: u64, a2333: u64, a2334: u64, a2335: u64, a2336: u64, a2337: u64, a2338: u64, a2339: u64,
+    a2340: u64, a2341: u64, a2342: u64, a2343: u64, a2344: u64, a2345: u64, a2346: u64, a2347: u64, a2348: u64, a2349: u64,
+    a2350: u64, a2351: u64, a2352: u64, a2353: u64, a2354: u64, a2355: u64, a2356: u64, a2357: u64, a2358: u64, a2359: u64,
+    a2360: u64, a2361: u64, a2362: u64, a2363: u64, a2364: u64, a2365: u64, a2366: u64, a2367: u64, a2368: u64, a2369: u64,
+    a2370: u64, a2371: u64, a2372: u64, a2373: u64, a2374: u64, a2375: u64, a2376: u64, a2377: u64, a2378: u64, a2379: u64,
+    a2380: u64, a2381: u64, a2382: u64, a2383: u64, a2384: u64, a2385: u64, a2386: u64, a2387: u64, a2388: u64, a2389: u64,
+    a2390: u64, a2391: u64, a2392: u64, a2393: u64, a2394: u64, a2395: u64, a2396: u64, a2397: u64, a2398: u64, a2399: u64,
+    a2400: u64, a2401: u64, a2402: u64, a2403: u64, a2404: u64, a2405: u64, a2406: u64, a2407: u64, a2408: u64, a2409: u64,
+    a2410: u64, a2411: u64, a2412: u64, a2413: u64, a2414: u64, a2415: u64, a2416: u64, a2417: u64, a2418: u64, a2419: u64,
+    a2420: u64, a2421: u64, a2422: u64, a2423: u64, a2424: u64, a2425: u64, a2426: u64, a2427: u64, a2428: u64, a2429: u64,
+    a2430: u64, a2431: u64, a2432: u64, a2433: u64, a2434: u64, a2435: u64, a2436: u64, a2437: u64, a2438: u64, a2439: u64,
+    a2440: u64, a2441: u64, a2442: u64, a2443: u64, a2444: u64, a2445: u64, a2446: u64, a2447: u64, a2448: u64, a2449: u64,
+    a2450: u64, a2451: u64, a2452: u64, a2453: u64, a2454: u64, a2455: u64, a2456: u64, a2457: u64, a2458: u64, a2459: u64,
+    a2460: u64, a2461: u64, a2462: u64, a2463: u64, a2464: u64, a2465: u64, a2466: u64, a2467: u64, a2468: u64, a2469: u64,
+    a2470: u64, a2471: u64, a2472: u64, a2473: u64, a2474: u64, a2475: u64, a2476: u64, a2477: u64, a2478: u64, a2479: u64,
+    a2480: u64, a2481: u64, a2482: u64, a2483: u64, a2484: u64, a2485: u64, a2486: u64, a2487: u64, a2488: u64, a2489: u64,
+    a2490: u64, a2491: u64, a2492: u64, a2493: u64, a2494: u64, a2495: u64, a2496: u64, a2497: u64, a2498: u64, a2499: u64,
+    a2500: u64, a2501: u64, a2502: u64, a2503: u64, a2504: u64, a2505: u64, a2506: u64, a2507: u64, a2508: u64, a2509: u64,
+    a2510: u64, a2511: u64, a2512: u64, a2513: u64, a2514: u64, a2515: u64, a2516: u64, a2517: u64, a2518: u64, a2519: u64,
+    a2520: u64, a2521: u64, a2522: u64, a2523: u64, a2524: u64, a2525: u64, a2526: u64, a2527: u64, a2528: u64, a2529: u64,
+    a2530: u64, a2531: u64, a2532: u64, a2533: u64, a2534: u64, a2535: u64, a2536: u64, a2537: u64, a2538: u64, a2539: u64,
+    a2540: u64, a2541: u64, a2542: u64, a2543: u64, a2544: u64, a2545: u64, a2546: u64, a2547: u64, a2548: u64, a2549: u64,
+    a2550: u64, a2551: u64, a2552: u64, a2553: u64, a2554: u64, a2555: u64, a2556: u64, a2557: u64, a2558: u64, a2559: u64,
+    a2560: u64, a2561: u64, a2562: u64, a2563: u64, a2564: u64, a2565: u64, a2566: u64, a2567: u64, a2568: u64, a2569: u64,
+    a2570: u64, a2571: u64, a2572: u64, a2573: u64, a2574: u64, a2575: u64, a2576: u64, a2577: u64, a2578: u64, a2579: u64,
+    a2580: u64, a2581: u64, a2582: u64, a2583: u64, a2584: u64, a2585: u64, a2586: u64, a2587: u64, a2588: u64, a2589: u64,
+    a2590: u64, a2591: u64, a2592: u64, a2593: u64, a2594: u64, a2595: u64, a2596: u64, a2597: u64, a2598: u64, a2599: u64,
+    a2600: u64, a2601: u64, a2602: u64, a2603: u64, a2604: u64, a2605: u64, a2606: u64, a2607: u64, a2608: u64, a2609: u64,
+    a2610: u64, a2611: u64, a2612: u64, a2613: u64, a2614: u64, a2615: u64, a2616: u64, a2617: u64, a2618: u64, a2619: u64,
+    a2620: u64, a2621: u64, a2622: u64, a2623: u64, a2624: u64, a2625: u64, a2626: u64, a2627: u64, a2628: u64, a2629: u64,
+    a2630: u64, a2631: u64, a2632: u64, a2633: u64, a2634: u64, a2635: u64, a2636: u64, a2637: u64, a2638: u64, a2639: u64,
+    a2640: u64, a2641: u64, a2642: u64, a2643: u64, a2644: u64, a2645: u64, a2646: u64, a2647: u64, a2648: u64, a2649: u64,
+    a2650: u64, a2651: u64, a2652: u64, a2653: u64, a2654: u64, a2655: u64, a2656: u64, a2657: u64, a2658: u64, a2659: u64,
+    a2660: u64, a2661: u64, a2662: u64, a2663: u64, a2664: u64, a2665: u64, a2666: u64, a2667: u64, a2668: u64, a2669: u64,
+    a2670: u64, a2671: u64, a2672: u64, a2673: u64, a2674: u64, a2675: u64, a2676: u64, a2677: u64, a2678: u64, a2679: u64,
+    a2680: u64, a2681: u64, a2682: u64, a2683: u64, a2684: u64, a2685: u64, a2686: u64, a2687: u64, a2688: u64, a2689: u64,
+    a2690: u64, a2691: u64, a2692: u64, a2693: u64, a2694: u64, a2695: u64, a2696: u64, a2697: u64, a2698: u64, a2699: u64,
+    a2700: u64, a2701: u64, a2702: u64, a2703: u64, a2704: u64, a2705: u64, a2706: u64, a2707: u64, a2708: u64, a2709: u64,
+    a2710: u64, a2711: u64, a2712: u64, a2713: u64, a2714: u64, a2715: u64, a2716: u64, a2717: u64, a2718: u64, a2719: u64,
+    a2720: u64, a2721: u64, a2722: u64, a2723: u64, a2724: u64, a2725: u64, a2726: u64, a2727: u64, a2728: u64, a2729: u64,
+    a2730: u64, a2731: u64, a2732: u64, a2733: u64, a2734: u64, a2735: u64, a2736: u64, a2737: u64, a2738: u64, a2739: u64,
+    a2740: u64, a2741: u64, a2742: u64, a2743: u64, a2744: u64, a2745: u64, a2746: u64, a2747: u64, a2748: u64, a2749: u64,
+    a2750: u64, a2751: u64, a2752: u64, a2753: u64, a2754: u64, a2755: u64, a2756: u64, a2757: u64, a2758: u64, a2759: u64,
+    a2760: u64, a2761: u64, a2762: u64, a2763: u64, a2764: u64, a2765: u64, a2766: u64, a2767: u64, a2768: u64, a2769: u64,
+    a2770: u64, a2771: u64, a2772: u64, a2773: u64, a2774: u64, a2775: u64, a2776: u64, a2777: u64, a2778: u64, a2779: u64,
+    a2780: u64, a2781: u64, a2782: u64, a2783: u64, a2784: u64, a2785: u64, a2786: u64, a2787: u64, a2788: u64, a2789: u64,
+    a2790: u64, a2791: u64, a2792: u64, a2793: u64, a2794: u64, a2795: u64, a2796: u64, a2797: u64, a2798: u64, a2799: u64,
+    a2800: u64, a2801: u64, a2802: u64, a2803: u64, a2804: u64, a2805: u64, a2806: u64, a2807: u64, a2808: u64, a2809: u64,
+    a2810: u64, a2811: u64, a2812: u64, a2813: u64, a2814: u64, a2815: u64, a2816: u64, a2817: u64, a2818: u64, a2819: u64,
+    a2820: u64, a2821: u64, a2822: u64, a2823: u64, a2824: u64, a2825: u64, a2826: u64, a2827: u64, a2828: u64, a2829: u64,
+    a2830: u64, a2831: u64, a2832: u64, a2833: u64, a2834: u64, a2835: u64, a2836: u64, a2837: u64, a2838: u64, a2839: u64,
+    a2840: u64, a2841: u64, a2842: u64, a2843: u64, a2844: u64, a2845: u64, a2846: u64, a2847: u64, a2848: u64, a2849: u64,
+    a2850: u64, a2851: u64, a2852: u64, a2853: u64, a2854: u64, a2855: u64, a2856: u64, a2857: u64, a2858: u64, a2859: u64,
+    a2860: u64, a2861: u64, a2862: u64, a2863: u64, a2864: u64, a2865: u64, a2866: u64, a2867: u64, a2868: u64, a2869: u64,
+    a2870: u64, a2871: u64, a2872: u64, a2873: u64, a2874: u64, a2875: u64, a2876: u64, a2877: u64, a2878: u64, a2879: u64,
+    a2880: u64, a2881: u64, a2882: u64, a2883: u64, a2884: u64, a2885: u64, a2886: u64, a2887: u64, a2888: u64, a2889: u64,
+    a2890: u64, a2891: u64, a2892: u64, a2893: u64, a2894: u64, a2895: u64, a2896: u64, a2897: u64, a2898: u64, a2899: u64,
+    a2900: u64, a2901: u64, a2902: u64, a2903: u64, a2904: u64, a2905: u64, a2906: u64, a2907: u64, a2908: u64, a2909: u64,
+    a2910: u64, a2911: u64, a2912: u64, a2913: u64, a2914: u64, a2915: u64, a2916: u64, a2917: u64, a2918: u64, a2919: u64,
+    a2920: u64, a2921: u64, a2922: u64, a2923: u64, a2924: u64, a2925: u64, a2926: u64, a2927: u64, a2928: u64, a2929: u64,
+    a2930: u64, a2931: u64, a2932: u64, a2933: u64, a2934: u64, a2935: u64, a2936: u64, a2937: u64, a2938: u64, a2939: u64,
+    a2940: u64, a2941: u64, a2942: u64, a2943: u64, a2944: u64, a2945: u64, a2946: u64, a2947: u64, a2948: u64, a2949: u64,
+    a2950: u64, a2951: u64, a2952: u64, a2953: u64, a2954: u64, a2955: u64, a2956: u64, a2957: u64, a2958: u64, a2959: u64,
+    a2960: u64, a2961: u64, a2962: u64, a2963: u64, a2964: u64, a2965: u64, a2966: u64, a2967: u64, a2968: u64, a2969: u64,
+    a2970: u64, a2971: u64, a2972: u64, a2973: u64, a2974: u64, a2975: u64, a2976: u64, a2977: u64, a2978: u64, a2979: u64,
+    a2980: u64, a2981: u64, a2982: u64, a2983: u64, a2984: u64, a2985: u64, a2986: u64, a2987: u64, a2988: u64, a2989: u64,
+    a2990: u64, a2991: u64, a2992: u64, a2993: u64, a2994: u64, a2995: u64, a2996: u64, a2997: u64, a2998: u64, a2999: u64,
+    a3000: u64, a3001: u64, a3002: u64, a3003: u64, a3004: u64, a3005: u64, a3006: u64, a3007: u64, a3008: u64, a3009: u64,
+    a3010: u64, a3011: u64, a3012: u64, a3013: u64, a3014: u64, a3015: u64, a3016: u64, a3017: u64, a3018: u64, a3019: u64,
+    a3020: u64, a3021: u64, a3022: u64, a3023: u64, a3024: u64, a3025: u64, a3026: u64, a3027: u64, a3028: u64, a3029: u64,
+    a3030: u64, a3031: u64, a3032: u64, a3033: u64, a3034: u64, a3035: u64, a3036: u64, a3037: u64, a3038: u64, a3039: u64,
+    a3040: u64, a3041: u64, a3042: u64, a3043: u64, a3044: u64, a3045: u64, a3046: u64, a3047: u64, a3048: u64, a3049: u64,
+    a3050: u64, a3051: u64, a3052: u64, a3053: u64, a3054: u64, a3055: u64, a3056: u64, a3057: u64, a3058: u64, a3059: u64,
+    a3060: u64, a3061: u64, a3062: u64, a3063: u64, a3064: u64, a3065: u64, a3066: u64, a3067: u64, a3068: u64, a3069: u64,
+    a3070: u64, a3071: u64, a3072: u64, a3073: u64, a3074: u64, a3075: u64, a3076: u64, a3077: u64, a3078: u64, a3079: u64,
+    a3080: u64, a3081: u64, a3082: u64, a3083: u64, a3084: u64, a3085: u64, a3086: u64, a3087: u64, a3088: u64, a3089: u64,
+    a3090: u64, a3091: u64, a3092: u64, a3093: u64, a3094: u64, a3095: u64, a3096: u64, a3097: u64, a3098: u64, a3099: u64,
+    a3100: u64, a3101: u64, a3102: u64, a3103: u64, a3104: u64, a3105: u64, a3106: u64, a3107: u64, a3108: u64, a3109: u64,
+    a3110: u64, a3111: u64, a3112: u64, a3113: u64, a3114: u64, a3115: u64, a3116: u64, a3117: u64, a3118: u64, a3119: u64,
+    a3120: u64, a3121: u64, a3122: u64, a3123: u64, a3124: u64, a3125: u64, a3126: u64, a3127: u64, a3128: u64, a3129: u64,
+    a3130: u64, a3131: u64, a3132: u64, a3133: u64, a3134: u64, a3135: u64, a3136: u64, a3137: u64, a3138: u64, a3139: u64,
+    a3140: u64, a3141: u64, a3142: u64, a3143: u64, a3144: u64, a3145: u64, a3146: u64, a3147: u64, a3148: u64, a3149: u64,
+    a3150: u64, a3151: u64, a3152: u64, a3153: u64, a3154: u64, a3155: u64, a3156: u64, a3157: u64, a3158: u64, a3159: u64,
+    a3160: u64, a3161: u64, a3162: u64, a3163: u64, a3164: u64, a3165: u64, a3166: u64, a3167: u64, a3168: u64, a3169: u64,
+    a3170: u64, a3171: u64, a3172: u64, a3173: u64, a3174: u64, a3175: u64, a3176: u64, a3177: u64, a3178: u64, a3179: u64,
+    a3180: u64, a3181: u64, a3182: u64, a3183: u64, a3184: u64, a3185: u64, a3186: u64, a3187: u64, a3188: u64, a3189: u64,
+    a3190: u64, a3191: u64, a3192: u64, a3193: u64, a3194: u64, a3195: u64, a3196: u64, a3197: u64, a3198: u64, a3199: u64,
+    a3200: u64, a3201: u64, a3202: u64, a3203: u64, a3204: u64, a3205: u64, a3206: u64, a3207: u64, a3208: u64, a3209: u64,
+    a3210: u64, a3211: u64, a3212: u64, a3213: u64, a3214: u64, a3215: u64, a3216: u64, a3217: u64, a3218: u64, a3219: u64,
+    a3220: u64, a3221: u64, a3222: u64, a3223: u64, a3224: u64, a3225: u64, a3226: u64, a3227: u64, a3228: u64, a3229: u64,
+    a3230: u64, a3231: u64, a3232: u64, a3233: u64, a3234: u64, a3235: u64, a3236: u64, a3237: u64, a3238: u64, a3239: u64,
+    a3240: u64, a3241: u64, a3242: u64, a3243: u64, a3244: u64, a3245: u64, a3246: u64, a3247: u64, a3248: u64, a3249: u64,
+    a3250: u64, a3251: u64, a3252: u64, a3253: u64, a3254: u64, a3255: u64, a3256: u64, a3257: u64, a3258: u64, a3259: u64,
+    a3260: u64, a3261: u64, a3262: u64, a3263: u64, a3264: u64, a3265: u64, a3266: u64, a3267: u64, a3268: u64, a3269: u64,
+    a3270: u64, a3271: u64, a3272: u64, a3273: u64, a3274: u64, a3275: u64, a3276: u64, a3277: u64, a3278: u64, a3279: u64,
+    a3280: u64, a3281: u64, a3282: u64, a3283: u64, a3284: u64, a3285: u64, a3286: u64, a3287: u64, a3288: u64, a3289: u64,
+    a3290: u64, a3291: u64, a3292: u64, a3293: u64, a3294: u64, a3295: u64, a3296: u64, a3297: u64, a3298: u64, a3299: u64,
+    a3300: u64, a3301: u64, a3302: u64, a3303: u64, a3304: u64, a3305: u64, a3306: u64, a3307: u64, a3308: u64, a3309: u64,
+    a3310: u64, a3311: u64, a3312: u64, a3313: u64, a3314: u64, a3315: u64, a3316: u64, a3317: u64, a3318: u64, a3319: u64,
+    a3320: u64, a3321: u64, a3322: u64, a3323: u64, a3324: u64, a3325: u64, a3326: u64, a3327: u64, a3328: u64, a3329: u64,
+    a3330: u64, a3331: u64, a3332: u64, a3333: u64, a3334: u64, a3335: u64, a3336: u64, a3337: u64, a3338: u64, a3339: u64,
+    a3340: u64, a3341: u64, a3342: u64, a3343: u64, a3344: u64, a3345: u64, a3346: u64, a3347: u64, a3348: u64, a3349: u64,
+    a3350: u64, a3351: u64, a3352: u64, a3353: u64, a3354: u64, a3355: u64, a3356: u64, a3357: u64, a3358: u64, a3359: u64,
+    a3360: u64, a3361: u64, a3362: u64, a3363: u64, a3364: u64, a3365: u64, a3366: u64, a3367: u64, a3368: u64, a3369: u64,
+    a3370: u64, a3371: u64, a3372: u64, a3373: u64, a3374: u64, a3375: u64, a3376: u64, a3377: u64, a3378: u64, a3379: u64,
+    a3380: u64, a3381: u64, a3382: u64, a3383: u64, a3384: u64, a3385: u64, a3386: u64, a3387: u64, a3388: u64, a3389: u64,
+    a3390: u64, a3391: u64, a3392: u64, a3393: u64, a3394: u64, a3395: u64, a3396: u64, a3397: u64, a3398: u64, a3399: u64,
+    a3400: u64, a3401: u64, a3402: u64, a3403: u64, a3404: u64, a3405: u64, a3406: u64, a3407: u64, a3408: u64, a3409: u64,
+    a3410: u64, a3411: u64, a3412: u64, a3413: u64, a3414: u64, a3415: u64, a3416: u64, a3417: u64, a3418: u64, a3419: u64,
+    a3420: u64, a3421: u64, a3422: u64, a3423: u64, a3424: u64, a3425: u64, a3426: u64, a3427: u64, a3428: u64, a3429: u64,
+    a3430: u64, a3431: u64, a3432: u64, a3433: u64, a3434: u64, a3435: u64, a3436: u64, a3437: u64, a3438: u64, a3439: u64,
+    a3440: u64, a3441: u64, a3442: u64, a3443: u64, a3444: u64, a3445: u64, a3446: u64, a3447: u64, a3448: u64, a3449: u64,
+    a3450: u64, a3451: u64, a3452: u64, a3453: u64, a3454: u64, a3455: u64, a3456: u64, a3457: u64, a3458: u64, a3459: u64,
+    a3460: u64, a3461: u64, a3462: u64, a3463: u64, a3464: u64, a3465: u64, a3466: u64, a3467: u64, a3468: u64, a3469: u64,
+    a3470: u64, a3471: u64, a3472: u64, a3473: u64, a3474: u64, a3475: u64, a3476: u64, a3477: u64, a3478: u64, a3479: u64,
+    a3480: u64, a3481: u64, a3482: u64, a3483: u64, a3484: u64, a3485: u64, a3486: u64, a3487: u64, a3488: u64, a3489: u64,
+    a3490: u64, a3491: u64, a3492: u64, a3493: u64, a3494: u64, a3495: u64, a3496: u64, a3497: u64, a3498: u64, a3499: u64,
+    a3500: u64, a3501: u64, a3502: u64, a3503: u64, a3504: u64, a3505: u64, a3506: u64, a3507: u64, a3508: u64, a3509: u64,
+    a3510: u64, a3511: u64, a3512: u64, a3513: u64, a3514: u64, a3515: u64, a3516: u64, a3517: u64, a3518: u64, a3519: u64,
+    a3520: u64, a3521: u64, a3522: u64, a3523: u64, a3524: u64, a3525: u64, a3526: u64, a3527: u64, a3528: u64, a3529: u64,
+    a3530: u64, a3531: u64, a3532: u64, a3533: u64, a3534: u64, a3535: u64, a3536: u64, a3537: u64, a3538: u64, a3539: u64,
+    a3540: u64, a3541: u64, a3542: u64, a3543: u64, a3544: u64, a3545: u64, a3546: u64, a3547: u64, a3548: u64, a3549: u64,
+    a3550: u64, a3551: u64, a3552: u64, a3553: u64, a3554: u64, a3555: u64, a3556: u64, a3557: u64, a3558: u64, a3559: u64,
+    a3560: u64, a3561: u64, a3562: u64, a3563: u64, a3564: u64, a3565: u64, a3566: u64, a3567: u64, a3568: u64, a3569: u64,
+    a3570: u64, a3571: u64, a3572: u64, a3573: u64, a3574: u64, a3575: u64, a3576: u64, a3577: u64, a3578: u64, a3579: u64,
+    a3580: u64, a3581: u64, a3582: u64, a3583: u64, a3584: u64, a3585: u64, a3586: u64, a3587: u64, a3588: u64, a3589: u64,
+    a3590: u64, a3591: u64, a3592: u64, a3593: u64, a3594: u64, a3595: u64, a3596: u64, a3597: u64, a3598: u64, a3599: u64,
+    a3600: u64, a3601: u64, a3602: u64, a3603: u64, a3604: u64, a3605: u64, a3606: u64, a3607: u64, a3608: u64, a3609: u64,
+    a3610: u64, a3611: u64, a3612: u64, a3613: u64, a3614: u64, a3615: u64, a3616: u64, a3617: u64, a3618: u64, a3619: u64,
+    a3620: u64, a3621: u64, a3622: u64, a3623: u64, a3624: u64, a3625: u64, a3626: u64, a3627: u64, a3628: u64, a3629: u64,
+    a3630: u64, a3631: u64, a3632: u64, a3633: u64, a3634: u64, a3635: u64, a3636: u64, a3637: u64, a3638: u64, a3639: u64,
+    a3640: u64, a3641: u64, a3642: u64, a3643: u64, a3644: u64, a3645: u64, a3646: u64, a3647: u64, a3648: u64, a3649: u64,
+    a3650: u64, a3651: u64, a3652: u64, a3653: u64, a3654: u64, a3655: u64, a3656: u64, a3657: u64, a3658: u64, a3659: u64,
+    a3660: u64, a3661: u64, a3662: u64, a3663: u64, a3664: u64, a3665: u64, a3666: u64, a3667: u64, a3668: u64, a3669: u64,
+    a3670: u64, a3671: u64, a3672: u64, a3673: u64, a3674: u64, a3675: u64, a3676: u64, a3677: u64, a3678: u64, a3679: u64,
+    a3680: u64, a3681: u64, a3682: u64, a3683: u64, a3684: u64, a3685: u64, a3686: u64, a3687: u64, a3688: u64, a3689: u64,
+    a3690: u64, a3691: u64, a3692: u64, a3693: u64, a3694: u64, a3695: u64, a3696: u64, a3697: u64, a3698: u64, a3699: u64,
+    a3700: u64, a3701: u64, a3702: u64, a3703: u64, a3704: u64, a3705: u64, a3706: u64, a3707: u64, a3708: u64, a3709: u64,
+    a3710: u64, a3711: u64, a3712: u64, a3713: u64, a3714: u64, a3715: u64, a3716: u64, a3717: u64, a3718: u64, a3719: u64,
+    a3720: u64, a3721: u64, a3722: u64, a3723: u64, a3724: u64, a3725: u64, a3726: u64, a3727: u64, a3728: u64, a3729: u64,
+    a3730: u64, a3731: u64, a3732: u64, a3733: u64, a3734: u64, a3735: u64, a3736: u64, a3737: u64, a3738: u64, a3739: u64,
+    a3740: u64, a3741: u64, a3742: u64, a3743: u64, a3744: u64, a3745: u64, a3746: u64, a3747: u64, a3748: u64, a3749: u64,
+    a3750: u64, a3751: u64, a3752: u64, a3753: u64, a3754: u64, a3755: u64, a3756: u64, a3757: u64, a3758: u64, a3759: u64,
+    a3760: u64, a3761: u64, a3762: u64, a3763: u64, a3764: u64, a3765: u64, a3766: u64, a3767: u64, a3768: u64, a3769: u64,
+    a3770: u64, a3771: u64, a3772: u64, a3773: u64, a3774: u64, a3775: u64, a3776: u64, a3777: u64, a3778: u64, a3779: u64,
+    a3780: u64, a3781: u64, a3782: u64, a3783: u64, a3784: u64, a3785: u64, a3786: u64, a3787: u64, a3788: u64, a3789: u64,
+    a3790: u64, a3791: u64, a3792: u64, a3793: u64, a3794: u64, a3795: u64, a3796: u64, a3797: u64, a3798: u64, a3799: u64,
+    a3800: u64, a3801: u64, a3802: u64, a3803: u64, a3804: u64, a3805: u64, a3806: u64, a3807: u64, a3808: u64, a3809: u64,
+    a3810: u64, a3811: u64, a3812: u64, a3813: u64, a3814: u64, a3815: u64, a3816: u64, a3817: u64, a3818: u64, a3819: u64,
+    a3820: u64, a3821: u64, a3822: u64, a3823: u64, a3824: u64, a3825: u64, a3826: u64, a3827: u64, a3828: u64, a3829: u64,
+    a3830: u64, a3831: u64, a3832: u64, a3833: u64, a3834: u64, a3835: u64, a3836: u64, a3837: u64, a3838: u64, a3839: u64,
+    a3840: u64, a3841: u64, a3842: u64, a3843: u64, a3844: u64, a3845: u64, a3846: u64, a3847: u64, a3848: u64, a3849: u64,
+    a3850: u64, a3851: u64, a3852: u64, a3853: u64, a3854: u64, a3855: u64, a3856: u64, a3857: u64, a3858: u64, a3859: u64,
+    a3860: u64, a3861: u64, a3862: u64, a3863: u64, a3864: u64, a3865: u64, a3866: u64, a3867: u64, a3868: u64, a3869: u64,
+    a3870: u64, a3871: u64, a3872: u64, a3873: u64, a3874: u64, a3875: u64, a3876: u64, a3877: u64, a3878: u64, a3879: u64,
+    a3880: u64, a3881: u64, a3882: u64, a3883: u64, a3884: u64, a3885: u64, a3886: u64, a3887: u64, a3888: u64, a3889: u64,
+    a3890: u64, a3891: u64, a3892: u64, a3893: u64, a3894: u64, a3895: u64, a3896: u64, a3897: u64, a3898: u64, a3899: u64,
+    a3900: u64, a3901: u64, a3902: u64, a3903: u64, a3904: u64, a3905: u64, a3906: u64, a3907: u64, a3908: u64, a3909: u64,
+    a3910: u64, a3911: u64, a3912: u64, a3913: u64, a3914: u64, a3915: u64, a3916: u64, a3917: u64, a3918: u64, a3919: u64,
+    a3920: u64, a3921: u64, a3922: u64, a3923: u64, a3924: u64, a3925: u64, a3926: u64, a3927: u64, a3928: u64, a3929: u64,
+    a3930: u64, a3931: u64, a3932: u64, a3933: u64, a3934: u64, a3935: u64, a3936: u64, a3937: u64, a3938: u64, a3939: u64,
+    a3940: u64, a3941: u64, a3942: u64, a3943: u64, a3944: u64, a3945: u64, a3946: u64, a3947: u64, a3948: u64, a3949: u64,
+    a3950: u64, a3951: u64, a3952: u64, a3953: u64, a3954: u64, a3955: u64, a3956: u64, a3957: u64, a3958: u64, a3959: u64,
+    a3960: u64, a3961: u64, a3962: u64, a3963: u64, a3964: u64, a3965: u64, a3966: u64, a3967: u64, a3968: u64, a3969: u64,
+    a3970: u64, a3971: u64, a3972: u64, a3973: u64, a3974: u64, a3975: u64, a3976: u64, a3977: u64, a3978: u64, a3979: u64,
+    a3980: u64, a3981: u64, a3982: u64, a3983: u64, a3984: u64, a3985: u64, a3986: u64, a3987: u64, a3988: u64, a3989: u64,
+    a3990: u64, a3991: u64, a3992: u64, a3993: u64, a3994: u64, a3995: u64, a3996: u64, a3997: u64, a3998: u64, a3999: u64,
+    a4000: u64, a4001: u64, a4002: u64, a4003: u64, a4004: u64, a4005: u64, a4006: u64, a4007: u64, a4008: u64, a4009: u64,
+    a4010: u64, a4011: u64, a4012: u64, a4013: u64, a4014: u64, a4015: u64, a4016: u64, a4017: u64, a4018: u64, a4019: u64,
+    a4020: u64, a4021: u64, a4022: u64, a4023: u64, a4024: u64, a4025: u64, a4026: u64, a4027: u64, a4028: u64, a4029: u64,
+    a4030: u64, a4031: u64, a4032: u64, a4033: u64, a4034: u64, a4035: u64, a4036: u64, a4037: u64, a4038: u64, a4039: u64,
+    a4040: u64, a4041: u64, a4042: u64, a4043: u64, a4044: u64, a4045: u64, a4046: u64, a4047: u64, a4048: u64, a4049: u64,
+    a4050: u64, a4051: u64, a4052: u64, a4053: u64, a4054: u64, a4055: u64, a4056: u64, a4057: u64, a4058: u64, a4059: u64,
+    a4060: u64, a4061: u64, a4062: u64, a4063: u64, a4064: u64, a4065: u64, a4066: u64, a4067: u64, a4068: u64, a4069: u64,
+    a4070: u64, a4071: u64, a4072: u64, a4073: u64, a4074: u64, a4075: u64, a4076: u64, a4077: u64, a4078: u64, a4079: u64,
+    a4080: u64, a4081: u64, a4082: u64, a4083: u64, a4084: u64, a4085: u64, a4086: u64, a4087: u64, a4088: u64, a4089: u64,
+    a4090: u64, a4091: u64, a4092: u64, a4093: u64, a4094: u64, a4095: u64, a4096: u64, a4097: u64, a4098: u64, a4099: u64,
+    a4100: u64, a4101: u64, a4102: u64, a4103: u64, a4104: u64, a4105: u64, a4106: u64, a4107: u64, a4108: u64, a4109: u64,
+) -> u64 {
+    a0 + a4108 * 2 + a4109 * 3
+}
+
+fn main() -> u64 {
+    pick(
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 7,
+    )
+}
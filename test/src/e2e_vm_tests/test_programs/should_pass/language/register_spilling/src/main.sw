@@ -0,0 +1,115 @@
+script;
+
+// Each of the values below is live until it is used in the loop at the end, which is more than can
+// be held in registers at once, so some of them must be spilled to the stack.
+#[inline(never)]
+fn sum_many(x: u64) -> u64 {
+    let v0 = x + 0;
+    let v1 = x + 1;
+    let v2 = x + 2;
+    let v3 = x + 3;
+    let v4 = x + 4;
+    let v5 = x + 5;
+    let v6 = x + 6;
+    let v7 = x + 7;
+    let v8 = x + 8;
+    let v9 = x + 9;
+    let v10 = x + 10;
+    let v11 = x + 11;
+    let v12 = x + 12;
+    let v13 = x + 13;
+    let v14 = x + 14;
+    let v15 = x + 15;
+    let v16 = x + 16;
+    let v17 = x + 17;
+    let v18 = x + 18;
+    let v19 = x + 19;
+    let v20 = x + 20;
+    let v21 = x + 21;
+    let v22 = x + 22;
+    let v23 = x + 23;
+    let v24 = x + 24;
+    let v25 = x + 25;
+    let v26 = x + 26;
+    let v27 = x + 27;
+    let v28 = x + 28;
+    let v29 = x + 29;
+    let v30 = x + 30;
+    let v31 = x + 31;
+    let v32 = x + 32;
+    let v33 = x + 33;
+    let v34 = x + 34;
+    let v35 = x + 35;
+    let v36 = x + 36;
+    let v37 = x + 37;
+    let v38 = x + 38;
+    let v39 = x + 39;
+    let v40 = x + 40;
+    let v41 = x + 41;
+    let v42 = x + 42;
+    let v43 = x + 43;
+    let v44 = x + 44;
+    let v45 = x + 45;
+    let v46 = x + 46;
+    let v47 = x + 47;
+
+    let mut i = 0;
+    let mut sum = 0;
+    while i < 2 {
+        sum = sum
+            + v0
+            + v1
+            + v2
+            + v3
+            + v4
+            + v5
+            + v6
+            + v7
+            + v8
+            + v9
+            + v10
+            + v11
+            + v12
+            + v13
+            + v14
+            + v15
+            + v16
+            + v17
+            + v18
+            + v19
+            + v20
+            + v21
+            + v22
+            + v23
+            + v24
+            + v25
+            + v26
+            + v27
+            + v28
+            + v29
+            + v30
+            + v31
+            + v32
+            + v33
+            + v34
+            + v35
+            + v36
+            + v37
+            + v38
+            + v39
+            + v40
+            + v41
+            + v42
+            + v43
+            + v44
+            + v45
+            + v46
+            + v47;
+        i = i + 1;
+    }
+    sum + v47 * v1
+}
+
+fn main() -> u64 {
+    sum_many(2)
+}
@@ -0,0 +1,11 @@
+contract;
+
+abi ContractA {
+    fn value() -> u64;
+}
+
+impl ContractA for Contract {
+    fn value() -> u64 {
+        42
+    }
+}
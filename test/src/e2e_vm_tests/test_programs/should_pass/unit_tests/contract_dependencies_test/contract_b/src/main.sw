@@ -0,0 +1,22 @@
+contract;
+
+abi ContractA {
+    fn value() -> u64;
+}
+
+abi ContractB {
+    fn value_of_a() -> u64;
+}
+
+impl ContractB for Contract {
+    fn value_of_a() -> u64 {
+        let caller = abi(ContractA, contract_a::CONTRACT_ID);
+        caller.value {}()
+    }
+}
+
+#[test]
+fn test_call_contract_dependency() {
+    let caller = abi(ContractA, contract_a::CONTRACT_ID);
+    assert(caller.value {}() == 42);
+}
@@ -0,0 +1,15 @@
+script;
+
+abi ContractB {
+    fn value_of_a() -> u64;
+}
+
+fn main() -> u64 {
+    let caller = abi(ContractB, contract_b::CONTRACT_ID);
+    caller.value_of_a {}()
+}
+
+#[test]
+fn test_call_transitive_contract_dependency() {
+    assert(main() == 42);
+}
@@ -0,0 +1,51 @@
+contract;
+
+storage {
+    value: u64 = 0,
+}
+
+abi MyContract {
+    #[storage(read)]
+    fn get_value() -> u64;
+    #[storage(write)]
+    fn set_value(value: u64);
+}
+
+impl MyContract for Contract {
+    #[storage(read)]
+    fn get_value() -> u64 {
+        storage.value
+    }
+
+    #[storage(write)]
+    fn set_value(value: u64) {
+        storage.value = value;
+    }
+}
+
+const CONTRACT_ID = 0xf183b11cf39f790920dc3f49c5f9837d219225cbdc5b696138ca0c768dced237;
+
+#[test]
+fn test_default_storage() {
+    let caller = abi(MyContract, CONTRACT_ID);
+    assert(caller.get_value {}() == 0);
+}
+
+#[test(storage_slots = "test_storage_slots.json")]
+fn test_seeded_storage() {
+    let caller = abi(MyContract, CONTRACT_ID);
+    assert(caller.get_value {}() == 42);
+}
+
+#[test]
+fn test_write_storage() {
+    let caller = abi(MyContract, CONTRACT_ID);
+    caller.set_value {}(7);
+    assert(caller.get_value {}() == 7);
+}
+
+#[test]
+fn test_storage_is_isolated() {
+    let caller = abi(MyContract, CONTRACT_ID);
+    assert(caller.get_value {}() == 0);
+}
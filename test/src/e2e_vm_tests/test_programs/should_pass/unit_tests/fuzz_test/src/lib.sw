@@ -0,0 +1,75 @@
+library fuzz_test;
+
+dep shapes;
+
+struct Point {
+    x: u64,
+    y: u8,
+    visible: bool,
+}
+
+fn max(a: u64, b: u64) -> u64 {
+    if a > b { a } else { b }
+}
+
+/// The `x` coordinate of a point, if it is visible.
+fn visible_x(p: Point) -> Option<u64> {
+    if p.visible { Option::Some(p.x) } else { Option::None }
+}
+
+#[test]
+fn test_max_is_commutative(a: u64, b: u64) {
+    assert(max(a, b) == max(b, a));
+}
+
+#[test]
+fn test_small_ints_in_range(a: u8, b: u16, c: u32) {
+    assert(a <= 255);
+    assert(b <= 65535);
+    assert(c <= 4294967295);
+}
+
+#[test]
+fn test_struct_fields_in_range(p: Point, b: b256) {
+    assert(p.y <= 255);
+    assert(max(p.x, 0) == p.x);
+    assert(b == b);
+}
+
+#[test]
+fn test_tuple_and_array_elements(t: (u64, u8), arr: [u8; 3]) {
+    assert(t.1 <= 255);
+    assert(arr[0] <= 255 && arr[1] <= 255 && arr[2] <= 255);
+}
+
+#[test(should_revert)]
+fn test_always_reverts(a: u64) {
+    assert(a != a);
+}
+
+#[test]
+fn test_max_is_the_larger_arg(a: u64, b: u64) {
+    let m = max(a, b);
+    assert(m >= a && m >= b);
+    assert(m == a || m == b);
+}
+
+#[test]
+fn test_visible_x_round_trips(p: Point) {
+    match visible_x(p) {
+        Option::Some(x) => {
+            assert(p.visible);
+            assert(x == p.x);
+        },
+        Option::None => assert(!p.visible),
+    }
+}
+
+#[test(should_revert)]
+fn test_reverts_for_points_beyond_x(p: Point, x: u64) {
+    // Exactly one of the branches reverts, depending on the arguments.
+    if p.x > x {
+        revert(1);
+    }
+    assert(p.x > x);
+}
@@ -0,0 +1,24 @@
+library shapes;
+
+struct Rect {
+    width: u64,
+    height: u64,
+}
+
+fn longest_side(r: Rect) -> u64 {
+    if r.width > r.height { r.width } else { r.height }
+}
+
+// Shares its name with a test of the parent module, but takes other parameters.
+#[test]
+fn test_max_is_commutative(r: Rect, rotate: bool) {
+    let side = longest_side(r);
+    assert(side >= r.width && side >= r.height);
+    if rotate {
+        let rotated = Rect {
+            width: r.height,
+            height: r.width,
+        };
+        assert(longest_side(rotated) == side);
+    }
+}
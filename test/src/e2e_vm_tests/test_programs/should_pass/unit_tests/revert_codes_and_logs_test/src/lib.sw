@@ -0,0 +1,36 @@
+library revert_codes_and_logs_test;
+
+use std::logging::log;
+
+#[test(should_revert = "42")]
+fn test_revert_with_code() {
+    revert(42);
+}
+
+#[test(should_revert = 42)]
+fn test_revert_with_int_code() {
+    revert(42);
+}
+
+#[test(should_revert = "0xffff_ffff_ffff_0000")]
+fn test_require_reverts_with_signal() {
+    require(false, 7);
+}
+
+#[test(should_log = 1, should_log = 3)]
+fn test_logs_in_order() {
+    log(1);
+    log(2);
+    log(3);
+}
+
+#[test(should_log_data = "0x0000000000000000000000000000000000000000000000000000000000000001")]
+fn test_log_data() {
+    log(0x0000000000000000000000000000000000000000000000000000000000000001);
+}
+
+#[test(should_revert = "42", should_log = 7)]
+fn test_log_before_revert() {
+    log(7);
+    revert(42);
+}
@@ -0,0 +1,201 @@
+contract;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+struct Grid {
+    cells: [[u64; 3]; 2],
+    origin: Point,
+}
+
+storage {
+    table: [u64; 5] = [1, 2, 3, 4, 5],
+    points: [Point; 3] = [
+        Point { x: 1, y: 2 },
+        Point { x: 3, y: 4 },
+        Point { x: 5, y: 6 },
+    ],
+    grid: Grid = Grid {
+        cells: [[1, 2, 3], [4, 5, 6]],
+        origin: Point { x: 7, y: 8 },
+    },
+    hashes: [b256; 2] = [
+        0x0000000000000000000000000000000000000000000000000000000000000001,
+        0x0000000000000000000000000000000000000000000000000000000000000002,
+    ],
+}
+
+abi StorageArrays {
+    #[storage(read)]
+    fn get_table(i: u64) -> u64;
+    #[storage(read)]
+    fn get_last_in_table() -> u64;
+    #[storage(write)]
+    fn set_table(i: u64, value: u64);
+    #[storage(read)]
+    fn get_whole_table() -> [u64; 5];
+    #[storage(write)]
+    fn set_whole_table(table: [u64; 5]);
+    #[storage(read)]
+    fn get_point(i: u64) -> Point;
+    #[storage(read)]
+    fn get_point_y(i: u64) -> u64;
+    #[storage(write)]
+    fn set_point(i: u64, point: Point);
+    #[storage(write)]
+    fn set_point_y(i: u64, y: u64);
+    #[storage(read)]
+    fn get_cell(i: u64, j: u64) -> u64;
+    #[storage(write)]
+    fn set_cell(i: u64, j: u64, value: u64);
+    #[storage(read)]
+    fn get_grid() -> Grid;
+    #[storage(read)]
+    fn get_hash(i: u64) -> b256;
+    #[storage(write)]
+    fn set_hash(i: u64, hash: b256);
+}
+
+impl StorageArrays for Contract {
+    #[storage(read)]
+    fn get_table(i: u64) -> u64 {
+        storage.table[i]
+    }
+
+    #[storage(read)]
+    fn get_last_in_table() -> u64 {
+        storage.table[4]
+    }
+
+    #[storage(write)]
+    fn set_table(i: u64, value: u64) {
+        storage.table[i] = value;
+    }
+
+    #[storage(read)]
+    fn get_whole_table() -> [u64; 5] {
+        storage.table
+    }
+
+    #[storage(write)]
+    fn set_whole_table(table: [u64; 5]) {
+        storage.table = table;
+    }
+
+    #[storage(read)]
+    fn get_point(i: u64) -> Point {
+        storage.points[i]
+    }
+
+    #[storage(read)]
+    fn get_point_y(i: u64) -> u64 {
+        storage.points[i].y
+    }
+
+    #[storage(write)]
+    fn set_point(i: u64, point: Point) {
+        storage.points[i] = point;
+    }
+
+    #[storage(write)]
+    fn set_point_y(i: u64, y: u64) {
+        storage.points[i].y = y;
+    }
+
+    #[storage(read)]
+    fn get_cell(i: u64, j: u64) -> u64 {
+        storage.grid.cells[i][j]
+    }
+
+    #[storage(write)]
+    fn set_cell(i: u64, j: u64, value: u64) {
+        storage.grid.cells[i][j] = value;
+    }
+
+    #[storage(read)]
+    fn get_grid() -> Grid {
+        storage.grid
+    }
+
+    #[storage(read)]
+    fn get_hash(i: u64) -> b256 {
+        storage.hashes[i]
+    }
+
+    #[storage(write)]
+    fn set_hash(i: u64, hash: b256) {
+        storage.hashes[i] = hash;
+    }
+}
+
+const CONTRACT_ID = 0x483a4b1d1d90e60d5470ca1489b59c590e28bbf4d54d452af3dd9dd18c698fe4;
+
+#[test]
+fn test_initial_values() {
+    let caller = abi(StorageArrays, CONTRACT_ID);
+    assert(caller.get_table {}(0) == 1);
+    assert(caller.get_table {}(3) == 4);
+    assert(caller.get_last_in_table {}() == 5);
+    assert(caller.get_point_y {}(2) == 6);
+    assert(caller.get_cell {}(1, 2) == 6);
+    assert(caller.get_hash {}(1) == 0x0000000000000000000000000000000000000000000000000000000000000002);
+
+    let table = caller.get_whole_table {}();
+    assert(table[0] == 1 && table[1] == 2 && table[2] == 3 && table[3] == 4 && table[4] == 5);
+
+    let grid = caller.get_grid {}();
+    assert(grid.cells[0][1] == 2 && grid.cells[1][0] == 4 && grid.origin.y == 8);
+}
+
+#[test]
+fn test_write_elements() {
+    let caller = abi(StorageArrays, CONTRACT_ID);
+    caller.set_table {}(3, 42);
+    assert(caller.get_table {}(3) == 42);
+    assert(caller.get_table {}(2) == 3);
+    assert(caller.get_table {}(4) == 5);
+
+    caller.set_point {}(1, Point { x: 10, y: 20 });
+    let point = caller.get_point {}(1);
+    assert(point.x == 10 && point.y == 20);
+    assert(caller.get_point_y {}(0) == 2);
+
+    caller.set_point_y {}(2, 60);
+    let point = caller.get_point {}(2);
+    assert(point.x == 5 && point.y == 60);
+    assert(caller.get_point_y {}(1) == 20);
+
+    caller.set_cell {}(0, 2, 99);
+    assert(caller.get_cell {}(0, 2) == 99);
+    assert(caller.get_cell {}(1, 2) == 6);
+    let grid = caller.get_grid {}();
+    assert(grid.cells[0][2] == 99 && grid.origin.x == 7);
+
+    caller.set_hash {}(0, 0x0000000000000000000000000000000000000000000000000000000000000003);
+    assert(caller.get_hash {}(0) == 0x0000000000000000000000000000000000000000000000000000000000000003);
+    assert(caller.get_hash {}(1) == 0x0000000000000000000000000000000000000000000000000000000000000002);
+}
+
+#[test]
+fn test_write_whole_array() {
+    let caller = abi(StorageArrays, CONTRACT_ID);
+    caller.set_whole_table {}([6, 7, 8, 9, 10]);
+    assert(caller.get_table {}(0) == 6);
+    assert(caller.get_last_in_table {}() == 10);
+    let table = caller.get_whole_table {}();
+    assert(table[1] == 7 && table[2] == 8 && table[3] == 9);
+}
+
+#[test(should_revert = "0xffff_ffff_ffff_0003")]
+fn test_read_out_of_bounds() {
+    let caller = abi(StorageArrays, CONTRACT_ID);
+    let _ = caller.get_table {}(5);
+}
+
+#[test(should_revert = "0xffff_ffff_ffff_0003")]
+fn test_write_out_of_bounds() {
+    let caller = abi(StorageArrays, CONTRACT_ID);
+    caller.set_point_y {}(3, 1);
+}
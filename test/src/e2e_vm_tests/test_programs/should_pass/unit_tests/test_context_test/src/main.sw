@@ -0,0 +1,66 @@
+contract;
+
+use std::{
+    auth::msg_sender,
+    block::{height, timestamp, timestamp_of_block},
+    inputs::{input_amount, input_count, input_owner},
+    outputs::{Output, output_amount, output_count, output_type},
+};
+
+abi ContextTest {
+    fn sender() -> Address;
+}
+
+impl ContextTest for Contract {
+    fn sender() -> Address {
+        match msg_sender().unwrap() {
+            Identity::Address(address) => address,
+            Identity::ContractId(_) => revert(0),
+        }
+    }
+}
+
+const CONTRACT_ID = 0x9aa6b9f944d063ecccdf35c2365115feeae8ee62ba1cc108ca25aa3ee51153cc;
+const SENDER = 0x0000000000000000000000000000000000000000000000000000000000000042;
+
+#[test(block_height = 100)]
+fn test_block_height() {
+    assert(height() == 100);
+}
+
+#[test(block_height = 10, timestamp = 4611686018427388914)]
+fn test_timestamp() {
+    assert(timestamp() == 4611686018427388914);
+    assert(timestamp_of_block(9) == 4611686018427388904);
+}
+
+#[test(sender = "0x0000000000000000000000000000000000000000000000000000000000000042")]
+fn test_sender() {
+    let caller = abi(ContextTest, CONTRACT_ID);
+    assert(caller.sender() == Address::from(SENDER));
+    assert(input_owner(0).unwrap() == Address::from(SENDER));
+}
+
+#[test(input_coin = "100", input_coin = "5:0x0101010101010101010101010101010101010101010101010101010101010101")]
+fn test_input_coins() {
+    // The default coin, the requested coins and the contract under test.
+    assert(input_count() == 4u8);
+    assert(input_amount(1).unwrap() == 100);
+    assert(input_amount(2).unwrap() == 5);
+    assert(input_owner(1).unwrap() == input_owner(0).unwrap());
+}
+
+#[test(input_coin = "100", output_coin = "0x0000000000000000000000000000000000000000000000000000000000000042:60", output_variables = 2)]
+fn test_outputs() {
+    // The output for the contract under test precedes the requested outputs.
+    assert(output_count() == 4);
+    match output_type(1) {
+        Output::Coin => (),
+        _ => revert(0),
+    }
+    assert(output_amount(1) == 60);
+    match output_type(3) {
+        Output::Variable => (),
+        _ => revert(0),
+    }
+}
@@ -205,7 +205,7 @@ pub(super) async fn run(filter_regex: Option<&regex::Regex>) -> Result<()> {
 
                 if let Some(asm_checker) = opt_asm_checker {
                     // Compile to ASM.
-                    let asm_result = compile_ir_to_asm(&ir, None);
+                    let asm_result = compile_ir_to_asm(&ir, &[], None);
                     if !asm_result.is_ok() {
                         println!("Errors when compiling {test_file_name} IR to ASM:\n");
                         for e in asm_result.errors {
@@ -0,0 +1,37 @@
+script;
+
+#[inline(never)]
+fn sum(a: u64, b: u64, c: u64, d: u64, e: u64, f: u64, g: u64) -> u64 {
+    a + b + c + d + e + f + g
+}
+
+fn main() -> u64 {
+    sum(1, 2, 3, 4, 5, 6, 7)
+}
+
+// ::check-ir::
+
+// check: fn main() -> u64
+// check: call $ID(v0, v1, v2, v3, v4, v5, v6)
+
+// check: fn $ID(a $MD: u64, b $MD: u64, c $MD: u64, d $MD: u64, e $MD: u64, f $MD: u64, g $MD: u64) -> u64
+
+// ::check-asm::
+//
+// regex: REG=\$[[:alpha:]][0-9[:alpha:]]*
+//
+// The caller passes the last two args in a block on the stack, whose address is passed in the
+// last args register:
+//
+// check: move $(stack_args=$REG) $$sp
+// check: cfei i16
+// check: sw   $stack_args $REG i0
+// check: sw   $stack_args $REG i1
+// check: move $$$$arg5 $stack_args
+// check: cfsi i16
+//
+// The callee loads them from the block:
+//
+// check: move $(callee_args=$REG) $$$$arg5
+// check: lw   $REG $callee_args i0
+// check: lw   $REG $callee_args i1